@@ -0,0 +1,74 @@
+use iced::{
+    widget::{column, container, text},
+    Element, Length, Theme,
+};
+use iced_anim::{widget::theme_preview, Animation, Spring, SpringEvent, SpringMotion};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Preview(Option<Theme>),
+    Select(Theme),
+    Animate(SpringEvent<Theme>),
+}
+
+struct State {
+    /// The theme picked by clicking a swatch.
+    selected: Theme,
+    /// The animated app theme, which follows hovered swatches before one is picked.
+    theme: Spring<Theme>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            selected: Theme::Light,
+            theme: Spring::new(Theme::Light).with_motion(SpringMotion::Smooth),
+        }
+    }
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Preview(theme) => {
+                let theme = theme.unwrap_or_else(|| self.selected.clone());
+                self.theme.interrupt(theme);
+            }
+            Message::Select(theme) => {
+                self.selected = theme.clone();
+                self.theme.interrupt(theme);
+            }
+            Message::Animate(event) => self.theme.update(event),
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        Animation::new(
+            &self.theme,
+            container(
+                column![
+                    text(format!("Selected: {}", self.selected)).size(20),
+                    theme_preview(Theme::ALL, Some(&self.selected), Message::Select)
+                        .on_preview(Message::Preview)
+                        .columns(5),
+                ]
+                .spacing(16),
+            )
+            .padding(16)
+            .style(|theme: &Theme| container::Style {
+                background: Some(theme.palette().background.into()),
+                ..Default::default()
+            })
+            .width(Length::Fill)
+            .height(Length::Fill),
+        )
+        .on_update(Message::Animate)
+        .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application("Theme preview", State::update, State::view)
+        .theme(|state| state.theme.value().clone())
+        .run()
+}
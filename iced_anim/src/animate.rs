@@ -36,6 +36,57 @@ pub trait Animate: Clone + PartialEq {
     /// This distance can be positive or negative and returns a vector that should be consistent
     /// with `Animate::components` and the update order in `Animate::update`.
     fn distance_to(&self, end: &Self) -> Vec<f32>;
+
+    /// The group that each component belongs to, in the same order as `Animate::distance_to`.
+    ///
+    /// Groups of components settle independently within a [`Spring`](crate::Spring), so
+    /// retargeting one group doesn't restart the progress of the others. All components belong
+    /// to the same group by default. You can assign groups to fields when deriving this trait
+    /// with `#[animate(group = "name")]`.
+    fn component_groups() -> Vec<usize> {
+        vec![0; Self::components()]
+    }
+}
+
+/// Implements [`Animate`] for a struct by animating each of the listed fields in order, for
+/// when the `derive` feature isn't enabled.
+///
+/// Every field has to be listed with its type, which has to implement [`Animate`] itself. This
+/// is handy for the style structs of custom widgets, which are usually made of colors, borders,
+/// and other values that already animate:
+///
+/// ```rust
+/// # use iced::{Border, Color};
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Style {
+///     background: Color,
+///     border: Border,
+///     opacity: f32,
+/// }
+///
+/// iced_anim::impl_animate!(Style {
+///     background: Color,
+///     border: Border,
+///     opacity: f32,
+/// });
+/// ```
+#[macro_export]
+macro_rules! impl_animate {
+    ($name:ty { $($field:ident: $type:ty),+ $(,)? }) => {
+        impl $crate::Animate for $name {
+            fn components() -> usize {
+                0 $(+ <$type as $crate::Animate>::components())+
+            }
+
+            fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+                $($crate::Animate::update(&mut self.$field, components);)+
+            }
+
+            fn distance_to(&self, end: &Self) -> Vec<f32> {
+                [$($crate::Animate::distance_to(&self.$field, &end.$field)),+].concat()
+            }
+        }
+    };
 }
 
 impl Animate for f32 {
@@ -320,6 +371,178 @@ where
     }
 }
 
+/// An optional color that fades in and out through its alpha channel.
+///
+/// The [`Animate`] impl for [`Option`] can only animate between two `Some` values, so changing
+/// to or from `None` snaps at the end of the animation. A [`FadingColor`] instead treats `None` as
+/// a fully transparent color, fading out the previous color when it becomes `None` and fading in
+/// the new color when it becomes `Some`. Wrap an `Option<Color>` in a [`FadingColor`] to opt into
+/// fading, or keep the plain `Option<Color>` for the snapping behavior.
+///
+/// ```rust
+/// # use iced::Color;
+/// # use iced_anim::{animate::FadingColor, Spring};
+/// let mut spring = Spring::new(FadingColor::from(Color::BLACK));
+/// spring.interrupt(FadingColor::from(None));
+/// ```
+///
+/// When `None` stands for a fallback color, like the theme's text color, start from
+/// [`FadingColor::hidden`] and draw [`FadingColor::over`] the fallback so the two fade into each
+/// other instead of through transparency.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FadingColor {
+    /// The current color, or the last visible color while hidden.
+    color: iced::Color,
+    /// How much of the color is showing, from `0.0` while hidden to `1.0` once it's faded in.
+    opacity: f32,
+}
+
+impl FadingColor {
+    /// Creates a new [`FadingColor`] from an optional `color`.
+    pub fn new(color: Option<iced::Color>) -> Self {
+        match color {
+            Some(color) => Self {
+                color,
+                opacity: 1.0,
+            },
+            None => Self::hidden(iced::Color::TRANSPARENT),
+        }
+    }
+
+    /// Creates a hidden [`FadingColor`] that fades in from the given `color`, rather than from
+    /// transparent black.
+    pub fn hidden(color: iced::Color) -> Self {
+        Self {
+            color,
+            opacity: 0.0,
+        }
+    }
+
+    /// The current color, or `None` if it has faded out.
+    pub fn color(&self) -> Option<iced::Color> {
+        (self.opacity > 0.0).then_some(iced::Color {
+            a: self.color.a * self.opacity,
+            ..self.color
+        })
+    }
+
+    /// The current color drawn in place of a `base` color, mixing the two while fading in or
+    /// out. Hidden colors are the `base` color.
+    pub fn over(&self, base: iced::Color) -> iced::Color {
+        let mix = |base: f32, color: f32| base + (color - base) * self.opacity;
+        iced::Color {
+            r: mix(base.r, self.color.r),
+            g: mix(base.g, self.color.g),
+            b: mix(base.b, self.color.b),
+            a: mix(base.a, self.color.a),
+        }
+    }
+}
+
+impl PartialEq for FadingColor {
+    fn eq(&self, other: &Self) -> bool {
+        self.color() == other.color()
+    }
+}
+
+impl From<Option<iced::Color>> for FadingColor {
+    fn from(color: Option<iced::Color>) -> Self {
+        Self::new(color)
+    }
+}
+
+impl From<iced::Color> for FadingColor {
+    fn from(color: iced::Color) -> Self {
+        Self::new(Some(color))
+    }
+}
+
+impl From<FadingColor> for Option<iced::Color> {
+    fn from(color: FadingColor) -> Self {
+        color.color()
+    }
+}
+
+impl Animate for FadingColor {
+    fn components() -> usize {
+        iced::Color::components() + 1
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.color.update(components);
+        self.opacity = (self.opacity + components.next().unwrap()).clamp(0.0, 1.0);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        // Fading out keeps the current color and only changes how much of it is showing.
+        let mut distance = if end.opacity > 0.0 {
+            self.color.distance_to(&end.color)
+        } else {
+            vec![0.0; iced::Color::components()]
+        };
+        distance.push(self.opacity - end.opacity);
+        distance
+    }
+}
+
+/// An integer step, like the current page of a wizard, that animates through the fractions
+/// between steps.
+///
+/// Integers can't hold the in-between values of an animation, so a [`Step`] keeps a fractional
+/// position instead. It's created from the index of a step and reports both the position while
+/// animating and the index of the nearest step.
+///
+/// ```rust
+/// # use iced_anim::{animate::Step, Spring};
+/// let mut spring = Spring::new(Step::new(0));
+/// spring.interrupt(Step::new(2));
+/// assert_eq!(spring.target().index(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Step {
+    /// The position between steps, where whole numbers are the indices of steps.
+    position: f32,
+}
+
+impl Step {
+    /// Creates a new [`Step`] resting at the step with the given `index`.
+    pub fn new(index: usize) -> Self {
+        Self {
+            position: index as f32,
+        }
+    }
+
+    /// The fractional position of the step, like 1.5 halfway between the second and third step.
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+
+    /// The index of the step nearest to the current position.
+    pub fn index(&self) -> usize {
+        self.position.round().max(0.0) as usize
+    }
+}
+
+impl From<usize> for Step {
+    fn from(index: usize) -> Self {
+        Self::new(index)
+    }
+}
+
+impl Animate for Step {
+    fn components() -> usize {
+        1
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.position.update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.position.distance_to(&end.position)
+    }
+}
+
 impl Animate for iced::border::Radius {
     fn components() -> usize {
         4
@@ -465,6 +688,50 @@ impl Animate for iced::Radians {
     }
 }
 
+impl Animate for iced::Degrees {
+    fn components() -> usize {
+        f32::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.0.distance_to(&end.0)
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.0.update(components);
+    }
+}
+
+/// Transformations are animated by their translation and scale rather than by the cells of their
+/// matrix, so that an animation between two transformations stays a valid combination of a
+/// translation and a uniform scale the whole way through.
+///
+/// Any other parts of the transformation, like a projection, are lost once it's animated, so
+/// only animate transformations built with `Transformation::translate` and
+/// `Transformation::scale`.
+impl Animate for iced::Transformation {
+    fn components() -> usize {
+        iced::Vector::<f32>::components() + f32::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.translation().distance_to(&end.translation()),
+            self.scale_factor().distance_to(&end.scale_factor()),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        let mut translation = self.translation();
+        let mut scale = self.scale_factor();
+        translation.update(components);
+        scale.update(components);
+        *self = iced::Transformation::translate(translation.x, translation.y)
+            * iced::Transformation::scale(scale);
+    }
+}
+
 impl Animate for iced::gradient::ColorStop {
     fn components() -> usize {
         f32::components() + iced::Color::components()
@@ -624,6 +891,156 @@ impl Animate for iced::widget::svg::Style {
     }
 }
 
+impl Animate for iced::widget::text::Style {
+    fn components() -> usize {
+        Option::<iced::Color>::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.color.distance_to(&end.color)
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.color.update(components);
+    }
+}
+
+impl Animate for iced::widget::slider::HandleShape {
+    fn components() -> usize {
+        f32::components() + iced::border::Radius::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        use iced::widget::slider::HandleShape;
+        match (self, end) {
+            (HandleShape::Circle { radius }, HandleShape::Circle { radius: end }) => [
+                radius.distance_to(end),
+                vec![0.0; iced::border::Radius::components()],
+            ]
+            .concat(),
+            (
+                HandleShape::Rectangle { border_radius, .. },
+                HandleShape::Rectangle {
+                    border_radius: end, ..
+                },
+            ) => [vec![0.0], border_radius.distance_to(end)].concat(),
+            // Handles can't animate between different shapes.
+            _ => vec![0.0; Self::components()],
+        }
+    }
+
+    /// Updates the radius of circles and the corners of rectangles. Rectangle widths are whole
+    /// pixels, so they change immediately instead of animating.
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        use iced::widget::slider::HandleShape;
+        match self {
+            HandleShape::Circle { radius } => {
+                radius.update(components);
+                components.nth(iced::border::Radius::components() - 1);
+            }
+            HandleShape::Rectangle { border_radius, .. } => {
+                components.next();
+                border_radius.update(components);
+            }
+        }
+    }
+}
+
+impl Animate for iced::widget::slider::Rail {
+    fn components() -> usize {
+        <(iced::Background, iced::Background)>::components()
+            + f32::components()
+            + iced::Border::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.backgrounds.distance_to(&end.backgrounds),
+            self.width.distance_to(&end.width),
+            self.border.distance_to(&end.border),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.backgrounds.update(components);
+        self.width.update(components);
+        self.border.update(components);
+    }
+}
+
+impl Animate for iced::widget::slider::Handle {
+    fn components() -> usize {
+        iced::widget::slider::HandleShape::components()
+            + iced::Background::components()
+            + f32::components()
+            + iced::Color::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.shape.distance_to(&end.shape),
+            self.background.distance_to(&end.background),
+            self.border_width.distance_to(&end.border_width),
+            self.border_color.distance_to(&end.border_color),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.shape.update(components);
+        self.background.update(components);
+        self.border_width.update(components);
+        self.border_color.update(components);
+    }
+}
+
+impl Animate for iced::widget::slider::Style {
+    fn components() -> usize {
+        iced::widget::slider::Rail::components() + iced::widget::slider::Handle::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.rail.distance_to(&end.rail),
+            self.handle.distance_to(&end.handle),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.rail.update(components);
+        self.handle.update(components);
+    }
+}
+
+impl Animate for iced::widget::text_input::Style {
+    fn components() -> usize {
+        iced::Background::components() + iced::Border::components() + iced::Color::components() * 4
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.border.distance_to(&end.border),
+            self.icon.distance_to(&end.icon),
+            self.placeholder.distance_to(&end.placeholder),
+            self.value.distance_to(&end.value),
+            self.selection.distance_to(&end.selection),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.border.update(components);
+        self.icon.update(components);
+        self.placeholder.update(components);
+        self.value.update(components);
+        self.selection.update(components);
+    }
+}
+
 impl<T1, T2> Animate for (T1, T2)
 where
     T1: Animate,
@@ -702,6 +1119,37 @@ where
 mod tests {
     use super::*;
 
+    /// Structs implemented with the macro should animate each field in order.
+    #[test]
+    fn impl_animate_macro() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Style {
+            color: iced::Color,
+            opacity: f32,
+        }
+
+        crate::impl_animate!(Style {
+            color: iced::Color,
+            opacity: f32,
+        });
+
+        assert_eq!(Style::components(), 5);
+        let start = Style {
+            color: iced::Color::BLACK,
+            opacity: 0.0,
+        };
+        let end = Style {
+            color: iced::Color::WHITE,
+            opacity: 1.0,
+        };
+        assert_eq!(start.distance_to(&end), vec![-1.0, -1.0, -1.0, 0.0, -1.0]);
+
+        let mut halfway = start;
+        halfway.update(&mut [0.5, 0.5, 0.5, 0.0, 0.5].into_iter());
+        assert_eq!(halfway.opacity, 0.5);
+        assert_eq!(halfway.color, iced::Color::from_rgb(0.5, 0.5, 0.5));
+    }
+
     #[test]
     fn f32_components() {
         assert_eq!(f32::components(), 1);
@@ -712,6 +1160,20 @@ mod tests {
         assert_eq!(iced::Point::<f32>::components(), 2);
     }
 
+    #[test]
+    fn transformation_animates_translation_and_scale() {
+        use iced::Transformation;
+
+        let start = Transformation::translate(10.0, 20.0) * Transformation::scale(2.0);
+        let end = Transformation::translate(30.0, 0.0) * Transformation::scale(1.0);
+        assert_eq!(start.distance_to(&end), vec![-20.0, 20.0, 1.0]);
+
+        let mut halfway = start;
+        halfway.update(&mut [10.0, -10.0, -0.5].into_iter());
+        assert_eq!(halfway.translation(), iced::Vector::new(20.0, 10.0));
+        assert_eq!(halfway.scale_factor(), 1.5);
+    }
+
     #[test]
     fn f32_color_components() {
         assert_eq!(iced::Color::components(), 4);
@@ -811,6 +1273,67 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    /// Fading out should only change how much of the current color is showing.
+    #[test]
+    fn fading_color_fades_out() {
+        let color = FadingColor::from(iced::Color::from_rgb(1.0, 0.5, 0.0));
+        let distance = color.distance_to(&FadingColor::from(None));
+        assert_eq!(distance, vec![0.0, 0.0, 0.0, 0.0, 1.0]);
+    }
+
+    /// Fading in should start from the last visible color, or transparent black when new.
+    #[test]
+    fn fading_color_fades_in() {
+        let end = FadingColor::from(iced::Color::WHITE);
+        let color = FadingColor::from(None);
+        assert_eq!(color.distance_to(&end), vec![-1.0; 5]);
+
+        let mut color = FadingColor::hidden(iced::Color::WHITE);
+        assert_eq!(color.distance_to(&end), vec![0.0, 0.0, 0.0, 0.0, -1.0]);
+
+        color.update(&mut [0.0, 0.0, 0.0, 0.0, 0.5].into_iter());
+        assert_eq!(
+            color.color(),
+            Some(iced::Color::from_rgba(1.0, 1.0, 1.0, 0.5))
+        );
+    }
+
+    /// Hidden colors are equal regardless of the last visible color.
+    #[test]
+    fn fading_color_hidden_eq() {
+        assert_eq!(
+            FadingColor::hidden(iced::Color::BLACK),
+            FadingColor::from(None)
+        );
+    }
+
+    /// Colors should mix into the base color while fading, keeping their own alpha when shown.
+    #[test]
+    fn fading_color_over() {
+        let base = iced::Color::BLACK;
+        let color = iced::Color::from_rgba(1.0, 1.0, 1.0, 0.5);
+        assert_eq!(FadingColor::from(color).over(base), color);
+        assert_eq!(FadingColor::hidden(color).over(base), base);
+
+        let mut fading = FadingColor::hidden(color);
+        fading.update(&mut [0.0, 0.0, 0.0, 0.0, 0.5].into_iter());
+        assert_eq!(
+            fading.over(base),
+            iced::Color::from_rgba(0.5, 0.5, 0.5, 0.75)
+        );
+    }
+
+    /// Steps should animate through fractions and report the nearest step.
+    #[test]
+    fn step_animates_fractions() {
+        let mut step = Step::new(1);
+        assert_eq!(step.distance_to(&Step::new(3)), vec![-2.0]);
+
+        step.update(&mut [0.5].into_iter());
+        assert_eq!(step.position(), 1.5);
+        assert_eq!(step.index(), 2);
+    }
+
     #[test]
     fn update_background() {
         let mut background = iced::Background::Color(iced::Color::BLACK);
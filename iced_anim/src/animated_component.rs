@@ -0,0 +1,333 @@
+//! Self-contained animations whose bookkeeping messages never reach your app.
+//!
+//! An [`AnimatedComponent`] owns its spring in the widget tree, similar to an
+//! [`AnimationBuilder`](crate::AnimationBuilder), but the content it builds can also change the
+//! spring by producing [`Action::Animate`] messages. These are handled internally, while
+//! [`Action::Publish`] messages are passed along to the app. This makes it possible to drop
+//! interactive animations into apps whose `Message` type can't be extended, e.g. when embedding
+//! into another framework.
+//!
+//! ```rust
+//! # use iced::{widget::{button, text}, Element};
+//! # use iced_anim::animated_component::{animated_component, Action};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Toggled,
+//! }
+//!
+//! fn toggle<'a>() -> Element<'a, Message> {
+//!     animated_component(0.0, |spring| {
+//!         let is_on = *spring.target() == 1.0;
+//!         button(text(format!("{:.2}", spring.value())))
+//!             .on_press_with(move || Action::target(if is_on { 0.0 } else { 1.0 }))
+//!             .into()
+//!     })
+//!     .into()
+//! }
+//! ```
+use std::cell::RefCell;
+
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    window, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+use crate::{Animate, Spring, SpringEvent, SpringMotion};
+
+/// A message produced by the content of an [`AnimatedComponent`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action<T, Message> {
+    /// Updates the component's spring internally without notifying the app.
+    Animate(SpringEvent<T>),
+    /// Publishes a message to the app.
+    Publish(Message),
+}
+
+impl<T, Message> Action<T, Message> {
+    /// Creates an action that animates the component's spring toward the given `target`.
+    pub fn target(target: T) -> Self {
+        Self::Animate(SpringEvent::Target(target))
+    }
+}
+
+/// The content built by an [`AnimatedComponent`], which produces [`Action`]s.
+pub type Content<'a, T, Message, Theme, Renderer> =
+    Element<'a, Action<T, Message>, Theme, Renderer>;
+
+/// A widget that owns an animated value, which its content can change through [`Action`]s.
+pub struct AnimatedComponent<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    T: 'static + Animate,
+{
+    /// The spring used when the component is first created.
+    initial: Spring<T>,
+    /// Builds the content of the component from its spring.
+    view: Box<dyn Fn(&Spring<T>) -> Content<'a, T, Message, Theme, Renderer> + 'a>,
+    /// The content built from the latest state of the spring.
+    content: RefCell<Content<'a, T, Message, Theme, Renderer>>,
+    /// Whether the layout will be affected by the animated value.
+    animates_layout: bool,
+}
+
+impl<'a, T, Message, Theme, Renderer> AnimatedComponent<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+{
+    /// Creates a new [`AnimatedComponent`] starting at the `initial` value, whose content
+    /// is built with the `view` function.
+    pub fn new(
+        initial: T,
+        view: impl Fn(&Spring<T>) -> Content<'a, T, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        let initial = Spring::new(initial);
+        let content = view(&initial);
+        Self {
+            initial,
+            view: Box::new(view),
+            content: RefCell::new(content),
+            animates_layout: false,
+        }
+    }
+
+    /// Defines the way the spring will animate the value.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.initial.set_motion(motion);
+        self
+    }
+
+    /// Indicates whether this widget should invalidate the application layout
+    /// when animating between changes.
+    ///
+    /// This is set to `false` by default for performance reasons, but you may
+    /// want to set this to `true` if you're animating a value that affects the
+    /// layout of the widget (e.g. its size, text, position, etc).
+    pub fn animates_layout(mut self, animates_layout: bool) -> Self {
+        self.animates_layout = animates_layout;
+        self
+    }
+
+    /// Rebuilds the content from the given `spring` and diffs it with the `tree`.
+    fn rebuild(&mut self, spring: &Spring<T>, tree: &mut Tree) {
+        let content = self.content.get_mut();
+        *content = (self.view)(spring);
+        tree.diff(&*content);
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AnimatedComponent<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Spring<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(self.initial.clone())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&*self.content.borrow())]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let spring = tree.state.downcast_mut::<Spring<T>>();
+        if spring.motion() != self.initial.motion() {
+            spring.set_motion(self.initial.motion());
+        }
+
+        // The content was built from the initial value, so rebuild it from the current spring.
+        let content = (self.view)(spring);
+        tree.diff_children(std::slice::from_ref(&content));
+        *self.content.borrow_mut() = content;
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.borrow().as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.borrow().as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .borrow()
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.borrow().as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut actions = Vec::new();
+        let mut content_shell = Shell::new(&mut actions);
+        let status = self.content.get_mut().as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut content_shell,
+            viewport,
+        );
+
+        if let Some(redraw_request) = content_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+        if content_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if content_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        let spring = tree.state.downcast_mut::<Spring<T>>();
+        let mut needs_rebuild = false;
+        for action in actions {
+            match action {
+                Action::Animate(event) => {
+                    spring.update(event);
+                    needs_rebuild = true;
+                }
+                Action::Publish(message) => shell.publish(message),
+            }
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if spring.has_energy() {
+                spring.tick(now);
+                needs_rebuild = true;
+            }
+        }
+
+        if spring.has_energy() {
+            #[cfg(feature = "tracing")]
+            tracing::trace!("requesting animation redraw");
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        if needs_rebuild {
+            let spring = spring.clone();
+            self.rebuild(&spring, &mut tree.children[0]);
+            if self.animates_layout {
+                shell.invalidate_layout();
+            }
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.borrow().as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.borrow().as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        // Overlays can't be mapped without access to the shell, so actions from overlays
+        // of the content are not supported yet.
+        let _ = (tree, layout, renderer, translation);
+        None
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<AnimatedComponent<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(component: AnimatedComponent<'a, T, Message, Theme, Renderer>) -> Self {
+        Self::new(component)
+    }
+}
+
+/// Creates a new [`AnimatedComponent`] starting at the `initial` value, whose content
+/// is built with the `view` function.
+pub fn animated_component<'a, T, Message, Theme, Renderer>(
+    initial: T,
+    view: impl Fn(&Spring<T>) -> Content<'a, T, Message, Theme, Renderer> + 'a,
+) -> AnimatedComponent<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+{
+    AnimatedComponent::new(initial, view)
+}
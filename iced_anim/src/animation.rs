@@ -43,6 +43,16 @@
 //!     }
 //! }
 //! ```
+//!
+//! Values that change many times per second can be rate limited by giving the spring in your
+//! state a [`RateLimit`](crate::RateLimit) with [`Spring::with_rate_limit`]. The widget keeps
+//! updating the spring while a held back target is waiting to be applied. The same goes for a
+//! delay before animating toward new targets, set with [`Spring::with_delay`].
+//!
+//! Scripted animations stored as [`Keyframes`] in your state can be driven the same way with
+//! [`Animation::keyframes`], passing the events to [`Keyframes::update`], and so can a
+//! [`Timeline`] of several tracks with [`Animation::timeline`]. The same goes for the momentum
+//! of a [`Decay`] with [`Animation::decay`].
 use std::time::Instant;
 
 use iced::{
@@ -50,15 +60,15 @@ use iced::{
     Element,
 };
 
-use crate::{Animate, Spring, SpringEvent};
+use crate::{Animate, AnimationGroup, Decay, Keyframes, Spring, SpringEvent, Timeline};
 
 /// A widget that helps you animate a value over time from your state.
 /// This is useful for animating changes to a widget's appearance or layout
 /// where you want to directly change the value stored in your state versus
 /// passively animating a value like the `AnimationBuilder`.
 pub struct Animation<'a, T: Animate, Message, Theme, Renderer> {
-    /// The spring that controls the animated value.
-    spring: &'a Spring<T>,
+    /// Whether the spring or keyframes controlling the animated value are still animating.
+    is_animating: bool,
     /// The content that will respond to the animation.
     content: Element<'a, Message, Theme, Renderer>,
     /// The function that will be called when the spring needs to be updated.
@@ -79,7 +89,71 @@ where
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
     ) -> Self {
         Self {
-            spring,
+            is_animating: spring.has_energy(),
+            content: content.into(),
+            on_update: None,
+            is_disabled: false,
+        }
+    }
+
+    /// Creates a new `Animation` that plays the given `keyframes` once they start playing.
+    ///
+    /// The events produced by [`Animation::on_update`] should be passed to
+    /// [`Keyframes::update`].
+    pub fn keyframes(
+        keyframes: &'a Keyframes<T>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            is_animating: keyframes.has_energy(),
+            content: content.into(),
+            on_update: None,
+            is_disabled: false,
+        }
+    }
+
+    /// Creates a new `Animation` that moves the `decay` until it comes to rest.
+    ///
+    /// The events produced by [`Animation::on_update`] should be passed to [`Decay::update`].
+    pub fn decay(
+        decay: &'a Decay<T>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            is_animating: decay.has_energy(),
+            content: content.into(),
+            on_update: None,
+            is_disabled: false,
+        }
+    }
+
+    /// Creates a new `Animation` that updates every spring in the `group` while any of them
+    /// are animating.
+    ///
+    /// The events produced by [`Animation::on_update`] should be passed to
+    /// [`AnimationGroup::update`].
+    pub fn group<K: PartialEq>(
+        group: &'a AnimationGroup<K, T>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            is_animating: group.has_energy(),
+            content: content.into(),
+            on_update: None,
+            is_disabled: false,
+        }
+    }
+
+    /// Creates a new `Animation` that advances the `timeline` while it's playing.
+    ///
+    /// The events produced by [`Animation::on_update`] should be passed to
+    /// [`Timeline::update`].
+    pub fn timeline<K: PartialEq>(
+        timeline: &'a Timeline<K, T>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            is_animating: timeline.has_energy(),
             content: content.into(),
             on_update: None,
             is_disabled: false,
@@ -225,11 +299,16 @@ where
             viewport,
         );
 
-        if !self.spring.has_energy() {
+        if !self.is_animating {
             return status;
         }
 
         if let Some(on_update) = &self.on_update {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(
+                is_disabled = self.is_disabled,
+                "publishing animation update"
+            );
             let event: SpringEvent<T> = if self.is_disabled {
                 SpringEvent::Settle
             } else {
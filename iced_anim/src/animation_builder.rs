@@ -59,6 +59,37 @@
 //! # }
 //! ```
 //!
+//! Values that change many times per second can be rate limited with
+//! [`AnimationBuilder::debounce`] or [`AnimationBuilder::throttle`], so the animation chases a
+//! calmer version of the value instead of changing direction on every update.
+//!
+//! Value changes can also wait before animating with [`AnimationBuilder::delay`], which staggers
+//! several animations that start from the same change.
+//!
+//! Animations that aren't essential, like the many cells of a dashboard, can be marked with
+//! [`Priority::Low`] through [`AnimationBuilder::priority`] so they skip straight to the new value
+//! while the app is over its animation budget, see the [`budget`](crate::budget) module.
+//!
+//! Scripted animations can be played with [`AnimationBuilder::keyframes`] instead. The
+//! [`Keyframes`] play once when the widget appears and again whenever they change:
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use iced::{Element, widget::{container, text}};
+//! # use iced_anim::{AnimationBuilder, Easing, Keyframes};
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! fn logo<'a>() -> Element<'a, Message> {
+//!     let intro = Keyframes::new(0.0)
+//!         .then(72.0, Duration::from_millis(400), Easing::EaseOut)
+//!         .then(64.0, Duration::from_millis(200), Easing::EaseInOut);
+//!
+//!     AnimationBuilder::keyframes(intro, |size| text("Logo").size(size).into())
+//!         .animates_layout(true)
+//!         .into()
+//! }
+//! ```
+//!
 //! # `AnimationBuilder` Limitations
 //!
 //! It might not be easy or possible to pass in non-clonable content like custom
@@ -82,7 +113,12 @@ use iced::{
     Element,
 };
 
-use crate::{animate::Animate, Spring, SpringMotion};
+use std::time::Duration;
+
+use crate::{
+    animate::Animate, budget::Priority, Keyframes, RateLimit, Repeat, RestThreshold, Spring,
+    SpringMotion,
+};
 
 /// A widget that implicitly animates a value anytime it changes.
 ///
@@ -98,6 +134,8 @@ where
     builder: Box<dyn Fn(T) -> Element<'a, Message, Theme, Renderer> + 'a>,
     /// The spring that animates the value of this widget.
     spring: Spring<T>,
+    /// The keyframes to play instead of springing between values, if any.
+    keyframes: Option<Keyframes<T>>,
     /// Whether the layout will be affected by the animated value.
     animates_layout: bool,
     /// Whether animations are disabled, in which case the value will be updated
@@ -121,17 +159,97 @@ where
             builder: Box::new(builder),
             cached_element: element,
             spring: Spring::new(value),
+            keyframes: None,
             animates_layout: false,
             is_disabled: false,
         }
     }
 
+    /// Creates a new `AnimationBuilder` that plays the given `keyframes` when it appears and
+    /// whenever they change, building its element with the animated value.
+    pub fn keyframes(
+        keyframes: Keyframes<T>,
+        builder: impl Fn(T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        let mut animation = Self::new(keyframes.rest().clone(), builder);
+        animation.keyframes = Some(keyframes);
+        animation
+    }
+
     /// Defines the way the spring will animate the value.
     pub fn motion(mut self, motion: SpringMotion) -> Self {
         self.spring = self.spring.with_motion(motion);
         self
     }
 
+    /// Animates the components in the given `group` with their own `motion`, see
+    /// [`Spring::with_group_motion`].
+    pub fn group_motion(mut self, group: usize, motion: SpringMotion) -> Self {
+        self.spring = self.spring.with_group_motion(group, motion);
+        self
+    }
+
+    /// Sets when the animation is close enough to the value to stop, see [`RestThreshold`].
+    pub fn rest_threshold(mut self, rest_threshold: RestThreshold) -> Self {
+        self.spring = self.spring.with_rest_threshold(rest_threshold);
+        self
+    }
+
+    /// Jumps to the value once the animation has been running for the `max_duration`, see
+    /// [`Spring::with_max_duration`].
+    pub fn max_duration(mut self, max_duration: Duration) -> Self {
+        self.spring = self.spring.with_max_duration(max_duration);
+        self
+    }
+
+    /// Waits until the value stops changing for the `duration` before animating toward it.
+    ///
+    /// This is useful for values that change in bursts, like the number of search results
+    /// while the user is typing.
+    pub fn debounce(mut self, duration: Duration) -> Self {
+        self.spring = self.spring.with_rate_limit(RateLimit::Debounce(duration));
+        self
+    }
+
+    /// Animates toward a new value at most once per `duration`, holding back the latest value
+    /// until the duration passes.
+    ///
+    /// This is useful for values that change steadily, like live telemetry.
+    pub fn throttle(mut self, duration: Duration) -> Self {
+        self.spring = self.spring.with_rate_limit(RateLimit::Throttle(duration));
+        self
+    }
+
+    /// Waits for the `delay` after the value changes before animating toward it.
+    ///
+    /// Giving each of several animations a slightly longer delay staggers them.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.spring = self.spring.with_delay(delay);
+        self
+    }
+
+    /// Plays the animation toward each new value more than once, starting over from where it
+    /// started each time it reaches the value.
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.spring = self.spring.with_repeat(repeat);
+        self
+    }
+
+    /// Animates back to the previous value each time the animation reaches a new value, like a
+    /// breathing effect. Combine with [`AnimationBuilder::repeat`] to make the round trip more
+    /// than once.
+    pub fn yoyo(mut self, yoyo: bool) -> Self {
+        self.spring = self.spring.with_yoyo(yoyo);
+        self
+    }
+
+    /// Sets the [`Priority`] of the animation when more springs are animating than the
+    /// animation budget allows.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.spring = self.spring.with_priority(priority);
+        self
+    }
+
     /// Indicates whether this widget should invalidate the application layout
     /// when animating between changes.
     ///
@@ -151,6 +269,30 @@ where
     }
 }
 
+/// The internal state of an [`AnimationBuilder`].
+struct State<T: Animate> {
+    /// The spring animating between value changes.
+    spring: Spring<T>,
+    /// The keyframes being played, if any.
+    keyframes: Option<Keyframes<T>>,
+}
+
+impl<'a, T, Message, Theme, Renderer> AnimationBuilder<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+{
+    /// Starts playing a copy of the widget's keyframes, if it has any.
+    fn play_keyframes(&self) -> Option<Keyframes<T>> {
+        let mut keyframes = self.keyframes.clone()?;
+        if self.is_disabled {
+            keyframes.settle();
+        } else {
+            keyframes.play();
+        }
+        Some(keyframes)
+    }
+}
+
 impl<'a, T, Message, Theme, Renderer> From<AnimationBuilder<'a, T, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -175,17 +317,59 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(self.spring.clone())
+        tree::State::new(State {
+            spring: self.spring.clone(),
+            keyframes: self.play_keyframes(),
+        })
     }
 
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<Spring<T>>()
+        tree::Tag::of::<State<T>>()
     }
 
     fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        // Replay the keyframes from the start if they've changed
+        if let Some(keyframes) = &self.keyframes {
+            let is_same = state
+                .keyframes
+                .as_ref()
+                .is_some_and(|playing| playing.has_same_frames(keyframes));
+            if !is_same {
+                state.keyframes = self.play_keyframes();
+            }
+            tree.diff_children(std::slice::from_ref(&self.cached_element));
+            return;
+        }
+        state.keyframes = None;
+
         // Update the spring's target if it has changed
-        let spring = tree.state.downcast_mut::<Spring<T>>();
-        if spring.target() != self.spring.value() {
+        let spring = &mut state.spring;
+        if spring.rate_limit() != self.spring.rate_limit() {
+            spring.set_rate_limit(self.spring.rate_limit());
+        }
+        if spring.rest_threshold() != self.spring.rest_threshold() {
+            spring.set_rest_threshold(self.spring.rest_threshold());
+        }
+        if spring.priority() != self.spring.priority() {
+            spring.set_priority(self.spring.priority());
+        }
+        if spring.delay() != self.spring.delay() {
+            spring.set_delay(self.spring.delay());
+        }
+        if spring.max_duration() != self.spring.max_duration() {
+            spring.set_max_duration(self.spring.max_duration());
+        }
+        if spring.repeat() != self.spring.repeat() {
+            spring.set_repeat(self.spring.repeat());
+        }
+        if spring.yoyo() != self.spring.yoyo() {
+            spring.set_yoyo(self.spring.yoyo());
+        }
+
+        // Compare against the latest target so held back targets don't restart their limit.
+        if spring.latest_target() != self.spring.value() {
             if self.is_disabled {
                 spring.settle();
             } else {
@@ -196,6 +380,14 @@ where
         if spring.motion() != self.spring.motion() {
             spring.set_motion(self.spring.motion())
         }
+        if spring.group_motions() != self.spring.group_motions() {
+            for (group, _) in spring.group_motions().to_vec() {
+                spring.set_group_motion(group, None);
+            }
+            for (group, motion) in self.spring.group_motions() {
+                spring.set_group_motion(*group, Some(*motion));
+            }
+        }
 
         tree.diff_children(std::slice::from_ref(&self.cached_element));
     }
@@ -309,10 +501,16 @@ where
             return status;
         };
 
-        let spring = tree.state.downcast_mut::<Spring<T>>();
+        let state = tree.state.downcast_mut::<State<T>>();
+        let has_energy = match &state.keyframes {
+            Some(keyframes) => keyframes.has_energy(),
+            None => state.spring.has_energy(),
+        };
 
-        // Request a redraw if the spring has remaining energy
-        if spring.has_energy() {
+        // Request a redraw if the spring or keyframes have remaining energy
+        if has_energy {
+            #[cfg(feature = "tracing")]
+            tracing::trace!("requesting animation redraw");
             shell.request_redraw(iced::window::RedrawRequest::NextFrame);
             // Only invalidate the layout if the user indicates to do so
             if self.animates_layout {
@@ -320,8 +518,17 @@ where
             }
 
             // Update the animation and request a redraw
-            spring.tick(now);
-            self.cached_element = (self.builder)(spring.value().clone());
+            let value = match &mut state.keyframes {
+                Some(keyframes) => {
+                    keyframes.tick(now);
+                    keyframes.value()
+                }
+                None => {
+                    state.spring.tick(now);
+                    state.spring.value()
+                }
+            };
+            self.cached_element = (self.builder)(value.clone());
         }
 
         status
@@ -0,0 +1,309 @@
+//! Springs animated together as a group, with a task to continue once all of them settle.
+//!
+//! Transitions that involve several elements often need to wait on all of them before moving
+//! on, like deleting the selected items of a list once every one of them finishes animating out.
+//! An [`AnimationGroup`] holds a spring for each element under a key and returns a [`Task`] from
+//! [`AnimationGroup::when_all_settled`] that produces a message once every spring has settled.
+//!
+//! ```rust
+//! # use iced::Task;
+//! # use iced_anim::{AnimationGroup, SpringEvent};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     DeleteSelected,
+//!     Deleted,
+//!     Opacity(SpringEvent<f32>),
+//! }
+//!
+//! struct State {
+//!     items: Vec<u32>,
+//!     selected: Vec<u32>,
+//!     opacity: AnimationGroup<u32, f32>,
+//! }
+//!
+//! impl State {
+//!     fn update(&mut self, message: Message) -> Task<Message> {
+//!         match message {
+//!             Message::DeleteSelected => {
+//!                 for item in &self.selected {
+//!                     self.opacity.interrupt(item, 0.0);
+//!                 }
+//!                 self.opacity.when_all_settled(Message::Deleted)
+//!             }
+//!             Message::Deleted => {
+//!                 for item in self.selected.drain(..) {
+//!                     self.items.retain(|other| *other != item);
+//!                     self.opacity.remove(&item);
+//!                 }
+//!                 Task::none()
+//!             }
+//!             Message::Opacity(event) => {
+//!                 self.opacity.update(event);
+//!                 Task::none()
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The springs still need to be ticked for the group to settle, e.g. with
+//! [`Animation::group`](crate::Animation::group).
+use std::time::{Duration, Instant};
+
+use iced::Task;
+
+use crate::{Animate, Spring, SpringEvent, SpringMotion};
+
+/// A group of springs stored under keys and animated together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationGroup<K, T> {
+    /// The springs in the group with their keys, in the order they were inserted.
+    springs: Vec<(K, Spring<T>)>,
+    /// The motion given to springs as they're inserted.
+    motion: SpringMotion,
+}
+
+impl<K, T> AnimationGroup<K, T>
+where
+    K: PartialEq,
+    T: Animate,
+{
+    /// Creates an empty [`AnimationGroup`] whose springs follow the given `motion`.
+    pub fn new(motion: SpringMotion) -> Self {
+        Self {
+            springs: Vec::new(),
+            motion,
+        }
+    }
+
+    /// The motion of the springs in the group.
+    pub fn motion(&self) -> SpringMotion {
+        self.motion
+    }
+
+    /// Sets the motion of every spring in the group.
+    pub fn set_motion(&mut self, motion: SpringMotion) {
+        self.motion = motion;
+        for (_, spring) in &mut self.springs {
+            spring.set_motion(motion);
+        }
+    }
+
+    /// The number of springs in the group.
+    pub fn len(&self) -> usize {
+        self.springs.len()
+    }
+
+    /// Whether the group has no springs.
+    pub fn is_empty(&self) -> bool {
+        self.springs.is_empty()
+    }
+
+    /// Whether the group has a spring with the given `key`.
+    pub fn contains(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Adds a spring resting at the `value`, or settles an existing spring there immediately.
+    pub fn insert(&mut self, key: K, value: T) {
+        match self.get_mut(&key) {
+            Some(spring) => spring.settle_at(value),
+            None => {
+                let spring = Spring::new(value).with_motion(self.motion);
+                self.springs.push((key, spring));
+            }
+        }
+    }
+
+    /// Removes the spring with the given `key`, returning it.
+    pub fn remove(&mut self, key: &K) -> Option<Spring<T>> {
+        let index = self.springs.iter().position(|(other, _)| other == key)?;
+        Some(self.springs.remove(index).1)
+    }
+
+    /// Returns the spring with the given `key`.
+    pub fn get(&self, key: &K) -> Option<&Spring<T>> {
+        self.springs
+            .iter()
+            .find(|(other, _)| other == key)
+            .map(|(_, spring)| spring)
+    }
+
+    /// Returns the spring with the given `key` mutably.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut Spring<T>> {
+        self.springs
+            .iter_mut()
+            .find(|(other, _)| other == key)
+            .map(|(_, spring)| spring)
+    }
+
+    /// Returns the current value of the spring with the given `key`.
+    pub fn value(&self, key: &K) -> Option<&T> {
+        self.get(key).map(Spring::value)
+    }
+
+    /// Iterates over the keys and springs in the group, in the order they were inserted.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &Spring<T>)> {
+        self.springs.iter().map(|(key, spring)| (key, spring))
+    }
+
+    /// Animates the spring with the given `key` toward the `target`, returning whether it exists.
+    pub fn interrupt(&mut self, key: &K, target: T) -> bool {
+        match self.get_mut(key) {
+            Some(spring) => {
+                spring.interrupt(target);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Delays each spring in the group by `delay_per_item` more than the one inserted before it,
+    /// so targets given to the whole group start in a cascading wave.
+    ///
+    /// The delay applies to the next targets of each spring, see [`Spring::with_delay`].
+    pub fn stagger(&mut self, delay_per_item: Duration) {
+        for (delay, (_, spring)) in crate::stagger::stagger(&mut self.springs, delay_per_item) {
+            spring.set_delay(delay);
+        }
+    }
+
+    /// Whether any spring in the group is animating, meaning the group needs to be ticked.
+    pub fn has_energy(&self) -> bool {
+        self.springs.iter().any(|(_, spring)| spring.has_energy())
+    }
+
+    /// Updates every spring in the group based on the elapsed time since its last update.
+    pub fn tick(&mut self, now: Instant) {
+        for (_, spring) in &mut self.springs {
+            spring.tick(now);
+        }
+    }
+
+    /// Settles every spring in the group at its target immediately.
+    pub fn settle(&mut self) {
+        for (_, spring) in &mut self.springs {
+            spring.settle();
+        }
+    }
+
+    /// Updates every spring in the group with the `event`, as with [`Spring::update`].
+    ///
+    /// A target event animates every spring toward the same target.
+    pub fn update(&mut self, event: SpringEvent<T>) {
+        match event {
+            SpringEvent::Tick(now) => self.tick(now),
+            SpringEvent::Target(target) => {
+                for (_, spring) in &mut self.springs {
+                    spring.interrupt(target.clone());
+                }
+            }
+            SpringEvent::Settle => self.settle(),
+        }
+    }
+
+    /// Returns a [`Task`] producing the `on_settled` message once every spring in the group has
+    /// settled, or right away if they're all at rest.
+    ///
+    /// Springs retargeted before they settle delay the message until they settle at their new
+    /// targets, while springs added to the group afterward aren't waited on.
+    pub fn when_all_settled<Message>(&mut self, on_settled: Message) -> Task<Message>
+    where
+        Message: Clone + Send + 'static,
+    {
+        if !self.has_energy() {
+            return Task::done(on_settled);
+        }
+
+        let settled = self
+            .springs
+            .iter_mut()
+            .filter(|(_, spring)| spring.has_energy())
+            .map(|(_, spring)| spring.settled());
+
+        Task::batch(settled)
+            .collect()
+            .map(move |_| on_settled.clone())
+    }
+}
+
+impl<K, T> Default for AnimationGroup<K, T>
+where
+    K: PartialEq,
+    T: Animate,
+{
+    fn default() -> Self {
+        Self::new(SpringMotion::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Inserting an existing key should settle its spring at the new value.
+    #[test]
+    fn insert_settles_existing_keys() {
+        let mut group = AnimationGroup::new(SpringMotion::Snappy);
+        group.insert("a", 0.0);
+        group.insert("b", 1.0);
+        assert!(group.interrupt(&"a", 5.0));
+        group.insert("a", 2.0);
+
+        assert_eq!(group.len(), 2);
+        assert_eq!(group.value(&"a"), Some(&2.0));
+        assert_eq!(group.get(&"b").unwrap().motion(), SpringMotion::Snappy);
+        assert!(!group.has_energy());
+        assert!(!group.interrupt(&"c", 1.0));
+    }
+
+    /// The group should have energy while any of its springs do.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn animates_springs_together() {
+        let mut group = AnimationGroup::default();
+        group.insert(1, 0.0);
+        group.insert(2, 0.0);
+        group.update(SpringEvent::Target(1.0));
+        assert!(group.has_energy());
+
+        group.tick(Instant::now() + Duration::from_millis(16));
+        assert!(group.iter().all(|(_, spring)| *spring.value() > 0.0));
+
+        group.update(SpringEvent::Settle);
+        assert!(!group.has_energy());
+        assert_eq!(group.value(&2), Some(&1.0));
+    }
+
+    /// Removing a spring should keep the order of the others.
+    #[test]
+    fn remove_keeps_order() {
+        let mut group = AnimationGroup::default();
+        group.insert(1, 0.0);
+        group.insert(2, 0.0);
+        group.insert(3, 0.0);
+
+        assert!(group.remove(&2).is_some());
+        assert!(group.remove(&2).is_none());
+        let keys: Vec<_> = group.iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, [1, 3]);
+    }
+
+    /// Staggering should delay each spring more than the one inserted before it.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn stagger_delays_in_insertion_order() {
+        let mut group = AnimationGroup::default();
+        group.insert(1, 0.0);
+        group.insert(2, 0.0);
+        group.stagger(Duration::from_millis(30));
+        group.update(SpringEvent::Target(1.0));
+
+        assert_eq!(group.get(&1).map(Spring::target), Some(&1.0));
+        assert_eq!(
+            group.get(&2).map(Spring::delay),
+            Some(Duration::from_millis(30))
+        );
+        assert_eq!(group.get(&2).map(Spring::target), Some(&0.0));
+    }
+}
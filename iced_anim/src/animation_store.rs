@@ -0,0 +1,325 @@
+//! Keep the animations of widgets going across drastic changes to the view.
+//!
+//! Widget state lives at the widget's position in the tree, so an animated widget that moves
+//! somewhere else starts its style animation over. Giving the widget an [`Id`] saves its
+//! animation in the app-wide [`AnimationStore::global`] store, which picks it back up wherever
+//! the widget shows up next. Widgets only save their animation while it changes, so the global
+//! store regularly [sweeps](AnimationStore::sweep) away animations that have been at rest for a
+//! while, and widgets that show up again after that start from their current style.
+//!
+//! Views that restructure heavily, like switching between entirely different layouts, can keep
+//! an [`AnimationStore`] of their own in the app state instead and have widgets opt in with
+//! `.animation_id(...)`. Since the app owns the store, it decides how long saved animations
+//! live, e.g. by clearing the store when leaving a screen or by giving it a
+//! [sweep interval](AnimationStore::with_sweep_interval).
+//!
+//! ```rust
+//! # use iced::{widget::container, Element};
+//! # use iced_anim::{widget::{button, Id}, AnimationStore};
+//! # #[derive(Debug, Clone)] enum Message { Save }
+//! struct App {
+//!     animations: AnimationStore,
+//!     is_compact: bool,
+//! }
+//!
+//! impl App {
+//!     fn view(&self) -> Element<'_, Message> {
+//!         let save = button("Save")
+//!             .on_press(Message::Save)
+//!             .animation_id(self.animations.id(Id::new("save")));
+//!
+//!         if self.is_compact {
+//!             save.into()
+//!         } else {
+//!             container(save).padding(16).into()
+//!         }
+//!     }
+//! }
+//! ```
+use std::{
+    any::Any,
+    fmt::Debug,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+pub use iced::advanced::widget::Id;
+
+/// How often the [global](AnimationStore::global) store sweeps away animations at rest.
+const GLOBAL_SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Saved animation states, keyed by [`Id`].
+///
+/// Clones of a store share the same saved states, so it can be kept in app state and handed to
+/// widgets as an [`AnimationId`] without copying anything.
+#[derive(Clone, Default)]
+pub struct AnimationStore {
+    saved: Arc<Mutex<Saved>>,
+}
+
+/// The states saved in an [`AnimationStore`].
+#[derive(Default)]
+struct Saved {
+    entries: Vec<Entry>,
+    /// The current generation, which advances with every sweep.
+    generation: u64,
+    /// How often the store sweeps itself as animations are saved, if at all.
+    sweep_interval: Option<Duration>,
+    /// The last time the store was swept.
+    last_sweep: Option<Instant>,
+}
+
+/// A state saved in an [`AnimationStore`].
+struct Entry {
+    id: Id,
+    state: Box<dyn Any + Send>,
+    /// The generation in which the state was last saved or restored.
+    generation: u64,
+}
+
+impl Saved {
+    /// Forgets the states that weren't saved or restored in the current generation, then starts
+    /// the next one.
+    fn sweep(&mut self, now: Instant) {
+        let generation = self.generation;
+        self.entries.retain(|entry| entry.generation == generation);
+        self.generation += 1;
+        self.last_sweep = Some(now);
+    }
+}
+
+/// An [`Id`] within an [`AnimationStore`], which widgets save their animations under.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationId {
+    store: AnimationStore,
+    id: Id,
+}
+
+impl AnimationStore {
+    /// Creates an empty [`AnimationStore`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sweeps the store every `interval` while animations are being saved, forgetting the ones
+    /// that haven't been saved or restored since the sweep before. Animations that stay at rest
+    /// are forgotten between one and two intervals after they last changed.
+    pub fn with_sweep_interval(self, interval: Duration) -> Self {
+        if let Ok(mut saved) = self.saved.lock() {
+            saved.sweep_interval = Some(interval);
+        }
+        self
+    }
+
+    /// The app-wide store used by widgets given an [`Id`] directly, which sweeps itself every 30
+    /// seconds.
+    pub fn global() -> &'static Self {
+        static GLOBAL: OnceLock<AnimationStore> = OnceLock::new();
+        GLOBAL.get_or_init(|| Self::new().with_sweep_interval(GLOBAL_SWEEP_INTERVAL))
+    }
+
+    /// The [`AnimationId`] for the given `id` within this store.
+    pub fn id(&self, id: Id) -> AnimationId {
+        AnimationId {
+            store: self.clone(),
+            id,
+        }
+    }
+
+    /// Whether an animation is saved under the given `id`.
+    pub fn contains(&self, id: &Id) -> bool {
+        self.saved
+            .lock()
+            .is_ok_and(|saved| saved.entries.iter().any(|entry| entry.id == *id))
+    }
+
+    /// The number of animations saved in the store.
+    pub fn len(&self) -> usize {
+        self.saved.lock().map_or(0, |saved| saved.entries.len())
+    }
+
+    /// Whether the store doesn't have any saved animations.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forgets the animation saved under the given `id`, returning whether there was one.
+    ///
+    /// A widget that's still in the view saves its animation again the next time it changes.
+    pub fn remove(&self, id: &Id) -> bool {
+        let Ok(mut saved) = self.saved.lock() else {
+            return false;
+        };
+        let count = saved.entries.len();
+        saved.entries.retain(|entry| entry.id != *id);
+        saved.entries.len() != count
+    }
+
+    /// Forgets every saved animation.
+    pub fn clear(&self) {
+        if let Ok(mut saved) = self.saved.lock() {
+            saved.entries.clear();
+        }
+    }
+
+    /// Forgets the animations that haven't been saved or restored since the last sweep.
+    ///
+    /// Sweeping whenever the view changes drastically, e.g. after switching screens, keeps the
+    /// animations of widgets that moved while forgetting the ones that went away for good.
+    pub fn sweep(&self) {
+        if let Ok(mut saved) = self.saved.lock() {
+            saved.sweep(Instant::now());
+        }
+    }
+
+    /// Saves the `state` under the given `id`, replacing any state saved under it before.
+    pub(crate) fn save<T: Any + Send>(&self, id: &Id, state: T) {
+        let Ok(mut saved) = self.saved.lock() else {
+            return;
+        };
+
+        if let Some(interval) = saved.sweep_interval {
+            let now = Instant::now();
+            let is_due = saved
+                .last_sweep
+                .is_none_or(|last_sweep| now.duration_since(last_sweep) >= interval);
+            if is_due {
+                saved.sweep(now);
+            }
+        }
+
+        let generation = saved.generation;
+        match saved.entries.iter_mut().find(|entry| entry.id == *id) {
+            Some(entry) => {
+                entry.state = Box::new(state);
+                entry.generation = generation;
+            }
+            None => saved.entries.push(Entry {
+                id: id.clone(),
+                state: Box::new(state),
+                generation,
+            }),
+        }
+    }
+
+    /// Returns a copy of the state saved under the given `id`, if it has the same type.
+    pub(crate) fn restore<T: Any + Clone>(&self, id: &Id) -> Option<T> {
+        let mut saved = self.saved.lock().ok()?;
+        let generation = saved.generation;
+        let entry = saved.entries.iter_mut().find(|entry| entry.id == *id)?;
+        entry.generation = generation;
+        entry.state.downcast_ref::<T>().cloned()
+    }
+}
+
+impl Debug for AnimationStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnimationStore")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Stores are equal if they share the same saved animations.
+impl PartialEq for AnimationStore {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.saved, &other.saved)
+    }
+}
+
+impl AnimationId {
+    /// The [`Id`] the animation is saved under.
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    /// The store the animation is saved in.
+    pub fn store(&self) -> &AnimationStore {
+        &self.store
+    }
+
+    /// Saves the `state` under this id.
+    pub(crate) fn save<T: Any + Send>(&self, state: T) {
+        self.store.save(&self.id, state);
+    }
+
+    /// Returns a copy of the state saved under this id, if it has the same type.
+    pub(crate) fn restore<T: Any + Clone>(&self) -> Option<T> {
+        self.store.restore(&self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Saved states should only be restored as the same type they were saved as.
+    #[test]
+    fn restores_saved_states() {
+        let store = AnimationStore::new();
+        let id = store.id(Id::new("card"));
+        assert_eq!(id.restore::<f32>(), None);
+
+        id.save(1.0_f32);
+        id.save(2.0_f32);
+        assert_eq!(id.restore::<f32>(), Some(2.0));
+        assert_eq!(id.restore::<u32>(), None);
+        assert_eq!(store.len(), 1);
+    }
+
+    /// Clones of a store should share their saved states, unlike separate stores.
+    #[test]
+    fn clones_share_states() {
+        let store = AnimationStore::new();
+        let other = AnimationStore::new();
+        store.clone().save(&Id::new("card"), 1.0_f32);
+
+        assert!(store.contains(&Id::new("card")));
+        assert!(!other.contains(&Id::new("card")));
+        assert_eq!(store, store.clone());
+        assert_ne!(store, other);
+    }
+
+    /// Removing and clearing should forget saved states.
+    #[test]
+    fn forgets_states() {
+        let store = AnimationStore::new();
+        store.save(&Id::new("a"), 1.0_f32);
+        store.save(&Id::new("b"), 2.0_f32);
+
+        assert!(store.remove(&Id::new("a")));
+        assert!(!store.remove(&Id::new("a")));
+        assert_eq!(store.len(), 1);
+
+        store.clear();
+        assert!(store.is_empty());
+    }
+
+    /// Sweeping should forget states that weren't saved or restored since the last sweep.
+    #[test]
+    fn sweeps_untouched_states() {
+        let store = AnimationStore::new();
+        store.save(&Id::new("a"), 1.0_f32);
+        store.save(&Id::new("b"), 2.0_f32);
+        store.sweep();
+        assert_eq!(store.len(), 2);
+
+        assert_eq!(store.restore::<f32>(&Id::new("a")), Some(1.0));
+        store.sweep();
+        assert!(store.contains(&Id::new("a")));
+        assert!(!store.contains(&Id::new("b")));
+    }
+
+    /// Stores with a sweep interval should sweep themselves as states are saved.
+    #[test]
+    fn sweeps_while_saving() {
+        let store = AnimationStore::new().with_sweep_interval(Duration::ZERO);
+        store.save(&Id::new("a"), 1.0_f32);
+        store.save(&Id::new("b"), 2.0_f32);
+        assert_eq!(store.len(), 2);
+
+        store.save(&Id::new("b"), 3.0_f32);
+        assert!(!store.contains(&Id::new("a")));
+        assert_eq!(store.restore::<f32>(&Id::new("b")), Some(3.0));
+    }
+}
@@ -0,0 +1,156 @@
+//! A budget for how many springs animate at once, to protect the frame rate of busy UIs.
+//!
+//! Data-dense dashboards can have hundreds of values animating at the same time, like every
+//! cell of a table changing when new data arrives. Each animating spring costs time every frame,
+//! so past a point the animations make the app stutter rather than feel smooth. Springs can be
+//! marked with [`Priority::Low`] for animations that are nice to have but not essential. When more
+//! springs are animating than the budget set with [`set_animation_budget`], low-priority springs
+//! jump straight to their targets instead of animating, leaving the frame time for the rest.
+//!
+//! ```rust
+//! # use iced_anim::{budget::{self, Priority}, Spring};
+//! // Skip low-priority animations when more than 50 springs are animating.
+//! budget::set_animation_budget(Some(50));
+//!
+//! let cell = Spring::new(0.0).with_priority(Priority::Low);
+//! # budget::set_animation_budget(None);
+//! ```
+//!
+//! Springs are counted as they tick, and springs ticking with the same instant are considered
+//! part of the same frame. The budget is compared against the larger of the previous frame's
+//! count and the count so far in the current frame, so a low-priority spring that ticks early in
+//! a frame still sees how busy the last frame was. Springs are counted on the thread they tick
+//! on, which is the UI thread in an Iced app, so counting them doesn't need a lock.
+use std::{
+    cell::Cell,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+/// The maximum number of springs animating at once before low-priority springs stop animating,
+/// where `usize::MAX` means there is no budget.
+static BUDGET: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+thread_local! {
+    /// The springs counted in the current and previous frames.
+    static FRAMES: Cell<FrameCount> = const { Cell::new(FrameCount::new()) };
+}
+
+/// How important it is for a spring to animate when the app is busy.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Priority {
+    /// Always animates.
+    #[default]
+    Normal,
+    /// Jumps to its target instead of animating while the animation budget is exceeded.
+    Low,
+}
+
+/// Sets the maximum number of springs animating at once before low-priority springs jump to
+/// their targets, or removes the budget when `None`.
+pub fn set_animation_budget(budget: Option<usize>) {
+    BUDGET.store(budget.unwrap_or(usize::MAX), Ordering::Relaxed);
+}
+
+/// The maximum number of springs animating at once before low-priority springs jump to their
+/// targets, if any.
+pub fn animation_budget() -> Option<usize> {
+    match BUDGET.load(Ordering::Relaxed) {
+        usize::MAX => None,
+        budget => Some(budget),
+    }
+}
+
+/// The number of springs that animated on the current thread in the last complete frame.
+pub fn active_springs() -> usize {
+    FRAMES.get().previous
+}
+
+/// Records a tick of an animating spring at `now`, returning whether a spring with the given
+/// `priority` should jump to its target because the budget is exceeded.
+pub(crate) fn record_tick(now: Instant, priority: Priority) -> bool {
+    let mut frames = FRAMES.get();
+    let active = frames.record(now);
+    FRAMES.set(frames);
+
+    exceeds_budget(active, priority, animation_budget())
+}
+
+/// Whether a spring with the given `priority` should jump to its target when `active` springs
+/// are animating against the `budget`.
+fn exceeds_budget(active: usize, priority: Priority, budget: Option<usize>) -> bool {
+    match (priority, budget) {
+        (Priority::Low, Some(budget)) => active > budget,
+        _ => false,
+    }
+}
+
+/// Counts the springs ticking in each frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FrameCount {
+    /// The instant of the current frame.
+    frame: Option<Instant>,
+    /// The number of springs that ticked in the current frame so far.
+    current: usize,
+    /// The number of springs that ticked in the previous frame.
+    previous: usize,
+}
+
+impl FrameCount {
+    /// Creates an empty [`FrameCount`].
+    const fn new() -> Self {
+        Self {
+            frame: None,
+            current: 0,
+            previous: 0,
+        }
+    }
+
+    /// Counts a spring ticking at `now`, returning the number of springs considered active.
+    fn record(&mut self, now: Instant) -> usize {
+        if self.frame != Some(now) {
+            self.frame = Some(now);
+            self.previous = self.current;
+            self.current = 0;
+        }
+
+        self.current += 1;
+        self.previous.max(self.current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Springs ticking with the same instant should be counted in the same frame.
+    #[test]
+    fn counts_springs_per_frame() {
+        let mut frames = FrameCount::new();
+        let first = Instant::now();
+        assert_eq!(frames.record(first), 1);
+        assert_eq!(frames.record(first), 2);
+        assert_eq!(frames.record(first), 3);
+
+        // The previous frame keeps counting toward the next one until it catches up.
+        let second = first + Duration::from_millis(16);
+        assert_eq!(frames.record(second), 3);
+        assert_eq!(frames.previous, 3);
+
+        let third = second + Duration::from_millis(16);
+        assert_eq!(frames.record(third), 1);
+        assert_eq!(frames.previous, 1);
+    }
+
+    /// Low-priority springs should jump to their targets while the budget is exceeded.
+    #[test]
+    fn low_priority_skips_over_budget() {
+        assert!(exceeds_budget(1, Priority::Low, Some(0)));
+        assert!(!exceeds_budget(1, Priority::Normal, Some(0)));
+        assert!(!exceeds_budget(50, Priority::Low, Some(50)));
+        assert!(!exceeds_budget(usize::MAX, Priority::Low, None));
+    }
+}
@@ -0,0 +1,149 @@
+//! Animatable values for drawing strokes in a canvas.
+//!
+//! The [`iced::widget::canvas::Stroke`] used to draw paths borrows its dash pattern and mixes
+//! gradients with solid colors, so it can't be animated directly. A [`StrokeStyle`] holds the
+//! parts of a stroke that can be animated, like its width, color, and a dash pattern with a fixed
+//! number of segments, and creates a [`Stroke`] when drawing.
+//!
+//! This makes it easy to animate emphasis, like thickening the outline of a selected shape, or
+//! create a marching ants effect by moving the dash offset over time with [`StrokeStyle::marching`].
+//!
+//! > Note: this module is only available when the `canvas` feature is enabled.
+//!
+//! ```rust
+//! # use iced::{Color, widget::canvas::{Frame, Path}};
+//! # use iced_anim::{canvas::StrokeStyle, Spring};
+//! # use std::time::Duration;
+//! fn draw_selection(
+//!     frame: &mut Frame,
+//!     path: &Path,
+//!     style: &Spring<StrokeStyle<2>>,
+//!     elapsed: Duration,
+//! ) {
+//!     let style = style.value().marching(elapsed, 20.0);
+//!     frame.stroke(path, style.to_stroke());
+//! }
+//! ```
+use std::time::Duration;
+
+use iced::{
+    widget::canvas::{LineDash, Stroke},
+    Color,
+};
+
+use crate::Animate;
+
+/// The animatable parts of a canvas stroke with a dash pattern of `N` segments.
+///
+/// A pattern without any segments draws a solid line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokeStyle<const N: usize> {
+    /// The color of the stroke.
+    pub color: Color,
+    /// The width of the stroke.
+    pub width: f32,
+    /// The lengths of alternating dashes and gaps.
+    pub dash: [f32; N],
+    /// How far along the dash pattern the stroke starts.
+    pub dash_offset: f32,
+}
+
+impl StrokeStyle<0> {
+    /// Creates a solid [`StrokeStyle`] with the given `color` and `width`.
+    pub fn solid(color: Color, width: f32) -> Self {
+        Self::new(color, width, [])
+    }
+}
+
+impl<const N: usize> StrokeStyle<N> {
+    /// Creates a new [`StrokeStyle`] with the given `color`, `width`, and `dash` pattern.
+    pub fn new(color: Color, width: f32, dash: [f32; N]) -> Self {
+        Self {
+            color,
+            width,
+            dash,
+            dash_offset: 0.0,
+        }
+    }
+
+    /// Returns an updated style with the given `dash_offset`.
+    pub fn with_dash_offset(mut self, dash_offset: f32) -> Self {
+        self.dash_offset = dash_offset;
+        self
+    }
+
+    /// The total length of the dash pattern.
+    pub fn dash_length(&self) -> f32 {
+        self.dash.iter().map(|segment| segment.max(0.0)).sum()
+    }
+
+    /// Returns a style whose dashes have moved along the stroke at `speed` pixels per second
+    /// for the `elapsed` time, which creates a marching ants effect when drawn each frame.
+    pub fn marching(&self, elapsed: Duration, speed: f32) -> Self {
+        let length = self.dash_length();
+        if length <= 0.0 {
+            return *self;
+        }
+
+        let offset = (self.dash_offset + elapsed.as_secs_f32() * speed).rem_euclid(length);
+        self.with_dash_offset(offset)
+    }
+
+    /// Creates a [`Stroke`] with this style that can be drawn in a canvas frame.
+    ///
+    /// Canvas dash offsets are whole pixels, so the dash offset is rounded when drawn.
+    pub fn to_stroke(&self) -> Stroke<'_> {
+        Stroke {
+            line_dash: LineDash {
+                segments: &self.dash,
+                offset: self.dash_offset.max(0.0).round() as usize,
+            },
+            ..Stroke::default()
+        }
+        .with_color(self.color)
+        .with_width(self.width.max(0.0))
+    }
+}
+
+impl<const N: usize> Animate for StrokeStyle<N> {
+    fn components() -> usize {
+        Color::components() + f32::components() + <[f32; N]>::components() + f32::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.color.distance_to(&end.color),
+            self.width.distance_to(&end.width),
+            self.dash.distance_to(&end.dash),
+            self.dash_offset.distance_to(&end.dash_offset),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.color.update(components);
+        self.width.update(components);
+        self.dash.update(components);
+        self.dash_offset.update(components);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Marching dashes should wrap around the length of the dash pattern.
+    #[test]
+    fn marching_wraps() {
+        let style = StrokeStyle::new(Color::BLACK, 1.0, [6.0, 4.0]);
+        let marched = style.marching(Duration::from_millis(1500), 10.0);
+        assert_eq!(marched.dash_offset, 5.0);
+    }
+
+    /// Solid strokes don't have any dashes to move.
+    #[test]
+    fn marching_solid() {
+        let style = StrokeStyle::solid(Color::BLACK, 1.0);
+        assert_eq!(style.marching(Duration::from_secs(1), 10.0), style);
+    }
+}
@@ -0,0 +1,278 @@
+//! Momentum that slows down with friction, like kinetic scrolling.
+//!
+//! Springs always animate toward a target, but some motion doesn't have one. After a fling, a
+//! scrolled list or a panned map keeps gliding with the velocity of the gesture and gradually
+//! slows to a stop wherever friction takes it. A [`Decay`] animates a value this way, with its
+//! velocity falling off exponentially over time.
+//!
+//! ```rust
+//! # use std::time::{Duration, Instant};
+//! # use iced_anim::Decay;
+//! let mut offset = Decay::new(0.0);
+//! let start = Instant::now();
+//! offset.fling_at(vec![1000.0], start);
+//!
+//! // Where the fling comes to rest is known up front, e.g. to load map tiles ahead of time.
+//! let rest = offset.rest();
+//!
+//! let mut now = start;
+//! while offset.has_energy() {
+//!     now += Duration::from_millis(16);
+//!     offset.tick(now);
+//! }
+//! assert!((offset.value() - rest).abs() < 0.01);
+//! ```
+//!
+//! Decays are driven by the same widgets as springs. Store them in your state and drive them with
+//! [`Animation::decay`](crate::Animation::decay), which produces [`SpringEvent`]s to pass to
+//! [`Decay::update`].
+use std::time::Instant;
+
+use crate::{spring::MAX_DURATION, Animate, SpringEvent};
+
+/// A value that moves with a velocity that slows down with friction until it comes to rest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decay<T: Animate> {
+    /// The current value.
+    value: T,
+    /// The current velocity of each component of the value, per second.
+    velocity: Vec<f32>,
+    /// The rate at which the velocity decays, per second.
+    friction: f32,
+    /// The velocity per second below which the value comes to rest.
+    rest_speed: f32,
+    /// The last time the decay was updated.
+    last_update: Instant,
+}
+
+impl<T: Animate> Decay<T> {
+    /// The default friction, which glides about as far as momentum scrolling on touch devices.
+    pub const DEFAULT_FRICTION: f32 = 2.0;
+
+    /// The default velocity per second below which the value comes to rest.
+    pub const DEFAULT_REST_SPEED: f32 = 1.0;
+
+    /// Creates a new [`Decay`] resting at the given `value`.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            velocity: vec![0.0; T::components()],
+            friction: Self::DEFAULT_FRICTION,
+            rest_speed: Self::DEFAULT_REST_SPEED,
+            last_update: Instant::now(),
+        }
+    }
+
+    /// Sets the `friction`, which is the rate at which the velocity decays per second.
+    ///
+    /// After one second, the velocity is `e^-friction` of what it was, so higher friction stops
+    /// sooner. Friction must be positive, so smaller values are clamped to [`f32::EPSILON`].
+    pub fn with_friction(mut self, friction: f32) -> Self {
+        self.set_friction(friction);
+        self
+    }
+
+    /// Sets the `friction`, see [`Decay::with_friction`].
+    pub fn set_friction(&mut self, friction: f32) {
+        self.friction = friction.max(f32::EPSILON);
+    }
+
+    /// The rate at which the velocity decays, per second.
+    pub fn friction(&self) -> f32 {
+        self.friction
+    }
+
+    /// Sets the velocity per second below which the value comes to rest, in the units of the
+    /// value. Values on a large scale, like offsets in pixels, can come to rest sooner.
+    pub fn with_rest_speed(mut self, rest_speed: f32) -> Self {
+        self.rest_speed = rest_speed.abs();
+        self
+    }
+
+    /// The velocity per second below which the value comes to rest.
+    pub fn rest_speed(&self) -> f32 {
+        self.rest_speed
+    }
+
+    /// The current value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The current velocity of each component of the value, per second.
+    pub fn velocity(&self) -> &[f32] {
+        &self.velocity
+    }
+
+    /// The value the decay comes to rest at with its current velocity.
+    pub fn rest(&self) -> T {
+        let mut value = self.value.clone();
+        let mut distance = self.velocity.iter().map(|v| v / self.friction);
+        value.update(&mut distance);
+        value
+    }
+
+    /// Whether the value is moving.
+    pub fn is_moving(&self) -> bool {
+        self.velocity.iter().any(|v| *v != 0.0)
+    }
+
+    /// Whether the value is moving and needs to be ticked, like [`Spring::has_energy`].
+    ///
+    /// [`Spring::has_energy`]: crate::Spring::has_energy
+    pub fn has_energy(&self) -> bool {
+        self.is_moving()
+    }
+
+    /// Sets the value in motion with the given `velocity` per second of each component, e.g.
+    /// the velocity of a drag gesture when it's released.
+    pub fn fling(&mut self, velocity: Vec<f32>) {
+        self.fling_at(velocity, Instant::now());
+    }
+
+    /// Sets the value in motion with the given `velocity` as of the given instant.
+    ///
+    /// The value jumps straight to where it would come to rest while animations are disabled
+    /// with [`set_animations_enabled`](crate::set_animations_enabled).
+    pub fn fling_at(&mut self, velocity: Vec<f32>, now: Instant) {
+        self.velocity = velocity;
+        self.velocity.resize(T::components(), 0.0);
+        self.last_update = now;
+
+        if !crate::animations_enabled() {
+            self.settle();
+        }
+    }
+
+    /// Stops the value where it is, e.g. when the user grabs a gliding map again.
+    pub fn stop(&mut self) {
+        self.velocity.fill(0.0);
+    }
+
+    /// Jumps to where the value would come to rest and stops.
+    pub fn settle(&mut self) {
+        self.value = self.rest();
+        self.stop();
+    }
+
+    /// Stops the value at the given `value`, e.g. to follow a drag gesture directly.
+    pub fn settle_at(&mut self, value: T) {
+        self.value = value;
+        self.stop();
+    }
+
+    /// Updates the value of a moving decay to the given instant, coming to rest once it's slower
+    /// than the [`rest_speed`](Self::rest_speed).
+    pub fn tick(&mut self, now: Instant) {
+        if !self.is_moving() {
+            return;
+        }
+
+        if !crate::animations_enabled() {
+            self.settle();
+            return;
+        }
+
+        let elapsed = now.saturating_duration_since(self.last_update);
+        self.last_update = now;
+
+        // Redraws were suspended, e.g. while the computer slept, so nobody saw the animation.
+        if crate::global::is_suspended(elapsed) {
+            self.settle();
+            return;
+        }
+        let dt = elapsed.min(MAX_DURATION).as_secs_f32() * crate::playback_speed();
+
+        // The velocity decays exponentially, so the distance covered has an exact solution.
+        let factor = (-self.friction * dt).exp();
+        let mut distance = self
+            .velocity
+            .iter()
+            .map(|v| v * (1.0 - factor) / self.friction);
+        self.value.update(&mut distance);
+        self.velocity.iter_mut().for_each(|v| *v *= factor);
+
+        // The remaining distance at this speed is too small to notice.
+        if self.velocity.iter().all(|v| v.abs() <= self.rest_speed) {
+            self.settle();
+        }
+    }
+
+    /// Updates the decay with a [`SpringEvent`], like [`Spring::update`].
+    ///
+    /// Since a decay doesn't have a target, a target event stops the value at the new value.
+    ///
+    /// [`Spring::update`]: crate::Spring::update
+    pub fn update(&mut self, event: SpringEvent<T>) {
+        match event {
+            SpringEvent::Tick(now) => self.tick(now),
+            SpringEvent::Target(value) => self.settle_at(value),
+            SpringEvent::Settle => self.settle(),
+        }
+    }
+}
+
+impl<T: Animate + Default> Default for Decay<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Flings should slow down and come to rest where they were projected to.
+    #[test]
+    fn comes_to_rest() {
+        let mut decay = Decay::new(0.0);
+        let start = Instant::now();
+        decay.fling_at(vec![1000.0], start);
+        assert_eq!(decay.rest(), 500.0);
+
+        let mut now = start + Duration::from_millis(30);
+        decay.tick(now);
+        let first_step = *decay.value();
+        assert!(first_step > 0.0);
+        assert!(decay.velocity()[0] < 1000.0);
+
+        let mut previous = first_step;
+        while decay.has_energy() {
+            now += Duration::from_millis(30);
+            decay.tick(now);
+            assert!(decay.value() - previous <= first_step);
+            previous = *decay.value();
+        }
+        assert!((decay.value() - 500.0).abs() < 0.01);
+        assert_eq!(decay.velocity(), &[0.0]);
+    }
+
+    /// Higher friction should stop sooner.
+    #[test]
+    fn friction_shortens_the_glide() {
+        let mut decay = Decay::new(iced::Point::ORIGIN).with_friction(10.0);
+        decay.fling_at(vec![1000.0, -500.0], Instant::now());
+        assert_eq!(decay.rest(), iced::Point::new(100.0, -50.0));
+    }
+
+    /// Spring events should tick, stop, and settle the decay.
+    #[test]
+    fn updates_with_spring_events() {
+        let mut decay = Decay::new(0.0);
+        let start = Instant::now();
+        decay.fling_at(vec![100.0], start);
+        decay.update(SpringEvent::Tick(start + Duration::from_millis(30)));
+        assert!(decay.value() > &0.0);
+
+        decay.update(SpringEvent::Target(20.0));
+        assert_eq!(decay.value(), &20.0);
+        assert!(!decay.has_energy());
+
+        decay.fling_at(vec![100.0], start);
+        decay.update(SpringEvent::Settle);
+        assert_eq!(decay.value(), &70.0);
+        assert!(!decay.has_energy());
+    }
+}
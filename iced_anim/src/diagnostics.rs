@@ -0,0 +1,119 @@
+//! Warnings for animations that keep the app from going idle.
+//!
+//! Springs request a redraw every frame while they have energy, so an animation that never
+//! settles will keep rendering frames and drain the battery without any visible sign. With the
+//! `debug` feature enabled, springs track how long they've been animating and log a warning when:
+//! - a spring has been animating for more than [`RUNAWAY_FACTOR`] times its expected duration,
+//!   which usually means its target is being changed every frame.
+//! - a spring has ticked [`IDLE_FRAMES`] times in a row without its value changing, which means
+//!   frames are being redrawn with no visible change.
+//!
+//! Each warning is logged once per animation through the [`log`] crate.
+//!
+//! > Note: this module is only available when the `debug` feature is enabled.
+use std::time::{Duration, Instant};
+
+use crate::SpringMotion;
+
+/// How many times longer than its motion's duration a spring can animate before it's considered
+/// a runaway animation.
+pub const RUNAWAY_FACTOR: u32 = 10;
+
+/// The number of consecutive ticks without a change in value before a warning is logged.
+pub const IDLE_FRAMES: u32 = 60;
+
+/// Tracks the progress of a spring's animation to detect animations that prevent idle.
+///
+/// Diagnostics don't affect the animation itself, so they're ignored when comparing springs.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Diagnostics {
+    /// The instant at which the current animation started.
+    started: Option<Instant>,
+    /// The number of consecutive ticks without a change in value.
+    idle_frames: u32,
+    /// Whether the current animation has already been reported as a runaway.
+    reported_runaway: bool,
+    /// Whether the current idle frames have already been reported.
+    reported_idle: bool,
+}
+
+impl Diagnostics {
+    /// Records a tick at `now` for a spring with the given `motion`, where `changed` indicates
+    /// whether the tick changed the spring's value.
+    pub(crate) fn tick(&mut self, now: Instant, motion: SpringMotion, changed: bool) {
+        let started = *self.started.get_or_insert(now);
+        let elapsed = now.saturating_duration_since(started);
+        let expected = motion.duration().max(Duration::from_millis(1));
+        if !self.reported_runaway && elapsed > expected * RUNAWAY_FACTOR {
+            self.reported_runaway = true;
+            log::warn!(
+                "Spring has been animating for {elapsed:?}, more than {RUNAWAY_FACTOR}x its \
+                 expected {expected:?}. Is its target changing every frame?"
+            );
+        }
+
+        if changed {
+            self.idle_frames = 0;
+            self.reported_idle = false;
+        } else {
+            self.idle_frames += 1;
+            if !self.reported_idle && self.idle_frames >= IDLE_FRAMES {
+                self.reported_idle = true;
+                log::warn!(
+                    "Spring has requested {} redraws without its value changing",
+                    self.idle_frames
+                );
+            }
+        }
+    }
+
+    /// Resets the diagnostics once the spring comes to rest.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl PartialEq for Diagnostics {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Animations running far longer than expected should be reported once.
+    #[test]
+    fn reports_runaway_once() {
+        let mut diagnostics = Diagnostics::default();
+        let motion = SpringMotion::Smooth;
+        let start = Instant::now();
+        diagnostics.tick(start, motion, true);
+        assert!(!diagnostics.reported_runaway);
+
+        diagnostics.tick(
+            start + motion.duration() * (RUNAWAY_FACTOR + 1),
+            motion,
+            true,
+        );
+        assert!(diagnostics.reported_runaway);
+
+        diagnostics.reset();
+        assert!(!diagnostics.reported_runaway);
+    }
+
+    /// Ticks that don't change the value should be reported after enough frames.
+    #[test]
+    fn reports_idle_frames() {
+        let mut diagnostics = Diagnostics::default();
+        let now = Instant::now();
+        for _ in 0..IDLE_FRAMES {
+            diagnostics.tick(now, SpringMotion::Smooth, false);
+        }
+        assert!(diagnostics.reported_idle);
+
+        diagnostics.tick(now, SpringMotion::Smooth, true);
+        assert!(!diagnostics.reported_idle);
+    }
+}
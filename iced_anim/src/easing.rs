@@ -0,0 +1,246 @@
+//! Easing curves for animations that run over a fixed duration.
+//!
+//! Springs pick their own duration based on where they start and how fast they're moving, but
+//! some animations need exact timing, like each step of [`Keyframes`](crate::Keyframes). An
+//! [`Easing`] maps the progress of such an animation, from `0.0` to `1.0`, to the fraction of the
+//! way between the start and end values.
+//!
+//! ```rust
+//! # use iced_anim::Easing;
+//! assert_eq!(Easing::Linear.ease(0.25), 0.25);
+//! assert!(Easing::EaseIn.ease(0.25) < 0.25);
+//! assert!(Easing::EaseOut.ease(0.25) > 0.25);
+//! ```
+
+/// A curve mapping the progress of a fixed-duration animation to the progress of its value.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    /// Moves at a constant speed.
+    Linear,
+    /// Starts slowly and speeds up toward the end, like CSS `ease-in`.
+    EaseIn,
+    /// Starts quickly and slows down toward the end, like CSS `ease-out`.
+    EaseOut,
+    /// Starts and ends slowly, like CSS `ease-in-out`.
+    #[default]
+    EaseInOut,
+    /// A cubic Bézier curve from `(0, 0)` to `(1, 1)` with the control points `(x1, y1)` and
+    /// `(x2, y2)`, like CSS `cubic-bezier(x1, y1, x2, y2)`.
+    ///
+    /// The `x` coordinates are clamped between `0.0` and `1.0` so the curve keeps moving forward
+    /// in time, while the `y` coordinates can overshoot to anticipate or bounce past the end.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// The fraction of the way between the start and end values at the given `progress` through
+    /// the animation, from `0.0` to `1.0`.
+    ///
+    /// This is a `const fn`, so curves can be sampled at compile time, see
+    /// [`MotionTable`](crate::MotionTable).
+    pub const fn ease(&self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        match *self {
+            Self::Linear => progress,
+            Self::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, progress),
+            Self::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, progress),
+            Self::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, progress),
+            Self::CubicBezier(x1, y1, x2, y2) => {
+                cubic_bezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2, progress)
+            }
+        }
+    }
+
+    /// The curve with the given `name`, as used by other animation crates like `lilt` and `anim`,
+    /// CSS, and <https://easings.net>, ignoring case and separators. `"EaseOutCubic"`,
+    /// `"ease_out_cubic"`, and `"ease-out-cubic"` are all the same curve.
+    ///
+    /// Sine, quadratic, cubic, quartic, quintic, exponential, circular, and back curves are
+    /// approximated by their usual cubic Bézier curves. Elastic and bounce curves can't be
+    /// described by a single cubic Bézier curve, so they return `None`.
+    ///
+    /// ```rust
+    /// # use iced_anim::Easing;
+    /// assert_eq!(Easing::from_name("EaseInOut"), Some(Easing::EaseInOut));
+    /// assert_eq!(
+    ///     Easing::from_name("ease_out_cubic"),
+    ///     Some(Easing::CubicBezier(0.33, 1.0, 0.68, 1.0)),
+    /// );
+    /// assert_eq!(Easing::from_name("ease-out-bounce"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name: String = name
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+
+        let easing = match name.as_str() {
+            "linear" => Self::Linear,
+            "ease" => Self::CubicBezier(0.25, 0.1, 0.25, 1.0),
+            "easein" => Self::EaseIn,
+            "easeout" => Self::EaseOut,
+            "easeinout" => Self::EaseInOut,
+            "easeinsine" => Self::CubicBezier(0.12, 0.0, 0.39, 0.0),
+            "easeoutsine" => Self::CubicBezier(0.61, 1.0, 0.88, 1.0),
+            "easeinoutsine" => Self::CubicBezier(0.37, 0.0, 0.63, 1.0),
+            "easeinquad" => Self::CubicBezier(0.11, 0.0, 0.5, 0.0),
+            "easeoutquad" => Self::CubicBezier(0.5, 1.0, 0.89, 1.0),
+            "easeinoutquad" => Self::CubicBezier(0.45, 0.0, 0.55, 1.0),
+            "easeincubic" => Self::CubicBezier(0.32, 0.0, 0.67, 0.0),
+            "easeoutcubic" => Self::CubicBezier(0.33, 1.0, 0.68, 1.0),
+            "easeinoutcubic" => Self::CubicBezier(0.65, 0.0, 0.35, 1.0),
+            "easeinquart" => Self::CubicBezier(0.5, 0.0, 0.75, 0.0),
+            "easeoutquart" => Self::CubicBezier(0.25, 1.0, 0.5, 1.0),
+            "easeinoutquart" => Self::CubicBezier(0.76, 0.0, 0.24, 1.0),
+            "easeinquint" => Self::CubicBezier(0.64, 0.0, 0.78, 0.0),
+            "easeoutquint" => Self::CubicBezier(0.22, 1.0, 0.36, 1.0),
+            "easeinoutquint" => Self::CubicBezier(0.83, 0.0, 0.17, 1.0),
+            "easeinexpo" => Self::CubicBezier(0.7, 0.0, 0.84, 0.0),
+            "easeoutexpo" => Self::CubicBezier(0.16, 1.0, 0.3, 1.0),
+            "easeinoutexpo" => Self::CubicBezier(0.87, 0.0, 0.13, 1.0),
+            "easeincirc" => Self::CubicBezier(0.55, 0.0, 1.0, 0.45),
+            "easeoutcirc" => Self::CubicBezier(0.0, 0.55, 0.45, 1.0),
+            "easeinoutcirc" => Self::CubicBezier(0.85, 0.0, 0.15, 1.0),
+            "easeinback" => Self::CubicBezier(0.36, 0.0, 0.66, -0.56),
+            "easeoutback" => Self::CubicBezier(0.34, 1.56, 0.64, 1.0),
+            "easeinoutback" => Self::CubicBezier(0.68, -0.6, 0.32, 1.6),
+            _ => return None,
+        };
+        Some(easing)
+    }
+}
+
+/// Evaluates a CSS-style cubic Bézier curve at `x`, finding the curve parameter for `x` with
+/// Newton's method and falling back to bisection where the slope is too flat.
+const fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    if x <= 0.0 || x >= 1.0 {
+        return x;
+    }
+
+    let mut t = x;
+    let mut iteration = 0;
+    while iteration < 8 {
+        let error = bezier(x1, x2, t) - x;
+        if error.abs() < 1e-6 {
+            return bezier(y1, y2, t);
+        }
+        let slope = bezier_slope(x1, x2, t);
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        t = (t - error / slope).clamp(0.0, 1.0);
+        iteration += 1;
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+    t = x;
+    iteration = 0;
+    while iteration < 32 {
+        let error = bezier(x1, x2, t) - x;
+        if error.abs() < 1e-6 {
+            break;
+        }
+        if error > 0.0 {
+            high = t;
+        } else {
+            low = t;
+        }
+        t = (low + high) / 2.0;
+        iteration += 1;
+    }
+
+    bezier(y1, y2, t)
+}
+
+/// One coordinate of a cubic Bézier curve from `0` to `1` with the control points `p1` and `p2`
+/// at the curve parameter `t`.
+const fn bezier(p1: f32, p2: f32, t: f32) -> f32 {
+    let inverse = 1.0 - t;
+    3.0 * inverse * inverse * t * p1 + 3.0 * inverse * t * t * p2 + t * t * t
+}
+
+/// The slope of [`bezier`] at the curve parameter `t`.
+const fn bezier_slope(p1: f32, p2: f32, t: f32) -> f32 {
+    let inverse = 1.0 - t;
+    3.0 * inverse * inverse * p1 + 6.0 * inverse * t * (p2 - p1) + 3.0 * t * t * (1.0 - p2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EASINGS: [Easing; 5] = [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+        Easing::CubicBezier(0.3, -0.5, 0.7, 1.5),
+    ];
+
+    #[test]
+    fn starts_and_ends_in_place() {
+        for easing in EASINGS {
+            assert_eq!(easing.ease(0.0), 0.0);
+            assert_eq!(easing.ease(1.0), 1.0);
+            assert_eq!(easing.ease(-1.0), 0.0);
+            assert_eq!(easing.ease(2.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn named_curves_match_their_shape() {
+        assert!(Easing::EaseIn.ease(0.5) < 0.5);
+        assert!(Easing::EaseOut.ease(0.5) > 0.5);
+        assert!((Easing::EaseInOut.ease(0.5) - 0.5).abs() < 1e-3);
+        assert!(Easing::EaseInOut.ease(0.2) < 0.2);
+        assert!(Easing::EaseInOut.ease(0.8) > 0.8);
+    }
+
+    #[test]
+    fn linear_bezier_is_linear() {
+        let easing = Easing::CubicBezier(0.25, 0.25, 0.75, 0.75);
+        for step in 1..10 {
+            let progress = step as f32 / 10.0;
+            assert!((easing.ease(progress) - progress).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn bezier_can_overshoot() {
+        let back = Easing::CubicBezier(0.3, 0.0, 0.5, 1.6);
+        assert!((0..100).any(|step| back.ease(step as f32 / 100.0) > 1.0));
+    }
+
+    #[test]
+    fn names_match_curves() {
+        assert_eq!(Easing::from_name("linear"), Some(Easing::Linear));
+        assert_eq!(Easing::from_name("Ease In"), Some(Easing::EaseIn));
+        assert_eq!(Easing::from_name("easeOut"), Some(Easing::EaseOut));
+        assert_eq!(
+            Easing::from_name("EaseInQuad"),
+            Easing::from_name("ease-in-quad")
+        );
+        assert!(Easing::from_name("EaseInQuad").unwrap().ease(0.5) < 0.5);
+        assert!(Easing::from_name("EaseOutExpo").unwrap().ease(0.5) > 0.9);
+
+        let back = Easing::from_name("ease_out_back").unwrap();
+        assert!((0..100).any(|step| back.ease(step as f32 / 100.0) > 1.0));
+
+        assert_eq!(Easing::from_name("EaseOutElastic"), None);
+        assert_eq!(Easing::from_name("wiggle"), None);
+    }
+
+    #[test]
+    fn curves_move_forward() {
+        for easing in [Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut] {
+            let mut previous = 0.0;
+            for step in 1..=100 {
+                let value = easing.ease(step as f32 / 100.0);
+                assert!(value >= previous - 1e-5);
+                previous = value;
+            }
+        }
+    }
+}
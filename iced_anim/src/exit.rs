@@ -0,0 +1,229 @@
+//! Coordinate exit transitions that must finish before the app quits.
+//!
+//! An [`ExitCoordinator`] keeps track of transitions registered with a key, like a sidebar
+//! sliding out or the window content fading away. Once [`ExitCoordinator::exit`] is called,
+//! each transition reports that it finished through [`ExitCoordinator::finish`] or
+//! [`ExitCoordinator::observe`], and the coordinator emits a completion message after every
+//! registered transition has finished. That message is a good place to actually quit the app,
+//! e.g. with [`iced::exit`].
+//!
+//! ```rust
+//! # use iced::Task;
+//! # use iced_anim::{ExitCoordinator, Spring, SpringEvent};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Quit,
+//!     Sidebar(SpringEvent<f32>),
+//!     Exit,
+//! }
+//!
+//! struct State {
+//!     sidebar: Spring<f32>,
+//!     exit: ExitCoordinator<&'static str, Message>,
+//! }
+//!
+//! impl State {
+//!     fn update(&mut self, message: Message) -> Task<Message> {
+//!         match message {
+//!             Message::Quit => {
+//!                 self.exit.register("sidebar");
+//!                 self.sidebar.interrupt(0.0);
+//!                 self.exit.exit(Message::Exit)
+//!             }
+//!             Message::Sidebar(event) => {
+//!                 self.sidebar.update(event);
+//!                 self.exit.observe("sidebar", &self.sidebar)
+//!             }
+//!             Message::Exit => iced::exit(),
+//!         }
+//!     }
+//! }
+//! ```
+use iced::Task;
+
+use crate::{Animate, Spring};
+
+/// Coordinates exit transitions and emits a message once all of them have finished.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExitCoordinator<Key, Message> {
+    /// The transitions that haven't finished yet.
+    pending: Vec<Key>,
+    /// The message emitted once every transition has finished, if exiting.
+    on_complete: Option<Message>,
+    /// Whether the coordinator has started exiting.
+    is_exiting: bool,
+}
+
+impl<Key, Message> ExitCoordinator<Key, Message>
+where
+    Key: PartialEq,
+    Message: Send + 'static,
+{
+    /// Creates a new [`ExitCoordinator`] without any registered transitions.
+    pub fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+            on_complete: None,
+            is_exiting: false,
+        }
+    }
+
+    /// Registers a transition with the given `key` that must finish before exiting.
+    /// Registering the same key more than once has no effect.
+    pub fn register(&mut self, key: Key) {
+        if !self.pending.contains(&key) {
+            self.pending.push(key);
+        }
+    }
+
+    /// Whether the coordinator has started exiting.
+    pub fn is_exiting(&self) -> bool {
+        self.is_exiting
+    }
+
+    /// Whether the transition with the given `key` is still pending.
+    pub fn is_pending(&self, key: &Key) -> bool {
+        self.pending.contains(key)
+    }
+
+    /// Whether every registered transition has finished after starting to exit.
+    pub fn is_complete(&self) -> bool {
+        self.is_exiting && self.pending.is_empty()
+    }
+
+    /// Starts exiting, emitting `on_complete` once every registered transition has finished.
+    /// This emits the message immediately if there aren't any pending transitions.
+    pub fn exit(&mut self, on_complete: Message) -> Task<Message> {
+        self.is_exiting = true;
+        self.on_complete = Some(on_complete);
+        self.completion()
+    }
+
+    /// Cancels exiting, leaving any registered transitions pending.
+    pub fn cancel(&mut self) {
+        self.is_exiting = false;
+        self.on_complete = None;
+    }
+
+    /// Marks the transition with the given `key` as finished.
+    pub fn finish(&mut self, key: &Key) -> Task<Message> {
+        self.pending.retain(|pending| pending != key);
+        self.completion()
+    }
+
+    /// Marks the transition with the given `key` as finished once the `spring` comes to rest.
+    pub fn observe<T: Animate>(&mut self, key: Key, spring: &Spring<T>) -> Task<Message> {
+        if spring.has_energy() {
+            Task::none()
+        } else {
+            self.finish(&key)
+        }
+    }
+
+    /// Returns the completion message if every transition has finished,
+    /// ensuring that it is only emitted once.
+    fn take_completion(&mut self) -> Option<Message> {
+        if self.is_complete() {
+            self.on_complete.take()
+        } else {
+            None
+        }
+    }
+
+    /// A task emitting the completion message if every transition has finished.
+    fn completion(&mut self) -> Task<Message> {
+        match self.take_completion() {
+            Some(message) => Task::done(message),
+            None => Task::none(),
+        }
+    }
+}
+
+impl<Key, Message> Default for ExitCoordinator<Key, Message>
+where
+    Key: PartialEq,
+    Message: Send + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Exiting without any registered transitions should complete immediately.
+    #[test]
+    fn exit_without_transitions_completes() {
+        let mut coordinator = ExitCoordinator::<&str, ()>::new();
+        let _ = coordinator.exit(());
+        assert!(coordinator.is_complete());
+        assert_eq!(coordinator.take_completion(), None);
+    }
+
+    /// The completion message should only be emitted after every transition finishes.
+    #[test]
+    fn completes_after_all_transitions_finish() {
+        let mut coordinator = ExitCoordinator::<&str, ()>::new();
+        coordinator.register("sidebar");
+        coordinator.register("content");
+        let _ = coordinator.exit(());
+
+        let _ = coordinator.finish(&"sidebar");
+        assert!(!coordinator.is_complete());
+        assert!(coordinator.is_pending(&"content"));
+
+        let _ = coordinator.finish(&"content");
+        assert!(coordinator.is_complete());
+        // The completion message was emitted by finishing the last transition.
+        assert_eq!(coordinator.on_complete, None);
+    }
+
+    /// Springs should only finish their transition once they come to rest.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn observe_waits_for_spring_to_settle() {
+        let mut coordinator = ExitCoordinator::<&str, ()>::new();
+        coordinator.register("sidebar");
+        let _ = coordinator.exit(());
+
+        let mut spring = Spring::new(1.0).with_target(0.0);
+        let _ = coordinator.observe("sidebar", &spring);
+        assert!(coordinator.is_pending(&"sidebar"));
+
+        spring.settle();
+        let _ = coordinator.observe("sidebar", &spring);
+        assert!(coordinator.is_complete());
+    }
+
+    /// Registering the same key twice should only require it to finish once.
+    #[test]
+    fn register_is_idempotent() {
+        let mut coordinator = ExitCoordinator::<&str, ()>::new();
+        coordinator.register("sidebar");
+        coordinator.register("sidebar");
+        assert_eq!(coordinator.pending.len(), 1);
+    }
+
+    /// Transitions shouldn't complete the coordinator before it starts exiting.
+    #[test]
+    fn not_complete_before_exiting() {
+        let mut coordinator = ExitCoordinator::<&str, ()>::new();
+        coordinator.register("sidebar");
+        let _ = coordinator.finish(&"sidebar");
+        assert!(!coordinator.is_complete());
+    }
+
+    /// Cancelling should stop exiting and drop the completion message.
+    #[test]
+    fn cancel() {
+        let mut coordinator = ExitCoordinator::<&str, ()>::new();
+        coordinator.register("sidebar");
+        let _ = coordinator.exit(());
+        coordinator.cancel();
+        assert!(!coordinator.is_exiting());
+        coordinator.pending.clear();
+        assert_eq!(coordinator.take_completion(), None);
+    }
+}
@@ -0,0 +1,150 @@
+//! Formatters that turn animated numbers into readable text.
+//!
+//! Animated numbers pass through every value between their start and end, so displaying them
+//! with `to_string` shows noisy intermediate values like `1234567.9`. The formatters in this
+//! module are applied to the interpolated value on every frame to keep it readable throughout
+//! the animation, e.g. `1.2 MB` while counting up a download size.
+//!
+//! Each formatter is a plain function of the value, so you can pass your own closures anywhere
+//! a formatter is accepted.
+//!
+//! ```rust
+//! # use iced_anim::format;
+//! assert_eq!(format::decimal(2)(3.14159), "3.14");
+//! assert_eq!(format::thousands(0, ',')(1234567.0), "1,234,567");
+//! assert_eq!(format::si(1, "B")(1_234_567.89), "1.2 MB");
+//! assert_eq!(format::with_unit(0, "%")(42.4), "42 %");
+//! assert_eq!(format::duration(0)(3725.0), "1:02:05");
+//! ```
+
+/// The metric prefixes used by [`si`], in increasing powers of 1000.
+const SI_PREFIXES: [&str; 7] = ["", "k", "M", "G", "T", "P", "E"];
+
+/// Formats values with a fixed number of decimal `places`.
+pub fn decimal(places: usize) -> impl Fn(f32) -> String + Clone {
+    move |value| fixed(value, places)
+}
+
+/// Formats values with a fixed number of decimal `places`, grouping the digits of the whole
+/// number into thousands with the `separator`.
+pub fn thousands(places: usize, separator: char) -> impl Fn(f32) -> String + Clone {
+    move |value| {
+        let formatted = fixed(value, places);
+        let (sign, unsigned) = match formatted.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", formatted.as_str()),
+        };
+        let (whole, fraction) = match unsigned.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut grouped = String::from(sign);
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            grouped.push('.');
+            grouped.push_str(fraction);
+        }
+        grouped
+    }
+}
+
+/// Formats values with a fixed number of decimal `places` followed by the `unit`.
+pub fn with_unit(places: usize, unit: &str) -> impl Fn(f32) -> String + Clone + '_ {
+    move |value| format!("{} {unit}", fixed(value, places))
+}
+
+/// Formats values scaled to the nearest metric prefix of the `unit`, with a fixed number of
+/// decimal `places`, e.g. `1.2 MB` for 1,234,567 bytes.
+pub fn si(places: usize, unit: &str) -> impl Fn(f32) -> String + Clone + '_ {
+    move |value| {
+        let mut scaled = value;
+        let mut prefix = 0;
+        while scaled.abs() >= 1000.0 && prefix < SI_PREFIXES.len() - 1 {
+            scaled /= 1000.0;
+            prefix += 1;
+        }
+        format!("{} {}{unit}", fixed(scaled, places), SI_PREFIXES[prefix])
+    }
+}
+
+/// Formats values in seconds as a clock duration like `1:05` or `1:02:05`, with a fixed
+/// number of decimal `places` for the seconds.
+pub fn duration(places: usize) -> impl Fn(f32) -> String + Clone {
+    move |value| {
+        let sign = if value < 0.0 && fixed(value, places) != fixed(0.0, places) {
+            "-"
+        } else {
+            ""
+        };
+
+        // Round once up front so that values like 59.99 carry over into the minutes.
+        let scale = 10_f64.powi(places as i32);
+        let total = (f64::from(value).abs() * scale).round() / scale;
+        let hours = (total / 3600.0).floor();
+        let minutes = ((total - hours * 3600.0) / 60.0).floor();
+        let seconds = total - hours * 3600.0 - minutes * 60.0;
+
+        let width = if places > 0 { places + 3 } else { 2 };
+        let seconds = format!("{seconds:0width$.places$}");
+        if hours > 0.0 {
+            format!("{sign}{hours}:{minutes:02}:{seconds}")
+        } else {
+            format!("{sign}{minutes}:{seconds}")
+        }
+    }
+}
+
+/// Formats the `value` with a fixed number of decimal `places`, avoiding negative zero.
+fn fixed(value: f32, places: usize) -> String {
+    let formatted = format!("{value:.places$}");
+    match formatted.strip_prefix('-') {
+        Some(unsigned) if unsigned.chars().all(|c| c == '0' || c == '.') => unsigned.to_owned(),
+        _ => formatted,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Digits should be grouped by thousands, leaving the sign and fraction alone.
+    #[test]
+    fn thousands_groups_digits() {
+        let format = thousands(2, ',');
+        assert_eq!(format(0.0), "0.00");
+        assert_eq!(format(999.0), "999.00");
+        assert_eq!(format(-1234.5), "-1,234.50");
+        assert_eq!(format(1000000.0), "1,000,000.00");
+    }
+
+    /// Values should scale to the nearest prefix in either direction.
+    #[test]
+    fn si_scales_prefixes() {
+        let format = si(1, "B");
+        assert_eq!(format(512.0), "512.0 B");
+        assert_eq!(format(1500.0), "1.5 kB");
+        assert_eq!(format(-2_500_000.0), "-2.5 MB");
+    }
+
+    /// Durations should carry rounded seconds into minutes and hours.
+    #[test]
+    fn duration_carries() {
+        assert_eq!(duration(0)(59.6), "1:00");
+        assert_eq!(duration(1)(65.25), "1:05.3");
+        assert_eq!(duration(0)(-90.0), "-1:30");
+        assert_eq!(duration(0)(-0.2), "0:00");
+    }
+
+    /// Negative values that round to zero shouldn't show a sign.
+    #[test]
+    fn decimal_avoids_negative_zero() {
+        assert_eq!(decimal(1)(-0.01), "0.0");
+        assert_eq!(decimal(0)(-0.4), "0");
+    }
+}
@@ -0,0 +1,273 @@
+//! Global settings that affect every animation in the app.
+//!
+//! Animations can be turned off at runtime with [`set_animations_enabled`], e.g. to respect a
+//! "disable animations" setting in your app. Every [`Spring`](crate::Spring) then jumps straight
+//! to its target, including springs that are animating when the setting changes, so the change
+//! takes effect immediately everywhere without threading the setting through each widget.
+//!
+//! ```rust
+//! # use iced_anim::{Spring, SpringEvent};
+//! iced_anim::set_animations_enabled(false);
+//!
+//! let mut spring = Spring::new(0.0);
+//! spring.update(SpringEvent::Target(5.0));
+//! assert_eq!(spring.value(), &5.0);
+//! # iced_anim::set_animations_enabled(true);
+//! ```
+//!
+//! Redraws stop while the app is minimized or the computer sleeps, so the next frame can arrive
+//! long after an animation started. Rather than resuming an animation nobody saw, springs jump
+//! to their targets once the time since their last update passes the threshold set with
+//! [`set_suspend_threshold`].
+//!
+//! Every spring can be slowed down to inspect an animation frame by frame, or sped up for users
+//! who find animations sluggish, with [`set_playback_speed`]. It multiplies the speed of each
+//! spring set with [`Spring::set_speed`](crate::Spring::set_speed).
+//!
+//! Springs solve their motion exactly, so the same spring moves the same way at 60Hz, 120Hz, or
+//! 144Hz, and check whether they've come to rest in small steps that evenly divide a frame at the
+//! refresh rate of the display, so they come to rest at the same time too. The refresh rate is
+//! detected from the time between the frames that springs are ticked in, or it can be set with
+//! [`set_refresh_rate`] when the app knows the refresh rate of its monitor.
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+        Mutex, OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+/// The default time between updates after which springs jump to their targets.
+pub const DEFAULT_SUSPEND_THRESHOLD: Duration = Duration::from_secs(1);
+
+/// Whether animations are enabled at runtime.
+static ANIMATIONS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// The speed of every animation, stored as the bits of an `f32`, starting at 1.0.
+static PLAYBACK_SPEED: AtomicU32 = AtomicU32::new(0x3F80_0000);
+
+/// The time between updates after which springs jump to their targets, in nanoseconds, or
+/// [`u64::MAX`] if they never do.
+static SUSPEND_THRESHOLD: AtomicU64 = AtomicU64::new(DEFAULT_SUSPEND_THRESHOLD.as_nanos() as u64);
+
+/// The refresh rate assumed until enough frames are seen to detect it, in Hz.
+pub const DEFAULT_REFRESH_RATE: f32 = 60.0;
+
+/// The refresh rate set by the app, stored as the bits of an `f32`, or 0 to detect it.
+static REFRESH_RATE: AtomicU32 = AtomicU32::new(0);
+
+/// The refresh rate detected from recent frames, stored as the bits of an `f32`, or 0 until
+/// enough frames are seen. This is updated once per frame so springs can read it without locking.
+static DETECTED_REFRESH_RATE: AtomicU32 = AtomicU32::new(0);
+
+/// The recent frames used to detect the refresh rate.
+static FRAMES: Mutex<FrameIntervals> = Mutex::new(FrameIntervals::new());
+
+/// The instant the first frame was recorded at, which [`LAST_FRAME`] is measured from.
+static FRAME_EPOCH: OnceLock<Instant> = OnceLock::new();
+
+/// The latest frame recorded, in nanoseconds after [`FRAME_EPOCH`] plus one, or 0 before the
+/// first frame. Every spring ticked in a frame records it, so only the first one has to lock.
+static LAST_FRAME: AtomicU64 = AtomicU64::new(0);
+
+/// Enables or disables all animations at runtime.
+///
+/// While disabled, springs jump to their targets instead of animating. This has no effect when
+/// animations are compiled out with the `animations-off` feature.
+pub fn set_animations_enabled(is_enabled: bool) {
+    ANIMATIONS_ENABLED.store(is_enabled, Ordering::Relaxed);
+}
+
+/// Whether animations are currently enabled, both at compile time and at runtime.
+pub fn animations_enabled() -> bool {
+    !cfg!(feature = "animations-off") && ANIMATIONS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Sets how fast every spring animates relative to its motion, like 0.1 to watch animations in
+/// slow motion or 2.0 to play them twice as fast. Negative speeds are treated as 0, which pauses
+/// every spring.
+pub fn set_playback_speed(speed: f32) {
+    let speed = if speed.is_finite() {
+        speed.max(0.0)
+    } else {
+        1.0
+    };
+    PLAYBACK_SPEED.store(speed.to_bits(), Ordering::Relaxed);
+}
+
+/// How fast every spring animates relative to its motion.
+pub fn playback_speed() -> f32 {
+    f32::from_bits(PLAYBACK_SPEED.load(Ordering::Relaxed))
+}
+
+/// Sets how long a spring can go without an update before it jumps to its target on the next
+/// one, or `None` to always resume animations where they left off.
+///
+/// The gap between updates is otherwise capped at [`MAX_DURATION`](crate::spring::MAX_DURATION)
+/// so that a single late frame doesn't make springs jump.
+pub fn set_suspend_threshold(threshold: Option<Duration>) {
+    let nanos = threshold.map_or(u64::MAX, |threshold| {
+        u64::try_from(threshold.as_nanos()).unwrap_or(u64::MAX)
+    });
+    SUSPEND_THRESHOLD.store(nanos, Ordering::Relaxed);
+}
+
+/// How long a spring can go without an update before it jumps to its target on the next one,
+/// if it ever does.
+pub fn suspend_threshold() -> Option<Duration> {
+    match SUSPEND_THRESHOLD.load(Ordering::Relaxed) {
+        u64::MAX => None,
+        nanos => Some(Duration::from_nanos(nanos)),
+    }
+}
+
+/// Whether updates were suspended for the `elapsed` time, meaning springs should jump to their
+/// targets.
+pub(crate) fn is_suspended(elapsed: Duration) -> bool {
+    exceeds_threshold(elapsed, suspend_threshold())
+}
+
+/// Whether the `elapsed` time is longer than the suspend `threshold`, if there is one.
+fn exceeds_threshold(elapsed: Duration, threshold: Option<Duration>) -> bool {
+    threshold.is_some_and(|threshold| elapsed > threshold)
+}
+
+/// Sets the refresh rate of the display in Hz, e.g. from the monitor the window is on, or `None`
+/// to detect it from the time between redraws.
+///
+/// Rates that aren't finite and positive are treated as `None`.
+pub fn set_refresh_rate(refresh_rate: Option<f32>) {
+    let refresh_rate = refresh_rate.filter(|rate| rate.is_finite() && *rate > 0.0);
+    REFRESH_RATE.store(refresh_rate.map_or(0, f32::to_bits), Ordering::Relaxed);
+}
+
+/// The refresh rate of the display in Hz, either set with [`set_refresh_rate`] or detected from
+/// the time between redraws, falling back to [`DEFAULT_REFRESH_RATE`].
+pub fn refresh_rate() -> f32 {
+    let bits = match REFRESH_RATE.load(Ordering::Relaxed) {
+        0 => DETECTED_REFRESH_RATE.load(Ordering::Relaxed),
+        bits => bits,
+    };
+
+    match bits {
+        0 => DEFAULT_REFRESH_RATE,
+        bits => f32::from_bits(bits),
+    }
+}
+
+/// Records a frame at `now` to detect the refresh rate, which every moving spring does when it's
+/// ticked. Frames at the same instant count as the same frame.
+pub(crate) fn record_frame(now: Instant) {
+    let epoch = *FRAME_EPOCH.get_or_init(|| now);
+    let frame = now.saturating_duration_since(epoch).as_nanos() as u64 + 1;
+    if LAST_FRAME.swap(frame, Ordering::Relaxed) == frame {
+        return;
+    }
+
+    let Ok(mut frames) = FRAMES.lock() else {
+        return;
+    };
+
+    if frames.record(now) {
+        let detected = frames.refresh_rate().map_or(0, f32::to_bits);
+        DETECTED_REFRESH_RATE.store(detected, Ordering::Relaxed);
+    }
+}
+
+/// The time between recent frames.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FrameIntervals {
+    /// The instant of the latest frame.
+    frame: Option<Instant>,
+    /// The most recent intervals between frames in seconds, oldest first once it's full.
+    intervals: [f32; Self::LEN],
+    /// The number of intervals recorded so far, up to [`Self::LEN`].
+    len: usize,
+    /// The index the next interval is recorded at.
+    next: usize,
+}
+
+impl FrameIntervals {
+    /// The number of intervals used to detect the refresh rate.
+    const LEN: usize = 15;
+
+    /// Gaps between frames longer than this are pauses between animations, not frames.
+    const MAX_INTERVAL: f32 = 0.1;
+
+    /// Creates an empty [`FrameIntervals`].
+    const fn new() -> Self {
+        Self {
+            frame: None,
+            intervals: [0.0; Self::LEN],
+            len: 0,
+            next: 0,
+        }
+    }
+
+    /// Records a frame at `now`, returning whether it added an interval.
+    fn record(&mut self, now: Instant) -> bool {
+        let Some(previous) = self.frame.replace(now) else {
+            return false;
+        };
+
+        let interval = now.saturating_duration_since(previous).as_secs_f32();
+        if interval > 0.0 && interval <= Self::MAX_INTERVAL {
+            self.intervals[self.next] = interval;
+            self.next = (self.next + 1) % Self::LEN;
+            self.len = (self.len + 1).min(Self::LEN);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The refresh rate detected from the median interval, which ignores the occasional
+    /// dropped frame, once all intervals are recorded.
+    fn refresh_rate(&self) -> Option<f32> {
+        if self.len < Self::LEN {
+            return None;
+        }
+
+        let mut intervals = self.intervals;
+        intervals.sort_by(f32::total_cmp);
+        Some(1.0 / intervals[Self::LEN / 2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The refresh rate should be detected from the typical time between frames.
+    #[test]
+    fn detects_refresh_rate() {
+        let mut frames = FrameIntervals::new();
+        let mut now = Instant::now();
+        let interval = Duration::from_secs_f32(1.0 / 144.0);
+        for index in 0..=FrameIntervals::LEN {
+            assert_eq!(frames.refresh_rate(), None);
+            frames.record(now);
+            frames.record(now);
+
+            // Dropped frames and pauses between animations don't change the refresh rate.
+            now += match index {
+                3 => interval * 2,
+                7 => Duration::from_secs(1),
+                _ => interval,
+            };
+        }
+        frames.record(now);
+
+        let refresh_rate = frames.refresh_rate().unwrap();
+        assert!((refresh_rate - 144.0).abs() < 0.1, "{refresh_rate}");
+    }
+
+    /// A longer suspend threshold should let springs resume after shorter gaps.
+    #[test]
+    fn suspend_threshold_is_configurable() {
+        let gap = Duration::from_secs(10);
+        assert!(exceeds_threshold(gap, Some(DEFAULT_SUSPEND_THRESHOLD)));
+        assert!(!exceeds_threshold(gap, Some(Duration::from_secs(3600))));
+        assert!(!exceeds_threshold(gap, None));
+    }
+}
@@ -0,0 +1,313 @@
+//! Animated node positions and edge endpoints for node graph editors.
+//!
+//! Node graphs animate many positions at once: every node moves when an auto-layout runs, and
+//! nodes should glide to a stop when they're flung by a drag. [`NodePositions`] keeps a spring
+//! for each node in a [`SpringVec`], addressed by the node's key rather than its index, and
+//! provides the animated endpoints of edges so they stay attached to their moving nodes.
+//!
+//! ```rust
+//! # use std::time::Instant;
+//! # use iced::Point;
+//! # use iced_anim::graph::NodePositions;
+//! let mut nodes = NodePositions::default();
+//! nodes.insert("input", Point::new(0.0, 0.0));
+//! nodes.insert("output", Point::new(200.0, 0.0));
+//!
+//! // Animate every node to the positions computed by an auto-layout.
+//! nodes.retarget([("input", Point::new(0.0, 100.0)), ("output", Point::new(150.0, 100.0))]);
+//!
+//! // Drag a node directly, then fling it with the velocity of the drag when it's released.
+//! nodes.drag("output", Point::new(180.0, 120.0));
+//! nodes.release(&"output");
+//!
+//! nodes.tick(Instant::now());
+//! let (from, to) = nodes.edge(&"input", &"output").unwrap();
+//! ```
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+use iced::{Point, Vector};
+
+use crate::{SpringMotion, SpringVec};
+
+/// How far ahead the velocity of a fling is projected to pick where the node comes to rest.
+const FLING_PROJECTION: Duration = Duration::from_millis(150);
+
+/// How quickly the measured drag velocity follows the latest movement, from 0 to 1.
+const VELOCITY_SMOOTHING: f32 = 0.4;
+
+/// Drags that stopped moving for longer than this before being released don't fling the node.
+const FLING_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The animated positions of the nodes in a graph, addressed by node key.
+#[derive(Debug, Clone)]
+pub struct NodePositions<K> {
+    /// The springs animating each node's position.
+    springs: SpringVec<Point>,
+    /// The key of the node at each index of the springs.
+    keys: Vec<K>,
+    /// The index of each node's spring.
+    indices: HashMap<K, usize>,
+    /// The drags in progress, by node.
+    drags: HashMap<K, Drag>,
+}
+
+/// A drag in progress on a node.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// The latest position of the node.
+    position: Point,
+    /// When the node was last dragged.
+    moved_at: Instant,
+    /// The smoothed velocity of the drag in pixels per second.
+    velocity: Vector,
+}
+
+impl<K> NodePositions<K>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Creates an empty set of nodes whose positions animate with the given `motion`.
+    pub fn new(motion: SpringMotion) -> Self {
+        Self {
+            springs: SpringVec::new(motion),
+            keys: Vec::new(),
+            indices: HashMap::new(),
+            drags: HashMap::new(),
+        }
+    }
+
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Whether there are no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Whether there is a node with the given `key`.
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// Adds a node resting at the `position`, or moves an existing node there immediately.
+    pub fn insert(&mut self, key: K, position: Point) {
+        match self.indices.get(&key) {
+            Some(index) => self.springs.settle_at(*index, position),
+            None => {
+                let index = self.springs.push(position);
+                self.keys.push(key.clone());
+                self.indices.insert(key, index);
+            }
+        }
+    }
+
+    /// Removes the node with the given `key`, returning its current position.
+    pub fn remove(&mut self, key: &K) -> Option<Point> {
+        let index = self.indices.remove(key)?;
+        self.drags.remove(key);
+        self.keys.remove(index);
+        for moved in self.indices.values_mut().filter(|moved| **moved > index) {
+            *moved -= 1;
+        }
+        Some(self.springs.remove(index))
+    }
+
+    /// Returns the animated position of the node with the given `key`.
+    pub fn position(&self, key: &K) -> Option<Point> {
+        self.indices
+            .get(key)
+            .map(|index| *self.springs.value(*index))
+    }
+
+    /// Returns the position that the node with the given `key` is animating toward.
+    pub fn target(&self, key: &K) -> Option<Point> {
+        self.indices
+            .get(key)
+            .map(|index| *self.springs.target(*index))
+    }
+
+    /// Returns the animated endpoints of the edge between the nodes with the given keys.
+    pub fn edge(&self, from: &K, to: &K) -> Option<(Point, Point)> {
+        Some((self.position(from)?, self.position(to)?))
+    }
+
+    /// Returns the animated endpoints of each of the `edges` whose nodes both exist.
+    pub fn edges<'a>(
+        &'a self,
+        edges: impl IntoIterator<Item = &'a (K, K)> + 'a,
+    ) -> impl Iterator<Item = (Point, Point)> + 'a {
+        edges
+            .into_iter()
+            .filter_map(|(from, to)| self.edge(from, to))
+    }
+
+    /// Iterates over the key and animated position of every node.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, Point)> {
+        self.keys.iter().zip(self.springs.values().iter().copied())
+    }
+
+    /// Animates the nodes toward new `positions`, e.g. after running an auto-layout.
+    ///
+    /// Nodes that don't exist yet are added at their position. Nodes being dragged keep
+    /// following the drag.
+    pub fn retarget(&mut self, positions: impl IntoIterator<Item = (K, Point)>) {
+        for (key, position) in positions {
+            match self.indices.get(&key) {
+                Some(_) if self.drags.contains_key(&key) => {}
+                Some(index) => self.springs.interrupt(*index, position),
+                None => self.insert(key, position),
+            }
+        }
+    }
+
+    /// Moves the node with the given `key` directly to the `position` of a drag, tracking the
+    /// velocity of the drag to fling the node once it's released.
+    pub fn drag(&mut self, key: K, position: Point) {
+        self.drag_at(key, position, Instant::now());
+    }
+
+    /// Releases the drag of the node with the given `key`, flinging it with the velocity of
+    /// the drag before it comes to rest.
+    pub fn release(&mut self, key: &K) {
+        self.release_at(key, Instant::now());
+    }
+
+    /// Whether the node with the given `key` is being dragged.
+    pub fn is_dragging(&self, key: &K) -> bool {
+        self.drags.contains_key(key)
+    }
+
+    /// Whether any node is animating, meaning the nodes need to be ticked.
+    pub fn has_energy(&self) -> bool {
+        self.springs.has_energy()
+    }
+
+    /// Updates every animating node based on the elapsed time since the last update.
+    pub fn tick(&mut self, now: Instant) {
+        self.springs.tick(now);
+    }
+
+    /// Settles every node at its target immediately.
+    pub fn settle(&mut self) {
+        self.springs.settle_all();
+    }
+
+    /// Returns the [`SpringVec`] animating the nodes, in the order they were inserted.
+    pub fn springs(&self) -> &SpringVec<Point> {
+        &self.springs
+    }
+
+    /// Moves the node with the given `key` to the `position` of a drag at `now`.
+    fn drag_at(&mut self, key: K, position: Point, now: Instant) {
+        let Some(index) = self.indices.get(&key).copied() else {
+            return;
+        };
+
+        let drag = self.drags.entry(key).or_insert(Drag {
+            position: *self.springs.value(index),
+            moved_at: now,
+            velocity: Vector::new(0.0, 0.0),
+        });
+        let elapsed = now.saturating_duration_since(drag.moved_at).as_secs_f32();
+        if elapsed > 0.0 {
+            let velocity = (position - drag.position) * (1.0 / elapsed);
+            drag.velocity = drag.velocity + (velocity - drag.velocity) * VELOCITY_SMOOTHING;
+        }
+        drag.position = position;
+        drag.moved_at = now;
+
+        self.springs.settle_at(index, position);
+    }
+
+    /// Releases the drag of the node with the given `key` at `now`.
+    fn release_at(&mut self, key: &K, now: Instant) {
+        let (Some(drag), Some(index)) = (self.drags.remove(key), self.indices.get(key)) else {
+            return;
+        };
+        if now.saturating_duration_since(drag.moved_at) > FLING_TIMEOUT {
+            return;
+        }
+
+        let velocity = drag.velocity;
+        let target = drag.position + velocity * FLING_PROJECTION.as_secs_f32();
+        self.springs.interrupt(*index, target);
+        self.springs.impulse(*index, &[velocity.x, velocity.y]);
+    }
+}
+
+impl<K> Default for NodePositions<K>
+where
+    K: Eq + Hash + Clone,
+{
+    fn default() -> Self {
+        Self::new(SpringMotion::Smooth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Removing a node should keep the other nodes addressable by their keys.
+    #[test]
+    fn remove_keeps_other_keys() {
+        let mut nodes = NodePositions::default();
+        nodes.insert('a', Point::new(0.0, 0.0));
+        nodes.insert('b', Point::new(1.0, 1.0));
+        nodes.insert('c', Point::new(2.0, 2.0));
+
+        assert_eq!(nodes.remove(&'a'), Some(Point::new(0.0, 0.0)));
+        assert_eq!(nodes.position(&'b'), Some(Point::new(1.0, 1.0)));
+        assert_eq!(nodes.position(&'c'), Some(Point::new(2.0, 2.0)));
+        assert_eq!(nodes.edge(&'a', &'c'), None);
+    }
+
+    /// Nodes being dragged should ignore new layouts, while new nodes are added.
+    #[test]
+    fn retarget_skips_dragged_nodes() {
+        let mut nodes = NodePositions::default();
+        nodes.insert('a', Point::new(0.0, 0.0));
+        nodes.insert('b', Point::new(0.0, 0.0));
+        nodes.drag('a', Point::new(5.0, 5.0));
+
+        nodes.retarget([
+            ('a', Point::new(10.0, 0.0)),
+            ('b', Point::new(10.0, 0.0)),
+            ('c', Point::new(20.0, 0.0)),
+        ]);
+        assert_eq!(nodes.target(&'a'), Some(Point::new(5.0, 5.0)));
+        assert_eq!(nodes.target(&'b'), Some(Point::new(10.0, 0.0)));
+        assert_eq!(nodes.position(&'c'), Some(Point::new(20.0, 0.0)));
+    }
+
+    /// Releasing a moving drag should fling the node ahead in the direction of the drag.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn release_flings_node() {
+        let start = Instant::now();
+        let mut nodes = NodePositions::default();
+        nodes.insert('a', Point::new(0.0, 0.0));
+        for frame in 1..=5 {
+            let now = start + Duration::from_millis(16 * frame);
+            nodes.drag_at('a', Point::new(10.0 * frame as f32, 0.0), now);
+        }
+        nodes.release_at(&'a', start + Duration::from_millis(90));
+
+        let target = nodes.target(&'a').unwrap();
+        assert!(target.x > 50.0, "{target:?} should be ahead of the drag");
+        assert_eq!(target.y, 0.0);
+        assert!(nodes.has_energy());
+
+        // A drag that stopped before being released shouldn't fling the node.
+        nodes.settle();
+        nodes.drag_at('a', Point::new(60.0, 0.0), start);
+        nodes.release_at(&'a', start + Duration::from_secs(1));
+        assert!(!nodes.has_energy());
+    }
+}
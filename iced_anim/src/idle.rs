@@ -0,0 +1,97 @@
+//! Tracking when the user last interacted with the app, for effects that only run while idle.
+//!
+//! Ambient effects like a breathing glow or a slow drift make an app feel alive, but they're
+//! distracting while the user is working. Widgets record every mouse, keyboard, and touch event
+//! with [`record_input`], so the whole app shares one idle clock no matter which widget saw the
+//! input. The [`Ambient`](crate::widget::ambient) widget uses it to play its effect only once the
+//! app has been idle for a while, stopping as soon as input arrives.
+//!
+//! Input the widgets can't see, like a global hotkey delivered through a subscription, can be
+//! recorded by the app as well:
+//!
+//! ```rust
+//! # use std::time::Instant;
+//! iced_anim::idle::record_input();
+//! assert!(iced_anim::idle::last_input().is_some_and(|input| input <= Instant::now()));
+//! ```
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use iced::{mouse, Event};
+
+/// When the user last interacted with the app, if they have.
+static LAST_INPUT: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Records that the user interacted with the app just now.
+pub fn record_input() {
+    record_input_at(Instant::now());
+}
+
+/// Records that the user interacted with the app at the given instant, unless a later input was
+/// already recorded.
+pub fn record_input_at(now: Instant) {
+    if let Ok(mut last_input) = LAST_INPUT.lock() {
+        if last_input.map_or(true, |last_input| last_input < now) {
+            *last_input = Some(now);
+        }
+    }
+}
+
+/// When the user last interacted with the app, if they have.
+pub fn last_input() -> Option<Instant> {
+    LAST_INPUT.lock().ok().and_then(|last_input| *last_input)
+}
+
+/// How long the app has been idle as of `now`, counting from `since` if the user hasn't
+/// interacted with the app after it.
+pub fn idle_time(since: Instant, now: Instant) -> Duration {
+    let active_at = last_input().map_or(since, |last_input| last_input.max(since));
+    now.saturating_duration_since(active_at)
+}
+
+/// Whether the `event` comes from the user interacting with the app.
+///
+/// The cursor leaving the window isn't counted, since the user is no longer interacting with it.
+pub fn is_input(event: &Event) -> bool {
+    match event {
+        Event::Mouse(mouse::Event::CursorLeft) => false,
+        Event::Mouse(_) | Event::Keyboard(_) | Event::Touch(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced::{keyboard, window, Point};
+
+    use super::*;
+
+    #[test]
+    fn detects_input_events() {
+        let moved = mouse::Event::CursorMoved {
+            position: Point::ORIGIN,
+        };
+        assert!(is_input(&Event::Mouse(moved)));
+        assert!(is_input(&Event::Keyboard(
+            keyboard::Event::ModifiersChanged(keyboard::Modifiers::SHIFT)
+        )));
+        assert!(!is_input(&Event::Mouse(mouse::Event::CursorLeft)));
+        assert!(!is_input(&Event::Window(window::Event::Focused)));
+    }
+
+    /// Only the latest input should count, even when inputs are recorded out of order.
+    #[test]
+    fn idles_since_the_latest_input() {
+        let since = Instant::now();
+        let input = since + Duration::from_secs(10);
+        record_input_at(input);
+        record_input_at(since);
+        assert!(last_input().is_some_and(|last_input| last_input >= input));
+
+        let now = input + Duration::from_secs(5);
+        assert!(idle_time(since, now) <= Duration::from_secs(5));
+        assert_eq!(idle_time(now, now), Duration::ZERO);
+    }
+}
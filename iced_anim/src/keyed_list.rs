@@ -0,0 +1,515 @@
+//! Track a list of keyed items between views to animate insertions, removals, and moves.
+//!
+//! Animating a list means knowing what happened to each item since the last view: which items
+//! are new, which are gone, and which moved. A [`KeyedList`] matches items by a key, like an id,
+//! each time it's given the latest items with [`KeyedList::diff`]. Every item gets an [`Entry`]
+//! with a spring for how present it is and a spring for its bounds within the list:
+//!
+//! - Inserted items fade in from nothing and are placed right where they belong.
+//! - Removed items stay in the list, at their place relative to their old neighbors, while they
+//!   fade out. They're dropped by [`KeyedList::take_exited`] once they've faded away.
+//! - Moved items spring from their old bounds to their new ones once they're placed again.
+//!
+//! Only the items that actually moved relative to the others are reported as moved, so moving a
+//! single item to the top of a long list doesn't report every other item as moved too.
+//!
+//! ```rust
+//! # use iced_anim::{keyed_list::{Change, KeyedList}, SpringMotion};
+//! let mut list = KeyedList::new(["a", "b", "c"], |item| *item, SpringMotion::Smooth);
+//! list.diff(["c", "a", "d"], |item| *item);
+//!
+//! let changes: Vec<_> = list.entries().iter().map(|entry| (entry.key, entry.change())).collect();
+//! assert_eq!(
+//!     changes,
+//!     vec![
+//!         ("c", Some(Change::Moved)),
+//!         ("a", None),
+//!         ("b", Some(Change::Removed)),
+//!         ("d", Some(Change::Inserted)),
+//!     ]
+//! );
+//! ```
+//!
+//! The [`keyed_column`](crate::widget::keyed_column) widget is built on a [`KeyedList`], and
+//! other widgets that animate lists can use it the same way: diff the items in `Widget::diff`,
+//! place each live entry during layout, tick the list on every frame, and draw each entry with
+//! its [transformation](Entry::transformation) and presence.
+//!
+//! # Moving entries
+//!
+//! Moves are animated with the FLIP technique: First, Last, Invert, Play. The bounds an entry is
+//! drawn at are the first bounds, and the bounds it's given by the next layout are the last
+//! bounds. The entry is still laid out at its last bounds, so it's interactive where it's headed,
+//! but it's drawn with a transformation that inverts the move and places it back at the first
+//! bounds. Playing the move then springs that transformation away. Since the first bounds are
+//! wherever the entry is drawn right now, entries that move again mid-flight, like while sorting
+//! and filtering in quick succession, continue smoothly from where they are.
+//!
+//! Each entry can have its own motion with [`Entry::set_motion`], like a snappier motion for
+//! the item that was just picked, while the rest follow the motion of the list.
+use std::time::Instant;
+
+use iced::{Point, Rectangle, Transformation};
+
+use crate::{Spring, SpringMotion};
+
+/// What happened to an entry during the latest diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// The item is new to the list.
+    Inserted,
+    /// The item was removed from the list and is on its way out.
+    Removed,
+    /// The item moved relative to the other items.
+    Moved,
+}
+
+/// An item of a [`KeyedList`] with its animations.
+#[derive(Debug)]
+pub struct Entry<K, T> {
+    /// The key that matches the item between diffs.
+    pub key: K,
+    /// The latest version of the item, which removed items keep while they fade out.
+    pub item: T,
+    /// How present the item is, from 0 when it's gone to 1 when it's fully shown.
+    presence: Spring<f32>,
+    /// The bounds the item is drawn at, which spring toward the bounds of the latest layout.
+    bounds: Spring<Rectangle>,
+    /// The motion of the item, if it doesn't follow the motion of the list.
+    motion: Option<SpringMotion>,
+    /// What happened to the item during the latest diff.
+    change: Option<Change>,
+    /// Whether the item has been placed since it was inserted.
+    is_placed: bool,
+}
+
+impl<K, T> Entry<K, T> {
+    /// Creates an entry that is already shown.
+    fn new(key: K, item: T, motion: SpringMotion) -> Self {
+        Self {
+            key,
+            item,
+            presence: Spring::new(1.0).with_motion(motion),
+            bounds: Spring::new(Rectangle::default()).with_motion(motion),
+            motion: None,
+            change: None,
+            is_placed: false,
+        }
+    }
+
+    /// Creates an entry that fades into view.
+    fn inserted(key: K, item: T, motion: SpringMotion) -> Self {
+        let mut entry = Self::new(key, item, motion);
+        entry.presence.settle_at(0.0);
+        entry.presence.interrupt(1.0);
+        entry.change = Some(Change::Inserted);
+        entry
+    }
+
+    /// What happened to the entry during the latest diff, if anything.
+    pub fn change(&self) -> Option<Change> {
+        self.change
+    }
+
+    /// Whether the entry was removed and is on its way out.
+    pub fn is_exiting(&self) -> bool {
+        self.change == Some(Change::Removed)
+    }
+
+    /// How present the entry is, from 0 when it's gone to 1 when it's fully shown.
+    pub fn presence(&self) -> f32 {
+        *self.presence.value()
+    }
+
+    /// The bounds the entry is drawn at, relative to the list.
+    pub fn bounds(&self) -> Rectangle {
+        *self.bounds.value()
+    }
+
+    /// The bounds the entry was given by the latest layout, relative to the list.
+    pub fn target_bounds(&self) -> Rectangle {
+        *self.bounds.target()
+    }
+
+    /// The transformation that draws the entry, laid out at its target bounds, at the bounds it's
+    /// currently animating through. The list is laid out at the given `origin`.
+    ///
+    /// The scale follows the width of the entry, since transformations can only scale uniformly.
+    pub fn transformation(&self, origin: Point) -> Transformation {
+        let first = self.bounds();
+        let last = self.target_bounds();
+        if first == last {
+            return Transformation::IDENTITY;
+        }
+
+        let scale = if last.width > 0.0 {
+            first.width / last.width
+        } else {
+            1.0
+        };
+        Transformation::translate(origin.x + first.x, origin.y + first.y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-origin.x - last.x, -origin.y - last.y)
+    }
+
+    /// Places the entry at the `bounds` given by a layout, relative to the list.
+    ///
+    /// Entries appear right at their first bounds and spring from wherever they're drawn to
+    /// every bounds after that. Removed entries stay where they were.
+    pub fn place(&mut self, bounds: Rectangle) {
+        if self.is_exiting() {
+            return;
+        }
+
+        if !self.is_placed {
+            self.is_placed = true;
+            self.bounds.settle_at(bounds);
+        } else if *self.bounds.target() != bounds {
+            self.bounds.interrupt(bounds);
+        }
+    }
+
+    /// The motion of the entry, if it doesn't follow the motion of the list.
+    pub fn motion(&self) -> Option<SpringMotion> {
+        self.motion
+    }
+
+    /// Sets the motion of the entry instead of following the motion of the list.
+    pub fn set_motion(&mut self, motion: SpringMotion) {
+        self.motion = Some(motion);
+        self.apply_motion(motion);
+    }
+
+    /// Sets the motion of the entry's animations.
+    fn apply_motion(&mut self, motion: SpringMotion) {
+        self.presence.set_motion(motion);
+        self.bounds.set_motion(motion);
+    }
+
+    /// Whether the entry has finished exiting and can be dropped.
+    fn has_exited(&self) -> bool {
+        self.is_exiting() && !self.presence.has_energy()
+    }
+}
+
+/// A list of keyed items that tracks insertions, removals, and moves between diffs.
+#[derive(Debug)]
+pub struct KeyedList<K, T> {
+    entries: Vec<Entry<K, T>>,
+    motion: SpringMotion,
+}
+
+impl<K, T> KeyedList<K, T>
+where
+    K: PartialEq,
+{
+    /// Creates a new [`KeyedList`] showing the `items`, matched by the given `key`.
+    pub fn new(
+        items: impl IntoIterator<Item = T>,
+        key: impl Fn(&T) -> K,
+        motion: SpringMotion,
+    ) -> Self {
+        Self {
+            entries: items
+                .into_iter()
+                .map(|item| Entry::new(key(&item), item, motion))
+                .collect(),
+            motion,
+        }
+    }
+
+    /// The entries of the list in order, including removed entries that are on their way out.
+    pub fn entries(&self) -> &[Entry<K, T>] {
+        &self.entries
+    }
+
+    /// The entries of the list in order, to place them during layout.
+    pub fn entries_mut(&mut self) -> &mut [Entry<K, T>] {
+        &mut self.entries
+    }
+
+    /// The entries that are part of the list, in order, without those on their way out.
+    pub fn live(&self) -> impl Iterator<Item = &Entry<K, T>> {
+        self.entries.iter().filter(|entry| !entry.is_exiting())
+    }
+
+    /// Returns the motion of the list's animations.
+    pub fn motion(&self) -> SpringMotion {
+        self.motion
+    }
+
+    /// Sets the motion of the list's animations, which applies to every entry without a motion
+    /// of its own.
+    pub fn set_motion(&mut self, motion: SpringMotion) {
+        self.motion = motion;
+        for entry in &mut self.entries {
+            entry.apply_motion(entry.motion.unwrap_or(motion));
+        }
+    }
+
+    /// Updates the list with the latest `items`, matched to the previous items by their `key`.
+    ///
+    /// Returns the index each entry had before the diff, or `None` for inserted entries, so
+    /// state kept alongside the entries can be reordered to match.
+    pub fn diff(
+        &mut self,
+        items: impl IntoIterator<Item = T>,
+        key: impl Fn(&T) -> K,
+    ) -> Vec<Option<usize>> {
+        let previous = std::mem::take(&mut self.entries);
+        let mut matched: Vec<Option<usize>> = vec![None; previous.len()];
+        let mut previous: Vec<Option<Entry<K, T>>> = previous.into_iter().map(Some).collect();
+
+        // Match each item to the live entry with the same key.
+        let mut entries = Vec::new();
+        let mut sources = Vec::new();
+        for item in items {
+            let key = key(&item);
+            let index = previous.iter().position(|entry| {
+                entry
+                    .as_ref()
+                    .is_some_and(|entry| !entry.is_exiting() && entry.key == key)
+            });
+
+            match index.and_then(|index| previous[index].take().map(|entry| (index, entry))) {
+                Some((index, mut entry)) => {
+                    matched[index] = Some(entries.len());
+                    entry.item = item;
+                    entry.change = None;
+                    entries.push(entry);
+                    sources.push(Some(index));
+                }
+                None => {
+                    entries.push(Entry::inserted(key, item, self.motion));
+                    sources.push(None);
+                }
+            }
+        }
+
+        // Items that kept their relative order stay put, while the rest moved.
+        let order: Vec<usize> = sources.iter().flatten().copied().collect();
+        let kept = longest_increasing(&order);
+        for (entry, source) in entries.iter_mut().zip(&sources) {
+            if source.is_some_and(|source| !kept.contains(&source)) {
+                entry.change = Some(Change::Moved);
+            }
+        }
+
+        // Removed entries stay after the nearest entry that was before them.
+        let mut exiting: Vec<Vec<(usize, Entry<K, T>)>> =
+            (0..=entries.len()).map(|_| Vec::new()).collect();
+        let mut anchor = 0;
+        for (index, entry) in previous.into_iter().enumerate() {
+            match entry {
+                Some(mut entry) => {
+                    if !entry.is_exiting() {
+                        entry.change = Some(Change::Removed);
+                        entry.presence.interrupt(0.0);
+                    }
+                    exiting[anchor].push((index, entry));
+                }
+                None => anchor = matched[index].map_or(anchor, |matched| matched + 1),
+            }
+        }
+
+        let mut groups = exiting.into_iter();
+        let mut ordered = Vec::with_capacity(entries.len());
+        let mut ordered_sources = Vec::with_capacity(entries.len());
+        for (index, entry) in groups.next().into_iter().flatten() {
+            ordered.push(entry);
+            ordered_sources.push(Some(index));
+        }
+        for ((entry, source), group) in entries.into_iter().zip(sources).zip(groups) {
+            ordered.push(entry);
+            ordered_sources.push(source);
+            for (index, entry) in group {
+                ordered.push(entry);
+                ordered_sources.push(Some(index));
+            }
+        }
+
+        self.entries = ordered;
+        ordered_sources
+    }
+
+    /// Updates the animations of every entry based on the elapsed time since the latest tick.
+    pub fn tick(&mut self, now: Instant) {
+        for entry in &mut self.entries {
+            entry.presence.tick(now);
+            entry.bounds.tick(now);
+        }
+    }
+
+    /// Whether any entry is still animating, meaning the list needs to be ticked.
+    pub fn has_energy(&self) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.presence.has_energy() || entry.bounds.has_energy())
+    }
+
+    /// Drops the removed entries that have finished fading out, returning their indices in
+    /// descending order so state kept alongside the entries can be removed the same way.
+    pub fn take_exited(&mut self) -> Vec<usize> {
+        let exited: Vec<usize> = (0..self.entries.len())
+            .rev()
+            .filter(|index| self.entries[*index].has_exited())
+            .collect();
+
+        for index in &exited {
+            self.entries.remove(*index);
+        }
+        exited
+    }
+}
+
+/// The values of the longest strictly increasing subsequence of the `values`.
+fn longest_increasing(values: &[usize]) -> Vec<usize> {
+    // The index of the smallest tail of an increasing run of each length, and the index of the
+    // previous value in the run that ends at each value.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous = vec![None; values.len()];
+
+    for (index, value) in values.iter().enumerate() {
+        let length = tails.partition_point(|tail| values[*tail] < *value);
+        if length > 0 {
+            previous[index] = Some(tails[length - 1]);
+        }
+        if length == tails.len() {
+            tails.push(index);
+        } else {
+            tails[length] = index;
+        }
+    }
+
+    let mut run = Vec::with_capacity(tails.len());
+    let mut current = tails.last().copied();
+    while let Some(index) = current {
+        run.push(values[index]);
+        current = previous[index];
+    }
+    run.reverse();
+    run
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "animations-off"))]
+    use std::time::Duration;
+
+    #[cfg(not(feature = "animations-off"))]
+    use iced::Size;
+
+    use super::*;
+
+    fn changes(list: &KeyedList<char, char>) -> Vec<(char, Option<Change>)> {
+        list.entries()
+            .iter()
+            .map(|entry| (entry.key, entry.change()))
+            .collect()
+    }
+
+    /// Insertions and removals should be detected, with removed items kept in their place.
+    #[test]
+    fn detects_insertions_and_removals() {
+        let mut list = KeyedList::new(['a', 'b', 'c'], |item| *item, SpringMotion::Smooth);
+        let sources = list.diff(['a', 'x', 'c'], |item| *item);
+
+        assert_eq!(
+            changes(&list),
+            vec![
+                ('a', None),
+                ('b', Some(Change::Removed)),
+                ('x', Some(Change::Inserted)),
+                ('c', None),
+            ]
+        );
+        assert_eq!(sources, vec![Some(0), Some(1), None, Some(2)]);
+    }
+
+    /// Only the items that moved relative to the others should be reported as moved.
+    #[test]
+    fn detects_minimal_moves() {
+        let mut list = KeyedList::new(['a', 'b', 'c', 'd'], |item| *item, SpringMotion::Smooth);
+        list.diff(['d', 'a', 'b', 'c'], |item| *item);
+
+        assert_eq!(
+            changes(&list),
+            vec![
+                ('d', Some(Change::Moved)),
+                ('a', None),
+                ('b', None),
+                ('c', None),
+            ]
+        );
+    }
+
+    /// Removed entries should be dropped once they've faded out.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn drops_exited_entries() {
+        let mut list = KeyedList::new(['a', 'b', 'c'], |item| *item, SpringMotion::Snappy);
+        list.diff(['a'], |item| *item);
+        assert!(list.take_exited().is_empty());
+
+        let start = Instant::now();
+        list.tick(start);
+        for frame in 1..=300 {
+            list.tick(start + Duration::from_millis(16 * frame));
+        }
+
+        assert_eq!(list.take_exited(), vec![2, 1]);
+        assert_eq!(changes(&list), vec![('a', None)]);
+    }
+
+    /// Entries should appear at their first bounds and spring to the next ones.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn places_entries() {
+        let first = Rectangle::new(Point::new(0.0, 10.0), Size::new(100.0, 20.0));
+        let last = Rectangle::new(Point::new(0.0, 40.0), Size::new(100.0, 20.0));
+        let mut list = KeyedList::new(['a'], |item| *item, SpringMotion::Smooth);
+        list.entries_mut()[0].place(first);
+        assert_eq!(list.entries()[0].bounds(), first);
+
+        list.entries_mut()[0].place(last);
+        assert_eq!(list.entries()[0].bounds(), first);
+        assert_eq!(list.entries()[0].target_bounds(), last);
+    }
+
+    /// Entries that move again mid-flight should continue from where they're drawn.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn moves_continue_from_drawn_bounds() {
+        let size = Size::new(100.0, 20.0);
+        let mut list = KeyedList::new(['a'], |item| *item, SpringMotion::Smooth);
+        list.entries_mut()[0].place(Rectangle::new(Point::new(0.0, 0.0), size));
+        list.entries_mut()[0].place(Rectangle::new(Point::new(0.0, 100.0), size));
+
+        let start = Instant::now();
+        list.tick(start);
+        list.tick(start + Duration::from_millis(100));
+        let drawn = list.entries()[0].bounds();
+        assert!(drawn.y > 0.0 && drawn.y < 100.0);
+
+        list.entries_mut()[0].place(Rectangle::new(Point::new(0.0, 50.0), size));
+        assert_eq!(list.entries()[0].bounds(), drawn);
+    }
+
+    /// Entries with their own motion should keep it when the motion of the list changes.
+    #[test]
+    fn entries_keep_their_motion() {
+        let mut list = KeyedList::new(['a', 'b'], |item| *item, SpringMotion::Smooth);
+        list.entries_mut()[0].set_motion(SpringMotion::Bouncy);
+        list.set_motion(SpringMotion::Snappy);
+
+        assert_eq!(list.entries()[0].bounds.motion(), SpringMotion::Bouncy);
+        assert_eq!(list.entries()[1].bounds.motion(), SpringMotion::Snappy);
+    }
+
+    #[test]
+    fn longest_increasing_run() {
+        assert_eq!(longest_increasing(&[3, 0, 1, 2]), vec![0, 1, 2]);
+        assert_eq!(longest_increasing(&[2, 1, 0]), vec![0]);
+        assert!(longest_increasing(&[]).is_empty());
+    }
+}
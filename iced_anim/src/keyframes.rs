@@ -0,0 +1,426 @@
+//! Scripted animations through a sequence of values.
+//!
+//! Springs are great at reacting to changes, but some animations are scripted ahead of time, like
+//! a logo intro that grows, pauses, and then settles into place. [`Keyframes`] play through a
+//! list of values, each reached after a fixed duration with its own [`Easing`].
+//!
+//! ```rust
+//! # use std::time::{Duration, Instant};
+//! # use iced_anim::{Easing, Keyframes};
+//! let mut scale = Keyframes::new(0.0)
+//!     .then(1.2, Duration::from_millis(300), Easing::EaseOut)
+//!     .hold(Duration::from_millis(100))
+//!     .then(1.0, Duration::from_millis(200), Easing::EaseInOut);
+//!
+//! let start = Instant::now();
+//! scale.play_at(start);
+//! scale.tick(start + Duration::from_millis(350));
+//! assert_eq!(scale.value(), &1.2);
+//!
+//! scale.tick(start + Duration::from_millis(600));
+//! assert_eq!(scale.value(), &1.0);
+//! assert!(!scale.is_playing());
+//! ```
+//!
+//! Keyframes are driven by the same widgets as springs. Store them in your state and drive them
+//! with [`Animation::keyframes`](crate::Animation::keyframes), which produces [`SpringEvent`]s to
+//! pass to [`Keyframes::update`], or let [`AnimationBuilder::keyframes`](crate::AnimationBuilder)
+//! play them without any state of your own.
+use std::time::{Duration, Instant};
+
+use crate::{Animate, Easing, Repeat, SpringEvent};
+
+/// A sequence of values to animate through, each reached after a fixed duration.
+#[derive(Debug, Clone)]
+pub struct Keyframes<T: Animate> {
+    /// The value the animation starts from.
+    start: T,
+    /// The values to animate through after the start.
+    frames: Vec<Keyframe<T>>,
+    /// How many times the animation plays, if more than once.
+    repeat: Option<Repeat>,
+    /// Whether each play goes back through the keyframes in reverse after reaching the end.
+    yoyo: bool,
+    /// When the animation started playing, if it's playing.
+    started_at: Option<Instant>,
+    /// The current value of the animation.
+    value: T,
+}
+
+/// A single value in [`Keyframes`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe<T> {
+    /// The value to animate to.
+    pub value: T,
+    /// How long it takes to reach the value from the previous one.
+    pub duration: Duration,
+    /// The easing between the previous value and this one.
+    pub easing: Easing,
+}
+
+impl<T: Animate> Keyframes<T> {
+    /// Creates new [`Keyframes`] starting at the `initial` value.
+    pub fn new(initial: T) -> Self {
+        Self {
+            start: initial.clone(),
+            frames: Vec::new(),
+            repeat: None,
+            yoyo: false,
+            started_at: None,
+            value: initial,
+        }
+    }
+
+    /// Animates to the `value` over the `duration` after the previous keyframe.
+    pub fn then(mut self, value: T, duration: Duration, easing: Easing) -> Self {
+        self.frames.push(Keyframe {
+            value,
+            duration,
+            easing,
+        });
+        self
+    }
+
+    /// Animates to the `value` so it's reached `time` after the start of the animation.
+    ///
+    /// Times that come before the end of the previous keyframe jump straight to the value.
+    pub fn at(self, time: Duration, value: T, easing: Easing) -> Self {
+        let duration = time.saturating_sub(self.duration());
+        self.then(value, duration, easing)
+    }
+
+    /// Holds the previous value for the `duration`.
+    pub fn hold(self, duration: Duration) -> Self {
+        let value = self.end().clone();
+        self.then(value, duration, Easing::Linear)
+    }
+
+    /// Plays the animation more than once, starting over from the initial value each time.
+    pub fn with_repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = Some(repeat);
+        self
+    }
+
+    /// How many times the animation plays, if more than once.
+    pub fn repeat(&self) -> Option<Repeat> {
+        self.repeat
+    }
+
+    /// Plays back through the keyframes in reverse after reaching the end, so each play ends
+    /// at the initial value.
+    pub fn with_yoyo(mut self, yoyo: bool) -> Self {
+        self.yoyo = yoyo;
+        self
+    }
+
+    /// Whether each play goes back through the keyframes in reverse after reaching the end.
+    pub fn yoyo(&self) -> bool {
+        self.yoyo
+    }
+
+    /// The keyframes after the initial value.
+    pub fn frames(&self) -> &[Keyframe<T>] {
+        &self.frames
+    }
+
+    /// The total duration of the animation.
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.duration).sum()
+    }
+
+    /// The duration of a single play, including the way back for yoyo animations.
+    fn play_duration(&self) -> Duration {
+        if self.yoyo {
+            self.duration() * 2
+        } else {
+            self.duration()
+        }
+    }
+
+    /// The current value of the animation.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The value at the end of the animation.
+    pub fn end(&self) -> &T {
+        self.frames.last().map_or(&self.start, |frame| &frame.value)
+    }
+
+    /// The value the animation rests at once it stops playing, which is the initial value for
+    /// yoyo animations.
+    pub fn rest(&self) -> &T {
+        if self.yoyo {
+            &self.start
+        } else {
+            self.end()
+        }
+    }
+
+    /// Whether the animation is playing.
+    pub fn is_playing(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    /// Whether the animation is playing and needs to be ticked, like [`Spring::has_energy`].
+    ///
+    /// [`Spring::has_energy`]: crate::Spring::has_energy
+    pub fn has_energy(&self) -> bool {
+        self.is_playing()
+    }
+
+    /// Plays the animation from the start.
+    pub fn play(&mut self) {
+        self.play_at(Instant::now());
+    }
+
+    /// Plays the animation from the start as of the given instant.
+    ///
+    /// The animation jumps straight to the end while animations are disabled with
+    /// [`set_animations_enabled`](crate::set_animations_enabled).
+    pub fn play_at(&mut self, now: Instant) {
+        if !crate::animations_enabled() {
+            self.settle();
+            return;
+        }
+
+        self.value = self.start.clone();
+        self.started_at = Some(now);
+    }
+
+    /// Stops the animation at its current value.
+    pub fn stop(&mut self) {
+        self.started_at = None;
+    }
+
+    /// Jumps to the end of the animation, or back to the initial value for yoyo animations.
+    pub fn settle(&mut self) {
+        self.value = self.rest().clone();
+        self.started_at = None;
+    }
+
+    /// Updates the value of a playing animation to the given instant, stopping at the end.
+    pub fn tick(&mut self, now: Instant) {
+        let Some(started_at) = self.started_at else {
+            return;
+        };
+
+        let mut elapsed = now.saturating_duration_since(started_at);
+        let duration = self.play_duration();
+        if let Some(repeat) = self.repeat.filter(|_| !duration.is_zero()) {
+            let plays = elapsed.as_nanos() / duration.as_nanos();
+            let plays = u32::try_from(plays).unwrap_or(u32::MAX);
+            if repeat.plays_again(plays) {
+                elapsed -= duration * plays;
+            }
+        }
+
+        let is_finished = elapsed >= duration;
+        let elapsed = elapsed.min(duration);
+        self.value = if self.yoyo && elapsed > self.duration() {
+            self.value_at(duration - elapsed)
+        } else {
+            self.value_at(elapsed)
+        };
+        if is_finished {
+            self.started_at = None;
+        }
+    }
+
+    /// The value of the animation the given time after it starts.
+    pub fn value_at(&self, elapsed: Duration) -> T {
+        let mut from = &self.start;
+        let mut remaining = elapsed;
+        for frame in &self.frames {
+            if remaining < frame.duration {
+                let progress = remaining.as_secs_f32() / frame.duration.as_secs_f32();
+                return interpolate(from, &frame.value, frame.easing.ease(progress));
+            }
+            remaining -= frame.duration;
+            from = &frame.value;
+        }
+
+        from.clone()
+    }
+
+    /// Updates the animation with a [`SpringEvent`], like [`Spring::update`].
+    ///
+    /// Since keyframes follow a script rather than chasing a target, a target event jumps
+    /// straight to the new value and stops the animation.
+    ///
+    /// [`Spring::update`]: crate::Spring::update
+    pub fn update(&mut self, event: SpringEvent<T>) {
+        match event {
+            SpringEvent::Tick(now) => self.tick(now),
+            SpringEvent::Target(value) => {
+                self.value = value;
+                self.started_at = None;
+            }
+            SpringEvent::Settle => self.settle(),
+        }
+    }
+
+    /// Whether both animations play through the same values, regardless of playback.
+    pub(crate) fn has_same_frames(&self, other: &Self) -> bool {
+        self.start == other.start
+            && self.frames == other.frames
+            && self.repeat == other.repeat
+            && self.yoyo == other.yoyo
+    }
+}
+
+/// The value `progress` of the way from `from` to `to`.
+pub(crate) fn interpolate<T: Animate>(from: &T, to: &T, progress: f32) -> T {
+    let mut value = from.clone();
+    let distance = from.distance_to(to);
+    value.update(&mut distance.into_iter().map(|component| -component * progress));
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intro() -> Keyframes<f32> {
+        Keyframes::new(0.0)
+            .then(10.0, Duration::from_millis(100), Easing::Linear)
+            .hold(Duration::from_millis(100))
+            .then(20.0, Duration::from_millis(200), Easing::Linear)
+    }
+
+    #[test]
+    fn interpolates_between_keyframes() {
+        let keyframes = intro();
+        assert_eq!(keyframes.duration(), Duration::from_millis(400));
+        assert_eq!(keyframes.value_at(Duration::ZERO), 0.0);
+        assert_eq!(keyframes.value_at(Duration::from_millis(50)), 5.0);
+        assert_eq!(keyframes.value_at(Duration::from_millis(150)), 10.0);
+        assert_eq!(keyframes.value_at(Duration::from_millis(300)), 15.0);
+        assert_eq!(keyframes.value_at(Duration::from_secs(1)), 20.0);
+    }
+
+    #[test]
+    fn applies_easing_per_segment() {
+        let keyframes = Keyframes::new(0.0)
+            .then(1.0, Duration::from_millis(100), Easing::EaseIn)
+            .then(2.0, Duration::from_millis(100), Easing::EaseOut);
+        assert!(keyframes.value_at(Duration::from_millis(50)) < 0.5);
+        assert!(keyframes.value_at(Duration::from_millis(150)) > 1.5);
+    }
+
+    #[test]
+    fn places_keyframes_at_timestamps() {
+        let keyframes = Keyframes::new(0.0)
+            .at(Duration::from_millis(100), 1.0, Easing::Linear)
+            .at(Duration::from_millis(300), 2.0, Easing::Linear)
+            .at(Duration::from_millis(200), 3.0, Easing::Linear);
+        let durations: Vec<_> = keyframes.frames().iter().map(|f| f.duration).collect();
+        assert_eq!(
+            durations,
+            [
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::ZERO
+            ]
+        );
+        assert_eq!(keyframes.end(), &3.0);
+    }
+
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn plays_until_the_end() {
+        let mut keyframes = intro();
+        let start = Instant::now();
+        assert!(!keyframes.has_energy());
+
+        keyframes.play_at(start);
+        assert!(keyframes.has_energy());
+        keyframes.tick(start + Duration::from_millis(50));
+        assert_eq!(keyframes.value(), &5.0);
+
+        keyframes.tick(start + Duration::from_millis(400));
+        assert_eq!(keyframes.value(), &20.0);
+        assert!(!keyframes.has_energy());
+    }
+
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn repeats_from_the_start() {
+        let mut keyframes = intro().with_repeat(Repeat::Times(2));
+        let start = Instant::now();
+        keyframes.play_at(start);
+
+        keyframes.tick(start + Duration::from_millis(450));
+        assert_eq!(keyframes.value(), &5.0);
+        assert!(keyframes.is_playing());
+
+        keyframes.tick(start + Duration::from_millis(800));
+        assert_eq!(keyframes.value(), &20.0);
+        assert!(!keyframes.is_playing());
+    }
+
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn yoyo_plays_back_in_reverse() {
+        let mut keyframes = intro().with_yoyo(true).with_repeat(Repeat::Times(2));
+        let start = Instant::now();
+        keyframes.play_at(start);
+
+        keyframes.tick(start + Duration::from_millis(500));
+        assert_eq!(keyframes.value(), &15.0);
+        keyframes.tick(start + Duration::from_millis(750));
+        assert_eq!(keyframes.value(), &5.0);
+        keyframes.tick(start + Duration::from_millis(850));
+        assert_eq!(keyframes.value(), &5.0);
+        assert!(keyframes.is_playing());
+
+        keyframes.tick(start + Duration::from_millis(1600));
+        assert_eq!(keyframes.value(), &0.0);
+        assert!(!keyframes.is_playing());
+
+        keyframes.play_at(start);
+        keyframes.settle();
+        assert_eq!(keyframes.value(), keyframes.rest());
+        assert_eq!(keyframes.value(), &0.0);
+    }
+
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn replays_from_the_start() {
+        let mut keyframes = intro();
+        keyframes.settle();
+        assert_eq!(keyframes.value(), &20.0);
+
+        let start = Instant::now();
+        keyframes.play_at(start);
+        assert_eq!(keyframes.value(), &0.0);
+    }
+
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn updates_with_spring_events() {
+        let mut keyframes = intro();
+        let start = Instant::now();
+        keyframes.play_at(start);
+
+        keyframes.update(SpringEvent::Tick(start + Duration::from_millis(100)));
+        assert_eq!(keyframes.value(), &10.0);
+
+        keyframes.update(SpringEvent::Settle);
+        assert_eq!(keyframes.value(), &20.0);
+        assert!(!keyframes.is_playing());
+
+        keyframes.play_at(start);
+        keyframes.update(SpringEvent::Target(7.0));
+        assert_eq!(keyframes.value(), &7.0);
+        assert!(!keyframes.is_playing());
+    }
+
+    #[test]
+    fn compares_frames_regardless_of_playback() {
+        let mut playing = intro();
+        playing.play_at(Instant::now());
+        assert!(playing.has_same_frames(&intro()));
+        assert!(!playing.has_same_frames(&Keyframes::new(0.0)));
+    }
+}
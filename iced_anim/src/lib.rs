@@ -44,31 +44,209 @@
 //! }
 //! ```
 //!
+//! Adding `#[animate(component_names)]` to a derived type also generates a `component_names`
+//! function that labels each animated component, which is handy for debugging:
+//!
+//! ```rust
+//! use iced_anim::Animate;
+//!
+//! #[derive(Animate, Clone, PartialEq)]
+//! #[animate(component_names)]
+//! struct MyType {
+//!     size: f32,
+//!     color: iced::Color,
+//! }
+//!
+//! assert_eq!(MyType::component_names()[0], "size");
+//! assert_eq!(MyType::component_names()[1], "color[0]");
+//! ```
+//!
+//! Fields can also be grouped with `#[animate(group = "name")]`. Each group settles on its own,
+//! so retargeting one group, like the position of a card, doesn't restart an ongoing animation
+//! of another group, like its color, even though both live in the same [`Spring`]:
+//!
+//! ```rust
+//! use iced_anim::Animate;
+//!
+//! #[derive(Animate, Clone, PartialEq)]
+//! struct Card {
+//!     #[animate(group = "position")]
+//!     x: f32,
+//!     #[animate(group = "position")]
+//!     y: f32,
+//!     color: iced::Color,
+//! }
+//!
+//! assert_eq!(Card::component_groups(), vec![1, 1, 0, 0, 0, 0]);
+//! ```
+//!
+//! Each group can also follow a motion of its own with [`Spring::with_group_motion`], like a
+//! bouncy position and a snappy color.
+//!
 //! ## Controlling the spring motion
 //!
 //! The spring motion of an [`AnimationBuilder`] can be customized. There are a few
-//! defaults like [`SpringMotion::Smooth`] and [`SpringMotion::Bouncy`], but you can
-//! provide a custom response and damping fraction with [`SpringMotion::Custom`].
+//! defaults like [`SpringMotion::Smooth`] and [`SpringMotion::Bouncy`], along with presets
+//! named after those of react-spring like [`SpringMotion::Gentle`] and [`SpringMotion::Wobbly`],
+//! but you can provide a custom response and damping fraction with [`SpringMotion::Custom`].
+//! Designs that require exact durations can use [`SpringMotion::Curve`] instead, which runs a
+//! fixed-duration tween along an [`Easing`] curve like `ease-in-out` or `cubic-bezier`.
+//!
+//! Hot paths that can't afford to solve a spring every frame, like shaders, can sample a
+//! [`MotionTable`] instead, which the [`motion_table!`] macro precomputes at compile time.
+//!
+//! ## Keyframes
+//!
+//! Scripted animations that step through several values with exact timing, like a logo intro,
+//! can be described with [`Keyframes`], using an [`Easing`] curve for each step. They're driven
+//! by the same [`Animation`] and [`AnimationBuilder`] widgets as springs.
+//!
+//! Scenes that animate many values at once, like an onboarding flow, can place keyframes for
+//! each value on the tracks of a [`Timeline`], which plays, pauses, and seeks all of them on one
+//! shared clock.
+//!
+//! Motion without a target, like the glide of a map after it's flung, can be animated with a
+//! [`Decay`], which slows a value down from an initial velocity with friction until it comes to
+//! rest.
+//!
+//! Looping effects that should only run while the user is away, like a breathing glow, can be
+//! played by the `Ambient` widget once the app has been idle for a while. It stops as soon as
+//! the user interacts with the app again, which is tracked by the [`idle`] module.
+//!
+//! ## Disabling animations
+//!
+//! Enable the `animations-off` feature to build a version of your app without animations, e.g.
+//! for low-end hardware. Every [`Spring`] then jumps straight to its target, so [`Animation`],
+//! [`AnimationBuilder`], and the animated widgets apply changes immediately and never request
+//! extra frames, without any changes to your code. Indeterminate progress indicators still loop,
+//! since they're the only sign that work is happening.
+//!
+//! To let users turn animations off while the app is running, call [`set_animations_enabled`]
+//! instead. The setting applies to every spring immediately, including ongoing animations.
+//!
+//! Springs also jump to their targets when the app stops redrawing for a while, like when the
+//! window is minimized or the computer sleeps, see [`set_suspend_threshold`].
+//!
+//! Apps with many animations at once, like data-dense dashboards, can set an animation budget
+//! with [`budget::set_animation_budget`]. Springs marked with [`budget::Priority::Low`] jump to
+//! their targets while more springs are animating than the budget allows.
+//!
+//! ## Sharing animations
+//!
+//! A [`SharedSpring`] is a handle to a single spring that can be cloned into several widgets,
+//! like a zoomable view and its minimap, so they all draw the same animated value each frame.
+//!
+//! An [`AnimationGroup`] holds several springs under keys, with a task that continues once all
+//! of them have settled, e.g. to delete items after they finish animating out.
+//!
+//! Animations that happen in steps can be chained with a [`Sequence`], which animates a spring
+//! through several stages in order and emits messages as each stage and the whole sequence
+//! complete.
+//!
+//! ## Animating lists
+//!
+//! A [`KeyedList`] matches items between views by a key to find the items that were inserted,
+//! removed, or moved, with springs to animate each of them. The
+//! [`animated_keyed_column`](widget::animated_keyed_column) widget uses it to animate a column of
+//! items, and other list widgets can build on it the same way.
+//!
+//! Live charts can keep their data in an [`AnimatedSeries`](series::AnimatedSeries), which morphs
+//! the plotted line into each new batch of data, even as points are added or removed. Their axes
+//! can rescale along with it in an [`AnimatedAxis`](series::AnimatedAxis).
+//!
+//! To make the items of a list animate in a cascading wave, [`stagger::stagger`] gives each item
+//! a delay a little longer than the one before it.
+//!
+//! ## Themes
+//!
+//! A [`Spring<Theme>`](Spring) blends between themes, a [`ThemeTransition`] passes through an
+//! intermediate theme between very different ones, and a [`ThemeSchedule`] switches themes at
+//! set times of day. Enable the `system-theme` feature to follow the light or dark appearance of
+//! the operating system instead, see the `system_theme` module.
+//!
+//! ## Diagnostics
+//!
+//! Enable the `debug` feature to log warnings about animations that keep the app from going
+//! idle, see the `diagnostics` module for details. The `tracing` feature emits spans for spring
+//! ticks, retargets, and settles, as well as events when widgets request another animation frame,
+//! so animation activity can be correlated with frame times in your tracing subscriber.
 //!
 //! ## Supported Iced versions
 //!
 //! This crate supports Iced 0.13 and newer.
 pub mod animate;
+pub mod animated_component;
 pub mod animation;
 pub mod animation_builder;
+pub mod animation_group;
+pub mod animation_store;
+pub mod budget;
+#[cfg(feature = "canvas")]
+pub mod canvas;
+pub mod decay;
+#[cfg(feature = "debug")]
+pub mod diagnostics;
+pub mod easing;
+pub mod exit;
+pub mod format;
+pub mod global;
+pub mod graph;
+pub mod idle;
+pub mod keyed_list;
+pub mod keyframes;
+pub mod motion_registry;
+pub mod motion_table;
+pub mod rate_limit;
+pub mod repeat;
+pub mod rest_threshold;
+pub mod sequence;
+pub mod series;
+pub mod shared_spring;
 pub mod spring;
 pub mod spring_event;
 pub mod spring_motion;
+pub mod spring_vec;
+pub mod stagger;
+#[cfg(feature = "system-theme")]
+pub mod system_theme;
+pub mod theme_schedule;
+pub mod theme_transition;
+pub mod timeline;
+pub mod tween;
 
 #[cfg(feature = "widgets")]
 pub mod widget;
 
 pub use animate::Animate;
+pub use animated_component::AnimatedComponent;
 pub use animation::Animation;
 pub use animation_builder::*;
-pub use spring::Spring;
+pub use animation_group::AnimationGroup;
+pub use animation_store::{AnimationId, AnimationStore};
+pub use decay::Decay;
+pub use easing::Easing;
+pub use exit::ExitCoordinator;
+pub use global::{
+    animations_enabled, playback_speed, refresh_rate, set_animations_enabled, set_playback_speed,
+    set_refresh_rate, set_suspend_threshold, suspend_threshold,
+};
+pub use keyed_list::KeyedList;
+pub use keyframes::Keyframes;
+pub use motion_registry::MotionRegistry;
+pub use motion_table::MotionTable;
+pub use rate_limit::RateLimit;
+pub use repeat::Repeat;
+pub use rest_threshold::RestThreshold;
+pub use sequence::Sequence;
+pub use shared_spring::SharedSpring;
+pub use spring::{Spring, TargetStrategy};
 pub use spring_event::SpringEvent;
-pub use spring_motion::SpringMotion;
+pub use spring_motion::{MotionError, SpringMotion};
+pub use spring_vec::SpringVec;
+pub use theme_schedule::ThemeSchedule;
+pub use theme_transition::ThemeTransition;
+pub use timeline::Timeline;
+pub use tween::Tween;
 
 #[cfg(feature = "derive")]
 pub use iced_anim_derive::Animate;
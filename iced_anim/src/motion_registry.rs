@@ -0,0 +1,193 @@
+//! Named spring motions that can be shared and tweaked across an app.
+//!
+//! A [`MotionRegistry`] maps names like `"button"` or `"sidebar"` to a [`SpringMotion`], so that
+//! the motion of related animations is defined in one place. Looking up a name that isn't in the
+//! registry falls back to the default motion, which keeps animations working while tokens are
+//! still being defined.
+//!
+//! With the `dev-tools` feature enabled, the registry can be loaded from a JSON file and reloaded
+//! whenever that file changes with [`MotionRegistry::watch`]. This lets designers tweak springs in
+//! a text editor and see the app update live. Each token in the file has a `response` in seconds
+//! and a `damping` fraction:
+//!
+//! ```json
+//! {
+//!     "button": { "response": 0.25, "damping": 0.9 },
+//!     "sidebar": { "response": 0.5, "damping": 0.75 }
+//! }
+//! ```
+use std::collections::HashMap;
+
+use crate::SpringMotion;
+
+/// A collection of spring motions looked up by name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MotionRegistry {
+    motions: HashMap<String, SpringMotion>,
+}
+
+impl MotionRegistry {
+    /// Creates an empty [`MotionRegistry`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an updated registry with the `motion` registered under the given `name`.
+    pub fn with(mut self, name: impl Into<String>, motion: SpringMotion) -> Self {
+        self.set(name, motion);
+        self
+    }
+
+    /// Registers the `motion` under the given `name`, replacing any existing motion.
+    pub fn set(&mut self, name: impl Into<String>, motion: SpringMotion) {
+        self.motions.insert(name.into(), motion);
+    }
+
+    /// The motion registered under the given `name`, or the default motion if there isn't one.
+    pub fn get(&self, name: &str) -> SpringMotion {
+        self.motions.get(name).copied().unwrap_or_default()
+    }
+
+    /// Whether a motion is registered under the given `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.motions.contains_key(name)
+    }
+
+    /// Merges the motions of `other` into this registry, replacing any with the same name.
+    pub fn merge(&mut self, other: MotionRegistry) {
+        self.motions.extend(other.motions);
+    }
+}
+
+#[cfg(feature = "dev-tools")]
+mod dev_tools {
+    use std::{
+        collections::HashMap,
+        path::{Path, PathBuf},
+        time::{Duration, SystemTime},
+    };
+
+    use iced::{futures::channel::mpsc, Subscription};
+
+    use super::MotionRegistry;
+    use crate::SpringMotion;
+
+    /// How often a watched file is checked for changes.
+    const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+    /// A single motion token as written in a motion file.
+    #[derive(serde::Deserialize)]
+    struct MotionToken {
+        /// The response of the spring in seconds.
+        response: f32,
+        /// The damping fraction of the spring.
+        damping: f32,
+    }
+
+    impl MotionRegistry {
+        /// Parses a registry from JSON mapping names to a `response` in seconds and a `damping`
+        /// fraction. Invalid parameters are clamped with [`SpringMotion::custom_clamped`].
+        pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+            let tokens: HashMap<String, MotionToken> = serde_json::from_str(json)?;
+            let motions = tokens
+                .into_iter()
+                .map(|(name, token)| {
+                    let response = Duration::from_secs_f32(token.response.max(0.0));
+                    (name, SpringMotion::custom_clamped(response, token.damping))
+                })
+                .collect();
+
+            Ok(Self { motions })
+        }
+
+        /// Loads a registry from the JSON file at the given `path`.
+        pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+            let json = std::fs::read_to_string(path)?;
+            Self::from_json(&json).map_err(std::io::Error::from)
+        }
+
+        /// Watches the JSON file at the given `path`, producing a new registry when it first
+        /// loads and whenever it changes afterward.
+        ///
+        /// Files that fail to load are logged and skipped, so a typo while editing doesn't
+        /// interrupt the app. This is intended for development and polls the file in a
+        /// background thread.
+        pub fn watch(path: impl Into<PathBuf>) -> Subscription<MotionRegistry> {
+            let path = path.into();
+            Subscription::run_with_id(path.clone(), watch(path))
+        }
+    }
+
+    /// Polls the file at `path` for changes, sending the reloaded registry through a channel.
+    fn watch(path: PathBuf) -> mpsc::UnboundedReceiver<MotionRegistry> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        std::thread::spawn(move || {
+            let mut last_modified: Option<SystemTime> = None;
+            while !sender.is_closed() {
+                let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+                if modified.is_some() && modified != last_modified {
+                    last_modified = modified;
+                    match MotionRegistry::load(&path) {
+                        Ok(registry) => {
+                            let _ = sender.unbounded_send(registry);
+                        }
+                        Err(error) => {
+                            log::warn!("Failed to load motions from {}: {error}", path.display());
+                        }
+                    }
+                }
+
+                std::thread::sleep(POLL_INTERVAL);
+            }
+        });
+
+        receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Names that haven't been registered should fall back to the default motion.
+    #[test]
+    fn get_falls_back_to_default() {
+        let registry = MotionRegistry::new().with("button", SpringMotion::Bouncy);
+        assert_eq!(registry.get("button"), SpringMotion::Bouncy);
+        assert_eq!(registry.get("sidebar"), SpringMotion::default());
+        assert!(!registry.contains("sidebar"));
+    }
+
+    /// Merging should replace motions with the same name and keep the others.
+    #[test]
+    fn merge() {
+        let mut registry = MotionRegistry::new()
+            .with("button", SpringMotion::Bouncy)
+            .with("sidebar", SpringMotion::Snappy);
+        registry.merge(MotionRegistry::new().with("button", SpringMotion::Smooth));
+        assert_eq!(registry.get("button"), SpringMotion::Smooth);
+        assert_eq!(registry.get("sidebar"), SpringMotion::Snappy);
+    }
+
+    #[cfg(feature = "dev-tools")]
+    #[test]
+    fn from_json() {
+        let registry = MotionRegistry::from_json(
+            r#"{ "button": { "response": 0.25, "damping": 0.9 }, "card": { "response": 0.5, "damping": 50.0 } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            registry.get("button"),
+            SpringMotion::Custom {
+                response: std::time::Duration::from_millis(250),
+                damping: 0.9
+            }
+        );
+        assert_eq!(
+            registry.get("card").damping(),
+            crate::spring_motion::MAX_DAMPING
+        );
+    }
+}
@@ -0,0 +1,298 @@
+//! Lookup tables of spring and easing curves, precomputed at compile time.
+//!
+//! Springs solve their motion every frame, which is cheap for a handful of values but adds up in
+//! very hot paths, like thousands of particles, and isn't possible at all in a shader. A
+//! [`MotionTable`] samples the progress of a motion from its start to its end ahead of time, so
+//! the motion can be looked up instead. The [`motion_table!`](crate::motion_table) macro builds
+//! the table while compiling, so it costs nothing at runtime:
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use iced_anim::{motion_table::MotionTable, Easing};
+//! static BOUNCE: MotionTable<256> = iced_anim::motion_table!(Bouncy, 256);
+//! static FADE: MotionTable<64> =
+//!     iced_anim::motion_table!(Easing::EaseOut, Duration::from_millis(300), 64);
+//!
+//! // Sample the progress of the motion at any point in time...
+//! let progress = BOUNCE.at(Duration::from_millis(120));
+//! assert!(progress > 0.0 && progress < 1.5);
+//!
+//! // ...or use it to animate between two values.
+//! let opacity = FADE.interpolate(&0.0, &1.0, Duration::from_millis(300));
+//! assert_eq!(opacity, 1.0);
+//! ```
+//!
+//! The [`samples`](MotionTable::samples) can also be uploaded to the GPU, e.g. as a uniform
+//! array, to animate with the same motion in a shader.
+//!
+//! Spring tables follow a spring from rest at the start until it comes to rest at the end, like a
+//! [`Spring`](crate::Spring) would. Unlike a spring, a table always plays from the start, so it
+//! can't pick up the velocity of an interrupted animation.
+use std::time::Duration;
+
+use crate::{
+    keyframes::interpolate,
+    spring::{ESPILON, MAX_STEP},
+    spring_motion::{MAX_DAMPING, MIN_DAMPING, MIN_RESPONSE},
+    Animate, Easing, SpringMotion,
+};
+
+/// The longest motion a spring table covers, for springs that take ages to come to rest.
+const MAX_SPRING_DURATION: Duration = Duration::from_secs(60);
+
+/// Evenly spaced samples of the progress of a motion over its duration, from `0.0` at the start
+/// to `1.0` at the end.
+///
+/// Tables are usually built at compile time with [`motion_table!`](crate::motion_table).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionTable<const N: usize> {
+    /// The progress of the motion at evenly spaced times, including the start and the end.
+    samples: [f32; N],
+    /// The duration of the motion.
+    duration: Duration,
+}
+
+impl<const N: usize> MotionTable<N> {
+    /// Samples the motion of a spring with the given `motion` from rest until it comes to rest
+    /// at its target.
+    ///
+    /// [`SpringMotion::Curve`] motions are sampled like [`MotionTable::easing`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table has fewer than 2 samples, which fails to compile in a const context.
+    pub const fn spring(motion: SpringMotion) -> Self {
+        if let SpringMotion::Curve { duration, easing } = motion {
+            return Self::easing(easing, duration);
+        }
+        assert!(N >= 2, "motion tables need at least 2 samples");
+
+        // Clamp the motion like `SpringMotion::clamped`, which can't run at compile time.
+        let response = motion.duration().as_secs_f32();
+        if response < MIN_RESPONSE.as_secs_f32() {
+            return Self {
+                samples: [1.0; N],
+                duration: Duration::ZERO,
+            };
+        }
+        let damping = match motion.damping() {
+            damping if damping.is_finite() => damping.clamp(MIN_DAMPING, MAX_DAMPING),
+            _ => SpringMotion::Smooth.damping(),
+        };
+        let stiffness = 39.478_416 / (response * response);
+        let damping = damping * 12.566_371 / response;
+
+        // Find how many steps the spring takes to come to rest from a distance of 1...
+        let max_steps = (MAX_SPRING_DURATION.as_nanos() / MAX_STEP.as_nanos()) as u32;
+        let (mut displacement, mut velocity): (f32, f32) = (1.0, 0.0);
+        let mut steps = 0;
+        while steps < max_steps && (displacement.abs() > ESPILON || velocity.abs() > ESPILON) {
+            (displacement, velocity) = step(displacement, velocity, stiffness, damping);
+            steps += 1;
+        }
+
+        // ...then sample the same motion at evenly spaced times between the steps.
+        let mut samples = [1.0; N];
+        let (mut displacement, mut velocity): (f32, f32) = (1.0, 0.0);
+        let mut elapsed = 0;
+        let mut index = 0;
+        while index < N - 1 {
+            let time = index as f32 * steps as f32 / (N - 1) as f32;
+            while (elapsed + 1) as f32 <= time {
+                (displacement, velocity) = step(displacement, velocity, stiffness, damping);
+                elapsed += 1;
+            }
+
+            let (next, _) = step(displacement, velocity, stiffness, damping);
+            let fraction = time - elapsed as f32;
+            samples[index] = 1.0 - (displacement + (next - displacement) * fraction);
+            index += 1;
+        }
+
+        Self {
+            samples,
+            duration: MAX_STEP.saturating_mul(steps),
+        }
+    }
+
+    /// Samples the `easing` curve of a tween lasting the given `duration`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table has fewer than 2 samples, which fails to compile in a const context.
+    pub const fn easing(easing: Easing, duration: Duration) -> Self {
+        assert!(N >= 2, "motion tables need at least 2 samples");
+
+        let mut samples = [0.0; N];
+        let mut index = 0;
+        while index < N {
+            samples[index] = easing.ease(index as f32 / (N - 1) as f32);
+            index += 1;
+        }
+
+        Self { samples, duration }
+    }
+
+    /// The progress of the motion at evenly spaced times, including the start and the end.
+    pub const fn samples(&self) -> &[f32; N] {
+        &self.samples
+    }
+
+    /// The duration of the motion.
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The progress of the motion at the given `fraction` of its duration, from `0.0` to `1.0`,
+    /// interpolating between the nearest samples.
+    pub fn sample(&self, fraction: f32) -> f32 {
+        let position = fraction.clamp(0.0, 1.0) * (N - 1) as f32;
+        let index = (position as usize).min(N - 2);
+        let (from, to) = (self.samples[index], self.samples[index + 1]);
+        from + (to - from) * (position - index as f32)
+    }
+
+    /// The progress of the motion the given `elapsed` time after it starts, which is `1.0` once
+    /// the motion is finished.
+    pub fn at(&self, elapsed: Duration) -> f32 {
+        if elapsed >= self.duration {
+            return 1.0;
+        }
+
+        self.sample(elapsed.as_secs_f32() / self.duration.as_secs_f32())
+    }
+
+    /// Whether the motion is finished the given `elapsed` time after it starts.
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.duration
+    }
+
+    /// The value between `from` and `to` the given `elapsed` time after the motion starts.
+    pub fn interpolate<T: Animate>(&self, from: &T, to: &T, elapsed: Duration) -> T {
+        interpolate(from, to, self.at(elapsed))
+    }
+}
+
+/// Integrates a spring from a `displacement` to its target and `velocity` forward by one
+/// [`MAX_STEP`], the same way a [`Spring`](crate::Spring) does.
+///
+/// Springs solve their motion exactly, which can't be done at compile time, so tables integrate
+/// it instead, in smaller steps when damping is strong to keep it stable.
+const fn step(displacement: f32, velocity: f32, stiffness: f32, damping: f32) -> (f32, f32) {
+    let mut steps = 1;
+    while damping * MAX_STEP.as_secs_f32() > steps as f32 {
+        steps += 1;
+    }
+
+    let dt = MAX_STEP.as_secs_f32() / steps as f32;
+    let (mut displacement, mut velocity) = (displacement, velocity);
+    while steps > 0 {
+        velocity += (displacement * stiffness - damping * velocity) * dt;
+        displacement -= velocity * dt;
+        steps -= 1;
+    }
+    (displacement, velocity)
+}
+
+/// Builds a [`MotionTable`] at compile time.
+///
+/// The table follows either a spring, given as the name of a [`SpringMotion`] preset or as any
+/// constant [`SpringMotion`], or an [`Easing`] curve over a duration. The last argument is the
+/// number of samples in the table.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use iced_anim::{motion_table::MotionTable, Easing, SpringMotion};
+/// const SMOOTH: MotionTable<128> = iced_anim::motion_table!(Smooth, 128);
+/// const SOFT: MotionTable<128> = iced_anim::motion_table!(
+///     SpringMotion::Custom {
+///         response: Duration::from_millis(800),
+///         damping: 0.6,
+///     },
+///     128
+/// );
+/// const EASE: MotionTable<32> =
+///     iced_anim::motion_table!(Easing::EaseInOut, Duration::from_millis(200), 32);
+/// ```
+#[macro_export]
+macro_rules! motion_table {
+    ($motion:ident, $len:expr) => {
+        $crate::motion_table!($crate::SpringMotion::$motion, $len)
+    };
+    ($motion:expr, $len:expr) => {{
+        const TABLE: $crate::motion_table::MotionTable<{ $len }> =
+            $crate::motion_table::MotionTable::spring($motion);
+        TABLE
+    }};
+    ($easing:expr, $duration:expr, $len:expr) => {{
+        const TABLE: $crate::motion_table::MotionTable<{ $len }> =
+            $crate::motion_table::MotionTable::easing($easing, $duration);
+        TABLE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "animations-off"))]
+    use std::time::Instant;
+
+    use super::*;
+    #[cfg(not(feature = "animations-off"))]
+    use crate::Spring;
+
+    /// Spring tables should follow the same motion as a spring.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn spring_tables_match_springs() {
+        const TABLE: MotionTable<64> = motion_table!(Bouncy, 64);
+        assert_eq!(TABLE.samples()[0], 0.0);
+        assert_eq!(TABLE.samples()[63], 1.0);
+        assert!(TABLE.samples().iter().any(|progress| *progress > 1.0));
+
+        let mut spring = Spring::new(0.0).with_motion(SpringMotion::Bouncy);
+        spring.interrupt(1.0);
+        let start = Instant::now();
+        spring.tick(start);
+
+        let mut elapsed = Duration::ZERO;
+        while spring.has_energy() {
+            elapsed += Duration::from_millis(16);
+            spring.tick(start + elapsed);
+            let progress = TABLE.at(elapsed);
+            assert!((spring.value() - progress).abs() < 0.02, "{elapsed:?}");
+        }
+        let settled = elapsed.abs_diff(TABLE.duration());
+        assert!(settled <= Duration::from_millis(32), "{settled:?}");
+    }
+
+    /// Easing tables should sample the curve over the duration.
+    #[test]
+    fn easing_tables_match_curves() {
+        const TABLE: MotionTable<11> =
+            motion_table!(Easing::EaseIn, Duration::from_millis(100), 11);
+        for (index, progress) in TABLE.samples().iter().enumerate() {
+            assert_eq!(*progress, Easing::EaseIn.ease(index as f32 / 10.0));
+        }
+        assert_eq!(
+            TABLE.sample(0.35),
+            (TABLE.samples()[3] + TABLE.samples()[4]) / 2.0
+        );
+        assert_eq!(
+            TABLE.at(Duration::from_millis(50)),
+            Easing::EaseIn.ease(0.5)
+        );
+        assert_eq!(TABLE.at(Duration::from_secs(1)), 1.0);
+        assert!(TABLE.is_finished(Duration::from_millis(100)));
+    }
+
+    /// Instant motions should be finished right away.
+    #[test]
+    fn instant_tables_are_finished() {
+        const TABLE: MotionTable<4> = MotionTable::spring(SpringMotion::Custom {
+            response: Duration::ZERO,
+            damping: 1.0,
+        });
+        assert_eq!(TABLE.duration(), Duration::ZERO);
+        assert_eq!(TABLE.interpolate(&0.0, &10.0, Duration::ZERO), 10.0);
+    }
+}
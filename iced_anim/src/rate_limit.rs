@@ -0,0 +1,41 @@
+//! Limits on how often a spring's target changes.
+//!
+//! Targets that change many times per second, like a count of search results updating as you
+//! type or live telemetry, restart the animation on every change. The animation never gets a
+//! chance to settle and constantly changes direction. A [`RateLimit`] holds back new targets so
+//! the spring chases a calmer version of the incoming values.
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use iced_anim::{rate_limit::RateLimit, Spring};
+//! let debounce = RateLimit::Debounce(Duration::from_millis(250));
+//! let mut results = Spring::new(0.0).with_rate_limit(debounce);
+//! results.interrupt(12.0);
+//! results.interrupt(8.0);
+//!
+//! // The new target waits until the values stop changing for 250ms.
+//! assert_eq!(results.target(), &0.0);
+//! assert_eq!(results.latest_target(), &8.0);
+//! ```
+use std::time::Duration;
+
+/// A limit on how often the target of a [`Spring`](crate::Spring) changes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimit {
+    /// Waits until the target stops changing for the given duration before moving toward the
+    /// latest target. Good for values that change in bursts, like search results while typing.
+    Debounce(Duration),
+    /// Moves toward a new target at most once per the given duration, holding back the latest
+    /// target until the duration passes. Good for values that change steadily, like telemetry.
+    Throttle(Duration),
+}
+
+impl RateLimit {
+    /// The duration of the limit.
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::Debounce(duration) | Self::Throttle(duration) => *duration,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Animations that play more than once.
+//!
+//! Some animations cycle on their own, like a pulsing highlight or a spinning loader. Rather than
+//! retargeting a spring every time it settles, give it a [`Repeat`] with [`Spring::with_repeat`]
+//! and it starts over from where it started each time it reaches its target. [`Keyframes`] can
+//! repeat the same way with [`Keyframes::with_repeat`].
+//!
+//! ```rust
+//! # use iced_anim::{Repeat, Spring};
+//! let mut pulse = Spring::new(0.0).with_repeat(Repeat::Forever);
+//! pulse.interrupt(1.0);
+//! assert!(pulse.has_energy());
+//! ```
+//!
+//! Yoyo animations head back to where they started instead of jumping there, so an effect like
+//! breathing opacity is just a spring with [`Spring::with_yoyo`] that repeats forever. Each play
+//! counts the round trip.
+//!
+//! [`Spring::with_yoyo`]: crate::Spring::with_yoyo
+//! [`Spring::with_repeat`]: crate::Spring::with_repeat
+//! [`Keyframes`]: crate::Keyframes
+//! [`Keyframes::with_repeat`]: crate::Keyframes::with_repeat
+
+/// How many times an animation plays.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repeat {
+    /// Plays the animation over and over until it's settled or given a new target.
+    Forever,
+    /// Plays the animation the given number of times in total.
+    Times(u32),
+}
+
+impl Repeat {
+    /// Whether the animation plays again after finishing the given number of `plays`.
+    pub fn plays_again(&self, plays: u32) -> bool {
+        match self {
+            Self::Forever => true,
+            Self::Times(times) => plays < *times,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plays_again() {
+        assert!(Repeat::Forever.plays_again(u32::MAX));
+        assert!(Repeat::Times(3).plays_again(2));
+        assert!(!Repeat::Times(3).plays_again(3));
+        assert!(!Repeat::Times(0).plays_again(1));
+    }
+}
@@ -0,0 +1,90 @@
+//! When a spring is close enough to its target to stop animating.
+//!
+//! Springs approach their target forever in theory, so they stop once the remaining distance and
+//! velocity are too small to notice. By default, both are measured relative to the distance the
+//! animation covers, which suits most UI values. Values on a very large scale, like an offset of
+//! thousands of pixels, can stop visibly short of their target this way, while tiny values may
+//! keep jittering long after they look settled. A [`RestThreshold`] tunes when the spring rests:
+//!
+//! ```rust
+//! # use iced_anim::{RestThreshold, Spring};
+//! // Rest within a tenth of a pixel of the target, no matter how far the spring travels.
+//! let scroll = Spring::new(0.0).with_rest_threshold(RestThreshold::absolute(0.1));
+//! ```
+//!
+//! [`Spring`]: crate::Spring
+use crate::spring::ESPILON;
+
+/// When a [`Spring`](crate::Spring) is close enough to its target to stop animating.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RestThreshold {
+    /// Rests once the distance to the target and the velocity per second are both within the
+    /// given fraction of the distance the animation started from.
+    Relative(f32),
+    /// Rests once the distance to the target is within `delta` and the velocity is within
+    /// `speed` per second, in the units of the animated value.
+    Absolute {
+        /// The largest distance from the target at which the spring can rest.
+        delta: f32,
+        /// The largest velocity per second at which the spring can rest.
+        speed: f32,
+    },
+}
+
+impl RestThreshold {
+    /// Rests once the distance to the target is within `delta`, and the velocity is within
+    /// `delta` per second.
+    pub fn absolute(delta: f32) -> Self {
+        Self::Absolute {
+            delta,
+            speed: delta,
+        }
+    }
+
+    /// Whether a component with the given displacement `d`, initial distance `i`, and
+    /// velocity `v` is close enough to its target with low enough velocity to rest.
+    pub(crate) fn is_near_end(&self, d: f32, i: f32, v: f32) -> bool {
+        match *self {
+            Self::Relative(epsilon) => match i {
+                0.0 => true,
+                _ => {
+                    let d_percent = (d / i).abs();
+                    let v_percent = (v / i).abs();
+                    d_percent <= epsilon && v_percent <= epsilon
+                }
+            },
+            Self::Absolute { delta, speed } => d.abs() <= delta && v.abs() <= speed,
+        }
+    }
+}
+
+impl Default for RestThreshold {
+    fn default() -> Self {
+        Self::Relative(ESPILON)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Relative thresholds should scale with the distance the animation covers.
+    #[test]
+    fn relative_scales_with_distance() {
+        let threshold = RestThreshold::default();
+        assert!(threshold.is_near_end(0.004, 1.0, 0.0));
+        assert!(threshold.is_near_end(15.0, 4000.0, 0.0));
+        assert!(!threshold.is_near_end(0.01, 1.0, 0.0));
+        assert!(threshold.is_near_end(1.0, 0.0, 1.0));
+    }
+
+    /// Absolute thresholds should ignore the distance the animation covers.
+    #[test]
+    fn absolute_ignores_distance() {
+        let threshold = RestThreshold::absolute(0.1);
+        assert!(!threshold.is_near_end(15.0, 4000.0, 0.0));
+        assert!(threshold.is_near_end(0.05, 4000.0, 0.05));
+        assert!(!threshold.is_near_end(0.05, 4000.0, 1.0));
+    }
+}
@@ -0,0 +1,310 @@
+//! Run several animations one after another, with a message after each stage.
+//!
+//! Some transitions happen in steps, like a card that moves into place, then changes color, and
+//! then grows. A [`Sequence`] animates a spring through a list of stages, starting each one once
+//! the previous stage settles. It can emit a message as each stage completes and another once
+//! the whole sequence completes, so apps don't need their own state machine to chain animations.
+//!
+//! ```rust
+//! # use iced::{Color, Point, Task};
+//! # use iced_anim::{Sequence, SpringEvent, SpringMotion};
+//! type Card = (Point, Color, f32);
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Place,
+//!     Moved,
+//!     Placed,
+//!     Card(SpringEvent<Card>),
+//! }
+//!
+//! struct State {
+//!     card: Sequence<Card, Message>,
+//! }
+//!
+//! impl State {
+//!     fn new() -> Self {
+//!         let start = (Point::ORIGIN, Color::WHITE, 1.0);
+//!         let card = Sequence::new(start)
+//!             .then((Point::new(200.0, 0.0), Color::WHITE, 1.0))
+//!             .on_stage_complete(Message::Moved)
+//!             .then((Point::new(200.0, 0.0), Color::from_rgb(0.2, 0.6, 1.0), 1.0))
+//!             .then((Point::new(200.0, 0.0), Color::from_rgb(0.2, 0.6, 1.0), 1.5))
+//!             .stage_motion(SpringMotion::Bouncy)
+//!             .on_complete(Message::Placed);
+//!         Self { card }
+//!     }
+//!
+//!     fn update(&mut self, message: Message) -> Task<Message> {
+//!         match message {
+//!             Message::Place => self.card.play(),
+//!             Message::Card(event) => self.card.update(event),
+//!             Message::Moved | Message::Placed => Task::none(),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The spring is driven like any other, e.g. with
+//! [`Animation::new`](crate::Animation::new) on [`Sequence::spring`], passing its events to
+//! [`Sequence::update`].
+use iced::Task;
+
+use crate::{Animate, Spring, SpringEvent, SpringMotion};
+
+/// A spring animated through several stages in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sequence<T, Message> {
+    /// The spring animating through the stages.
+    spring: Spring<T>,
+    /// The stages to animate through, in order.
+    stages: Vec<Stage<T, Message>>,
+    /// The index of the stage that's playing, if the sequence is playing.
+    current: Option<usize>,
+    /// The motion of stages that don't have a motion of their own.
+    motion: SpringMotion,
+    /// The message emitted once every stage has completed.
+    on_complete: Option<Message>,
+}
+
+/// A single stage of a [`Sequence`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stage<T, Message> {
+    /// The value the spring animates to during the stage.
+    pub target: T,
+    /// The motion of the stage, or the motion of the sequence if `None`.
+    pub motion: Option<SpringMotion>,
+    /// The message emitted once the stage completes.
+    pub on_complete: Option<Message>,
+}
+
+impl<T, Message> Sequence<T, Message>
+where
+    T: Animate,
+    Message: Clone + Send + 'static,
+{
+    /// Creates a new [`Sequence`] without any stages, resting at the `initial` value.
+    pub fn new(initial: T) -> Self {
+        Self {
+            spring: Spring::new(initial),
+            stages: Vec::new(),
+            current: None,
+            motion: SpringMotion::default(),
+            on_complete: None,
+        }
+    }
+
+    /// Adds a stage that animates to the `target` after the previous stage.
+    pub fn then(mut self, target: T) -> Self {
+        self.stages.push(Stage {
+            target,
+            motion: None,
+            on_complete: None,
+        });
+        self
+    }
+
+    /// Sets the motion of the last stage added.
+    pub fn stage_motion(mut self, motion: SpringMotion) -> Self {
+        if let Some(stage) = self.stages.last_mut() {
+            stage.motion = Some(motion);
+        }
+        self
+    }
+
+    /// Emits the message once the last stage added completes.
+    pub fn on_stage_complete(mut self, message: Message) -> Self {
+        if let Some(stage) = self.stages.last_mut() {
+            stage.on_complete = Some(message);
+        }
+        self
+    }
+
+    /// Emits the message once every stage has completed.
+    pub fn on_complete(mut self, message: Message) -> Self {
+        self.on_complete = Some(message);
+        self
+    }
+
+    /// Sets the motion of stages that don't have a motion of their own.
+    pub fn with_motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// The motion of stages that don't have a motion of their own.
+    pub fn motion(&self) -> SpringMotion {
+        self.motion
+    }
+
+    /// The stages of the sequence, in order.
+    pub fn stages(&self) -> &[Stage<T, Message>] {
+        &self.stages
+    }
+
+    /// The spring animating through the stages, to be driven by an
+    /// [`Animation`](crate::Animation).
+    pub fn spring(&self) -> &Spring<T> {
+        &self.spring
+    }
+
+    /// The current value of the sequence.
+    pub fn value(&self) -> &T {
+        self.spring.value()
+    }
+
+    /// The index of the stage that's playing, if the sequence is playing.
+    pub fn stage(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Whether the sequence is playing.
+    pub fn is_playing(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// Plays the stages in order from the current value, restarting the sequence if it's
+    /// already playing.
+    ///
+    /// Returns a task with the messages of any stages that complete right away, like when
+    /// animations are disabled.
+    pub fn play(&mut self) -> Task<Message> {
+        let Some(stage) = self.stages.first() else {
+            self.current = None;
+            return self.on_complete.clone().map_or(Task::none(), Task::done);
+        };
+
+        self.current = Some(0);
+        self.spring.set_motion(stage.motion.unwrap_or(self.motion));
+        self.spring.interrupt(stage.target.clone());
+        self.advance(false)
+    }
+
+    /// Stops the sequence where it is, letting the spring settle on the current stage's target
+    /// without starting the next one.
+    pub fn stop(&mut self) {
+        self.current = None;
+    }
+
+    /// Updates the sequence with the given `event`, starting the next stage once the current
+    /// one settles and returning a task with the messages of the stages that completed.
+    ///
+    /// A target event stops the sequence and animates toward the new target instead, while a
+    /// settle event skips straight to the end of the sequence.
+    pub fn update(&mut self, event: SpringEvent<T>) -> Task<Message> {
+        match event {
+            SpringEvent::Tick(now) => {
+                self.spring.tick(now);
+                self.advance(false)
+            }
+            SpringEvent::Target(target) => {
+                self.stop();
+                self.spring.set_motion(self.motion);
+                self.spring.interrupt(target);
+                Task::none()
+            }
+            SpringEvent::Settle => {
+                self.spring.settle();
+                self.advance(true)
+            }
+        }
+    }
+
+    /// Moves on to the next stage each time the current one settles, settling every remaining
+    /// stage if `settle` is set, and returns a task with the messages of the completed stages.
+    fn advance(&mut self, settle: bool) -> Task<Message> {
+        let mut messages = Vec::new();
+        while let Some(index) = self.current {
+            if settle {
+                self.spring.settle();
+            }
+            if self.spring.has_energy() {
+                break;
+            }
+
+            messages.extend(self.stages[index].on_complete.clone());
+            match self.stages.get(index + 1) {
+                Some(stage) => {
+                    self.current = Some(index + 1);
+                    self.spring.set_motion(stage.motion.unwrap_or(self.motion));
+                    self.spring.interrupt(stage.target.clone());
+                }
+                None => {
+                    self.current = None;
+                    messages.extend(self.on_complete.clone());
+                }
+            }
+        }
+
+        Task::batch(messages.into_iter().map(Task::done))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::Easing;
+
+    fn sequence() -> Sequence<f32, &'static str> {
+        Sequence::new(0.0)
+            .with_motion(SpringMotion::curve(
+                Duration::from_millis(100),
+                Easing::Linear,
+            ))
+            .then(1.0)
+            .on_stage_complete("first")
+            .then(2.0)
+            .stage_motion(SpringMotion::Snappy)
+            .on_complete("done")
+    }
+
+    /// Each stage should start once the previous one settles, with its own motion.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn plays_stages_in_order() {
+        let mut sequence = sequence();
+        let _ = sequence.play();
+        assert_eq!(sequence.stage(), Some(0));
+        assert_eq!(sequence.spring().target(), &1.0);
+
+        let start = sequence.spring().last_update();
+        for step in 1..=4 {
+            let _ = sequence.update(SpringEvent::Tick(start + Duration::from_millis(step * 30)));
+        }
+        assert_eq!(sequence.stage(), Some(1));
+        assert_eq!(sequence.value(), &1.0);
+        assert_eq!(sequence.spring().target(), &2.0);
+        assert_eq!(sequence.spring().motion(), SpringMotion::Snappy);
+
+        let _ = sequence.update(SpringEvent::Settle);
+        assert!(!sequence.is_playing());
+        assert_eq!(sequence.value(), &2.0);
+    }
+
+    /// Settling should skip every remaining stage.
+    #[test]
+    fn settle_skips_to_the_end() {
+        let mut sequence = sequence();
+        let _ = sequence.play();
+        let _ = sequence.update(SpringEvent::Settle);
+        assert!(!sequence.is_playing());
+        assert_eq!(sequence.value(), &2.0);
+        assert!(!sequence.spring().has_energy());
+    }
+
+    /// A new target should stop the sequence.
+    #[test]
+    fn target_stops_the_sequence() {
+        let mut sequence = sequence();
+        let _ = sequence.play();
+        let _ = sequence.update(SpringEvent::Target(5.0));
+        assert!(!sequence.is_playing());
+        assert_eq!(sequence.spring().target(), &5.0);
+        assert_eq!(sequence.spring().motion(), sequence.motion());
+
+        let _ = sequence.update(SpringEvent::Tick(Instant::now() + Duration::from_secs(1)));
+        assert!(!sequence.is_playing());
+    }
+}
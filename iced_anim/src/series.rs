@@ -0,0 +1,516 @@
+//! Animated data series for live charts.
+//!
+//! Charts that redraw with every new batch of data jump from one shape to the next. An
+//! [`AnimatedSeries`] keeps a spring for each point of a series in a [`SpringVec`], so the line
+//! morphs into its new shape instead. Series can change length too: new points grow out of the
+//! end of the line, removed points shrink into it, and the spacing between points follows along
+//! so the line doesn't jump sideways.
+//!
+//! ```rust
+//! # use std::time::{Duration, Instant};
+//! # use iced::Size;
+//! # use iced_anim::{series::AnimatedSeries, SpringMotion};
+//! let mut series = AnimatedSeries::new([1.0, 3.0, 2.0], SpringMotion::Smooth);
+//! series.set_data([2.0, 4.0, 3.0, 5.0]);
+//!
+//! series.tick(Instant::now() + Duration::from_millis(16));
+//! assert!(series.has_energy());
+//!
+//! // Plot the animated points in a canvas with a y axis from 0 to 5.
+//! let points: Vec<_> = series.points(Size::new(300.0, 100.0), 0.0..=5.0).collect();
+//! assert_eq!(points.len(), 4);
+//! ```
+//!
+//! Series don't need a widget to animate. Tick them on each frame while they have energy, e.g.
+//! with the `iced::window::frames` subscription, and plot the [`AnimatedSeries::values`] when
+//! drawing the canvas.
+//!
+//! Charts that rescale to fit their data can animate their axes along with it. An
+//! [`AnimatedAxis`] springs its range toward new bounds and places ticks at round values, so the
+//! grid lines and labels slide into place as the data morphs:
+//!
+//! ```rust
+//! # use std::time::{Duration, Instant};
+//! # use iced::Size;
+//! # use iced_anim::{series::{AnimatedAxis, AnimatedSeries}, SpringMotion};
+//! let mut series = AnimatedSeries::new([1.0, 3.0, 2.0], SpringMotion::Smooth);
+//! let mut y_axis = AnimatedAxis::new(0.0..=5.0, SpringMotion::Smooth);
+//!
+//! series.set_data([20.0, 45.0, 30.0]);
+//! y_axis.fit(series.data());
+//! assert_eq!(y_axis.target(), 20.0..=45.0);
+//!
+//! let now = Instant::now() + Duration::from_millis(16);
+//! series.tick(now);
+//! y_axis.tick(now);
+//!
+//! let size = Size::new(300.0, 100.0);
+//! let points: Vec<_> = series.points(size, y_axis.range()).collect();
+//! for tick in y_axis.ticks() {
+//!     let y = size.height - y_axis.position(tick, size.height);
+//!     // Draw a grid line and label at `y`...
+//! }
+//! ```
+use std::{ops::RangeInclusive, time::Instant};
+
+use iced::{Point, Size};
+
+use crate::{Spring, SpringMotion, SpringVec};
+
+/// The round multiples of each power of ten that ticks are spaced by.
+const TICK_STEPS: [f32; 4] = [1.0, 2.0, 5.0, 10.0];
+
+/// A series of values that animates toward new data, including when its length changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimatedSeries {
+    /// A spring for each point, including removed points that are still shrinking into the end
+    /// of the series.
+    points: SpringVec<f32>,
+    /// The number of points in the data, which comes before any removed points.
+    len: usize,
+    /// The animated number of points, used to space them out.
+    length: Spring<f32>,
+    /// The value new points grow from when the series is empty.
+    baseline: f32,
+}
+
+impl AnimatedSeries {
+    /// Creates a series resting at the given `data`, which animates with the given `motion`.
+    pub fn new(data: impl IntoIterator<Item = f32>, motion: SpringMotion) -> Self {
+        let points = SpringVec::from_values(data, motion);
+        let len = points.len();
+        Self {
+            points,
+            len,
+            length: Spring::new(len as f32).with_motion(motion),
+            baseline: 0.0,
+        }
+    }
+
+    /// Sets the value new points grow from when the series is empty, which defaults to `0.0`.
+    pub fn with_baseline(mut self, baseline: f32) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// The value new points grow from when the series is empty.
+    pub fn baseline(&self) -> f32 {
+        self.baseline
+    }
+
+    /// The motion of the series.
+    pub fn motion(&self) -> SpringMotion {
+        self.points.motion()
+    }
+
+    /// Sets the motion of the series.
+    pub fn set_motion(&mut self, motion: SpringMotion) {
+        self.points.set_motion(motion);
+        self.length.set_motion(motion);
+    }
+
+    /// The number of points in the data.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the data is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The data the series is animating toward.
+    pub fn data(&self) -> &[f32] {
+        &self.points.targets()[..self.len]
+    }
+
+    /// The current values of the series, including removed points that are still shrinking into
+    /// the end of it.
+    pub fn values(&self) -> &[f32] {
+        self.points.values()
+    }
+
+    /// The animated number of points, which is fractional while points are added or removed.
+    ///
+    /// Spacing the points by this length, rather than by the number of [`values`](Self::values),
+    /// slides them into place when the length of the series changes.
+    pub fn length(&self) -> f32 {
+        *self.length.value()
+    }
+
+    /// Animates the series toward new `data`.
+    ///
+    /// Points that are added grow out of the current end of the series, or from the
+    /// [`baseline`](Self::baseline) if it's empty, and points that are removed shrink into the
+    /// new end of it.
+    pub fn set_data(&mut self, data: impl IntoIterator<Item = f32>) {
+        let mut len = 0;
+        for value in data {
+            if len == self.points.len() {
+                let start = self.points.values().last().copied();
+                self.points.push(start.unwrap_or(self.baseline));
+            }
+            self.points.interrupt(len, value);
+            len += 1;
+        }
+
+        let end = match len {
+            0 => self.baseline,
+            len => *self.points.target(len - 1),
+        };
+        for index in len..self.points.len() {
+            self.points.interrupt(index, end);
+        }
+
+        self.len = len;
+        self.length.interrupt(len as f32);
+    }
+
+    /// Adds a point to the end of the data, which grows out of the current end of the series.
+    pub fn push(&mut self, value: f32) {
+        let data = self
+            .data()
+            .iter()
+            .copied()
+            .chain([value])
+            .collect::<Vec<_>>();
+        self.set_data(data);
+    }
+
+    /// Whether the series is animating, meaning it needs to be ticked.
+    pub fn has_energy(&self) -> bool {
+        self.points.has_energy() || self.length.has_energy()
+    }
+
+    /// Updates the series based on the elapsed time since the last update.
+    pub fn tick(&mut self, now: Instant) {
+        self.points.tick(now);
+        self.length.tick(now);
+        self.remove_shrunk_points();
+    }
+
+    /// Settles every point at its data immediately.
+    pub fn settle(&mut self) {
+        self.points.settle_all();
+        self.length.settle();
+        self.remove_shrunk_points();
+    }
+
+    /// The positions of the points within a canvas of the given `size`, with the `range` of
+    /// values spanning its height from the bottom up.
+    ///
+    /// Points are spaced evenly by the animated [`length`](Self::length), so the first point is
+    /// at the left edge and the last point of the data is at the right edge.
+    pub fn points(
+        &self,
+        size: Size,
+        range: RangeInclusive<f32>,
+    ) -> impl Iterator<Item = Point> + '_ {
+        let spacing = size.width / (self.length() - 1.0).max(1.0);
+        let (min, max) = range.into_inner();
+        let scale = if max == min {
+            0.0
+        } else {
+            size.height / (max - min)
+        };
+
+        self.values().iter().enumerate().map(move |(index, value)| {
+            let x = (index as f32 * spacing).min(size.width);
+            Point::new(x, size.height - (value - min) * scale)
+        })
+    }
+
+    /// Removes the points after the data once they've shrunk into the end of the series.
+    fn remove_shrunk_points(&mut self) {
+        while self.points.len() > self.len && !self.points.is_animating(self.points.len() - 1) {
+            self.points.remove(self.points.len() - 1);
+        }
+    }
+}
+
+impl Default for AnimatedSeries {
+    fn default() -> Self {
+        Self::new([], SpringMotion::default())
+    }
+}
+
+/// The range of a chart axis, which animates when the chart rescales, with ticks at round values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimatedAxis {
+    /// The animated start and end of the range.
+    range: Spring<(f32, f32)>,
+    /// The approximate number of intervals between ticks.
+    ticks: usize,
+}
+
+impl AnimatedAxis {
+    /// The default approximate number of intervals between ticks.
+    pub const DEFAULT_TICKS: usize = 5;
+
+    /// Creates an axis resting at the given `range`, which animates with the given `motion`.
+    pub fn new(range: RangeInclusive<f32>, motion: SpringMotion) -> Self {
+        Self {
+            range: Spring::new(range.into_inner()).with_motion(motion),
+            ticks: Self::DEFAULT_TICKS,
+        }
+    }
+
+    /// Sets the approximate number of intervals between ticks, which defaults to
+    /// [`DEFAULT_TICKS`](Self::DEFAULT_TICKS). The exact number depends on the round value the
+    /// ticks are spaced by.
+    pub fn with_ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks.max(1);
+        self
+    }
+
+    /// The motion of the axis.
+    pub fn motion(&self) -> SpringMotion {
+        self.range.motion()
+    }
+
+    /// Sets the motion of the axis.
+    pub fn set_motion(&mut self, motion: SpringMotion) {
+        self.range.set_motion(motion);
+    }
+
+    /// The current animated range of the axis.
+    pub fn range(&self) -> RangeInclusive<f32> {
+        let (start, end) = *self.range.value();
+        start..=end
+    }
+
+    /// The range the axis is animating toward.
+    pub fn target(&self) -> RangeInclusive<f32> {
+        let (start, end) = *self.range.target();
+        start..=end
+    }
+
+    /// Animates the axis toward a new `range`.
+    pub fn set_range(&mut self, range: RangeInclusive<f32>) {
+        self.range.interrupt(range.into_inner());
+    }
+
+    /// Animates the axis toward the smallest range of round values that covers the `data`, so
+    /// it starts and ends on a tick. The range stays the same for empty data.
+    pub fn fit(&mut self, data: &[f32]) {
+        let (min, max) = data
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+                (min.min(*value), max.max(*value))
+            });
+        if min > max {
+            return;
+        }
+
+        // Flat data still needs a range for the points to sit in.
+        let (min, max) = if min == max {
+            (min - 1.0, max + 1.0)
+        } else {
+            (min, max)
+        };
+        let step = tick_step(max - min, self.ticks);
+        self.set_range((min / step).floor() * step..=(max / step).ceil() * step);
+    }
+
+    /// Whether the axis is animating, meaning it needs to be ticked.
+    pub fn has_energy(&self) -> bool {
+        self.range.has_energy()
+    }
+
+    /// Updates the axis based on the elapsed time since the last update.
+    pub fn tick(&mut self, now: Instant) {
+        self.range.tick(now);
+    }
+
+    /// Settles the axis at its target range immediately.
+    pub fn settle(&mut self) {
+        self.range.settle();
+    }
+
+    /// The distance of the `value` along an axis of the given `extent`, from `0.0` at the start
+    /// of the current range to `extent` at the end of it.
+    ///
+    /// Vertical axes usually start at the bottom, so subtract the position from the height of
+    /// the chart to plot them.
+    pub fn position(&self, value: f32, extent: f32) -> f32 {
+        let (start, end) = *self.range.value();
+        if start == end {
+            return 0.0;
+        }
+
+        (value - start) / (end - start) * extent
+    }
+
+    /// The values of the ticks within the target range, spaced by a round value.
+    ///
+    /// Ticks follow the target rather than the animated range, so they don't change while the
+    /// axis animates. Placing them with [`position`](Self::position) slides them along with the
+    /// range instead, and ticks that are still outside of it can be clipped while they slide in.
+    pub fn ticks(&self) -> impl Iterator<Item = f32> {
+        let (start, end) = *self.range.target();
+        let (min, max) = (start.min(end), start.max(end));
+        let step = tick_step(max - min, self.ticks);
+        let first = (min / step).ceil() as i64;
+        let last = (max / step).floor() as i64;
+        (first..=last).map(move |index| index as f32 * step)
+    }
+}
+
+impl Default for AnimatedAxis {
+    fn default() -> Self {
+        Self::new(0.0..=1.0, SpringMotion::default())
+    }
+}
+
+/// The round value that splits a range of the given `span` into about `ticks` intervals.
+fn tick_step(span: f32, ticks: usize) -> f32 {
+    if !span.is_finite() || span <= 0.0 {
+        return 1.0;
+    }
+
+    let raw = span / ticks as f32;
+    let magnitude = 10f32.powf(raw.log10().floor());
+    let step = TICK_STEPS
+        .into_iter()
+        .find(|step| step * magnitude >= raw)
+        .unwrap_or(10.0);
+    step * magnitude
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "animations-off"))]
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Ticks the series until it comes to rest.
+    #[cfg(not(feature = "animations-off"))]
+    fn play(series: &mut AnimatedSeries) {
+        let mut now = Instant::now();
+        while series.has_energy() {
+            now += Duration::from_millis(30);
+            series.tick(now);
+        }
+    }
+
+    /// Points should animate toward new data of the same length.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn morphs_into_new_data() {
+        let mut series = AnimatedSeries::new([0.0, 10.0], SpringMotion::Smooth);
+        series.set_data([10.0, 0.0]);
+        assert_eq!(series.values(), &[0.0, 10.0]);
+        assert_eq!(series.data(), &[10.0, 0.0]);
+
+        series.tick(Instant::now() + Duration::from_millis(30));
+        assert!(series.values()[0] > 0.0 && series.values()[1] < 10.0);
+
+        play(&mut series);
+        assert_eq!(series.values(), &[10.0, 0.0]);
+    }
+
+    /// Added points should grow from the end of the series.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn grows_new_points_from_the_end() {
+        let mut series = AnimatedSeries::new([1.0, 2.0], SpringMotion::Smooth);
+        series.push(8.0);
+        assert_eq!(series.values(), &[1.0, 2.0, 2.0]);
+        assert_eq!(series.length(), 2.0);
+
+        play(&mut series);
+        assert_eq!(series.values(), &[1.0, 2.0, 8.0]);
+        assert_eq!(series.length(), 3.0);
+
+        let mut empty = AnimatedSeries::default().with_baseline(-1.0);
+        empty.set_data([5.0]);
+        assert_eq!(empty.values(), &[-1.0]);
+    }
+
+    /// Removed points should shrink into the end of the series before they're dropped.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn shrinks_removed_points_into_the_end() {
+        let mut series = AnimatedSeries::new([1.0, 2.0, 3.0, 4.0], SpringMotion::Smooth);
+        series.set_data([5.0, 6.0]);
+        assert_eq!(series.len(), 2);
+        assert_eq!(series.values().len(), 4);
+
+        series.tick(Instant::now() + Duration::from_millis(30));
+        assert_eq!(series.values().len(), 4);
+
+        play(&mut series);
+        assert_eq!(series.values(), &[5.0, 6.0]);
+
+        series.set_data([]);
+        series.settle();
+        assert!(series.values().is_empty());
+        assert_eq!(series.length(), 0.0);
+    }
+
+    /// Ticks should be spaced by round values within the range.
+    #[test]
+    fn ticks_at_round_values() {
+        let axis = AnimatedAxis::new(0.0..=10.0, SpringMotion::Smooth);
+        assert_eq!(
+            axis.ticks().collect::<Vec<_>>(),
+            [0.0, 2.0, 4.0, 6.0, 8.0, 10.0]
+        );
+
+        let axis = AnimatedAxis::new(-0.3..=0.7, SpringMotion::Smooth).with_ticks(2);
+        assert_eq!(axis.ticks().collect::<Vec<_>>(), [0.0, 0.5]);
+
+        assert_eq!(tick_step(0.0, 5), 1.0);
+        assert_eq!(tick_step(730.0, 5), 200.0);
+    }
+
+    /// Fitted ranges should cover the data and start and end on a tick.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn fits_data() {
+        let mut axis = AnimatedAxis::default();
+        axis.fit(&[3.0, 47.0, 12.0]);
+        assert_eq!(axis.target(), 0.0..=50.0);
+        assert_eq!(axis.range(), 0.0..=1.0);
+
+        axis.fit(&[5.0]);
+        assert_eq!(axis.target(), 4.0..=6.0);
+
+        axis.fit(&[]);
+        assert_eq!(axis.target(), 4.0..=6.0);
+    }
+
+    /// Positions should follow the animated range as it rescales.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn positions_follow_the_range() {
+        let mut axis = AnimatedAxis::new(0.0..=10.0, SpringMotion::Smooth);
+        assert_eq!(axis.position(5.0, 100.0), 50.0);
+
+        axis.set_range(0.0..=20.0);
+        axis.tick(Instant::now() + Duration::from_millis(30));
+        let position = axis.position(10.0, 100.0);
+        assert!(position > 50.0 && position < 100.0, "{position}");
+
+        axis.settle();
+        assert_eq!(axis.position(10.0, 100.0), 50.0);
+        assert!(!axis.has_energy());
+    }
+
+    /// Points should span the canvas from left to right and bottom to top.
+    #[test]
+    fn plots_points_in_a_canvas() {
+        let series = AnimatedSeries::new([0.0, 5.0, 10.0], SpringMotion::Smooth);
+        let points: Vec<_> = series.points(Size::new(100.0, 50.0), 0.0..=10.0).collect();
+        assert_eq!(
+            points,
+            [
+                Point::new(0.0, 50.0),
+                Point::new(50.0, 25.0),
+                Point::new(100.0, 0.0)
+            ]
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! A handle to a single spring that several widgets can read from and drive.
+//!
+//! Some animations belong to more than one view, like the view of a zoomable canvas that is
+//! also outlined by a minimap, or a selection that's highlighted in a list and in a preview.
+//! Keeping a [`Spring`] for each view means sending every change to each of them and hoping
+//! they stay in step. A [`SharedSpring`] is a cheap, cloneable handle to a single spring
+//! instead, so every clone samples the same animated value on every frame.
+//!
+//! ```rust
+//! # use std::time::{Duration, Instant};
+//! # use iced_anim::SharedSpring;
+//! let opacity = SharedSpring::new(0.0);
+//! let in_preview = opacity.clone();
+//!
+//! // Changing the spring through one handle changes it for every handle.
+//! opacity.interrupt(1.0);
+//! assert_eq!(in_preview.target(), 1.0);
+//!
+//! // Each view can tick the spring during the same frame without speeding it up.
+//! let now = Instant::now() + Duration::from_millis(16);
+//! opacity.tick(now);
+//! let value = in_preview.value();
+//! in_preview.tick(now);
+//! assert_eq!(in_preview.value(), value);
+//! ```
+//!
+//! # Mutation semantics
+//!
+//! Every change made through any handle applies to the shared spring immediately, so it's
+//! visible to all handles the next time they're read. The last change wins, just like calling
+//! the same methods on a single [`Spring`] in a row.
+//!
+//! # Redraws
+//!
+//! Only a single tick per frame advances the spring: [`SharedSpring::tick`] ignores any `now`
+//! that isn't later than the latest tick. Every widget that draws a shared spring can therefore
+//! tick it and request another frame while it [has energy](SharedSpring::has_energy), without
+//! coordinating which widget is responsible for it.
+//!
+//! Handles are reference counted with [`Rc`], so they're meant to be shared between the
+//! widgets of a single window rather than across threads.
+use std::{
+    cell::{Ref, RefCell, RefMut},
+    rc::Rc,
+    time::Instant,
+};
+
+use crate::{Animate, Spring, SpringEvent, SpringMotion};
+
+/// A cloneable handle to a single [`Spring`] shared by several widgets.
+#[derive(Debug)]
+pub struct SharedSpring<T> {
+    inner: Rc<RefCell<Inner<T>>>,
+}
+
+/// The spring shared by every clone of a [`SharedSpring`].
+#[derive(Debug)]
+struct Inner<T> {
+    spring: Spring<T>,
+    /// The time of the latest tick, which later ticks in the same frame are ignored against.
+    ticked_at: Option<Instant>,
+}
+
+impl<T> Clone for SharedSpring<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> SharedSpring<T>
+where
+    T: Animate,
+{
+    /// Creates a new [`SharedSpring`] at rest at the given `value`.
+    pub fn new(value: T) -> Self {
+        Self::from(Spring::new(value))
+    }
+
+    /// Sets the motion of the shared spring.
+    pub fn with_motion(self, motion: SpringMotion) -> Self {
+        self.set_motion(motion);
+        self
+    }
+
+    /// Returns the current value of the shared spring.
+    pub fn value(&self) -> T {
+        self.inner.borrow().spring.value().clone()
+    }
+
+    /// Returns the target of the shared spring.
+    pub fn target(&self) -> T {
+        self.inner.borrow().spring.target().clone()
+    }
+
+    /// Returns the motion of the shared spring.
+    pub fn motion(&self) -> SpringMotion {
+        self.inner.borrow().spring.motion()
+    }
+
+    /// Sets the motion of the shared spring.
+    pub fn set_motion(&self, motion: SpringMotion) {
+        self.inner.borrow_mut().spring.set_motion(motion);
+    }
+
+    /// Whether the shared spring is animating, meaning it needs to be ticked.
+    pub fn has_energy(&self) -> bool {
+        self.inner.borrow().spring.has_energy()
+    }
+
+    /// Animates the shared spring toward a new target, as with [`Spring::interrupt`].
+    pub fn interrupt(&self, target: T) {
+        self.inner.borrow_mut().spring.interrupt(target);
+    }
+
+    /// Adds the given `velocity` to the shared spring, as with [`Spring::impulse`].
+    pub fn impulse(&self, velocity: Vec<f32>) {
+        self.inner.borrow_mut().spring.impulse(velocity);
+    }
+
+    /// Replaces the velocity of the shared spring, as with [`Spring::set_velocity`].
+    pub fn set_velocity(&self, velocity: Vec<f32>) {
+        self.inner.borrow_mut().spring.set_velocity(velocity);
+    }
+
+    /// Animates the shared spring toward a new target with the given `velocity`, as with
+    /// [`Spring::interrupt_with_velocity`].
+    pub fn interrupt_with_velocity(&self, target: T, velocity: Vec<f32>) {
+        self.inner
+            .borrow_mut()
+            .spring
+            .interrupt_with_velocity(target, velocity);
+    }
+
+    /// Settles the shared spring at its target immediately.
+    pub fn settle(&self) {
+        self.inner.borrow_mut().spring.settle();
+    }
+
+    /// Settles the shared spring at the given `value` immediately.
+    pub fn settle_at(&self, value: T) {
+        self.inner.borrow_mut().spring.settle_at(value);
+    }
+
+    /// Updates the shared spring with an event, as with [`Spring::update`].
+    ///
+    /// Ticks are ignored if the spring was already ticked for the same frame.
+    pub fn update(&self, event: SpringEvent<T>) {
+        match event {
+            SpringEvent::Tick(now) => self.tick(now),
+            event => self.inner.borrow_mut().spring.update(event),
+        }
+    }
+
+    /// Updates the shared spring based on the elapsed time since the latest tick.
+    ///
+    /// Ticks with a `now` that isn't later than the latest tick are ignored, so every widget
+    /// showing the spring can tick it once per frame.
+    pub fn tick(&self, now: Instant) {
+        let mut inner = self.inner.borrow_mut();
+        if inner.ticked_at.is_some_and(|ticked_at| now <= ticked_at) {
+            return;
+        }
+
+        inner.ticked_at = Some(now);
+        inner.spring.tick(now);
+    }
+
+    /// Borrows the shared spring to read more of it than its value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spring is currently borrowed mutably through [`SharedSpring::borrow_mut`].
+    pub fn borrow(&self) -> Ref<'_, Spring<T>> {
+        Ref::map(self.inner.borrow(), |inner| &inner.spring)
+    }
+
+    /// Borrows the shared spring mutably to change it in ways not covered by the other methods.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spring is currently borrowed through any handle.
+    pub fn borrow_mut(&self) -> RefMut<'_, Spring<T>> {
+        RefMut::map(self.inner.borrow_mut(), |inner| &mut inner.spring)
+    }
+
+    /// Whether both handles share the same spring.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T> Default for SharedSpring<T>
+where
+    T: Animate + Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<Spring<T>> for SharedSpring<T> {
+    fn from(spring: Spring<T>) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(Inner {
+                spring,
+                ticked_at: None,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Ticking through several handles in the same frame should only advance the spring once.
+    #[test]
+    fn ticks_once_per_frame() {
+        let start = Instant::now();
+        let shared = SharedSpring::new(0.0);
+        let other = shared.clone();
+        shared.interrupt(10.0);
+        shared.tick(start);
+
+        let mut single = Spring::new(0.0);
+        single.interrupt(10.0);
+        single.tick(start);
+
+        for frame in 1..=5 {
+            let now = start + Duration::from_millis(16 * frame);
+            shared.tick(now);
+            other.tick(now);
+            single.tick(now);
+        }
+
+        assert_eq!(shared.value(), *single.value());
+        assert_eq!(other.value(), *single.value());
+    }
+
+    /// Changes through one handle should be visible through every other handle.
+    #[test]
+    fn changes_are_shared() {
+        let shared = SharedSpring::new(0.0);
+        let other = shared.clone();
+        other.settle_at(5.0);
+
+        assert!(shared.ptr_eq(&other));
+        assert_eq!(shared.value(), 5.0);
+        assert!(!shared.ptr_eq(&SharedSpring::new(5.0)));
+    }
+}
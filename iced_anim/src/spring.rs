@@ -1,25 +1,150 @@
 //! Spring physics to enable natural and interactive animations.
 use std::{
-    fmt::Debug,
+    fmt::{self, Debug},
     time::{Duration, Instant},
 };
 
-use crate::{spring_event::SpringEvent, Animate, SpringMotion};
+use iced::{futures::channel::oneshot, Task};
+
+use crate::{
+    budget::{self, Priority},
+    rate_limit::RateLimit,
+    repeat::Repeat,
+    rest_threshold::RestThreshold,
+    spring_event::SpringEvent,
+    Animate, Easing, SpringMotion,
+};
 
 /// The minimum percent at which a spring is considered near its target.
 ///
 /// This value is used to determine when a spring is near its target and has low velocity.
 /// It needs to strike a balance between stopping the animation too early and continuing
-/// needlessly when the target is effectively reached.
+/// needlessly when the target is effectively reached. Springs can rest at a different threshold
+/// with [`Spring::with_rest_threshold`].
 pub const ESPILON: f32 = 0.005;
 
 /// The maximum duration between spring updates that is allowed before clamping the time
 /// to avoid large jumps in the spring's value.
 ///
-/// This is particularly noticeable when the window loses focus and the app stops receiving
-/// redraws, causing the next update to have a much larger duration than the last one.
+/// This is particularly noticeable when a frame takes longer than usual, causing the next update
+/// to have a much larger duration than the last one. Springs that go without updates for much
+/// longer, like while the window is minimized, jump to their targets instead, see
+/// [`set_suspend_threshold`](crate::set_suspend_threshold).
 pub const MAX_DURATION: Duration = Duration::from_millis(33);
 
+/// The longest time a spring advances in a single step, which is a quarter of a frame at 60Hz.
+///
+/// Springs are solved exactly, so their motion doesn't depend on the length of a step. Steps
+/// decide how often a spring checks whether it has come to rest and updates the target it
+/// predicts, so each frame is split into equal steps that evenly divide a frame at the
+/// [`refresh_rate`](crate::refresh_rate), and springs come to rest at the same time regardless
+/// of how often they're ticked.
+pub const MAX_STEP: Duration = Duration::from_nanos(4_166_667);
+
+/// The duration of each step in seconds for a display with the given `refresh_rate`, which is a
+/// frame split into as few equal steps as fit within [`MAX_STEP`].
+pub(crate) fn step_duration(refresh_rate: f32) -> f32 {
+    let frame = 1.0 / refresh_rate;
+    frame / (frame / MAX_STEP.as_secs_f32()).ceil().max(1.0)
+}
+
+/// Advances a component of a spring with the given `stiffness` and `damping` forces by `dt`
+/// seconds from its `displacement` to the target, returning how far it moved and its new
+/// `velocity`.
+///
+/// The motion is solved exactly rather than integrated, so it's the same for any `dt` and stays
+/// stable for the stiffest and most strongly damped springs.
+pub(crate) fn advance(
+    stiffness: f32,
+    damping: f32,
+    displacement: f32,
+    velocity: f32,
+    dt: f32,
+) -> (f32, f32) {
+    // Solve for the offset from the target, which is the opposite of the displacement to it.
+    let offset = -displacement;
+    let critical = 2.0 * stiffness.sqrt();
+    if damping > critical * CRITICAL_MARGIN {
+        advance_overdamped(stiffness, damping, offset, velocity, dt)
+    } else if damping * CRITICAL_MARGIN < critical {
+        advance_underdamped(stiffness, damping, offset, velocity, dt)
+    } else {
+        advance_critical(critical / 2.0, offset, velocity, dt)
+    }
+}
+
+/// How far the damping can be from critical damping for springs to be solved as critically
+/// damped, which keeps the rates of decay and oscillation far enough from zero to solve for.
+const CRITICAL_MARGIN: f32 = 1.001;
+
+/// The exact motion of an overdamped spring after `dt` seconds from an `offset` to its target,
+/// which is the sum of a fast and a slow exponential decay toward the target.
+fn advance_overdamped(
+    stiffness: f32,
+    damping: f32,
+    offset: f32,
+    velocity: f32,
+    dt: f32,
+) -> (f32, f32) {
+    let spread = (damping * damping / 4.0 - stiffness).sqrt();
+    let fast = -damping / 2.0 - spread;
+    // The rates multiply to the stiffness, which avoids cancelling out when damping is strong.
+    let slow = stiffness / fast;
+
+    let slow_part = (velocity - fast * offset) / (slow - fast);
+    let fast_part = offset - slow_part;
+    let (slow_decay, fast_decay) = ((slow * dt).exp(), (fast * dt).exp());
+
+    // Measure the movement directly so that tiny steps aren't lost to rounding.
+    let movement = slow_part * (slow * dt).exp_m1() + fast_part * (fast * dt).exp_m1();
+    let velocity = slow * slow_part * slow_decay + fast * fast_part * fast_decay;
+    (movement, velocity)
+}
+
+/// The exact motion of an underdamped spring after `dt` seconds from an `offset` to its target,
+/// which oscillates around the target with a decaying amplitude.
+fn advance_underdamped(
+    stiffness: f32,
+    damping: f32,
+    offset: f32,
+    velocity: f32,
+    dt: f32,
+) -> (f32, f32) {
+    let decay_rate = damping / 2.0;
+    let frequency = (stiffness - decay_rate * decay_rate).sqrt();
+    let cos_part = offset;
+    let sin_part = (velocity + decay_rate * offset) / frequency;
+    let (sin, cos) = (frequency * dt).sin_cos();
+    let decay = (-decay_rate * dt).exp();
+
+    // Measure the movement directly so that tiny steps aren't lost to rounding, using that
+    // `decay * cos - 1` is `(decay - 1) * cos + (cos - 1)`.
+    let half_sin = (frequency * dt / 2.0).sin();
+    let cos_m1 = (-decay_rate * dt).exp_m1() * cos - 2.0 * half_sin * half_sin;
+    let movement = cos_part * cos_m1 + sin_part * decay * sin;
+    let velocity = decay
+        * ((sin_part * frequency - decay_rate * cos_part) * cos
+            - (cos_part * frequency + decay_rate * sin_part) * sin);
+    (movement, velocity)
+}
+
+/// The exact motion of a critically damped spring with the given natural `frequency` after `dt`
+/// seconds from an `offset` to its target, which reaches the target as fast as it can without
+/// overshooting it.
+fn advance_critical(frequency: f32, offset: f32, velocity: f32, dt: f32) -> (f32, f32) {
+    let linear_part = velocity + frequency * offset;
+    let decay = (-frequency * dt).exp();
+    let movement = offset * (-frequency * dt).exp_m1() + linear_part * dt * decay;
+    let velocity = (linear_part - frequency * (offset + linear_part * dt)) * decay;
+    (movement, velocity)
+}
+
+/// The number of steps of the [`step_duration`] that make up the time `dt`, with at least one.
+pub(crate) fn steps(dt: f32) -> u32 {
+    let step = step_duration(crate::refresh_rate());
+    (dt / step).round().max(1.0) as u32
+}
+
 /// A representation of a spring animation that interpolates between values.
 ///
 /// You can use this alongside the `Animation` widget to animate changes to your UI
@@ -28,6 +153,27 @@ pub const MAX_DURATION: Duration = Duration::from_millis(33);
 /// As this is designed for GUI animations and not general-purpose physics simulations,
 /// it includes some features targeted toward avoiding UI issues like overshooting.
 /// See [`MAX_DURATION`] and [`ESPILON`] for examples of this.
+///
+/// Targets that change rapidly can be held back with a [`RateLimit`] so that the spring chases
+/// a calmer version of the incoming values, see [`Spring::with_rate_limit`].
+///
+/// New targets can wait for a fixed delay before the spring starts moving toward them, which
+/// helps stagger animations that start at the same time, see [`Spring::with_delay`].
+///
+/// Animations that cycle on their own, like a pulsing highlight, can start over each time they
+/// reach their target with a [`Repeat`], see [`Spring::with_repeat`].
+///
+/// Targets that are continuously updated, like a stream of progress values, can be followed
+/// without lagging behind by predicting where the target is headed, see [`TargetStrategy`].
+///
+/// Springs that aren't essential can be marked with [`Priority::Low`] so that they jump to their
+/// targets when too many springs are animating at once, see the [`budget`](crate::budget) module.
+///
+/// With the `serde` feature enabled, springs serialize their value, target, motion, and velocity
+/// so that an in-flight animation picks up where it left off after being restored.
+///
+/// Workflows that continue once an animation finishes, like removing a panel after it slides
+/// out, can chain onto the [`Task`] returned by [`Spring::animate_to`] or [`Spring::settled`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Spring<T> {
@@ -37,17 +183,146 @@ pub struct Spring<T> {
     target: T,
     /// The type of motion that the spring will follow, which controls damping/stiffness.
     motion: SpringMotion,
+    /// Motions that override the motion for the components in a given group.
+    #[cfg_attr(feature = "serde", serde(default))]
+    group_motions: Vec<(usize, SpringMotion)>,
     /// The last instant at which this spring's value was updated.
     #[cfg_attr(feature = "serde", serde(skip, default = "Instant::now"))]
     last_update: Instant,
     /// The current velocity components that make up this spring animation.
-    #[cfg_attr(feature = "serde", serde(skip, default))]
+    #[cfg_attr(feature = "serde", serde(default))]
     velocity: Vec<f32>,
     /// The initial distance from the target when the animation was started or interrupted.
     /// This is used to help determine when the spring is near its target and is precomputed
     /// to avoid recalculating it every frame.
-    #[cfg_attr(feature = "serde", serde(skip, default))]
+    #[cfg_attr(feature = "serde", serde(default))]
     initial_distance: Vec<f32>,
+    /// When the spring is close enough to its target to stop animating.
+    #[cfg_attr(feature = "serde", serde(default))]
+    rest_threshold: RestThreshold,
+    /// Limits how often the target changes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    rate_limit: Option<RateLimit>,
+    /// The latest target held back by the rate limit, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pending_target: Option<T>,
+    /// How long new targets wait before the spring starts moving toward them.
+    #[cfg_attr(feature = "serde", serde(default))]
+    delay: Duration,
+    /// The longest the spring animates toward a target before jumping to it, if limited.
+    #[cfg_attr(feature = "serde", serde(default))]
+    max_duration: Option<Duration>,
+    /// How long the current animation has been running, used to enforce the maximum duration.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    animating_for: Duration,
+    /// The latest target waiting for the delay to pass and when it will be applied, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    delayed_target: Option<(T, Instant)>,
+    /// When the rate limit was last reset, which is when the pending target was received for
+    /// debouncing or when the last target was applied for throttling.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    limited_at: Option<Instant>,
+    /// How the spring follows changes to its target.
+    #[cfg_attr(feature = "serde", serde(default))]
+    target_strategy: TargetStrategy,
+    /// The estimated velocity of each component of the target, used to predict the target.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    target_velocity: Vec<f32>,
+    /// The latest target and when it was received, used to estimate the target velocity.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    observed_target: Option<(T, Instant)>,
+    /// The latest time between target updates, used to stop predicting once updates stop.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    target_interval: Duration,
+    /// How important it is for the spring to animate when the animation budget is exceeded.
+    #[cfg_attr(feature = "serde", serde(default))]
+    priority: Priority,
+    /// How many times the animation plays each time the spring is given a new target, if more
+    /// than once.
+    #[cfg_attr(feature = "serde", serde(default))]
+    repeat: Option<Repeat>,
+    /// Whether each play animates back to where it started after reaching the target.
+    #[cfg_attr(feature = "serde", serde(default))]
+    yoyo: bool,
+    /// The value the current animation started from, while it has plays left to repeat or has
+    /// yet to return to it.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    repeat_from: Option<T>,
+    /// The target given to a yoyo animation, while the spring returns to where it started.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    yoyo_target: Option<T>,
+    /// The number of times the current animation has finished playing.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    plays: u32,
+    /// How fast the spring animates relative to its motion.
+    #[cfg_attr(feature = "serde", serde(default = "default_speed"))]
+    speed: f32,
+    /// How long the current tween has been running when following a [`SpringMotion::Curve`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    tween_elapsed: Duration,
+    /// The tasks waiting for the spring to settle.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    waiters: SettleWaiters,
+    /// Tracks the animation to warn about animations that prevent the app from going idle.
+    #[cfg(feature = "debug")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    diagnostics: crate::diagnostics::Diagnostics,
+}
+
+/// The tasks waiting for a [`Spring`] to settle.
+///
+/// Waiters belong to a single spring, so clones of the spring start without any.
+#[derive(Default)]
+struct SettleWaiters(Vec<oneshot::Sender<()>>);
+
+impl SettleWaiters {
+    /// Waits for the next time the spring settles.
+    fn wait(&mut self) -> oneshot::Receiver<()> {
+        let (sender, receiver) = oneshot::channel();
+        self.0.push(sender);
+        receiver
+    }
+
+    /// Notifies every waiting task that the spring settled.
+    fn notify(&mut self) {
+        for sender in self.0.drain(..) {
+            let _ = sender.send(());
+        }
+    }
+}
+
+impl Clone for SettleWaiters {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl PartialEq for SettleWaiters {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Debug for SettleWaiters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SettleWaiters").field(&self.0.len()).finish()
+    }
+}
+
+/// How a [`Spring`] follows changes to its target.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TargetStrategy {
+    /// Animates toward each new target as it arrives.
+    #[default]
+    Follow,
+    /// Estimates how fast the target is moving from its recent updates and aims ahead of it.
+    ///
+    /// A spring chasing a steadily moving target, like a progress percentage streamed from a
+    /// download, always trails behind it. Predicting the target cancels out that lag so the
+    /// value keeps up with the stream. The prediction fades out once updates stop arriving at
+    /// their usual pace, letting the spring settle on the final target.
+    Predict,
 }
 
 // Impls that don't require an `Animate` bound.
@@ -58,6 +333,11 @@ impl<T> Spring<T> {
         self
     }
 
+    /// Returns the current velocity of each component of the spring, per second.
+    pub fn velocity(&self) -> &[f32] {
+        &self.velocity
+    }
+
     /// Returns a reference to this spring's current value.
     pub fn value(&self) -> &T {
         &self.value
@@ -73,16 +353,297 @@ impl<T> Spring<T> {
         self.motion
     }
 
+    /// Returns the motion of the components in the given `group`, which is the spring's motion
+    /// unless the group has a motion of its own.
+    pub fn group_motion(&self, group: usize) -> SpringMotion {
+        self.group_motions
+            .iter()
+            .find(|(existing, _)| *existing == group)
+            .map_or(self.motion, |(_, motion)| *motion)
+    }
+
+    /// Returns the groups that have a motion of their own, along with their motions.
+    pub fn group_motions(&self) -> &[(usize, SpringMotion)] {
+        &self.group_motions
+    }
+
+    /// Updates the `motion` of the components in the given `group`, falling back to the spring's
+    /// motion if `None`. See [`Spring::with_group_motion`].
+    pub fn set_group_motion(&mut self, group: usize, motion: Option<SpringMotion>) {
+        self.group_motions
+            .retain(|(existing, _)| *existing != group);
+        if let Some(motion) = motion {
+            self.group_motions.push((group, motion));
+        }
+    }
+
+    /// Returns an updated spring where the components in the given `group` follow their own
+    /// `motion`, e.g. so the color of a style is snappy while its size is bouncy.
+    ///
+    /// Groups are numbered like [`Animate::component_groups`], so fields grouped with
+    /// `#[animate(group = "name")]` are numbered from 1 in the order the groups first appear,
+    /// while the rest are in group 0. Group motions only apply to springs, since a
+    /// [`SpringMotion::Curve`] tweens the whole value at once, and a curve given as a group
+    /// motion behaves like a smooth spring with the same duration.
+    ///
+    /// ```rust
+    /// # use iced::Color;
+    /// # use iced_anim::{Animate, Spring, SpringMotion};
+    /// #[derive(Debug, Clone, PartialEq, Animate)]
+    /// struct Swatch {
+    ///     color: Color,
+    ///     #[animate(group = "size")]
+    ///     size: f32,
+    /// }
+    ///
+    /// let swatch = Swatch { color: Color::WHITE, size: 24.0 };
+    /// let spring = Spring::new(swatch)
+    ///     .with_motion(SpringMotion::Snappy)
+    ///     .with_group_motion(1, SpringMotion::Bouncy);
+    /// assert_eq!(spring.group_motion(0), SpringMotion::Snappy);
+    /// assert_eq!(spring.group_motion(1), SpringMotion::Bouncy);
+    /// ```
+    pub fn with_group_motion(mut self, group: usize, motion: SpringMotion) -> Self {
+        self.set_group_motion(group, Some(motion));
+        self
+    }
+
+    /// Returns the latest target given to the spring, including a target that is still being
+    /// held back by its [`RateLimit`] or waiting for its delay, or that a yoyo animation is
+    /// returning from.
+    pub fn latest_target(&self) -> &T {
+        self.pending_target
+            .as_ref()
+            .or(self.delayed_target.as_ref().map(|(target, _)| target))
+            .or(self.yoyo_target.as_ref())
+            .unwrap_or(&self.target)
+    }
+
+    /// Returns how long new targets wait before the spring starts moving toward them.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Updates how long new targets wait before the spring starts moving toward them.
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay;
+    }
+
+    /// Returns an updated spring whose new targets wait for the `delay` before the spring
+    /// starts moving toward them.
+    ///
+    /// A target given while another is still waiting replaces it and restarts the delay. Targets
+    /// held back by a [`RateLimit`] start their delay once the limit lets them through.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use iced_anim::Spring;
+    /// let mut spring = Spring::new(0.0).with_delay(Duration::from_millis(100));
+    /// spring.interrupt(5.0);
+    ///
+    /// assert_eq!(spring.target(), &0.0);
+    /// assert_eq!(spring.latest_target(), &5.0);
+    /// assert!(spring.has_energy());
+    /// ```
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns the longest the spring animates toward a target before jumping to it, if limited.
+    pub fn max_duration(&self) -> Option<Duration> {
+        self.max_duration
+    }
+
+    /// Updates the longest the spring animates toward a target before jumping to it, or removes
+    /// the limit when `None`.
+    pub fn set_max_duration(&mut self, max_duration: Option<Duration>) {
+        self.max_duration = max_duration;
+    }
+
+    /// Returns an updated spring that jumps to its target and settles once it has been animating
+    /// toward it for the `max_duration`.
+    ///
+    /// Springs with little damping or a long response can keep oscillating around their target
+    /// for a while, so this guarantees that an animation never runs longer than the limit. Each
+    /// new target, velocity, or play of a repeating animation starts the limit over.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, Instant};
+    /// # use iced_anim::{Spring, SpringMotion};
+    /// let mut spring = Spring::new(0.0)
+    ///     .with_motion(SpringMotion::Bouncy)
+    ///     .with_max_duration(Duration::from_millis(200));
+    /// spring.interrupt(5.0);
+    ///
+    /// let start = Instant::now();
+    /// spring.tick(start + Duration::from_millis(100));
+    /// assert!(spring.has_energy());
+    ///
+    /// spring.tick(start + Duration::from_millis(250));
+    /// assert_eq!(spring.value(), &5.0);
+    /// assert!(!spring.has_energy());
+    /// ```
+    pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+
+    /// Returns the spring's current [`RateLimit`], if any.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit
+    }
+
+    /// Updates the spring's [`RateLimit`], or removes it when `None`.
+    pub fn set_rate_limit(&mut self, rate_limit: Option<RateLimit>) {
+        self.rate_limit = rate_limit;
+    }
+
+    /// Returns an updated spring that limits how often its target changes.
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Returns when the spring is close enough to its target to stop animating.
+    pub fn rest_threshold(&self) -> RestThreshold {
+        self.rest_threshold
+    }
+
+    /// Updates when the spring is close enough to its target to stop animating.
+    pub fn set_rest_threshold(&mut self, rest_threshold: RestThreshold) {
+        self.rest_threshold = rest_threshold;
+    }
+
+    /// Returns an updated spring that stops animating once it's within the `rest_threshold`
+    /// of its target, see [`RestThreshold`].
+    pub fn with_rest_threshold(mut self, rest_threshold: RestThreshold) -> Self {
+        self.rest_threshold = rest_threshold;
+        self
+    }
+
+    /// Returns how the spring follows changes to its target.
+    pub fn target_strategy(&self) -> TargetStrategy {
+        self.target_strategy
+    }
+
+    /// Updates how the spring follows changes to its target.
+    pub fn set_target_strategy(&mut self, target_strategy: TargetStrategy) {
+        self.target_strategy = target_strategy;
+    }
+
+    /// Returns an updated spring that follows changes to its target with the given strategy.
+    pub fn with_target_strategy(mut self, target_strategy: TargetStrategy) -> Self {
+        self.target_strategy = target_strategy;
+        self
+    }
+
+    /// Returns how many times the animation plays for each new target, if more than once.
+    pub fn repeat(&self) -> Option<Repeat> {
+        self.repeat
+    }
+
+    /// Updates how many times the animation plays for each new target, starting with the next
+    /// target. Use `None` to play it once.
+    pub fn set_repeat(&mut self, repeat: Option<Repeat>) {
+        self.repeat = repeat;
+    }
+
+    /// Returns an updated spring that plays its animation toward each new target more than once,
+    /// jumping back to where the animation started each time it reaches the target.
+    ///
+    /// Settling the spring stops the animation from repeating.
+    pub fn with_repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = Some(repeat);
+        self
+    }
+
+    /// Returns whether each play animates back to where it started after reaching the target.
+    pub fn yoyo(&self) -> bool {
+        self.yoyo
+    }
+
+    /// Updates whether each play animates back to where it started, starting with the next
+    /// target.
+    pub fn set_yoyo(&mut self, yoyo: bool) {
+        self.yoyo = yoyo;
+    }
+
+    /// Returns an updated spring that animates back to where it started each time it reaches a
+    /// new target, like a breathing highlight.
+    ///
+    /// Combined with a [`Repeat`], each play goes to the target and back. The spring rests where
+    /// it started once it's out of plays, while [`Spring::latest_target`] still reports the
+    /// target it was given.
+    ///
+    /// ```rust
+    /// # use iced_anim::{Repeat, Spring};
+    /// let mut opacity = Spring::new(0.4).with_yoyo(true).with_repeat(Repeat::Forever);
+    /// opacity.interrupt(1.0);
+    /// assert!(opacity.has_energy());
+    /// ```
+    pub fn with_yoyo(mut self, yoyo: bool) -> Self {
+        self.yoyo = yoyo;
+        self
+    }
+
+    /// Returns the spring's [`Priority`] when the animation budget is exceeded.
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Updates the spring's [`Priority`] when the animation budget is exceeded.
+    pub fn set_priority(&mut self, priority: Priority) {
+        self.priority = priority;
+    }
+
+    /// Returns an updated spring with the given [`Priority`] when the animation budget is
+    /// exceeded.
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns how fast the spring animates relative to its motion.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Updates how fast the spring animates relative to its motion, see [`Spring::with_speed`].
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = if speed.is_finite() {
+            speed.max(0.0)
+        } else {
+            1.0
+        };
+    }
+
+    /// Returns an updated spring that animates `speed` times as fast as its motion, like 0.5 to
+    /// take twice as long. Negative speeds are treated as 0, which pauses the spring.
+    ///
+    /// The speed is multiplied by the [`playback_speed`](crate::playback_speed) of the whole
+    /// app, and applies to fixed-duration [`SpringMotion::Curve`] motions as well.
+    ///
+    /// ```rust
+    /// # use iced_anim::Spring;
+    /// let spring = Spring::new(0.0).with_speed(0.5);
+    /// assert_eq!(spring.speed(), 0.5);
+    /// ```
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.set_speed(speed);
+        self
+    }
+
+    /// How fast the spring animates, combining its own speed with the app's playback speed.
+    fn playback_speed(&self) -> f32 {
+        self.speed * crate::playback_speed()
+    }
+
     /// Returns the instant at which the spring was last updated.
     pub fn last_update(&self) -> Instant {
         self.last_update
     }
 
-    /// Updates the spring's `motion` to the given value.
-    pub fn set_motion(&mut self, motion: SpringMotion) {
-        self.motion = motion;
-    }
-
     /// Returns an updated spring with the given `motion`.
     pub fn with_motion(mut self, motion: SpringMotion) -> Self {
         self.motion = motion;
@@ -103,10 +664,45 @@ where
             value: value.clone(),
             target: value,
             motion,
+            group_motions: Vec::new(),
             last_update: Instant::now(),
             velocity: vec![0.0; T::components()],
             initial_distance: vec![0.0; T::components()],
+            rest_threshold: RestThreshold::default(),
+            rate_limit: None,
+            pending_target: None,
+            delay: Duration::ZERO,
+            max_duration: None,
+            animating_for: Duration::ZERO,
+            delayed_target: None,
+            limited_at: None,
+            target_strategy: TargetStrategy::default(),
+            target_velocity: vec![0.0; T::components()],
+            observed_target: None,
+            target_interval: Duration::ZERO,
+            priority: Priority::default(),
+            repeat: None,
+            yoyo: false,
+            repeat_from: None,
+            yoyo_target: None,
+            plays: 0,
+            speed: 1.0,
+            tween_elapsed: Duration::ZERO,
+            waiters: SettleWaiters::default(),
+            #[cfg(feature = "debug")]
+            diagnostics: Default::default(),
+        }
+    }
+
+    /// Updates the spring's `motion` to the given value.
+    ///
+    /// Switching to a different [`SpringMotion::Curve`] starts a new tween from the current value.
+    pub fn set_motion(&mut self, motion: SpringMotion) {
+        if motion.easing().is_some() && motion != self.motion {
+            self.tween_elapsed = Duration::ZERO;
+            self.initial_distance = self.value.distance_to(&self.target);
         }
+        self.motion = motion;
     }
 
     /// Returns an updated spring with the given `target`.
@@ -115,9 +711,15 @@ where
         self
     }
 
-    /// A spring has energy if it has not yet reached its target or if it is still moving.
-    /// This being `true` means the spring is at rest and doesn't need to be updated.
+    /// A spring has energy if it has not yet reached its target, if it is still moving, or if
+    /// it is waiting to move toward a target held back by its [`RateLimit`] or delay.
+    /// This being `false` means the spring is at rest and doesn't need to be updated.
     pub fn has_energy(&self) -> bool {
+        self.is_moving() || self.pending_target.is_some() || self.delayed_target.is_some()
+    }
+
+    /// Whether the spring has not yet reached its target or is still moving.
+    fn is_moving(&self) -> bool {
         self.value != self.target || self.velocity.iter().any(|&v| v != 0.0)
     }
 
@@ -141,6 +743,9 @@ where
     pub fn update(&mut self, event: SpringEvent<T>) {
         match event {
             SpringEvent::Tick(now) => self.tick(now),
+            SpringEvent::Target(target) if cfg!(feature = "animations-off") => {
+                self.settle_at(target);
+            }
             SpringEvent::Target(target) => self.interrupt(target),
             SpringEvent::Settle => self.settle(),
         }
@@ -149,127 +754,892 @@ where
     /// Updates the spring's value based on the elapsed time since the last update.
     /// The spring will automatically reach its target when the remaining time reaches zero.
     /// This function will do nothing if the spring has no energy.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "spring.tick", level = "trace", skip_all)
+    )]
     pub fn tick(&mut self, now: Instant) {
-        // Don't attempt to update anything if the spring has no energy.
-        if !self.has_energy() {
+        // Targets are applied immediately when animations are compiled out, so there's nothing
+        // to step.
+        if cfg!(feature = "animations-off") {
+            if self.has_energy() {
+                self.settle();
+            }
             return;
         }
 
-        let dt = now.duration_since(self.last_update).min(MAX_DURATION);
-        self.last_update = now;
+        self.apply_pending_target(now);
+        self.apply_delayed_target(now);
 
-        // End the animation if the spring is near the target wiht low velocity.
-        if self.is_near_end() {
-            self.settle();
+        // Don't attempt to update anything if the spring isn't moving.
+        if !self.is_moving() {
             return;
         }
 
-        // Still animating, so calculate the new velocity and update the values.
-        let velocity: Vec<f32> = self
-            .target
-            .distance_to(&self.value)
-            .into_iter()
-            .zip(self.velocity.iter().copied())
-            .map(|(d, v)| self.new_velocity(d, v, dt.as_secs_f32()))
-            .collect();
+        crate::global::record_frame(now);
+        if !crate::animations_enabled() || budget::record_tick(now, self.priority) {
+            self.finish();
+            return;
+        }
 
-        self.velocity.clone_from(&velocity);
-        let mut components = velocity.iter().map(|v| v * dt.as_secs_f32());
-        self.value.update(&mut components);
-    }
+        // Springs restored without their velocity, e.g. when deserialized from state saved by
+        // an older version, resume from rest instead of getting stuck before their target.
+        if self.velocity.len() != T::components() {
+            self.velocity.resize(T::components(), 0.0);
+        }
+        if self.initial_distance.len() != T::components() {
+            self.initial_distance = self.value.distance_to(&self.target);
+        }
 
-    /// Gets the new velocity of the spring given the `displacement` and `velocity`.
-    fn new_velocity(&self, displacement: f32, velocity: f32, dt: f32) -> f32 {
-        let spring: f32 = displacement * self.motion.applied_stiffness();
-        let damping = -self.motion.applied_damping() * velocity;
+        #[cfg(feature = "debug")]
+        let previous = self.value.clone();
 
-        let acceleration = spring + damping;
+        let elapsed = now.duration_since(self.last_update);
+        self.last_update = now;
 
-        velocity + acceleration * dt
-    }
+        // Redraws were suspended, e.g. while the computer slept, so nobody saw the animation.
+        if crate::global::is_suspended(elapsed) {
+            self.complete();
+            return;
+        }
 
-    /// Interrupts the existing animation and starts a new one with the `new_target`.
-    pub fn interrupt(&mut self, new_target: T) {
-        // Reset the last update if the spring doesn't have any energy.
-        // This avoids resetting the last update during continuously interrupted animations.
-        if !self.has_energy() {
-            self.last_update = Instant::now();
+        self.animating_for = self.animating_for.saturating_add(elapsed);
+        if self
+            .max_duration
+            .is_some_and(|max_duration| self.animating_for >= max_duration)
+        {
+            self.complete();
+            return;
         }
+        let dt = elapsed.min(MAX_DURATION).mul_f32(self.playback_speed());
 
-        self.target = new_target;
-        self.initial_distance = self.value.distance_to(&self.target);
-    }
+        if let SpringMotion::Curve { duration, easing } = self.motion {
+            self.tween(dt, duration, easing);
+            return;
+        }
 
-    /// Causes the spring to settle immediately at the target value,
-    /// ending any ongoing animation and setting the velocity to zero.
-    pub fn settle(&mut self) {
-        self.value = self.target.clone();
-        self.velocity = vec![0.0; T::components()];
-    }
+        // The motion of each component and the groups they're in stay the same between steps,
+        // as does the buffer used to check which groups are finished.
+        let motions = self.component_motions();
+        let groups = T::component_groups();
+        let has_groups = groups
+            .first()
+            .is_some_and(|first| groups.iter().any(|group| group != first));
+        let groups = has_groups.then_some(groups.as_slice());
+        let mut near_end = Vec::new();
 
-    /// Makes the spring value and target immediately settle at the given `value`.
-    pub fn settle_at(&mut self, value: T) {
-        self.value = value.clone();
-        self.target = value;
-        self.velocity = vec![0.0; T::components()];
+        // Advance in steps of the same length at every frame rate, including fast playback.
+        let steps = steps(dt.as_secs_f32());
+        for _ in 0..steps {
+            let dt = dt.as_secs_f32() / steps as f32;
+            if !self.step(dt, now, &motions, groups, &mut near_end) {
+                return;
+            }
+        }
+
+        #[cfg(feature = "debug")]
+        if self.is_moving() {
+            self.diagnostics
+                .tick(now, self.motion, self.value != previous);
+        }
     }
 
-    /// Whether the spring is near the end of its animation.
+    /// Advances the spring forward by `dt` seconds, returning whether it's still animating the
+    /// current play.
     ///
-    /// The animation will be stopped when the spring is near the target and has low velocity
-    /// to avoid needlessly animating imperceptible changes.
-    fn is_near_end(&self) -> bool {
-        self.motion.duration().is_zero()
-            || self
-                .value
-                .distance_to(&self.target)
-                .iter()
-                .zip(&self.initial_distance)
-                .zip(&self.velocity)
-                .all(|((d, i), v)| match i {
-                    0.0 => true,
-                    _ => {
-                        let d_percent = (d / i).abs();
-                        let v_percent = (v / i).abs();
-                        d_percent <= ESPILON && v_percent <= ESPILON
-                    }
-                })
-    }
-}
+    /// The `motions` of each component and its `groups`, if there are several, are computed once
+    /// per tick, and `near_end` is a buffer reused between steps.
+    fn step(
+        &mut self,
+        dt: f32,
+        now: Instant,
+        motions: &[SpringMotion],
+        groups: Option<&[usize]>,
+        near_end: &mut Vec<bool>,
+    ) -> bool {
+        // End the animation if the spring is near the target with low velocity.
+        if self.is_near_end(motions) {
+            self.complete();
+            return false;
+        }
 
-impl<T> Default for Spring<T>
-where
-    T: Animate + Default,
-{
-    fn default() -> Self {
-        Self::new(T::default())
-    }
-}
+        // Groups of components that reached their target don't wait on the others.
+        if let Some(groups) = groups {
+            self.settle_finished_groups(groups, motions, near_end);
+        }
 
-#[cfg(test)]
-mod tests {
-    use std::time::Duration;
+        // Still animating, so calculate the new velocity and update the values, turning the
+        // displacement into the movement in place.
+        let mut displacement = self.target.distance_to(&self.value);
+        self.predict_displacement(&mut displacement, motions, now);
+        for ((d, v), motion) in displacement
+            .iter_mut()
+            .zip(self.velocity.iter_mut())
+            .zip(motions)
+        {
+            let stiffness = motion.applied_stiffness();
+            let damping = motion.applied_damping();
+            debug_assert!(
+                stiffness.is_finite() && damping.is_finite(),
+                "spring forces should be finite for {motion:?}",
+            );
+            (*d, *v) = advance(stiffness, damping, *d, *v, dt);
+        }
 
-    use super::*;
+        // Snap to the target rather than letting non-finite values reach the renderer.
+        if !self.velocity.iter().all(|v| v.is_finite()) {
+            log::debug!(
+                "Spring velocity became non-finite ({:?}), settling at target",
+                self.velocity
+            );
+            self.finish();
+            return false;
+        }
 
-    /// The maximum duration between spring updates should be 33ms, or 1 frame at 30fps.
-    #[test]
-    fn max_duration_time() {
-        assert_eq!(MAX_DURATION, Duration::from_millis(33));
+        self.value.update(&mut displacement.into_iter());
+
+        if !self.is_finite() {
+            log::debug!("Spring value became non-finite, settling at target");
+            self.finish();
+            return false;
+        }
+
+        true
     }
 
-    /// Initial springs should have no energy.
-    #[test]
-    fn new_springs_have_no_energy() {
-        let spring = Spring::new(0.0);
-        assert!(!spring.has_energy());
+    /// Advances a fixed-duration tween toward the target along the `easing` curve.
+    fn tween(&mut self, dt: Duration, duration: Duration, easing: Easing) {
+        self.tween_elapsed += dt;
+        if self.tween_elapsed >= duration {
+            self.complete();
+            return;
+        }
+
+        // The initial distance is measured from the target, so it shrinks to nothing at the end.
+        let progress = self.tween_elapsed.as_secs_f32() / duration.as_secs_f32();
+        let remaining = 1.0 - easing.ease(progress);
+        let mut value = self.target.clone();
+        value.update(&mut self.initial_distance.iter().map(|d| d * remaining));
+
+        // Track the velocity so switching to a spring motion mid-tween keeps the momentum.
+        let dt = dt.as_secs_f32();
+        if dt > 0.0 {
+            self.velocity = value
+                .distance_to(&self.value)
+                .into_iter()
+                .map(|d| d / dt)
+                .collect();
+        }
+        self.value = value;
     }
 
-    /// Springs should have energy when the current value is not equal to the target.
-    #[test]
-    fn has_energy_when_target_is_not_current() {
-        let spring = Spring::new(0.0).with_target(5.0);
-        assert!(spring.has_energy());
+    /// The motion of each component, which differs between components in groups with a motion
+    /// of their own.
+    fn component_motions(&self) -> Vec<SpringMotion> {
+        if self.group_motions.is_empty() {
+            return vec![self.motion; T::components()];
+        }
+
+        T::component_groups()
+            .into_iter()
+            .map(|group| self.group_motion(group))
+            .collect()
+    }
+
+    /// Aims the `displacement` ahead of the target by its estimated velocity when predicting,
+    /// given the motion of each component.
+    fn predict_displacement(
+        &self,
+        displacement: &mut [f32],
+        motions: &[SpringMotion],
+        now: Instant,
+    ) {
+        if self.target_strategy != TargetStrategy::Predict {
+            return;
+        }
+        let Some((_, observed_at)) = &self.observed_target else {
+            return;
+        };
+        let interval = self.target_interval.as_secs_f32();
+        if interval <= 0.0 {
+            return;
+        }
+
+        // Fade out the prediction once updates stop arriving at their usual pace.
+        let elapsed = now.saturating_duration_since(*observed_at).as_secs_f32();
+        let fade = (2.0 - elapsed / interval).clamp(0.0, 1.0);
+
+        // A spring following a target moving at a steady velocity trails behind it by the
+        // velocity times the ratio of damping to stiffness, so aim that far ahead of it.
+        for ((d, v), motion) in displacement
+            .iter_mut()
+            .zip(&self.target_velocity)
+            .zip(motions)
+        {
+            let lead = motion.applied_damping() / motion.applied_stiffness() * fade;
+            if lead.is_finite() {
+                *d += v * lead;
+            }
+        }
+    }
+
+    /// Whether the spring's current value is finite relative to its target.
+    fn is_finite(&self) -> bool {
+        self.value
+            .distance_to(&self.target)
+            .iter()
+            .all(|d| d.is_finite())
+    }
+
+    /// Interrupts the existing animation and starts a new one with the `new_target`.
+    ///
+    /// The spring jumps to the `new_target` instead while animations are disabled, see
+    /// [`set_animations_enabled`](crate::set_animations_enabled). With a [`RateLimit`], the
+    /// `new_target` may be held back until a later [`Spring::tick`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "spring.interrupt", level = "trace", skip_all)
+    )]
+    pub fn interrupt(&mut self, new_target: T) {
+        if !crate::animations_enabled() {
+            self.settle_at(new_target);
+            return;
+        }
+
+        if let Some(rate_limit) = self.rate_limit {
+            let now = Instant::now();
+            let is_limited = match rate_limit {
+                RateLimit::Debounce(_) => {
+                    self.limited_at = Some(now);
+                    true
+                }
+                RateLimit::Throttle(duration) => self
+                    .limited_at
+                    .is_some_and(|limited_at| now < limited_at + duration),
+            };
+
+            if is_limited {
+                self.pending_target = Some(new_target);
+                return;
+            }
+
+            self.limited_at = Some(now);
+            self.pending_target = None;
+        }
+
+        self.schedule(new_target, Instant::now());
+    }
+
+    /// Moves toward the `new_target` right away, or once the delay has passed after `now`.
+    fn schedule(&mut self, new_target: T, now: Instant) {
+        if self.delay.is_zero() {
+            self.delayed_target = None;
+            self.retarget(new_target);
+        } else {
+            self.delayed_target = Some((new_target, now + self.delay));
+        }
+    }
+
+    /// Moves toward the target waiting for the delay once the delay has passed.
+    fn apply_delayed_target(&mut self, now: Instant) {
+        if self
+            .delayed_target
+            .as_ref()
+            .is_some_and(|(_, applied_at)| now >= *applied_at)
+        {
+            if let Some((target, _)) = self.delayed_target.take() {
+                self.retarget(target);
+            }
+        }
+    }
+
+    /// Moves toward the target held back by the rate limit once the limit has passed.
+    fn apply_pending_target(&mut self, now: Instant) {
+        let (Some(rate_limit), Some(limited_at)) = (self.rate_limit, self.limited_at) else {
+            return;
+        };
+        if self.pending_target.is_none() || now < limited_at + rate_limit.duration() {
+            return;
+        }
+
+        if let RateLimit::Throttle(_) = rate_limit {
+            self.limited_at = Some(now);
+        }
+        if let Some(target) = self.pending_target.take() {
+            self.schedule(target, now);
+        }
+    }
+
+    /// Starts animating toward the `new_target` from the current value and velocity.
+    fn retarget(&mut self, new_target: T) {
+        // Reset the last update if the spring isn't moving.
+        // This avoids resetting the last update during continuously interrupted animations.
+        if !self.is_moving() {
+            self.last_update = Instant::now();
+        }
+
+        // Tweens restart from the current value, so every group restarts along with them.
+        self.tween_elapsed = Duration::ZERO;
+        self.animating_for = Duration::ZERO;
+        let groups = T::component_groups();
+        let is_grouped =
+            groups.iter().any(|group| *group != groups[0]) && self.motion.easing().is_none();
+        if is_grouped && self.initial_distance.len() == T::components() {
+            // Only restart the progress of groups whose target changed.
+            let changed: Vec<bool> = new_target
+                .distance_to(&self.target)
+                .iter()
+                .map(|d| *d != 0.0)
+                .collect();
+            let distance = self.value.distance_to(&new_target);
+            for (i, group) in groups.iter().enumerate() {
+                let is_group_changed = groups
+                    .iter()
+                    .zip(&changed)
+                    .any(|(other, changed)| other == group && *changed);
+                if is_group_changed {
+                    self.initial_distance[i] = distance[i];
+                }
+            }
+        } else {
+            self.initial_distance = self.value.distance_to(&new_target);
+        }
+
+        if self.target_strategy == TargetStrategy::Predict {
+            self.observe_target(&new_target);
+        }
+        if self.repeat.is_some() || self.yoyo {
+            self.repeat_from = Some(self.value.clone());
+            self.plays = 0;
+        }
+        self.yoyo_target = None;
+        self.target = new_target;
+    }
+
+    /// Animates toward the `target` like [`Spring::interrupt`], returning a [`Task`] that
+    /// completes once the spring settles.
+    ///
+    /// The spring still needs to be ticked, e.g. by an [`Animation`](crate::Animation) widget,
+    /// for the task to complete. Other logic can then be chained onto the task:
+    ///
+    /// ```rust
+    /// # use iced::Task;
+    /// # use iced_anim::{Spring, SpringEvent};
+    /// # #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Close,
+    ///     Closed,
+    ///     Panel(SpringEvent<f32>),
+    /// }
+    ///
+    /// struct State {
+    ///     panel: Spring<f32>,
+    ///     is_open: bool,
+    /// }
+    ///
+    /// impl State {
+    ///     fn update(&mut self, message: Message) -> Task<Message> {
+    ///         match message {
+    ///             Message::Close => self.panel.animate_to(0.0).map(|()| Message::Closed),
+    ///             Message::Closed => {
+    ///                 self.is_open = false;
+    ///                 Task::none()
+    ///             }
+    ///             Message::Panel(event) => {
+    ///                 self.panel.update(event);
+    ///                 Task::none()
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn animate_to(&mut self, target: T) -> Task<()> {
+        self.interrupt(target);
+        self.settled()
+    }
+
+    /// Returns a [`Task`] that completes the next time the spring settles, or right away if
+    /// it's already at rest.
+    ///
+    /// Retargeting the spring before it settles delays the task until it settles at the new
+    /// target. The task never completes if the spring is dropped before settling.
+    pub fn settled(&mut self) -> Task<()> {
+        if !self.has_energy() {
+            return Task::done(());
+        }
+
+        Task::future(self.waiters.wait()).and_then(Task::done)
+    }
+
+    /// Estimates the velocity of the target from the time since the last observed target.
+    fn observe_target(&mut self, new_target: &T) {
+        // Targets are timed by frame, so several targets in the same frame share one time.
+        let now = self.last_update;
+        let observed_at = match &self.observed_target {
+            Some((previous, observed_at)) if now > *observed_at => {
+                let elapsed = now.duration_since(*observed_at);
+                self.target_velocity = new_target
+                    .distance_to(previous)
+                    .into_iter()
+                    .map(|d| d / elapsed.as_secs_f32())
+                    .collect();
+                self.target_interval = elapsed;
+                now
+            }
+            Some((_, observed_at)) => *observed_at,
+            None => now,
+        };
+
+        self.observed_target = Some((new_target.clone(), observed_at));
+    }
+
+    /// Adds the given `velocity` to the spring, causing it to move away from
+    /// and then return to its target without changing the target itself.
+    ///
+    /// This is useful for one-shot effects like shaking a field after an error,
+    /// where a spring at rest is kicked and then oscillates back to where it was.
+    ///
+    /// ```rust
+    /// # use iced_anim::Spring;
+    /// let mut spring = Spring::new(0.0);
+    /// spring.impulse(vec![100.0]);
+    /// assert!(spring.has_energy());
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "spring.impulse", level = "trace", skip_all)
+    )]
+    pub fn impulse(&mut self, velocity: Vec<f32>) {
+        let velocity = self
+            .velocity
+            .iter()
+            .zip(velocity)
+            .map(|(current, added)| current + added)
+            .collect();
+        self.set_velocity(velocity);
+    }
+
+    /// Replaces the velocity of the spring with the given `velocity` per second of each
+    /// component, e.g. to hand off the velocity of a drag gesture when it's released so the
+    /// value keeps its momentum instead of starting from rest.
+    ///
+    /// Fixed-duration [`SpringMotion::Curve`] motions don't follow the velocity.
+    ///
+    /// ```rust
+    /// # use iced_anim::Spring;
+    /// let mut spring = Spring::new(0.0);
+    /// spring.set_velocity(vec![500.0]);
+    /// assert_eq!(spring.velocity(), &[500.0]);
+    /// assert!(spring.has_energy());
+    /// ```
+    pub fn set_velocity(&mut self, velocity: Vec<f32>) {
+        if !crate::animations_enabled() {
+            return;
+        }
+
+        if !self.is_moving() {
+            self.last_update = Instant::now();
+        }
+        self.velocity = velocity;
+        self.velocity.resize(T::components(), 0.0);
+        self.animating_for = Duration::ZERO;
+
+        // Springs at their target don't have an initial distance to measure whether
+        // they're near the end, so use the approximate amplitude of the velocity instead.
+        let period = self.motion.duration().as_secs_f32() / std::f32::consts::TAU;
+        for (current, initial) in self.velocity.iter().zip(&mut self.initial_distance) {
+            *initial = initial.abs().max((*current * period).abs());
+        }
+    }
+
+    /// Interrupts the existing animation like [`Spring::interrupt`], continuing toward the
+    /// `new_target` with the given `velocity` rather than the spring's own, see
+    /// [`Spring::set_velocity`].
+    ///
+    /// This is how a fling works: when a drag is released, the spring animates toward where
+    /// the value should come to rest, starting with the velocity of the drag.
+    pub fn interrupt_with_velocity(&mut self, new_target: T, velocity: Vec<f32>) {
+        self.interrupt(new_target);
+        self.set_velocity(velocity);
+    }
+
+    /// Causes the spring to settle immediately at the target value,
+    /// ending any ongoing animation and setting the velocity to zero.
+    ///
+    /// Any target held back by the [`RateLimit`] or waiting for the delay is applied immediately
+    /// as well.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "spring.settle", level = "trace", skip_all)
+    )]
+    pub fn settle(&mut self) {
+        let delayed_target = self.delayed_target.take().map(|(target, _)| target);
+        if let Some(target) = self.pending_target.take().or(delayed_target) {
+            self.target = target;
+        }
+        self.finish();
+    }
+
+    /// Ends a play of the animation that reached its target, starting over if it repeats.
+    ///
+    /// Yoyo animations head back to where they started first, and start over by heading back
+    /// out to the target they were given.
+    fn complete(&mut self) {
+        if self.yoyo && self.yoyo_target.is_none() {
+            if let Some(start) = self.repeat_from.clone() {
+                self.value = std::mem::replace(&mut self.target, start);
+                self.yoyo_target = Some(self.value.clone());
+                self.restart();
+                return;
+            }
+        }
+
+        self.plays = self.plays.saturating_add(1);
+        let plays_again = self
+            .repeat
+            .is_some_and(|repeat| repeat.plays_again(self.plays));
+        match self.repeat_from.clone() {
+            Some(start) if plays_again => {
+                self.value = match self.yoyo_target.take() {
+                    Some(target) => std::mem::replace(&mut self.target, target),
+                    None => start,
+                };
+                self.restart();
+            }
+            _ => self.finish(),
+        }
+    }
+
+    /// Starts a new play of the animation toward the target from the current value at rest.
+    fn restart(&mut self) {
+        self.initial_distance = self.value.distance_to(&self.target);
+        self.velocity = vec![0.0; T::components()];
+        self.tween_elapsed = Duration::ZERO;
+        self.animating_for = Duration::ZERO;
+    }
+
+    /// Ends the current animation at the target, leaving any pending target for later.
+    fn finish(&mut self) {
+        self.repeat_from = None;
+        self.value = self.target.clone();
+        self.velocity = vec![0.0; T::components()];
+        if self.pending_target.is_none() && self.delayed_target.is_none() {
+            self.waiters.notify();
+        }
+        #[cfg(feature = "debug")]
+        self.diagnostics.reset();
+    }
+
+    /// Makes the spring value and target immediately settle at the given `value`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "spring.settle_at", level = "trace", skip_all)
+    )]
+    pub fn settle_at(&mut self, value: T) {
+        self.repeat_from = None;
+        self.yoyo_target = None;
+        self.pending_target = None;
+        self.delayed_target = None;
+        self.observed_target = None;
+        self.target_velocity = vec![0.0; T::components()];
+        self.value = value.clone();
+        self.target = value;
+        self.velocity = vec![0.0; T::components()];
+        self.waiters.notify();
+        #[cfg(feature = "debug")]
+        self.diagnostics.reset();
+    }
+
+    /// Whether the spring is near the end of its animation.
+    ///
+    /// The animation will be stopped when the spring is near the target and has low velocity
+    /// to avoid needlessly animating imperceptible changes.
+    fn is_near_end(&self, motions: &[SpringMotion]) -> bool {
+        self.value
+            .distance_to(&self.target)
+            .iter()
+            .zip(&self.initial_distance)
+            .zip(&self.velocity)
+            .zip(motions)
+            .all(|(((d, i), v), motion)| self.is_component_near_end(*motion, *d, *i, *v))
+    }
+
+    /// Whether a component with the given `motion` is near the end of its animation, which is
+    /// always the case for components with an instant motion.
+    fn is_component_near_end(&self, motion: SpringMotion, d: f32, i: f32, v: f32) -> bool {
+        motion.clamped().duration().is_zero() || self.rest_threshold.is_near_end(d, i, v)
+    }
+
+    /// Snaps each of the `groups` of components that is near its target to the target, so that
+    /// groups settle independently of each other, using `near_end` as a buffer.
+    fn settle_finished_groups(
+        &mut self,
+        groups: &[usize],
+        motions: &[SpringMotion],
+        near_end: &mut Vec<bool>,
+    ) {
+        near_end.clear();
+        near_end.extend(
+            self.value
+                .distance_to(&self.target)
+                .iter()
+                .zip(&self.initial_distance)
+                .zip(&self.velocity)
+                .zip(motions)
+                .map(|(((d, i), v), motion)| self.is_component_near_end(*motion, *d, *i, *v)),
+        );
+        let is_finished = |group: usize| {
+            groups
+                .iter()
+                .zip(near_end.iter())
+                .all(|(other, near_end)| *other != group || *near_end)
+        };
+
+        // Only move the components of finished groups, which stop where they are.
+        let mut snap = self.target.distance_to(&self.value);
+        for ((snap, velocity), group) in snap.iter_mut().zip(&mut self.velocity).zip(groups) {
+            if is_finished(*group) {
+                *velocity = 0.0;
+            } else {
+                *snap = 0.0;
+            }
+        }
+        self.value.update(&mut snap.into_iter());
+    }
+}
+
+impl<T> Default for Spring<T>
+where
+    T: Animate + Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// The default speed of deserialized springs.
+#[cfg(feature = "serde")]
+fn default_speed() -> f32 {
+    1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    #[cfg(not(feature = "animations-off"))]
+    use crate::spring_motion::MAX_DAMPING;
+    use crate::spring_motion::{MIN_DAMPING, MIN_RESPONSE};
+
+    /// The maximum duration between spring updates should be 33ms, or 1 frame at 30fps.
+    #[test]
+    fn max_duration_time() {
+        assert_eq!(MAX_DURATION, Duration::from_millis(33));
+    }
+
+    /// Frames at common refresh rates should be split into equal steps no longer than
+    /// [`MAX_STEP`].
+    #[test]
+    fn steps_divide_frames() {
+        let max = MAX_STEP.as_secs_f32();
+        for (refresh_rate, steps) in [(30.0, 8.0), (60.0, 4.0), (120.0, 2.0), (144.0, 2.0)] {
+            let step = step_duration(refresh_rate);
+            assert!(step <= max * 1.0001);
+            assert!((step * steps * refresh_rate - 1.0).abs() < 0.0001);
+        }
+        assert!((step_duration(480.0) - 1.0 / 480.0).abs() < 0.0001);
+    }
+
+    /// Overdamped springs should approach their target without overshooting or blowing up, even
+    /// with the shortest responses.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn overdamped_springs_never_overshoot() {
+        for response in [MIN_RESPONSE, Duration::from_millis(300)] {
+            for damping in [1.01, 1.5, 3.0, MAX_DAMPING] {
+                let motion = SpringMotion::Custom { response, damping };
+                let mut spring = Spring::new(0.0).with_motion(motion);
+                spring.interrupt(100.0);
+                let mut now = spring.last_update();
+                let mut previous = 0.0;
+                let mut ticks = 0;
+                while spring.has_energy() {
+                    now += Duration::from_millis(30);
+                    spring.tick(now);
+                    assert!(*spring.value() >= previous, "{motion:?} moved backward");
+                    assert!(*spring.value() <= 100.0, "{motion:?} overshot");
+                    previous = *spring.value();
+                    ticks += 1;
+                }
+                assert!(ticks > 1, "{motion:?} jumped to the target");
+                assert_eq!(spring.value(), &100.0);
+            }
+        }
+    }
+
+    /// Springs should match a finely integrated spring whether they're underdamped, critically
+    /// damped, or overdamped.
+    #[test]
+    fn exact_motion_matches_integration() {
+        let stiffness = 100.0;
+        for damping in [2.0, 19.0, 20.0, 21.0, 60.0] {
+            let (mut d, mut v) = (1.0, 0.5);
+            for _ in 0..100_000 {
+                let dt = 0.1 / 100_000.0;
+                v += (d * stiffness - damping * v) * dt;
+                d -= v * dt;
+            }
+
+            let (movement, exact_v) = advance(stiffness, damping, 1.0, 0.5, 0.1);
+            let exact_d = 1.0 - movement;
+            assert!((exact_d - d).abs() < 1e-3, "{damping}: {exact_d} != {d}");
+            assert!((exact_v - v).abs() < 1e-3, "{damping}: {exact_v} != {v}");
+        }
+    }
+
+    /// Springs should stay stable with the shortest response at any damping.
+    #[test]
+    fn short_responses_are_stable() {
+        for damping in [MIN_DAMPING, 0.5, 1.0] {
+            let motion = SpringMotion::Custom {
+                response: MIN_RESPONSE,
+                damping,
+            };
+            let mut spring = Spring::new(0.0).with_motion(motion);
+            spring.interrupt(100.0);
+            let mut now = spring.last_update();
+            for _ in 0..100 {
+                now += Duration::from_millis(30);
+                spring.tick(now);
+                assert!(spring.value().abs() < 200.0, "{motion:?} blew up");
+            }
+            assert!(!spring.has_energy(), "{motion:?} didn't settle");
+        }
+    }
+
+    /// Springs should follow the same motion regardless of the frame rate they're ticked at.
+    #[test]
+    fn same_motion_at_any_frame_rate() {
+        let motions = [
+            SpringMotion::Smooth,
+            SpringMotion::Snappy,
+            SpringMotion::Bouncy,
+        ];
+        for motion in motions {
+            let mut at_rates = [60.0, 120.0, 144.0].map(|refresh_rate| {
+                let mut spring = Spring::new(0.0).with_motion(motion);
+                spring.interrupt(100.0);
+                let start = spring.last_update();
+                let frames = (refresh_rate / 4.0) as u32;
+                for frame in 1..=frames {
+                    spring.tick(start + Duration::from_secs_f32(frame as f32 / refresh_rate));
+                }
+                *spring.value()
+            });
+
+            // Every rate should land on the same value after a quarter second.
+            at_rates.sort_by(f32::total_cmp);
+            assert!(at_rates[2] - at_rates[0] < 0.01, "{motion:?}: {at_rates:?}");
+        }
+    }
+
+    /// Springs that go without updates for longer than the suspend threshold should jump to
+    /// their targets rather than resuming, while repeating springs start another play.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn settles_after_suspension() {
+        let mut spring = Spring::new(0.0).with_target(1.0);
+        let start = spring.last_update();
+        spring.tick(start + Duration::from_millis(30));
+        assert!(spring.has_energy());
+
+        spring.tick(start + Duration::from_secs(7200));
+        assert_eq!(spring.value(), &1.0);
+        assert!(!spring.has_energy());
+
+        let mut repeating = Spring::new(0.0).with_repeat(Repeat::Forever);
+        repeating.interrupt(1.0);
+        let start = repeating.last_update();
+        repeating.tick(start + Duration::from_secs(7200));
+        assert_eq!(repeating.value(), &0.0);
+        assert!(repeating.has_energy());
+    }
+
+    /// Faster springs should cover more distance in the same time, even past a single step.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn speed_scales_elapsed_time() {
+        let mut normal = Spring::new(0.0);
+        let mut fast = Spring::new(0.0).with_speed(4.0);
+        normal.interrupt(1.0);
+        fast.interrupt(1.0);
+        normal.tick(normal.last_update() + Duration::from_millis(30));
+        fast.tick(fast.last_update() + Duration::from_millis(30));
+        assert!(fast.value() > normal.value());
+        assert!(*fast.value() <= 1.5);
+
+        let mut tween = Spring::new(0.0)
+            .with_motion(SpringMotion::curve(
+                Duration::from_millis(100),
+                Easing::Linear,
+            ))
+            .with_speed(2.0);
+        tween.interrupt(1.0);
+        let start = tween.last_update();
+        tween.tick(start + Duration::from_millis(25));
+        assert!((tween.value() - 0.5).abs() < 0.01);
+
+        assert_eq!(Spring::new(0.0).with_speed(-1.0).speed(), 0.0);
+    }
+
+    /// Springs should jump to their target once they've animated for their maximum duration,
+    /// and keep animating without one.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn max_duration_settles_springs() {
+        let motion = SpringMotion::Custom {
+            response: Duration::from_secs(2),
+            damping: MIN_DAMPING,
+        };
+        let mut spring = Spring::new(0.0)
+            .with_motion(motion)
+            .with_max_duration(Duration::from_secs(2));
+        spring.interrupt(10.0);
+        let start = spring.last_update();
+
+        let mut elapsed = Duration::ZERO;
+        while spring.has_energy() {
+            elapsed += Duration::from_millis(16);
+            spring.tick(start + elapsed);
+        }
+        assert_eq!(spring.value(), &10.0);
+        assert!(elapsed <= Duration::from_secs(2), "{elapsed:?}");
+
+        spring.interrupt(0.0);
+        spring.set_max_duration(None);
+        let start = spring.last_update();
+        let mut elapsed = Duration::ZERO;
+        while elapsed < Duration::from_secs(3) {
+            elapsed += Duration::from_millis(16);
+            spring.tick(start + elapsed);
+        }
+        assert!(spring.has_energy());
+    }
+
+    /// Initial springs should have no energy.
+    #[test]
+    fn new_springs_have_no_energy() {
+        let spring = Spring::new(0.0);
+        assert!(!spring.has_energy());
+    }
+
+    /// Springs should have energy when the current value is not equal to the target.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn has_energy_when_target_is_not_current() {
+        let spring = Spring::new(0.0).with_target(5.0);
+        assert!(spring.has_energy());
     }
 
     /// Springs should have energy when the velocity is not zero.
@@ -290,6 +1660,7 @@ mod tests {
         assert_eq!(spring.velocity, vec![0.0]);
     }
 
+    #[cfg(not(feature = "animations-off"))]
     #[test]
     fn tick_changes_value_and_last_update_time() {
         let mut spring = Spring::new(0.0).with_target(1.0);
@@ -329,6 +1700,7 @@ mod tests {
     /// This is to avoid resetting the last update during continuously interrupted animations,
     /// which can cause the Diff -> Event loop to have 0ms duration between updates when the real
     /// duration between renders is much longer.
+    #[cfg(not(feature = "animations-off"))]
     #[test]
     fn interrupt_does_not_reset_last_update_with_energy() {
         let mut spring = Spring::new(0.0).with_target(10.0).with_velocity(vec![1.0]);
@@ -356,6 +1728,213 @@ mod tests {
         assert_eq!(spring.velocity, vec![0.0]);
     }
 
+    /// Springs missing their velocity should resume animating toward their target.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn tick_without_velocity_resumes() {
+        let mut spring = Spring::new(0.0).with_target(1.0).with_velocity(vec![]);
+        spring.initial_distance.clear();
+
+        let now = spring.last_update() + Duration::from_millis(16);
+        spring.tick(now);
+        assert!(*spring.value() > 0.0);
+        assert_eq!(spring.velocity.len(), 1);
+    }
+
+    /// An impulse should oscillate around the target before coming to rest at it.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn impulse_oscillates_around_target() {
+        let mut spring = Spring::new(0.0).with_motion(SpringMotion::Bouncy);
+        spring.impulse(vec![100.0]);
+        assert_eq!(spring.target(), &0.0);
+
+        let mut now = spring.last_update();
+        let (mut min, mut max) = (0.0_f32, 0.0_f32);
+        for _ in 0..500 {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+            min = min.min(*spring.value());
+            max = max.max(*spring.value());
+        }
+
+        assert!(max > 0.0);
+        assert!(min < 0.0);
+        assert!(!spring.has_energy());
+        assert_eq!(spring.value(), &0.0);
+    }
+
+    /// A pair of values that settle independently of each other.
+    #[cfg(not(feature = "animations-off"))]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Grouped(f32, f32);
+
+    #[cfg(not(feature = "animations-off"))]
+    impl Animate for Grouped {
+        fn components() -> usize {
+            2
+        }
+
+        fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+            self.0.update(components);
+            self.1.update(components);
+        }
+
+        fn distance_to(&self, end: &Self) -> Vec<f32> {
+            [self.0.distance_to(&end.0), self.1.distance_to(&end.1)].concat()
+        }
+
+        fn component_groups() -> Vec<usize> {
+            vec![0, 1]
+        }
+    }
+
+    /// Retargeting one group shouldn't restart the progress of other groups.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn interrupt_keeps_other_groups() {
+        let mut spring = Spring::new(Grouped(0.0, 0.0)).with_target(Grouped(10.0, 10.0));
+        let mut now = spring.last_update();
+        for _ in 0..10 {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+        }
+
+        spring.interrupt(Grouped(20.0, 10.0));
+        assert_eq!(spring.initial_distance[1], -10.0);
+        assert_ne!(spring.initial_distance[0], -10.0);
+    }
+
+    /// Groups that reach their target should settle without waiting on other groups.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn groups_settle_independently() {
+        let mut spring = Spring::new(Grouped(0.0, 0.0))
+            .with_motion(SpringMotion::Snappy)
+            .with_target(Grouped(10.0, 0.0));
+        let mut now = spring.last_update();
+        for _ in 0..20 {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+        }
+
+        spring.interrupt(Grouped(10.0, 10.0));
+        while spring.value().0 != 10.0 {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+        }
+        assert!(spring.has_energy());
+        assert_eq!(spring.velocity[0], 0.0);
+    }
+
+    /// Handing off a velocity should carry the spring further than starting from rest.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn interrupt_with_velocity_keeps_momentum() {
+        let mut flung = Spring::new(0.0);
+        let mut dropped = flung.clone();
+        flung.interrupt_with_velocity(100.0, vec![2000.0]);
+        dropped.interrupt(100.0);
+        assert_eq!(flung.velocity(), &[2000.0]);
+
+        let now = flung.last_update() + Duration::from_millis(30);
+        flung.tick(now);
+        dropped.tick(now);
+        assert!(flung.value() > dropped.value());
+    }
+
+    /// Setting a velocity at rest should move the spring away and back to its target.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn set_velocity_at_rest() {
+        let mut spring = Spring::new(0.0);
+        spring.set_velocity(vec![100.0, 5.0]);
+        assert_eq!(spring.velocity(), &[100.0]);
+
+        let mut now = spring.last_update();
+        let mut max = 0.0_f32;
+        while spring.has_energy() {
+            now += Duration::from_millis(30);
+            spring.tick(now);
+            max = max.max(*spring.value());
+        }
+        assert!(max > 0.0);
+        assert_eq!(spring.value(), &0.0);
+    }
+
+    /// An absolute rest threshold should keep large animations going until they're close.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn rest_threshold_sets_when_to_rest() {
+        let final_jump = |rest_threshold: RestThreshold| {
+            let mut spring = Spring::new(0.0)
+                .with_rest_threshold(rest_threshold)
+                .with_target(4000.0);
+            let mut now = spring.last_update();
+            let mut previous = 0.0;
+            while spring.has_energy() {
+                previous = *spring.value();
+                now += Duration::from_millis(30);
+                spring.tick(now);
+            }
+            4000.0 - previous
+        };
+
+        assert!(final_jump(RestThreshold::default()) > 1.0);
+        assert!(final_jump(RestThreshold::absolute(0.1)) <= 0.1);
+    }
+
+    /// Groups with a motion of their own should follow it instead of the spring's motion.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn groups_follow_their_motion() {
+        let mut spring = Spring::new(Grouped(0.0, 0.0))
+            .with_motion(SpringMotion::Smooth)
+            .with_group_motion(1, SpringMotion::Bouncy)
+            .with_target(Grouped(10.0, 10.0));
+        assert_eq!(spring.group_motion(0), SpringMotion::Smooth);
+        assert_eq!(spring.group_motion(1), SpringMotion::Bouncy);
+
+        let mut now = spring.last_update();
+        let (mut max_smooth, mut max_bouncy) = (0.0_f32, 0.0_f32);
+        while spring.has_energy() {
+            now += Duration::from_millis(30);
+            spring.tick(now);
+            max_smooth = max_smooth.max(spring.value().0);
+            max_bouncy = max_bouncy.max(spring.value().1);
+        }
+        assert!(max_smooth <= 10.0);
+        assert!(max_bouncy > 10.0);
+
+        spring.set_group_motion(1, None);
+        assert_eq!(spring.group_motion(1), SpringMotion::Smooth);
+        assert!(spring.group_motions().is_empty());
+    }
+
+    /// Springs should jump to their target when animations are compiled out.
+    #[cfg(feature = "animations-off")]
+    #[test]
+    fn interrupt_jumps_when_animations_off() {
+        let spring = Spring::new(0.0).with_target(5.0);
+        assert_eq!(spring.value(), &5.0);
+        assert!(!spring.has_energy());
+    }
+
+    /// Updates should assign targets directly and ticks should leave springs at rest when
+    /// animations are compiled out.
+    #[cfg(feature = "animations-off")]
+    #[test]
+    fn update_jumps_when_animations_off() {
+        let mut spring = Spring::new(0.0);
+        spring.update(SpringEvent::Target(5.0));
+        assert_eq!(spring.value(), &5.0);
+        assert_eq!(spring.target(), &5.0);
+
+        spring.update(SpringEvent::Tick(Instant::now()));
+        assert_eq!(spring.value(), &5.0);
+        assert!(!spring.has_energy());
+    }
+
     /// Springs should implement [`Default`] if `T` does.
     #[test]
     fn default_impl() {
@@ -372,7 +1951,7 @@ mod tests {
                 response: Duration::ZERO,
                 damping: 0.5,
             });
-        assert!(spring.is_near_end());
+        assert!(spring.is_near_end(&spring.component_motions()));
     }
 
     /// A spring with a response of zero should settle immediately.
@@ -386,4 +1965,365 @@ mod tests {
         spring.update(SpringEvent::Tick(Instant::now()));
         assert_eq!(spring.value(), spring.target());
     }
+
+    /// Responses that are too short to animate should settle immediately
+    /// instead of producing non-finite values.
+    #[test]
+    fn update_pathological_response() {
+        let mut spring = Spring::new(0.0)
+            .with_target(1.0)
+            .with_motion(SpringMotion::Custom {
+                response: Duration::from_nanos(1),
+                damping: 1.0,
+            });
+        spring.update(SpringEvent::Tick(Instant::now()));
+        assert_eq!(spring.value(), spring.target());
+    }
+
+    /// Invalid damping should still produce finite motion toward the target.
+    #[test]
+    fn update_pathological_damping() {
+        let mut spring = Spring::new(0.0)
+            .with_target(1.0)
+            .with_motion(SpringMotion::Custom {
+                response: Duration::from_millis(300),
+                damping: f32::NAN,
+            });
+        spring.update(SpringEvent::Tick(
+            Instant::now() + Duration::from_millis(16),
+        ));
+        assert!(spring.value().is_finite());
+    }
+
+    /// Non-finite velocities should snap the spring to its target instead of
+    /// propagating NaNs into the value.
+    #[test]
+    fn tick_with_non_finite_velocity_settles() {
+        let mut spring = Spring::new(0.0)
+            .with_target(1.0)
+            .with_velocity(vec![f32::NAN]);
+        spring.tick(Instant::now() + Duration::from_millis(16));
+        assert_eq!(spring.value(), &1.0);
+        assert!(!spring.has_energy());
+    }
+
+    /// An infinite target can't be reached smoothly, so the spring should snap to it.
+    #[test]
+    fn tick_with_infinite_target_settles() {
+        let mut spring = Spring::new(0.0).with_target(f32::INFINITY);
+        spring.tick(Instant::now() + Duration::from_millis(16));
+        assert_eq!(spring.value(), &f32::INFINITY);
+        assert!(!spring.has_energy());
+    }
+
+    /// A frame with zero elapsed time shouldn't change the value or produce NaNs.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn tick_with_zero_duration_frame() {
+        let mut spring = Spring::new(0.0).with_target(1.0);
+        let now = spring.last_update();
+        spring.tick(now);
+        assert_eq!(spring.value(), &0.0);
+        assert!(spring.velocity.iter().all(|v| v.is_finite()));
+        assert!(spring.has_energy());
+    }
+
+    /// Denormal distances should animate and settle without producing non-finite values.
+    #[test]
+    fn tick_with_denormal_values() {
+        let target = f32::MIN_POSITIVE / 4.0;
+        let mut spring = Spring::new(0.0).with_target(target);
+        let mut now = spring.last_update();
+        for _ in 0..1000 {
+            if !spring.has_energy() {
+                break;
+            }
+            now += Duration::from_millis(16);
+            spring.tick(now);
+            assert!(spring.value().is_finite());
+        }
+        assert!(!spring.has_energy());
+        assert_eq!(spring.value(), &target);
+    }
+
+    /// Debounced targets should wait until the target stops changing for the duration.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn debounce_holds_target_until_quiet() {
+        let start = Instant::now();
+        let mut spring =
+            Spring::new(0.0).with_rate_limit(RateLimit::Debounce(Duration::from_millis(100)));
+        spring.interrupt(5.0);
+        spring.interrupt(8.0);
+        assert_eq!(spring.target(), &0.0);
+        assert_eq!(spring.latest_target(), &8.0);
+        assert!(spring.has_energy());
+
+        spring.tick(start + Duration::from_millis(50));
+        assert_eq!(spring.target(), &0.0);
+
+        spring.tick(start + Duration::from_millis(200));
+        assert_eq!(spring.target(), &8.0);
+        assert!(*spring.value() > 0.0);
+    }
+
+    /// Throttled targets should apply immediately, then at most once per duration.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn throttle_limits_target_changes() {
+        let start = Instant::now();
+        let mut spring =
+            Spring::new(0.0).with_rate_limit(RateLimit::Throttle(Duration::from_millis(100)));
+        spring.interrupt(5.0);
+        assert_eq!(spring.target(), &5.0);
+
+        spring.interrupt(8.0);
+        assert_eq!(spring.target(), &5.0);
+        assert_eq!(spring.latest_target(), &8.0);
+
+        spring.tick(start + Duration::from_millis(200));
+        assert_eq!(spring.target(), &8.0);
+    }
+
+    /// Settling should jump to targets held back by the rate limit.
+    #[test]
+    fn settle_applies_pending_target() {
+        let mut spring =
+            Spring::new(0.0).with_rate_limit(RateLimit::Debounce(Duration::from_secs(1)));
+        spring.interrupt(5.0);
+        spring.settle();
+        assert_eq!(spring.value(), &5.0);
+        assert!(!spring.has_energy());
+    }
+
+    /// Predicting a steadily moving target should lag behind it less than following it.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn predict_reduces_lag() {
+        let lag = |strategy| {
+            let start = Instant::now();
+            let mut spring = Spring::new(0.0).with_target_strategy(strategy);
+            for frame in 1..=120 {
+                spring.interrupt(frame as f32);
+                spring.tick(start + Duration::from_millis(16 * frame));
+            }
+            (spring.target() - spring.value()).abs()
+        };
+
+        let follow = lag(TargetStrategy::Follow);
+        let predict = lag(TargetStrategy::Predict);
+        assert!(
+            predict < follow / 2.0,
+            "{predict} should be less than {follow}"
+        );
+    }
+
+    /// Waiting tasks should be notified once the spring settles at its final target.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn notifies_waiters_on_settle() {
+        let mut spring = Spring::new(0.0).with_target(5.0);
+        let mut receiver = spring.waiters.wait();
+        spring.interrupt(10.0);
+        spring.tick(Instant::now());
+        assert_eq!(receiver.try_recv(), Ok(None));
+
+        spring.settle();
+        assert_eq!(receiver.try_recv(), Ok(Some(())));
+    }
+
+    /// Waiting tasks should keep waiting while a held back target hasn't been reached.
+    #[test]
+    fn waiters_wait_for_pending_target() {
+        let debounce = RateLimit::Debounce(Duration::from_secs(60));
+        let mut spring = Spring::new(0.0).with_rate_limit(debounce);
+        spring.interrupt(5.0);
+        let mut receiver = spring.waiters.wait();
+        spring.tick(Instant::now());
+        assert_eq!(receiver.try_recv(), Ok(None));
+
+        spring.settle_at(1.0);
+        assert_eq!(receiver.try_recv(), Ok(Some(())));
+    }
+
+    /// Cloned springs shouldn't complete the tasks waiting on the original.
+    #[test]
+    fn clones_start_without_waiters() {
+        let mut spring = Spring::new(0.0).with_target(5.0);
+        let _receiver = spring.waiters.wait();
+        assert_eq!(spring.clone().waiters.0.len(), 0);
+        assert_eq!(spring.clone(), spring);
+    }
+
+    /// Curves should reach their target after exactly their duration along their easing.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn curve_tweens_for_its_duration() {
+        let motion = SpringMotion::curve(Duration::from_millis(100), Easing::Linear);
+        let mut spring = Spring::new(0.0).with_motion(motion).with_target(10.0);
+        let start = spring.last_update();
+
+        spring.tick(start + Duration::from_millis(25));
+        assert!((spring.value() - 2.5).abs() < 1e-3);
+        spring.tick(start + Duration::from_millis(50));
+        assert!((spring.value() - 5.0).abs() < 1e-3);
+        assert!(spring.velocity[0] > 0.0);
+
+        spring.tick(start + Duration::from_millis(75));
+        spring.tick(start + Duration::from_millis(100));
+        assert_eq!(spring.value(), &10.0);
+        assert!(!spring.has_energy());
+    }
+
+    /// Retargeting a curve should start a new tween from the current value.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn curve_restarts_from_current_value() {
+        let motion = SpringMotion::curve(Duration::from_millis(100), Easing::Linear);
+        let mut spring = Spring::new(0.0).with_motion(motion).with_target(10.0);
+        let start = spring.last_update();
+        spring.tick(start + Duration::from_millis(30));
+        spring.tick(start + Duration::from_millis(50));
+
+        spring.interrupt(0.0);
+        spring.tick(start + Duration::from_millis(75));
+        assert!((spring.value() - 3.75).abs() < 1e-3);
+    }
+
+    /// Switching to a curve mid-animation should continue from the current value.
+    #[test]
+    fn switching_to_curve_continues_from_value() {
+        let mut spring = Spring::new(0.0).with_target(10.0);
+        let start = spring.last_update();
+        spring.tick(start + Duration::from_millis(16));
+        spring.tick(start + Duration::from_millis(32));
+        let value = *spring.value();
+
+        spring.set_motion(SpringMotion::curve(
+            Duration::from_millis(100),
+            Easing::Linear,
+        ));
+        spring.tick(start + Duration::from_millis(33));
+        assert!(*spring.value() >= value);
+        assert!(*spring.value() < value + 1.0);
+    }
+
+    /// New targets should wait for the delay before the spring moves toward them.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn delay_holds_targets_back() {
+        let delay = Duration::from_millis(100);
+        let mut spring = Spring::new(0.0).with_delay(delay);
+        spring.interrupt(5.0);
+        let (_, applied_at) = spring.delayed_target.unwrap();
+
+        spring.tick(applied_at - Duration::from_millis(1));
+        assert_eq!(spring.target(), &0.0);
+        assert_eq!(spring.value(), &0.0);
+        assert!(spring.has_energy());
+
+        spring.tick(applied_at);
+        assert_eq!(spring.target(), &5.0);
+        assert!(spring.has_energy());
+    }
+
+    /// Settling should apply a target that's waiting for the delay.
+    #[test]
+    fn settle_applies_delayed_target() {
+        let mut spring = Spring::new(0.0).with_delay(Duration::from_secs(60));
+        spring.interrupt(5.0);
+        let mut receiver = spring.waiters.wait();
+        spring.tick(Instant::now());
+        assert_eq!(receiver.try_recv(), Ok(None));
+
+        spring.settle();
+        assert_eq!(spring.value(), &5.0);
+        assert!(!spring.has_energy());
+        assert_eq!(receiver.try_recv(), Ok(Some(())));
+    }
+
+    /// Repeating springs should start over from where they started until out of plays.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn repeats_from_start() {
+        let motion = SpringMotion::curve(Duration::from_millis(100), Easing::Linear);
+        let mut spring = Spring::new(0.0)
+            .with_motion(motion)
+            .with_repeat(Repeat::Times(2))
+            .with_target(10.0);
+        let start = spring.last_update();
+
+        spring.tick(start + Duration::from_millis(30));
+        spring.tick(start + Duration::from_millis(60));
+        spring.tick(start + Duration::from_millis(90));
+        spring.tick(start + Duration::from_millis(120));
+        assert_eq!(spring.value(), &0.0);
+        assert!(spring.has_energy());
+
+        for step in 1..=5 {
+            spring.tick(start + Duration::from_millis(120 + step * 30));
+        }
+        assert_eq!(spring.value(), &10.0);
+        assert!(!spring.has_energy());
+    }
+
+    /// Settling should stop a spring from repeating.
+    #[test]
+    fn settle_stops_repeating() {
+        let mut spring = Spring::new(0.0)
+            .with_repeat(Repeat::Forever)
+            .with_target(1.0);
+        spring.settle();
+        spring.tick(Instant::now() + Duration::from_millis(16));
+        assert_eq!(spring.value(), &1.0);
+        assert!(!spring.has_energy());
+    }
+
+    /// Yoyo springs should animate back to where they started, repeating the round trip.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn yoyo_returns_to_start() {
+        let motion = SpringMotion::curve(Duration::from_millis(100), Easing::Linear);
+        let mut spring = Spring::new(0.0)
+            .with_motion(motion)
+            .with_yoyo(true)
+            .with_repeat(Repeat::Times(2))
+            .with_target(10.0);
+        let start = spring.last_update();
+
+        for step in 1..=4 {
+            spring.tick(start + Duration::from_millis(step * 30));
+        }
+        assert_eq!(spring.value(), &10.0);
+        assert_eq!(spring.target(), &0.0);
+        assert_eq!(spring.latest_target(), &10.0);
+
+        for step in 5..=8 {
+            spring.tick(start + Duration::from_millis(step * 30));
+        }
+        assert_eq!(spring.value(), &0.0);
+        assert_eq!(spring.target(), &10.0);
+        assert!(spring.has_energy());
+
+        for step in 9..=20 {
+            spring.tick(start + Duration::from_millis(step * 30));
+        }
+        assert_eq!(spring.value(), &0.0);
+        assert_eq!(spring.latest_target(), &10.0);
+        assert!(!spring.has_energy());
+    }
+
+    /// Retargeting a yoyo spring should forget the target it was returning from.
+    #[test]
+    fn yoyo_retargets() {
+        let mut spring = Spring::new(0.0).with_yoyo(true).with_target(1.0);
+        spring.settle();
+        spring.tick(Instant::now() + Duration::from_millis(16));
+        assert_eq!(spring.latest_target(), &1.0);
+
+        spring.interrupt(2.0);
+        assert_eq!(spring.latest_target(), &2.0);
+        spring.settle_at(3.0);
+        assert_eq!(spring.latest_target(), &3.0);
+    }
 }
@@ -21,8 +21,40 @@
 //! assert_eq!(spring_1.target(), &5.0);
 //! assert_eq!(spring_2.target(), &5.0);
 //! ```
+//!
+//! Values arriving from a subscription, like download progress or sensor readings, can be mapped
+//! directly into target events with [`SpringEvent::targets`]. The spring then smooths out any
+//! jittery data without needing a dedicated message for each new value.
+//!
+//! ```rust
+//! # use iced::Subscription;
+//! # use iced_anim::{Spring, SpringEvent};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Progress(SpringEvent<f32>),
+//! }
+//!
+//! struct State {
+//!     progress: Spring<f32>,
+//! }
+//!
+//! impl State {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Progress(event) => self.progress.update(event),
+//!         }
+//!     }
+//!
+//!     fn subscription(&self) -> Subscription<Message> {
+//!         SpringEvent::targets(download_progress()).map(Message::Progress)
+//!     }
+//! }
+//! # fn download_progress() -> Subscription<f32> { Subscription::none() }
+//! ```
 use std::time::Instant;
 
+use iced::Subscription;
+
 use crate::Animate;
 
 /// An event associated with an animated `Spring` value.
@@ -49,6 +81,17 @@ pub enum SpringEvent<T> {
     Settle,
 }
 
+impl<T> SpringEvent<T>
+where
+    T: 'static,
+{
+    /// Maps each value produced by the `subscription` into a [`SpringEvent::Target`],
+    /// retargeting the spring that receives the events whenever a new value arrives.
+    pub fn targets(subscription: Subscription<T>) -> Subscription<Self> {
+        subscription.map(SpringEvent::Target)
+    }
+}
+
 // Impl `Copy` for `SpringEvent` when `T` is `Copy`.
 impl<T> Copy for SpringEvent<T> where T: Copy {}
 
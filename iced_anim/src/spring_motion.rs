@@ -1,17 +1,91 @@
 //! Presets for spring animations that define the variables of a spring.
+//!
+//! Designs that call for exact timing rather than physics can use [`SpringMotion::Curve`]
+//! instead, which runs a fixed-duration tween with an [`Easing`] curve anywhere a motion is
+//! accepted:
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use iced_anim::{Easing, Spring, SpringMotion};
+//! let motion = SpringMotion::curve(Duration::from_millis(200), Easing::CubicBezier(0.2, 0.0, 0.0, 1.0));
+//! let spring = Spring::new(0.0).with_motion(motion);
+//! ```
 use std::{fmt::Display, time::Duration};
 
+use crate::Easing;
+
+/// The shortest non-zero response that a spring can have.
+///
+/// Springs that respond faster than a single frame at 60fps can't be meaningfully
+/// animated and quickly become unstable, so shorter responses are treated as instant.
+pub const MIN_RESPONSE: Duration = Duration::from_millis(16);
+
+/// The smallest damping fraction that a spring can have.
+///
+/// Springs with little or no damping oscillate around their target almost forever,
+/// which would keep requesting redraws without any meaningful change.
+pub const MIN_DAMPING: f32 = 0.05;
+
+/// The largest damping fraction that a spring can have.
+///
+/// Fractions above 1.0 are overdamped, so the spring creeps toward its target without ever
+/// overshooting it. The more damping, the longer it creeps, and springs with even more damping
+/// would take so long to come to rest that they'd look stuck.
+pub const MAX_DAMPING: f32 = 10.0;
+
+/// An error describing why the parameters of a [`SpringMotion`] are invalid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MotionError {
+    /// The response is non-zero but shorter than [`MIN_RESPONSE`].
+    ResponseTooShort(Duration),
+    /// The damping fraction is NaN or infinite.
+    NonFiniteDamping(f32),
+    /// The damping fraction is outside of [`MIN_DAMPING`] and [`MAX_DAMPING`].
+    DampingOutOfRange(f32),
+}
+
+impl Display for MotionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ResponseTooShort(response) => write!(
+                f,
+                "response of {response:?} is shorter than the minimum of {MIN_RESPONSE:?}"
+            ),
+            Self::NonFiniteDamping(damping) => write!(f, "damping of {damping} is not finite"),
+            Self::DampingOutOfRange(damping) => write!(
+                f,
+                "damping of {damping} is outside of the range {MIN_DAMPING}..={MAX_DAMPING}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MotionError {}
+
 /// The motion associated with a spring animation.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub enum SpringMotion {
-    /// A smooth animation without any overshoot of the target.
+    /// A smooth animation without any overshoot of the target, which is critically damped.
     #[default]
     Smooth,
     /// A small overshoot of the target before settling.
     Snappy,
     /// A bouncier animation where the value overshoots the target before settling.
     Bouncy,
+    /// A quick animation with a noticeable overshoot, like the `stiff` preset of react-spring.
+    Stiff,
+    /// A relaxed animation with a soft overshoot, like the `gentle` preset of react-spring.
+    Gentle,
+    /// An animation that wobbles around the target a few times before settling, like the
+    /// `wobbly` preset of react-spring.
+    Wobbly,
+    /// A long, overdamped animation that eases slowly into the target without overshooting it,
+    /// for transitions that should take their time.
+    Slow,
+    /// An animation that reaches the target as quickly as possible without overshooting it,
+    /// like the `default` config of react-spring.
+    CriticallyDamped,
     /// A custom spring animation with the given `response` and `damping`.
     Custom {
         /// The stiffness of the spring, defined as an approximate duration in seconds.
@@ -24,14 +98,161 @@ pub enum SpringMotion {
         /// coming to a stop.
         damping: f32,
     },
+    /// A tween reaching the target after exactly `duration` along the `easing` curve.
+    ///
+    /// Retargeting a tween starts a new one from the current value. The physics of a spring
+    /// don't apply, so the [`SpringMotion::damping`] of a curve is always `1.0`.
+    Curve {
+        /// How long the tween takes to reach the target.
+        duration: Duration,
+        /// The curve the tween follows toward the target.
+        easing: Easing,
+    },
 }
 
 impl SpringMotion {
+    /// Creates a custom spring motion after validating the `response` and `damping`.
+    ///
+    /// A `response` of zero is valid and creates an instant motion.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use iced_anim::SpringMotion;
+    /// assert!(SpringMotion::try_custom(Duration::from_millis(300), 0.8).is_ok());
+    /// assert!(SpringMotion::try_custom(Duration::from_millis(300), f32::NAN).is_err());
+    /// ```
+    pub fn try_custom(response: Duration, damping: f32) -> Result<Self, MotionError> {
+        let motion = Self::Custom { response, damping };
+        motion.validate().map(|_| motion)
+    }
+
+    /// Creates a tween reaching the target after exactly `duration` along the `easing` curve.
+    pub fn curve(duration: Duration, easing: Easing) -> Self {
+        Self::Curve { duration, easing }
+    }
+
+    /// The easing curve of a [`SpringMotion::Curve`], or `None` for spring motions.
+    pub fn easing(&self) -> Option<Easing> {
+        match self {
+            Self::Curve { easing, .. } => Some(*easing),
+            _ => None,
+        }
+    }
+
+    /// Creates a custom spring motion, clamping the `response` and `damping` to sensible values.
+    /// See [`SpringMotion::clamped`] for details.
+    pub fn custom_clamped(response: Duration, damping: f32) -> Self {
+        Self::Custom { response, damping }.clamped()
+    }
+
+    /// Checks whether this motion's parameters will produce a well-behaved animation.
+    ///
+    /// Curves of any duration are valid, since they can't become unstable like springs.
+    pub fn validate(&self) -> Result<(), MotionError> {
+        if let Self::Curve { .. } = self {
+            return Ok(());
+        }
+
+        let response = self.duration();
+        let damping = self.damping();
+
+        if !response.is_zero() && response < MIN_RESPONSE {
+            Err(MotionError::ResponseTooShort(response))
+        } else if !damping.is_finite() {
+            Err(MotionError::NonFiniteDamping(damping))
+        } else if !(MIN_DAMPING..=MAX_DAMPING).contains(&damping) {
+            Err(MotionError::DampingOutOfRange(damping))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns a motion whose parameters are guaranteed to produce a well-behaved animation.
+    ///
+    /// Responses shorter than [`MIN_RESPONSE`] become instant, non-finite damping falls back to
+    /// the default damping, and the remaining damping values are clamped between [`MIN_DAMPING`]
+    /// and [`MAX_DAMPING`]. Valid motions are returned unchanged.
+    pub fn clamped(self) -> Self {
+        if self.validate().is_ok() {
+            return self;
+        }
+
+        let response = match self.duration() {
+            response if response < MIN_RESPONSE => Duration::ZERO,
+            response => response,
+        };
+        let damping = match self.damping() {
+            damping if damping.is_finite() => damping.clamp(MIN_DAMPING, MAX_DAMPING),
+            _ => Self::default().damping(),
+        };
+
+        Self::Custom { response, damping }
+    }
+
+    /// Creates a smooth motion that roughly matches a tween lasting the given `duration`.
+    ///
+    /// This helps migrate animations from duration-based crates like `lilt` or `anim`, where a
+    /// transition is described by how long it takes rather than by the physics of a spring.
+    /// The returned motion is critically damped and gets 99% of the way to its target after
+    /// `duration`, which is about when an eased tween would visually finish. See
+    /// [`Tween`](crate::Tween) to copy over the rest of their settings as well.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use iced_anim::SpringMotion;
+    /// let motion = SpringMotion::from_tween(Duration::from_millis(300));
+    /// assert!(motion.duration() < Duration::from_millis(300));
+    /// ```
+    pub fn from_tween(duration: Duration) -> Self {
+        // A critically damped spring is 99% of the way to its target
+        // after its angular frequency multiplied by time reaches ~6.64.
+        const SETTLED_PHASE: f32 = 6.638_352;
+        Self::Custom {
+            response: duration.mul_f32(std::f32::consts::TAU / SETTLED_PHASE),
+            damping: 1.0,
+        }
+    }
+
+    /// Creates a spring motion from the physical `stiffness`, `damping` coefficient, and `mass`
+    /// of a spring, like the configs used by react-spring (`tension`, `friction`, and `mass`)
+    /// and Framer Motion.
+    ///
+    /// The spring behaves exactly like one with those parameters, as long as the resulting
+    /// response and damping fraction are within the limits of [`SpringMotion::validate`].
+    /// A stiffness or mass that isn't positive creates an instant motion.
+    ///
+    /// ```rust
+    /// # use iced_anim::SpringMotion;
+    /// // The default config of react-spring.
+    /// let motion = SpringMotion::from_physics(170.0, 26.0, 1.0);
+    /// assert!((motion.applied_stiffness() - 170.0).abs() < 0.01);
+    /// assert!((motion.applied_damping() - 26.0).abs() < 0.01);
+    /// ```
+    pub fn from_physics(stiffness: f32, damping: f32, mass: f32) -> Self {
+        if stiffness <= 0.0 || mass <= 0.0 {
+            return Self::instant();
+        }
+
+        // The natural frequency of the spring determines its response, and the damping is a
+        // fraction of the damping that would critically damp it.
+        let frequency = (stiffness / mass).sqrt();
+        Self::Custom {
+            response: Duration::try_from_secs_f32(std::f32::consts::TAU / frequency)
+                .unwrap_or_default(),
+            damping: damping / (2.0 * (stiffness * mass).sqrt()),
+        }
+    }
+
     /// Create a custom spring motion with the given response `duration`.
+    ///
+    /// Curves keep their easing and take the new `duration` instead.
     pub fn with_duration(self, duration: Duration) -> Self {
-        Self::Custom {
-            response: duration,
-            damping: self.damping(),
+        match self {
+            Self::Curve { easing, .. } => Self::Curve { duration, easing },
+            _ => Self::Custom {
+                response: duration,
+                damping: self.damping(),
+            },
         }
     }
 
@@ -45,34 +266,45 @@ impl SpringMotion {
 
     /// The estimated duration of how long the spring animation.
     /// This is used in the spring physics calculations and does not represent
-    /// a strict duration for the animation.
-    pub fn duration(&self) -> Duration {
+    /// a strict duration for the animation, except for a [`SpringMotion::Curve`].
+    pub const fn duration(&self) -> Duration {
         match self {
             Self::Bouncy | Self::Smooth | Self::Snappy => Duration::from_millis(500),
+            Self::Stiff => Duration::from_millis(434),
+            Self::Gentle => Duration::from_millis(574),
+            Self::Wobbly => Duration::from_millis(468),
+            Self::Slow => Duration::from_millis(800),
+            Self::CriticallyDamped => Duration::from_millis(482),
             Self::Custom { response, .. } => *response,
+            Self::Curve { duration, .. } => *duration,
         }
     }
 
     /// The fractional amount of drag applied needed to produce critical damping.
-    pub fn damping(&self) -> f32 {
+    pub const fn damping(&self) -> f32 {
         match self {
             Self::Bouncy => 0.7,
-            Self::Smooth => 1.0,
+            Self::Smooth | Self::CriticallyDamped | Self::Curve { .. } => 1.0,
             Self::Snappy => 0.85,
+            Self::Stiff => 0.69,
+            Self::Gentle => 0.64,
+            Self::Wobbly => 0.45,
+            Self::Slow => 1.2,
             Self::Custom { damping, .. } => *damping,
         }
     }
 
     /// The amount of stiffness applied to the spring, which varies based on the `duration`.
     pub fn applied_stiffness(&self) -> f32 {
-        let duration_fraction = self.duration().as_secs_f32();
+        let duration_fraction = self.clamped().duration().as_secs_f32();
         39.478_416 / duration_fraction.powi(2)
     }
 
     /// The amount of damping applied to the spring, which varies based on the `duration`.
     pub fn applied_damping(&self) -> f32 {
-        let duration = self.duration().as_secs_f32();
-        self.damping() * 12.566_371 / duration
+        let motion = self.clamped();
+        let duration = motion.duration().as_secs_f32();
+        motion.damping() * 12.566_371 / duration
     }
 
     /// Creates a motion that causes all animations to transition instantly.
@@ -90,7 +322,13 @@ impl Display for SpringMotion {
             Self::Smooth => write!(f, "Smooth"),
             Self::Snappy => write!(f, "Snappy"),
             Self::Bouncy => write!(f, "Bouncy"),
+            Self::Stiff => write!(f, "Stiff"),
+            Self::Gentle => write!(f, "Gentle"),
+            Self::Wobbly => write!(f, "Wobbly"),
+            Self::Slow => write!(f, "Slow"),
+            Self::CriticallyDamped => write!(f, "CriticallyDamped"),
             Self::Custom { .. } => write!(f, "Custom"),
+            Self::Curve { .. } => write!(f, "Curve"),
         }
     }
 }
@@ -183,6 +421,56 @@ mod tests {
         assert_eq!(motion.applied_stiffness().trunc(), 157.0);
     }
 
+    /// Presets named after react-spring should apply the same forces as its configs.
+    #[test]
+    fn react_spring_presets() {
+        let presets = [
+            (SpringMotion::Stiff, 210.0, 20.0),
+            (SpringMotion::Gentle, 120.0, 14.0),
+            (SpringMotion::Wobbly, 180.0, 12.0),
+            (SpringMotion::CriticallyDamped, 170.0, 26.0),
+        ];
+        for (motion, tension, friction) in presets {
+            assert!(motion.validate().is_ok());
+            let stiffness = motion.applied_stiffness();
+            let damping = motion.applied_damping();
+            assert!(
+                (stiffness / tension - 1.0).abs() < 0.01,
+                "{motion}: {stiffness}"
+            );
+            assert!(
+                (damping / friction - 1.0).abs() < 0.01,
+                "{motion}: {damping}"
+            );
+        }
+    }
+
+    /// Each preset should be in the damping regime its name promises, and slow presets should
+    /// take longer to respond than the others.
+    #[test]
+    fn preset_damping_regimes() {
+        let underdamped = [
+            SpringMotion::Snappy,
+            SpringMotion::Bouncy,
+            SpringMotion::Stiff,
+            SpringMotion::Gentle,
+            SpringMotion::Wobbly,
+        ];
+        for motion in underdamped {
+            assert!(motion.damping() < 1.0, "{motion}");
+        }
+        assert_eq!(SpringMotion::Smooth.damping(), 1.0);
+        assert_eq!(SpringMotion::CriticallyDamped.damping(), 1.0);
+        assert!(SpringMotion::Slow.damping() > 1.0);
+
+        for motion in underdamped.into_iter().chain([SpringMotion::Smooth]) {
+            assert!(
+                SpringMotion::Slow.duration() > motion.duration(),
+                "{motion}"
+            );
+        }
+    }
+
     #[test]
     fn custom_applied_forces() {
         let motion = SpringMotion::Custom {
@@ -193,6 +481,42 @@ mod tests {
         assert_eq!(motion.applied_stiffness().trunc(), 631.0);
     }
 
+    /// Motions created from a tween should nearly reach their target after the tween's duration.
+    #[test]
+    fn from_tween() {
+        let duration = Duration::from_millis(300);
+        let motion = SpringMotion::from_tween(duration);
+        assert_eq!(motion.damping(), 1.0);
+
+        // Position of a critically damped spring moving from 0 to 1.
+        let omega = std::f32::consts::TAU / motion.duration().as_secs_f32();
+        let t = duration.as_secs_f32();
+        let progress = 1.0 - (1.0 + omega * t) * (-omega * t).exp();
+        assert!((progress - 0.99).abs() < 0.001);
+    }
+
+    /// Physical spring parameters should convert to the same applied forces per unit of mass.
+    #[test]
+    fn from_physics() {
+        let motion = SpringMotion::from_physics(100.0, 10.0, 1.0);
+        assert!((motion.damping() - 0.5).abs() < 0.001);
+        assert!((motion.applied_stiffness() - 100.0).abs() < 0.01);
+        assert!((motion.applied_damping() - 10.0).abs() < 0.01);
+
+        let heavy = SpringMotion::from_physics(170.0, 26.0, 2.0);
+        assert!((heavy.applied_stiffness() - 85.0).abs() < 0.01);
+        assert!((heavy.applied_damping() - 13.0).abs() < 0.01);
+
+        assert_eq!(
+            SpringMotion::from_physics(0.0, 10.0, 1.0),
+            SpringMotion::instant()
+        );
+        assert_eq!(
+            SpringMotion::from_physics(100.0, 10.0, -1.0),
+            SpringMotion::instant()
+        );
+    }
+
     /// [SpringMotion::instant] should have zero duration and the default damping.
     #[test]
     fn instant() {
@@ -200,4 +524,87 @@ mod tests {
         assert_eq!(motion.duration(), Duration::ZERO);
         assert_eq!(motion.damping(), SpringMotion::default().damping());
     }
+
+    /// Curves should report their duration and keep their easing when changing it.
+    #[test]
+    fn curve() {
+        let motion = SpringMotion::curve(Duration::from_millis(200), Easing::Linear);
+        assert_eq!(motion.duration(), Duration::from_millis(200));
+        assert_eq!(motion.easing(), Some(Easing::Linear));
+        assert_eq!(SpringMotion::Smooth.easing(), None);
+        assert_eq!(
+            motion.with_duration(Duration::from_millis(1)),
+            SpringMotion::curve(Duration::from_millis(1), Easing::Linear)
+        );
+        assert!(motion
+            .with_duration(Duration::from_millis(1))
+            .validate()
+            .is_ok());
+    }
+
+    /// The built-in presets should all be valid.
+    #[test]
+    fn presets_are_valid() {
+        assert!(SpringMotion::Smooth.validate().is_ok());
+        assert!(SpringMotion::Snappy.validate().is_ok());
+        assert!(SpringMotion::Bouncy.validate().is_ok());
+        assert!(SpringMotion::instant().validate().is_ok());
+    }
+
+    #[test]
+    fn try_custom_valid() {
+        let motion = SpringMotion::try_custom(Duration::from_millis(300), 0.5);
+        assert_eq!(
+            motion,
+            Ok(SpringMotion::Custom {
+                response: Duration::from_millis(300),
+                damping: 0.5
+            })
+        );
+    }
+
+    #[test]
+    fn try_custom_invalid() {
+        assert_eq!(
+            SpringMotion::try_custom(Duration::from_millis(1), 1.0),
+            Err(MotionError::ResponseTooShort(Duration::from_millis(1)))
+        );
+        assert!(matches!(
+            SpringMotion::try_custom(Duration::from_millis(300), f32::NAN),
+            Err(MotionError::NonFiniteDamping(_))
+        ));
+        assert_eq!(
+            SpringMotion::try_custom(Duration::from_millis(300), -1.0),
+            Err(MotionError::DampingOutOfRange(-1.0))
+        );
+        assert_eq!(
+            SpringMotion::try_custom(Duration::from_millis(300), 50.0),
+            Err(MotionError::DampingOutOfRange(50.0))
+        );
+    }
+
+    /// Pathological parameters should be clamped into a usable range.
+    #[test]
+    fn clamped() {
+        let motion = SpringMotion::custom_clamped(Duration::from_millis(1), f32::NAN);
+        assert_eq!(motion.duration(), Duration::ZERO);
+        assert_eq!(motion.damping(), SpringMotion::default().damping());
+
+        let motion = SpringMotion::custom_clamped(Duration::from_millis(300), 50.0);
+        assert_eq!(motion.damping(), MAX_DAMPING);
+
+        let motion = SpringMotion::custom_clamped(Duration::from_millis(300), -1.0);
+        assert_eq!(motion.damping(), MIN_DAMPING);
+    }
+
+    /// Applied forces should always be finite for non-instant motions, even with invalid damping.
+    #[test]
+    fn applied_forces_are_finite_for_invalid_damping() {
+        let motion = SpringMotion::Custom {
+            response: Duration::from_millis(300),
+            damping: f32::NAN,
+        };
+        assert!(motion.applied_damping().is_finite());
+        assert!(motion.applied_stiffness().is_finite());
+    }
 }
@@ -0,0 +1,414 @@
+//! Many springs of the same type, updated together in a single pass.
+//!
+//! Animating hundreds of values at once, like the items of a long list or the nodes of a graph,
+//! with a [`Spring`](crate::Spring) each means hundreds of separate ticks that each allocate and
+//! walk their own components. A [`SpringVec`] stores the components of every spring side by side
+//! in flat arrays instead, so a single [`SpringVec::tick`] updates all of them in one tight loop
+//! that the compiler can vectorize. All springs in a [`SpringVec`] share the same motion.
+//!
+//! ```rust
+//! # use std::time::{Duration, Instant};
+//! # use iced::Point;
+//! # use iced_anim::{SpringMotion, SpringVec};
+//! let mut particles = SpringVec::new(SpringMotion::Bouncy);
+//! let first = particles.push(Point::ORIGIN);
+//! let second = particles.push(Point::new(10.0, 10.0));
+//!
+//! particles.interrupt(first, Point::new(100.0, 50.0));
+//! particles.interrupt(second, Point::new(-20.0, 40.0));
+//! particles.tick(Instant::now() + Duration::from_millis(16));
+//!
+//! assert!(particles.value(first).x > 0.0);
+//! assert!(particles.has_energy());
+//! ```
+use std::time::Instant;
+
+use crate::{
+    rest_threshold::RestThreshold,
+    spring::{self, MAX_DURATION},
+    Animate, SpringMotion,
+};
+
+/// Many springs of the same type stored in struct-of-arrays form and updated together.
+///
+/// Springs are addressed by their index, which is returned by [`SpringVec::push`] and stays the
+/// same until an earlier spring is removed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpringVec<T> {
+    /// The current value of each spring.
+    values: Vec<T>,
+    /// The target of each spring.
+    targets: Vec<T>,
+    /// The motion shared by every spring.
+    motion: SpringMotion,
+    /// The distance of each component from its target, for every spring in order.
+    displacement: Vec<f32>,
+    /// The velocity of each component, for every spring in order.
+    velocity: Vec<f32>,
+    /// The distance of each component from its target when its animation started, used to
+    /// determine when the spring is near its target.
+    initial_distance: Vec<f32>,
+    /// Whether each spring is animating.
+    is_animating: Vec<bool>,
+    /// The last instant at which the springs were updated.
+    last_update: Instant,
+}
+
+impl<T> SpringVec<T>
+where
+    T: Animate,
+{
+    /// Creates an empty [`SpringVec`] whose springs follow the given `motion`.
+    pub fn new(motion: SpringMotion) -> Self {
+        Self {
+            values: Vec::new(),
+            targets: Vec::new(),
+            motion,
+            displacement: Vec::new(),
+            velocity: Vec::new(),
+            initial_distance: Vec::new(),
+            is_animating: Vec::new(),
+            last_update: Instant::now(),
+        }
+    }
+
+    /// Creates a [`SpringVec`] with springs resting at each of the `values`.
+    pub fn from_values(values: impl IntoIterator<Item = T>, motion: SpringMotion) -> Self {
+        let mut springs = Self::new(motion);
+        for value in values {
+            springs.push(value);
+        }
+        springs
+    }
+
+    /// Returns the motion shared by every spring.
+    pub fn motion(&self) -> SpringMotion {
+        self.motion
+    }
+
+    /// Updates the motion shared by every spring.
+    pub fn set_motion(&mut self, motion: SpringMotion) {
+        self.motion = motion;
+    }
+
+    /// Returns an updated [`SpringVec`] with the given `motion`.
+    pub fn with_motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// The number of springs.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether there are no springs.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Adds a spring resting at the `value`, returning its index.
+    pub fn push(&mut self, value: T) -> usize {
+        self.targets.push(value.clone());
+        self.values.push(value);
+        let components = self.values.len() * T::components();
+        self.displacement.resize(components, 0.0);
+        self.velocity.resize(components, 0.0);
+        self.initial_distance.resize(components, 0.0);
+        self.is_animating.push(false);
+        self.values.len() - 1
+    }
+
+    /// Removes the spring at `index`, returning its current value. Springs after it move down
+    /// by one index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        let range = Self::components(index);
+        self.displacement.drain(range.clone());
+        self.velocity.drain(range.clone());
+        self.initial_distance.drain(range);
+        self.is_animating.remove(index);
+        self.targets.remove(index);
+        self.values.remove(index)
+    }
+
+    /// Removes every spring.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.targets.clear();
+        self.displacement.clear();
+        self.velocity.clear();
+        self.initial_distance.clear();
+        self.is_animating.clear();
+    }
+
+    /// Returns the current value of the spring at `index`.
+    pub fn value(&self, index: usize) -> &T {
+        &self.values[index]
+    }
+
+    /// Returns the target of the spring at `index`.
+    pub fn target(&self, index: usize) -> &T {
+        &self.targets[index]
+    }
+
+    /// Returns the current values of every spring.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the targets of every spring.
+    pub fn targets(&self) -> &[T] {
+        &self.targets
+    }
+
+    /// Returns the velocity of each component of the spring at `index`.
+    pub fn velocity(&self, index: usize) -> &[f32] {
+        &self.velocity[Self::components(index)]
+    }
+
+    /// Whether the spring at `index` is animating.
+    pub fn is_animating(&self, index: usize) -> bool {
+        self.is_animating[index]
+    }
+
+    /// Whether any spring is animating, meaning the springs need to be ticked.
+    pub fn has_energy(&self) -> bool {
+        self.is_animating.iter().any(|is_animating| *is_animating)
+    }
+
+    /// Starts animating the spring at `index` toward the `target` from its current value and
+    /// velocity. The spring jumps to the `target` instead while animations are disabled.
+    pub fn interrupt(&mut self, index: usize, target: T) {
+        if !crate::animations_enabled() {
+            self.settle_at(index, target);
+            return;
+        }
+
+        // Avoid a large first step when nothing has ticked for a while.
+        if !self.has_energy() {
+            self.last_update = Instant::now();
+        }
+
+        let range = Self::components(index);
+        let distance = target.distance_to(&self.values[index]);
+        for ((displacement, initial), distance) in self.displacement[range.clone()]
+            .iter_mut()
+            .zip(&mut self.initial_distance[range])
+            .zip(distance)
+        {
+            *displacement = distance;
+            *initial = distance;
+        }
+
+        self.targets[index] = target;
+        self.is_animating[index] = true;
+    }
+
+    /// Adds the `velocity` of each component to the spring at `index`, e.g. to fling it after
+    /// a drag is released. Does nothing while animations are disabled.
+    pub fn impulse(&mut self, index: usize, velocity: &[f32]) {
+        if !crate::animations_enabled() {
+            return;
+        }
+
+        if !self.has_energy() {
+            self.last_update = Instant::now();
+        }
+
+        // Like a single spring, use the approximate amplitude of the impulse when the spring
+        // has no distance to its target to measure whether it's near the end.
+        let period = self.motion.duration().as_secs_f32() / std::f32::consts::TAU;
+        let range = Self::components(index);
+        for ((current, added), initial) in self.velocity[range.clone()]
+            .iter_mut()
+            .zip(velocity)
+            .zip(&mut self.initial_distance[range])
+        {
+            *current += added;
+            *initial = initial.abs().max((*current * period).abs());
+        }
+        self.is_animating[index] = true;
+    }
+
+    /// Settles the spring at `index` at its target immediately.
+    pub fn settle(&mut self, index: usize) {
+        self.values[index] = self.targets[index].clone();
+        self.rest(index);
+    }
+
+    /// Settles the spring at `index` at the given `value` immediately, which also becomes its
+    /// target.
+    pub fn settle_at(&mut self, index: usize, value: T) {
+        self.targets[index] = value.clone();
+        self.values[index] = value;
+        self.rest(index);
+    }
+
+    /// Settles every spring at its target immediately.
+    pub fn settle_all(&mut self) {
+        for index in 0..self.len() {
+            if self.is_animating[index] {
+                self.settle(index);
+            }
+        }
+    }
+
+    /// Updates every animating spring based on the elapsed time since the last update.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "spring_vec.tick", level = "trace", skip_all)
+    )]
+    pub fn tick(&mut self, now: Instant) {
+        if !self.has_energy() {
+            return;
+        }
+
+        if !crate::animations_enabled() || self.motion.clamped().duration().is_zero() {
+            self.settle_all();
+            return;
+        }
+
+        let dt = now
+            .duration_since(self.last_update)
+            .min(MAX_DURATION)
+            .as_secs_f32();
+        self.last_update = now;
+
+        // Update every component at once. Springs at rest have no displacement or velocity, so
+        // they're left unchanged without branching on whether they're animating.
+        let stiffness = self.motion.applied_stiffness();
+        let damping = self.motion.applied_damping();
+        let steps = spring::steps(dt);
+        let step = dt / steps as f32;
+        for _ in 0..steps {
+            for (displacement, velocity) in self.displacement.iter_mut().zip(&mut self.velocity) {
+                let (movement, next) =
+                    spring::advance(stiffness, damping, *displacement, *velocity, step);
+                *displacement -= movement;
+                *velocity = next;
+            }
+        }
+
+        for index in 0..self.len() {
+            if !self.is_animating[index] {
+                continue;
+            }
+
+            let range = Self::components(index);
+            let is_finite = self.velocity[range.clone()].iter().all(|v| v.is_finite());
+            let is_near_end = self.displacement[range.clone()]
+                .iter()
+                .zip(&self.initial_distance[range.clone()])
+                .zip(&self.velocity[range.clone()])
+                .all(|((d, i), v)| RestThreshold::default().is_near_end(*d, *i, *v));
+
+            if !is_finite || is_near_end {
+                self.settle(index);
+            } else {
+                let mut value = self.targets[index].clone();
+                value.update(&mut self.displacement[range].iter().map(|d| -d));
+                self.values[index] = value;
+            }
+        }
+    }
+
+    /// Resets the spring at `index` to rest at its current value.
+    fn rest(&mut self, index: usize) {
+        let range = Self::components(index);
+        self.displacement[range.clone()].fill(0.0);
+        self.velocity[range.clone()].fill(0.0);
+        self.initial_distance[range].fill(0.0);
+        self.is_animating[index] = false;
+    }
+
+    /// The range of the components of the spring at `index` within the flat arrays.
+    fn components(index: usize) -> std::ops::Range<usize> {
+        index * T::components()..(index + 1) * T::components()
+    }
+}
+
+impl<T> Default for SpringVec<T>
+where
+    T: Animate,
+{
+    fn default() -> Self {
+        Self::new(SpringMotion::default())
+    }
+}
+
+impl<T> FromIterator<T> for SpringVec<T>
+where
+    T: Animate,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Self {
+        Self::from_values(values, SpringMotion::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Spring;
+
+    /// Springs in a `SpringVec` should follow the same path as individual springs.
+    #[test]
+    fn matches_individual_springs() {
+        let start = Instant::now();
+        let mut springs = SpringVec::from_values([0.0, 10.0], SpringMotion::Bouncy);
+        springs.interrupt(0, 5.0);
+        springs.interrupt(1, -5.0);
+        springs.last_update = start;
+
+        let mut single = Spring::new(0.0).with_motion(SpringMotion::Bouncy);
+        single.interrupt(5.0);
+        single.tick(start);
+
+        for frame in 1..=10 {
+            let now = start + Duration::from_millis(16 * frame);
+            springs.tick(now);
+            single.tick(now);
+            assert!((springs.value(0) - single.value()).abs() < 1e-4);
+        }
+        assert!(*springs.value(1) < 10.0);
+    }
+
+    /// Every spring should eventually settle at its target.
+    #[test]
+    fn settles_at_targets() {
+        let start = Instant::now();
+        let mut springs: SpringVec<(f32, f32)> =
+            [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)].into_iter().collect();
+        springs.interrupt(0, (10.0, -10.0));
+        springs.interrupt(2, (5.0, 5.0));
+        assert!(!springs.is_animating(1));
+
+        for frame in 1..=200 {
+            springs.tick(start + Duration::from_millis(16 * frame));
+        }
+
+        assert!(!springs.has_energy());
+        assert_eq!(springs.values(), &[(10.0, -10.0), (1.0, 1.0), (5.0, 5.0)]);
+    }
+
+    /// Removing a spring should keep the state of the springs after it.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn remove_keeps_other_springs() {
+        let mut springs = SpringVec::from_values([0.0, 1.0, 2.0], SpringMotion::Smooth);
+        springs.interrupt(2, 10.0);
+        springs.impulse(2, &[3.0]);
+        assert_eq!(springs.remove(0), 0.0);
+
+        assert_eq!(springs.len(), 2);
+        assert_eq!(springs.target(1), &10.0);
+        assert_eq!(springs.velocity(1), &[3.0]);
+        assert!(!springs.is_animating(0));
+        assert!(springs.is_animating(1));
+    }
+}
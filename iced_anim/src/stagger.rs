@@ -0,0 +1,87 @@
+//! Offset the start of each item's animation so collections animate in a cascading wave.
+//!
+//! Animating every row of a list at once looks mechanical, while starting each row slightly
+//! after the one before it makes the list ripple into place. [`stagger`] pairs each item with a
+//! delay that grows by the same amount per item, ready to pass to [`Spring::with_delay`] or
+//! [`AnimationBuilder::delay`]:
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use iced::{widget::{column, text}, Element};
+//! # use iced_anim::{stagger::stagger, AnimationBuilder};
+//! # #[derive(Clone)] enum Message {}
+//! fn rows<'a>(names: &'a [String], is_visible: bool) -> Element<'a, Message> {
+//!     let opacity = if is_visible { 1.0 } else { 0.0 };
+//!     let rows = stagger(names, Duration::from_millis(40)).map(|(delay, name)| {
+//!         AnimationBuilder::new(opacity, move |opacity| {
+//!             text(name).color([1.0, 1.0, 1.0, opacity]).into()
+//!         })
+//!         .delay(delay)
+//!         .into()
+//!     });
+//!
+//!     column(rows).into()
+//! }
+//! ```
+//!
+//! Springs kept in your own state can be staggered the same way, or all at once in an
+//! [`AnimationGroup`](crate::AnimationGroup) with
+//! [`AnimationGroup::stagger`](crate::AnimationGroup::stagger).
+//!
+//! [`Spring::with_delay`]: crate::Spring::with_delay
+//! [`AnimationBuilder::delay`]: crate::AnimationBuilder::delay
+use std::time::Duration;
+
+/// Pairs each of the `items` with a delay that grows by `delay_per_item` for each item, starting
+/// with no delay for the first one.
+pub fn stagger<I>(items: I, delay_per_item: Duration) -> impl Iterator<Item = (Duration, I::Item)>
+where
+    I: IntoIterator,
+{
+    items
+        .into_iter()
+        .enumerate()
+        .map(move |(index, item)| (delay_for(index, delay_per_item), item))
+}
+
+/// The delay of the item at the `index` when each item starts `delay_per_item` after the last.
+pub(crate) fn delay_for(index: usize, delay_per_item: Duration) -> Duration {
+    delay_per_item.saturating_mul(u32::try_from(index).unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "animations-off"))]
+    use crate::Spring;
+
+    #[test]
+    fn delays_each_item_more_than_the_last() {
+        let delays: Vec<_> = stagger(["a", "b", "c"], Duration::from_millis(50))
+            .map(|(delay, _)| delay)
+            .collect();
+        assert_eq!(
+            delays,
+            [
+                Duration::ZERO,
+                Duration::from_millis(50),
+                Duration::from_millis(100)
+            ]
+        );
+    }
+
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn staggers_springs() {
+        let mut springs = vec![Spring::new(0.0); 3];
+        for (delay, spring) in stagger(&mut springs, Duration::from_millis(20)) {
+            spring.set_delay(delay);
+            spring.interrupt(1.0);
+        }
+
+        assert_eq!(springs[0].target(), &1.0);
+        assert_eq!(springs[2].delay(), Duration::from_millis(40));
+        assert_eq!(springs[2].target(), &0.0);
+        assert_eq!(springs[2].latest_target(), &1.0);
+    }
+}
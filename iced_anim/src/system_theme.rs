@@ -0,0 +1,199 @@
+//! Follow the light or dark appearance of the operating system with an animated theme.
+//!
+//! > Note: this module is only available when the `system-theme` feature is enabled.
+//!
+//! A [`SystemTheme`] pairs a light and a dark theme. Its [`SystemTheme::subscription`] produces
+//! the theme matching the system appearance once when it starts and again whenever the user
+//! switches between light and dark mode. Feeding those themes into the [`SystemTheme::spring`]
+//! fades the whole app to the new appearance, rather than flashing at the switch.
+//!
+//! ```rust
+//! # use iced::{Subscription, Theme};
+//! # use iced_anim::{system_theme::SystemTheme, Spring, SpringEvent};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Theme(SpringEvent<Theme>),
+//! }
+//!
+//! struct State {
+//!     system_theme: SystemTheme,
+//!     theme: Spring<Theme>,
+//! }
+//!
+//! impl Default for State {
+//!     fn default() -> Self {
+//!         let system_theme = SystemTheme::new().dark(Theme::TokyoNight);
+//!         let theme = system_theme.spring();
+//!         Self { system_theme, theme }
+//!     }
+//! }
+//!
+//! impl State {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Theme(event) => self.theme.update(event),
+//!         }
+//!     }
+//!
+//!     fn subscription(&self) -> Subscription<Message> {
+//!         SpringEvent::targets(self.system_theme.subscription()).map(Message::Theme)
+//!     }
+//! }
+//! ```
+//!
+//! Systems that don't report an appearance are treated as light.
+use std::time::Duration;
+
+use iced::{
+    futures::{channel::mpsc, stream, StreamExt},
+    Subscription, Theme,
+};
+
+use crate::{Spring, SpringMotion};
+
+/// How often the system appearance is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The light or dark appearance of the operating system.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Appearance {
+    /// The system uses light mode.
+    #[default]
+    Light,
+    /// The system uses dark mode.
+    Dark,
+}
+
+impl Appearance {
+    /// Detects the current appearance of the operating system, falling back to light mode if
+    /// the system doesn't report one.
+    pub fn detect() -> Self {
+        match dark_light::detect() {
+            dark_light::Mode::Dark => Self::Dark,
+            dark_light::Mode::Light | dark_light::Mode::Default => Self::Light,
+        }
+    }
+}
+
+/// A light and a dark theme that follow the appearance of the operating system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemTheme {
+    /// The theme used in light mode.
+    light: Theme,
+    /// The theme used in dark mode.
+    dark: Theme,
+    /// The motion used to animate between the themes.
+    motion: SpringMotion,
+}
+
+impl SystemTheme {
+    /// The default motion between the themes, which is slow enough to feel like a fade.
+    pub const DEFAULT_MOTION: SpringMotion = SpringMotion::Custom {
+        response: Duration::from_millis(1500),
+        damping: 1.0,
+    };
+
+    /// Creates a [`SystemTheme`] that follows the system with [`Theme::Light`] and
+    /// [`Theme::Dark`].
+    pub fn new() -> Self {
+        Self {
+            light: Theme::Light,
+            dark: Theme::Dark,
+            motion: Self::DEFAULT_MOTION,
+        }
+    }
+
+    /// Sets the theme used in light mode.
+    pub fn light(mut self, theme: Theme) -> Self {
+        self.light = theme;
+        self
+    }
+
+    /// Sets the theme used in dark mode.
+    pub fn dark(mut self, theme: Theme) -> Self {
+        self.dark = theme;
+        self
+    }
+
+    /// Sets the motion used to animate between the themes.
+    pub fn with_motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// The motion used to animate between the themes.
+    pub fn motion(&self) -> SpringMotion {
+        self.motion
+    }
+
+    /// The theme used for the given `appearance`.
+    pub fn theme_for(&self, appearance: Appearance) -> &Theme {
+        match appearance {
+            Appearance::Light => &self.light,
+            Appearance::Dark => &self.dark,
+        }
+    }
+
+    /// Creates a spring resting at the theme matching the current system appearance, which
+    /// animates toward new themes with the [`motion`](Self::motion).
+    pub fn spring(&self) -> Spring<Theme> {
+        let theme = self.theme_for(Appearance::detect()).clone();
+        Spring::new(theme).with_motion(self.motion)
+    }
+
+    /// Produces the theme matching the system appearance when subscribed and whenever the
+    /// appearance changes.
+    ///
+    /// The appearance is checked every couple of seconds in a background thread, which is only
+    /// started once the subscription runs.
+    pub fn subscription(&self) -> Subscription<Theme> {
+        let id = (self.light.to_string(), self.dark.to_string());
+        let system_theme = self.clone();
+        Subscription::run_with_id(
+            id,
+            stream::once(async move { follow(system_theme) }).flatten(),
+        )
+    }
+}
+
+impl Default for SystemTheme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Follows the system appearance, sending the matching theme through a channel whenever the
+/// appearance changes.
+fn follow(system_theme: SystemTheme) -> mpsc::UnboundedReceiver<Theme> {
+    let (sender, receiver) = mpsc::unbounded();
+
+    std::thread::spawn(move || {
+        let mut current = None;
+        while !sender.is_closed() {
+            let appearance = Appearance::detect();
+            if current != Some(appearance) {
+                current = Some(appearance);
+                let theme = system_theme.theme_for(appearance).clone();
+                let _ = sender.unbounded_send(theme);
+            }
+
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Each appearance should map to its own theme.
+    #[test]
+    fn themes_for_appearances() {
+        let system_theme = SystemTheme::new().dark(Theme::Nord);
+        assert_eq!(system_theme.theme_for(Appearance::Light), &Theme::Light);
+        assert_eq!(system_theme.theme_for(Appearance::Dark), &Theme::Nord);
+        assert_eq!(system_theme.motion(), SystemTheme::DEFAULT_MOTION);
+    }
+}
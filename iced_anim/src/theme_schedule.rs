@@ -0,0 +1,262 @@
+//! Switch themes automatically at set times of day, like dark mode after sunset.
+//!
+//! A [`ThemeSchedule`] maps times of day to themes. Its [`ThemeSchedule::subscription`] produces
+//! the scheduled theme once when it starts and again whenever the schedule reaches a new theme.
+//! Feeding those themes into a [`Spring<Theme>`] with the slow [`ThemeSchedule::motion`] fades
+//! the whole app between themes over several seconds, rather than flashing at the switch.
+//!
+//! ```rust
+//! # use iced::{Subscription, Theme};
+//! # use iced_anim::{theme_schedule::ThemeSchedule, Spring, SpringEvent};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Theme(SpringEvent<Theme>),
+//! }
+//!
+//! struct State {
+//!     schedule: ThemeSchedule,
+//!     theme: Spring<Theme>,
+//! }
+//!
+//! impl Default for State {
+//!     fn default() -> Self {
+//!         // Light at sunrise and dark at sunset, in a time zone two hours ahead of UTC.
+//!         let schedule = ThemeSchedule::new()
+//!             .at(6, 45, Theme::Light)
+//!             .at(19, 30, Theme::Dark)
+//!             .utc_offset(120);
+//!         let theme = schedule.spring();
+//!         Self { schedule, theme }
+//!     }
+//! }
+//!
+//! impl State {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Theme(event) => self.theme.update(event),
+//!         }
+//!     }
+//!
+//!     fn subscription(&self) -> Subscription<Message> {
+//!         SpringEvent::targets(self.schedule.subscription()).map(Message::Theme)
+//!     }
+//! }
+//! ```
+//!
+//! The schedule doesn't know where the sun is. Apps that follow the actual sunset can compute
+//! it for the user's location and rebuild the schedule each day.
+use std::time::{Duration, SystemTime};
+
+use iced::{
+    futures::{channel::mpsc, stream, StreamExt},
+    Subscription, Theme,
+};
+
+use crate::{Spring, SpringMotion};
+
+/// The number of seconds in a day.
+const DAY: u64 = 24 * 60 * 60;
+
+/// The longest the schedule sleeps before checking the clock again, so that changes to the
+/// system clock or waking from sleep are noticed reasonably quickly.
+const MAX_SLEEP: Duration = Duration::from_secs(60);
+
+/// Themes to switch to at set times of day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeSchedule {
+    /// The themes to switch to, by seconds after midnight, sorted by time.
+    entries: Vec<(u64, Theme)>,
+    /// The offset of the local time zone from UTC in minutes.
+    utc_offset: i32,
+    /// The motion used to animate between scheduled themes.
+    motion: SpringMotion,
+}
+
+impl ThemeSchedule {
+    /// The default motion between scheduled themes, which takes several seconds.
+    pub const DEFAULT_MOTION: SpringMotion = SpringMotion::Custom {
+        response: Duration::from_secs(4),
+        damping: 1.0,
+    };
+
+    /// Creates an empty [`ThemeSchedule`] in UTC.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            utc_offset: 0,
+            motion: Self::DEFAULT_MOTION,
+        }
+    }
+
+    /// Switches to the `theme` every day at the given `hour` and `minute`, replacing any theme
+    /// already scheduled at that time.
+    pub fn at(mut self, hour: u8, minute: u8, theme: Theme) -> Self {
+        let time = (u64::from(hour) % 24) * 60 * 60 + (u64::from(minute) % 60) * 60;
+        self.entries.retain(|(existing, _)| *existing != time);
+        let index = self
+            .entries
+            .partition_point(|(existing, _)| *existing < time);
+        self.entries.insert(index, (time, theme));
+        self
+    }
+
+    /// Sets the offset of the local time zone from UTC in minutes, e.g. `-300` for UTC-5.
+    pub fn utc_offset(mut self, minutes: i32) -> Self {
+        self.utc_offset = minutes;
+        self
+    }
+
+    /// Sets the motion used to animate between scheduled themes.
+    pub fn with_motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// The motion used to animate between scheduled themes.
+    pub fn motion(&self) -> SpringMotion {
+        self.motion
+    }
+
+    /// Creates a spring resting at the theme currently scheduled, which animates toward newly
+    /// scheduled themes with the schedule's [`motion`](Self::motion).
+    pub fn spring(&self) -> Spring<Theme> {
+        let theme = self
+            .theme_at(SystemTime::now())
+            .cloned()
+            .unwrap_or_default();
+        Spring::new(theme).with_motion(self.motion)
+    }
+
+    /// The theme scheduled at the given `time`, or `None` if the schedule is empty.
+    pub fn theme_at(&self, time: SystemTime) -> Option<&Theme> {
+        let now = self.time_of_day(time);
+
+        // Before the first entry of the day, the last entry from the previous day still applies.
+        self.entries
+            .iter()
+            .rev()
+            .find(|(at, _)| *at <= now)
+            .or(self.entries.last())
+            .map(|(_, theme)| theme)
+    }
+
+    /// The next time after `time` at which the scheduled theme changes, or `None` if the
+    /// schedule is empty.
+    pub fn next_change(&self, time: SystemTime) -> Option<SystemTime> {
+        let now = self.time_of_day(time);
+        let next = self
+            .entries
+            .iter()
+            .map(|(at, _)| *at)
+            .find(|at| *at > now)
+            .or_else(|| self.entries.first().map(|(at, _)| at + DAY))?;
+
+        Some(time + Duration::from_secs(next - now))
+    }
+
+    /// Produces the scheduled theme when subscribed and whenever the scheduled theme changes.
+    ///
+    /// The schedule is followed in a background thread that sleeps until the next change.
+    /// The thread is only started once the subscription runs, so calling this on every update
+    /// doesn't spawn any extra threads.
+    pub fn subscription(&self) -> Subscription<Theme> {
+        let id = (
+            self.utc_offset,
+            self.entries
+                .iter()
+                .map(|(at, theme)| (*at, theme.to_string()))
+                .collect::<Vec<_>>(),
+        );
+        let schedule = self.clone();
+        Subscription::run_with_id(id, stream::once(async move { follow(schedule) }).flatten())
+    }
+
+    /// The number of whole seconds since local midnight at the given `time`.
+    fn time_of_day(&self, time: SystemTime) -> u64 {
+        let since_epoch = time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        let local = since_epoch + i64::from(self.utc_offset) * 60;
+        local.rem_euclid(DAY as i64) as u64
+    }
+}
+
+impl Default for ThemeSchedule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Follows the `schedule`, sending the scheduled theme through a channel whenever it changes.
+fn follow(schedule: ThemeSchedule) -> mpsc::UnboundedReceiver<Theme> {
+    let (sender, receiver) = mpsc::unbounded();
+
+    std::thread::spawn(move || {
+        let mut current = None;
+        while !sender.is_closed() {
+            let now = SystemTime::now();
+            let Some(theme) = schedule.theme_at(now) else {
+                return;
+            };
+            if current.as_ref() != Some(theme) {
+                current = Some(theme.clone());
+                let _ = sender.unbounded_send(theme.clone());
+            }
+
+            let wait = schedule
+                .next_change(now)
+                .and_then(|next| next.duration_since(now).ok())
+                .unwrap_or(MAX_SLEEP);
+            std::thread::sleep(wait.min(MAX_SLEEP));
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A time at the given `hour` and `minute` in UTC.
+    fn utc(hour: u64, minute: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(10 * DAY + hour * 3600 + minute * 60)
+    }
+
+    /// The latest entry before the time should apply, wrapping around from the previous day.
+    #[test]
+    fn theme_at_wraps_around_midnight() {
+        let schedule = ThemeSchedule::new()
+            .at(19, 30, Theme::Dark)
+            .at(6, 45, Theme::Light);
+        assert_eq!(schedule.theme_at(utc(12, 0)), Some(&Theme::Light));
+        assert_eq!(schedule.theme_at(utc(20, 0)), Some(&Theme::Dark));
+        assert_eq!(schedule.theme_at(utc(3, 0)), Some(&Theme::Dark));
+        assert_eq!(ThemeSchedule::new().theme_at(utc(3, 0)), None);
+    }
+
+    /// Times should be measured in the local time zone.
+    #[test]
+    fn utc_offset_shifts_schedule() {
+        let schedule = ThemeSchedule::new()
+            .at(6, 0, Theme::Light)
+            .at(18, 0, Theme::Dark)
+            .utc_offset(-300);
+        assert_eq!(schedule.theme_at(utc(20, 0)), Some(&Theme::Light));
+        assert_eq!(schedule.theme_at(utc(23, 30)), Some(&Theme::Dark));
+    }
+
+    /// The next change should wrap around to the first entry of the next day.
+    #[test]
+    fn next_change_wraps_to_next_day() {
+        let schedule = ThemeSchedule::new()
+            .at(6, 45, Theme::Light)
+            .at(19, 30, Theme::Dark);
+        assert_eq!(schedule.next_change(utc(12, 0)), Some(utc(19, 30)));
+        assert_eq!(
+            schedule.next_change(utc(20, 0)),
+            Some(utc(6, 45) + Duration::from_secs(DAY))
+        );
+    }
+}
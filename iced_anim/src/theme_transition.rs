@@ -0,0 +1,206 @@
+//! Animate between themes with custom blending for specific pairs of themes.
+//!
+//! Animating a [`Spring<Theme>`] blends every color in the palettes directly, which works well for
+//! similar themes but can produce garish colors halfway between very different ones. A
+//! [`ThemeTransition`] lets you provide a blend function that picks an intermediate theme for a
+//! pair of themes, like a neutral gray theme between a light and a dark theme. The transition
+//! animates to the intermediate theme first and then continues to the requested theme.
+//!
+//! ```rust
+//! # use iced::{widget::text, Element, Theme};
+//! # use iced_anim::{theme_transition::{self, ThemeTransition}, Animation, SpringEvent};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ChangeTheme(SpringEvent<Theme>),
+//! }
+//!
+//! struct State {
+//!     theme: ThemeTransition,
+//! }
+//!
+//! impl Default for State {
+//!     fn default() -> Self {
+//!         Self {
+//!             theme: ThemeTransition::new(Theme::Light).with_blend(theme_transition::neutral),
+//!         }
+//!     }
+//! }
+//!
+//! impl State {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::ChangeTheme(event) => self.theme.update(event),
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<Message> {
+//!         Animation::new(self.theme.spring(), text("Hello!"))
+//!             .on_update(Message::ChangeTheme)
+//!             .into()
+//!     }
+//! }
+//! ```
+use iced::{theme::Palette, Color, Theme};
+
+use crate::{Spring, SpringEvent, SpringMotion};
+
+/// A function returning the theme to pass through when animating between two themes,
+/// or `None` to blend between them directly.
+pub type BlendFn = fn(from: &Theme, to: &Theme) -> Option<Theme>;
+
+/// An animated theme that can pass through an intermediate theme for specific pairs of themes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeTransition {
+    /// The animated theme, which may be heading toward an intermediate theme.
+    spring: Spring<Theme>,
+    /// The theme to continue to after reaching the intermediate theme.
+    destination: Option<Theme>,
+    /// Picks the intermediate theme between two themes.
+    blend: Option<BlendFn>,
+}
+
+impl ThemeTransition {
+    /// Creates a new [`ThemeTransition`] starting at the given `theme`.
+    pub fn new(theme: Theme) -> Self {
+        Self {
+            spring: Spring::new(theme),
+            destination: None,
+            blend: None,
+        }
+    }
+
+    /// Returns an updated transition that uses the `blend` function to pick intermediate themes.
+    pub fn with_blend(mut self, blend: BlendFn) -> Self {
+        self.blend = Some(blend);
+        self
+    }
+
+    /// Returns an updated transition with the given `motion`.
+    pub fn with_motion(mut self, motion: SpringMotion) -> Self {
+        self.spring.set_motion(motion);
+        self
+    }
+
+    /// The spring animating the theme, which can be passed to an [`Animation`](crate::Animation).
+    pub fn spring(&self) -> &Spring<Theme> {
+        &self.spring
+    }
+
+    /// The current animated theme.
+    pub fn value(&self) -> &Theme {
+        self.spring.value()
+    }
+
+    /// The theme that the transition will end at, skipping any intermediate theme.
+    pub fn target(&self) -> &Theme {
+        self.destination
+            .as_ref()
+            .unwrap_or_else(|| self.spring.target())
+    }
+
+    /// Updates the transition with the given `event`.
+    pub fn update(&mut self, event: SpringEvent<Theme>) {
+        match event {
+            SpringEvent::Tick(now) => {
+                self.spring.tick(now);
+                if !self.spring.has_energy() {
+                    if let Some(destination) = self.destination.take() {
+                        self.spring.interrupt(destination);
+                    }
+                }
+            }
+            SpringEvent::Target(target) => {
+                let intermediate = self
+                    .blend
+                    .and_then(|blend| blend(self.spring.value(), &target));
+                match intermediate {
+                    Some(intermediate) => {
+                        self.destination = Some(target);
+                        self.spring.interrupt(intermediate);
+
+                        // Continue right away if the intermediate theme was reached immediately,
+                        // since there won't be a tick to move on to the destination.
+                        if !self.spring.has_energy() {
+                            if let Some(destination) = self.destination.take() {
+                                self.spring.interrupt(destination);
+                            }
+                        }
+                    }
+                    None => {
+                        self.destination = None;
+                        self.spring.interrupt(target);
+                    }
+                }
+            }
+            SpringEvent::Settle => {
+                if let Some(destination) = self.destination.take() {
+                    self.spring.settle_at(destination);
+                } else {
+                    self.spring.settle();
+                }
+            }
+        }
+    }
+}
+
+impl Default for ThemeTransition {
+    fn default() -> Self {
+        Self::new(Theme::default())
+    }
+}
+
+/// A [`BlendFn`] that passes through a desaturated theme when switching between light and dark
+/// themes, so the colors fade through grays instead of mixing into unrelated hues.
+pub fn neutral(from: &Theme, to: &Theme) -> Option<Theme> {
+    let (from, to) = (from.palette(), to.palette());
+    let is_light = |palette: &Palette| luminance(palette.background) > 0.5;
+    if is_light(&from) == is_light(&to) {
+        return None;
+    }
+
+    let gray = |a: Color, b: Color| {
+        let l = (luminance(a) + luminance(b)) / 2.0;
+        Color::from_rgba(l, l, l, (a.a + b.a) / 2.0)
+    };
+
+    Some(Theme::custom(
+        "Neutral".to_owned(),
+        Palette {
+            background: gray(from.background, to.background),
+            text: gray(from.text, to.text),
+            primary: gray(from.primary, to.primary),
+            success: gray(from.success, to.success),
+            danger: gray(from.danger, to.danger),
+        },
+    ))
+}
+
+/// The relative luminance of a color, ignoring its alpha.
+fn luminance(color: Color) -> f32 {
+    0.2126 * color.r + 0.7152 * color.g + 0.0722 * color.b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Similar themes should blend directly without an intermediate theme.
+    #[test]
+    fn neutral_skips_similar_themes() {
+        assert!(neutral(&Theme::Light, &Theme::SolarizedLight).is_none());
+        assert!(neutral(&Theme::Light, &Theme::Dark).is_some());
+    }
+
+    /// Transitions should head to the intermediate theme before the requested theme.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn target_passes_through_intermediate() {
+        let mut transition = ThemeTransition::new(Theme::Light).with_blend(neutral);
+        transition.update(SpringEvent::Target(Theme::Dark));
+        assert_eq!(transition.target(), &Theme::Dark);
+        assert_eq!(transition.spring().target().to_string(), "Neutral");
+
+        transition.update(SpringEvent::Settle);
+        assert_eq!(transition.value(), &Theme::Dark);
+    }
+}
@@ -0,0 +1,331 @@
+//! Coordinate several scripted animations on one clock that can be played, paused, and seeked.
+//!
+//! Complex scenes like an onboarding flow often animate many values at once, each starting at a
+//! different moment. Rather than juggling a spring for each of them, a [`Timeline`] holds a track
+//! of [`Keyframes`] for each value under a key, placed at an offset on a shared clock. Playing,
+//! pausing, or seeking the timeline moves every track together.
+//!
+//! ```rust
+//! # use std::time::{Duration, Instant};
+//! # use iced_anim::{Easing, Keyframes, Timeline};
+//! let fade_in = Keyframes::new(0.0).then(1.0, Duration::from_millis(300), Easing::EaseOut);
+//! let mut intro = Timeline::new()
+//!     .with_track("title", Duration::ZERO, fade_in.clone())
+//!     .with_track("subtitle", Duration::from_millis(200), fade_in.clone())
+//!     .with_track("button", Duration::from_millis(400), fade_in);
+//! assert_eq!(intro.duration(), Duration::from_millis(700));
+//!
+//! let start = Instant::now();
+//! intro.play_at(start);
+//! intro.tick(start + Duration::from_millis(300));
+//! assert_eq!(intro.value(&"title"), Some(&1.0));
+//! assert_eq!(intro.value(&"button"), Some(&0.0));
+//!
+//! intro.pause();
+//! intro.seek(Duration::from_millis(700));
+//! assert_eq!(intro.value(&"button"), Some(&1.0));
+//! ```
+//!
+//! Timelines are driven like keyframes, e.g. with [`Animation::timeline`], passing its events to
+//! [`Timeline::update`]. Each track plays its keyframes once, so their repeat and yoyo settings
+//! don't apply within a timeline.
+//!
+//! [`Animation::timeline`]: crate::Animation::timeline
+use std::time::{Duration, Instant};
+
+use crate::{Animate, Keyframes, SpringEvent};
+
+/// Tracks of keyframes under keys, played together on a shared clock.
+#[derive(Debug, Clone)]
+pub struct Timeline<K, T: Animate> {
+    /// The tracks in the timeline, in the order they were inserted.
+    tracks: Vec<Track<K, T>>,
+    /// The time on the clock.
+    position: Duration,
+    /// When the clock last advanced, if the timeline is playing.
+    last_tick: Option<Instant>,
+}
+
+/// A single track of a [`Timeline`].
+#[derive(Debug, Clone)]
+pub struct Track<K, T: Animate> {
+    /// The key of the track.
+    key: K,
+    /// When the keyframes of the track start on the clock.
+    offset: Duration,
+    /// The keyframes played by the track.
+    keyframes: Keyframes<T>,
+    /// The value of the track at the time on the clock.
+    value: T,
+}
+
+impl<K, T: Animate> Track<K, T> {
+    /// The key of the track.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// When the keyframes of the track start on the clock.
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+
+    /// The keyframes played by the track.
+    pub fn keyframes(&self) -> &Keyframes<T> {
+        &self.keyframes
+    }
+
+    /// The value of the track at the time on the clock.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// When the keyframes of the track end on the clock.
+    fn end(&self) -> Duration {
+        self.offset + self.keyframes.duration()
+    }
+
+    /// Moves the value of the track to the `position` on the clock.
+    fn seek(&mut self, position: Duration) {
+        self.value = self
+            .keyframes
+            .value_at(position.saturating_sub(self.offset));
+    }
+}
+
+impl<K, T> Timeline<K, T>
+where
+    K: PartialEq,
+    T: Animate,
+{
+    /// Creates a new [`Timeline`] without any tracks, paused at the start.
+    pub fn new() -> Self {
+        Self {
+            tracks: Vec::new(),
+            position: Duration::ZERO,
+            last_tick: None,
+        }
+    }
+
+    /// Returns the timeline with a track playing the `keyframes` under the `key`, starting at
+    /// the `offset` on the clock.
+    pub fn with_track(mut self, key: K, offset: Duration, keyframes: Keyframes<T>) -> Self {
+        self.insert(key, offset, keyframes);
+        self
+    }
+
+    /// Adds a track playing the `keyframes` under the `key`, starting at the `offset` on the
+    /// clock. An existing track with the same key is replaced.
+    pub fn insert(&mut self, key: K, offset: Duration, keyframes: Keyframes<T>) {
+        let mut track = Track {
+            key,
+            offset,
+            value: keyframes.value_at(Duration::ZERO),
+            keyframes,
+        };
+        track.seek(self.position);
+
+        match self.tracks.iter_mut().find(|other| other.key == track.key) {
+            Some(existing) => *existing = track,
+            None => self.tracks.push(track),
+        }
+    }
+
+    /// Removes the track with the given `key`, returning it if it existed.
+    pub fn remove(&mut self, key: &K) -> Option<Track<K, T>> {
+        let index = self.tracks.iter().position(|track| track.key == *key)?;
+        Some(self.tracks.remove(index))
+    }
+
+    /// Returns the track with the given `key`.
+    pub fn track(&self, key: &K) -> Option<&Track<K, T>> {
+        self.tracks.iter().find(|track| track.key == *key)
+    }
+
+    /// Iterates over the tracks in the timeline, in the order they were inserted.
+    pub fn tracks(&self) -> impl Iterator<Item = &Track<K, T>> {
+        self.tracks.iter()
+    }
+
+    /// Returns the value of the track with the given `key` at the time on the clock.
+    pub fn value(&self, key: &K) -> Option<&T> {
+        self.track(key).map(Track::value)
+    }
+
+    /// The total duration of the timeline, until its last track ends.
+    pub fn duration(&self) -> Duration {
+        self.tracks.iter().map(Track::end).max().unwrap_or_default()
+    }
+
+    /// The time on the clock.
+    pub fn position(&self) -> Duration {
+        self.position
+    }
+
+    /// How far through the timeline the clock is, from 0 to 1.
+    pub fn progress(&self) -> f32 {
+        let duration = self.duration();
+        if duration.is_zero() {
+            1.0
+        } else {
+            self.position.as_secs_f32() / duration.as_secs_f32()
+        }
+    }
+
+    /// Whether the timeline is playing.
+    pub fn is_playing(&self) -> bool {
+        self.last_tick.is_some()
+    }
+
+    /// Whether the timeline is playing and needs to be ticked, like
+    /// [`Spring::has_energy`](crate::Spring::has_energy).
+    pub fn has_energy(&self) -> bool {
+        self.is_playing()
+    }
+
+    /// Plays the timeline from the time on the clock, or from the start if it's at the end.
+    pub fn play(&mut self) {
+        self.play_at(Instant::now());
+    }
+
+    /// Plays the timeline from the time on the clock as of the given instant, or from the start
+    /// if it's at the end.
+    ///
+    /// The timeline jumps straight to the end while animations are disabled with
+    /// [`set_animations_enabled`](crate::set_animations_enabled).
+    pub fn play_at(&mut self, now: Instant) {
+        if !crate::animations_enabled() {
+            self.settle();
+            return;
+        }
+
+        if self.position >= self.duration() {
+            self.seek(Duration::ZERO);
+        }
+        self.last_tick = Some(now);
+    }
+
+    /// Pauses the timeline at the time on the clock.
+    pub fn pause(&mut self) {
+        self.last_tick = None;
+    }
+
+    /// Moves the clock to the `position`, limited to the duration of the timeline, updating the
+    /// value of every track. A playing timeline keeps playing from there.
+    pub fn seek(&mut self, position: Duration) {
+        self.position = position.min(self.duration());
+        for track in &mut self.tracks {
+            track.seek(self.position);
+        }
+    }
+
+    /// Jumps to the end of the timeline and pauses it there.
+    pub fn settle(&mut self) {
+        self.seek(self.duration());
+        self.pause();
+    }
+
+    /// Advances the clock of a playing timeline to the given instant, pausing at the end.
+    pub fn tick(&mut self, now: Instant) {
+        let Some(last_tick) = self.last_tick else {
+            return;
+        };
+
+        self.last_tick = Some(now);
+        self.seek(self.position + now.saturating_duration_since(last_tick));
+        if self.position >= self.duration() {
+            self.pause();
+        }
+    }
+
+    /// Updates the timeline with a [`SpringEvent`], like [`Spring::update`].
+    ///
+    /// Since every track follows its script, target events are ignored.
+    ///
+    /// [`Spring::update`]: crate::Spring::update
+    pub fn update(&mut self, event: SpringEvent<T>) {
+        match event {
+            SpringEvent::Tick(now) => self.tick(now),
+            SpringEvent::Target(_) => {}
+            SpringEvent::Settle => self.settle(),
+        }
+    }
+}
+
+impl<K, T> Default for Timeline<K, T>
+where
+    K: PartialEq,
+    T: Animate,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, not(feature = "animations-off")))]
+mod tests {
+    use super::*;
+    use crate::Easing;
+
+    fn timeline() -> Timeline<&'static str, f32> {
+        let ramp = Keyframes::new(0.0).then(1.0, Duration::from_millis(100), Easing::Linear);
+        Timeline::new()
+            .with_track("a", Duration::ZERO, ramp.clone())
+            .with_track("b", Duration::from_millis(100), ramp)
+    }
+
+    /// Tracks should start at their offsets on the shared clock.
+    #[test]
+    fn plays_tracks_at_their_offsets() {
+        let mut timeline = timeline();
+        assert_eq!(timeline.duration(), Duration::from_millis(200));
+
+        let start = Instant::now();
+        timeline.play_at(start);
+        timeline.tick(start + Duration::from_millis(50));
+        assert_eq!(timeline.value(&"a"), Some(&0.5));
+        assert_eq!(timeline.value(&"b"), Some(&0.0));
+
+        timeline.tick(start + Duration::from_millis(150));
+        assert_eq!(timeline.value(&"a"), Some(&1.0));
+        assert_eq!(timeline.value(&"b"), Some(&0.5));
+
+        timeline.tick(start + Duration::from_millis(250));
+        assert_eq!(timeline.position(), timeline.duration());
+        assert!(!timeline.is_playing());
+    }
+
+    /// Pausing should hold the clock until the timeline plays again.
+    #[test]
+    fn pause_holds_the_clock() {
+        let mut timeline = timeline();
+        let start = Instant::now();
+        timeline.play_at(start);
+        timeline.tick(start + Duration::from_millis(50));
+        timeline.pause();
+        timeline.tick(start + Duration::from_millis(500));
+        assert_eq!(timeline.position(), Duration::from_millis(50));
+
+        timeline.play_at(start + Duration::from_secs(1));
+        timeline.tick(start + Duration::from_millis(1100));
+        assert_eq!(timeline.position(), Duration::from_millis(150));
+    }
+
+    /// Seeking should move every track, and playing from the end should restart.
+    #[test]
+    fn seeks_every_track() {
+        let mut timeline = timeline();
+        timeline.seek(Duration::from_secs(5));
+        assert_eq!(timeline.position(), Duration::from_millis(200));
+        assert_eq!(timeline.value(&"b"), Some(&1.0));
+        assert_eq!(timeline.progress(), 1.0);
+
+        timeline.play();
+        assert_eq!(timeline.position(), Duration::ZERO);
+        assert_eq!(timeline.value(&"a"), Some(&0.0));
+
+        timeline.update(SpringEvent::Settle);
+        assert!(!timeline.is_playing());
+        assert_eq!(timeline.value(&"a"), Some(&1.0));
+    }
+}
@@ -0,0 +1,257 @@
+//! Duration-based animations, described the way other animation crates describe them.
+//!
+//! Crates like `lilt` and `anim` describe an animation by how long it takes, its easing curve, a
+//! delay before it starts, how many times it repeats, and whether it plays back in reverse. A
+//! [`Tween`] holds the same description, so moving an animation over to this crate is a matter
+//! of copying its settings. The tween then becomes a [`SpringMotion`], a [`Spring`], or
+//! [`Keyframes`], and [`Easing::from_name`] finds the curves those crates name.
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use iced_anim::{Easing, Repeat, Tween};
+//! // Like `Animated::new(0.0).duration(300.).easing(Easing::EaseOutCubic).repeat(3)` in `lilt`.
+//! let tween = Tween::from_millis(300.0)
+//!     .with_easing(Easing::from_name("EaseOutCubic").unwrap())
+//!     .with_repeat(Repeat::Times(3));
+//!
+//! // Keep the exact timing...
+//! let mut opacity = tween.spring(0.0);
+//! opacity.interrupt(1.0);
+//!
+//! // ...trade it for a spring that reacts to interruptions...
+//! let scale = iced_anim::Spring::new(1.0).with_motion(tween.spring_motion());
+//!
+//! // ...or script it ahead of time.
+//! let intro = tween.keyframes(0.0, 1.0);
+//! assert_eq!(intro.duration(), Duration::from_millis(300));
+//! ```
+//!
+//! Tweens also describe the timing of this crate's own [`SpringMotion::Curve`] motions and each
+//! [`Keyframe`], see [`Tween::from_motion`] and `Tween::from(&keyframe)`.
+use std::time::Duration;
+
+use crate::{keyframes::Keyframe, Animate, Easing, Keyframes, Repeat, Spring, SpringMotion};
+
+/// An animation that takes a fixed duration, like the ones in `lilt` and `anim`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween {
+    /// How long each play of the animation takes.
+    duration: Duration,
+    /// The easing curve of the animation.
+    easing: Easing,
+    /// How long the animation waits before it starts.
+    delay: Duration,
+    /// How many times the animation plays, if more than once.
+    repeat: Option<Repeat>,
+    /// Whether each play goes back to the start after reaching the end.
+    yoyo: bool,
+}
+
+impl Tween {
+    /// Creates a [`Tween`] taking the given `duration`, with the default easing.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            easing: Easing::default(),
+            delay: Duration::ZERO,
+            repeat: None,
+            yoyo: false,
+        }
+    }
+
+    /// Creates a [`Tween`] taking the given number of milliseconds, which is how `lilt` measures
+    /// durations. Durations that aren't finite and positive are instant.
+    pub fn from_millis(millis: f32) -> Self {
+        Self::new(Duration::try_from_secs_f64(f64::from(millis) / 1000.0).unwrap_or_default())
+    }
+
+    /// The tween of a [`SpringMotion::Curve`], or `None` for spring motions, which don't take a
+    /// fixed duration.
+    pub fn from_motion(motion: SpringMotion) -> Option<Self> {
+        match motion {
+            SpringMotion::Curve { duration, easing } => {
+                Some(Self::new(duration).with_easing(easing))
+            }
+            _ => None,
+        }
+    }
+
+    /// Sets the easing curve of the animation.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets how long the animation waits before it starts.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Plays the animation more than once.
+    pub fn with_repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = Some(repeat);
+        self
+    }
+
+    /// Plays back to the start after reaching the end, like `auto_reverse` in `lilt` and `anim`.
+    pub fn with_yoyo(mut self, yoyo: bool) -> Self {
+        self.yoyo = yoyo;
+        self
+    }
+
+    /// How long each play of the animation takes.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The easing curve of the animation.
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// How long the animation waits before it starts.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// How many times the animation plays, if more than once.
+    pub fn repeat(&self) -> Option<Repeat> {
+        self.repeat
+    }
+
+    /// Whether each play goes back to the start after reaching the end.
+    pub fn yoyo(&self) -> bool {
+        self.yoyo
+    }
+
+    /// The [`SpringMotion::Curve`] with the exact timing of the tween.
+    pub fn motion(&self) -> SpringMotion {
+        SpringMotion::Curve {
+            duration: self.duration,
+            easing: self.easing,
+        }
+    }
+
+    /// A spring motion that settles in about the same time as the tween, see
+    /// [`SpringMotion::from_tween`]. Unlike the tween, the spring keeps its momentum when it's
+    /// interrupted.
+    pub fn spring_motion(&self) -> SpringMotion {
+        SpringMotion::from_tween(self.duration)
+    }
+
+    /// A [`Spring`] resting at the `value` that animates toward new targets like the tween,
+    /// including its delay, repeats, and yoyo.
+    pub fn spring<T: Animate>(&self, value: T) -> Spring<T> {
+        let spring = Spring::new(value)
+            .with_motion(self.motion())
+            .with_delay(self.delay)
+            .with_yoyo(self.yoyo);
+
+        match self.repeat {
+            Some(repeat) => spring.with_repeat(repeat),
+            None => spring,
+        }
+    }
+
+    /// [`Keyframes`] that animate from `from` to `to` like the tween. The delay holds `from` at
+    /// the start of every play.
+    pub fn keyframes<T: Animate>(&self, from: T, to: T) -> Keyframes<T> {
+        let mut keyframes = Keyframes::new(from);
+        if !self.delay.is_zero() {
+            keyframes = keyframes.hold(self.delay);
+        }
+
+        let keyframes = keyframes
+            .then(to, self.duration, self.easing)
+            .with_yoyo(self.yoyo);
+        match self.repeat {
+            Some(repeat) => keyframes.with_repeat(repeat),
+            None => keyframes,
+        }
+    }
+}
+
+impl From<Tween> for SpringMotion {
+    fn from(tween: Tween) -> Self {
+        tween.motion()
+    }
+}
+
+/// The timing of a single keyframe.
+impl<T> From<&Keyframe<T>> for Tween {
+    fn from(keyframe: &Keyframe<T>) -> Self {
+        Self::new(keyframe.duration).with_easing(keyframe.easing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "animations-off"))]
+    use std::time::Instant;
+
+    use super::*;
+
+    /// Milliseconds should convert to durations, with invalid ones becoming instant.
+    #[test]
+    fn from_millis() {
+        assert_eq!(
+            Tween::from_millis(250.0).duration(),
+            Duration::from_millis(250)
+        );
+        assert_eq!(Tween::from_millis(-1.0).duration(), Duration::ZERO);
+        assert_eq!(Tween::from_millis(f32::NAN).duration(), Duration::ZERO);
+    }
+
+    /// Tweens should round trip through curve motions and describe keyframe timings.
+    #[test]
+    fn converts_timings() {
+        let tween = Tween::from_millis(300.0).with_easing(Easing::EaseOut);
+        assert_eq!(Tween::from_motion(tween.motion()), Some(tween));
+        assert_eq!(Tween::from_motion(SpringMotion::Smooth), None);
+        assert_eq!(SpringMotion::from(tween), tween.motion());
+        assert_eq!(
+            tween.spring_motion(),
+            SpringMotion::from_tween(tween.duration())
+        );
+
+        let keyframes = Keyframes::new(0.0).then(1.0, Duration::from_millis(300), Easing::EaseOut);
+        assert_eq!(Tween::from(&keyframes.frames()[0]), tween);
+    }
+
+    /// Springs should follow the curve of the tween and finish after its duration.
+    #[cfg(not(feature = "animations-off"))]
+    #[test]
+    fn springs_follow_the_tween() {
+        let tween = Tween::from_millis(100.0).with_easing(Easing::Linear);
+        let mut spring = tween.spring(0.0);
+        spring.interrupt(1.0);
+
+        let start = Instant::now();
+        spring.tick(start);
+        spring.tick(start + Duration::from_millis(20));
+        spring.tick(start + Duration::from_millis(50));
+        assert!((spring.value() - 0.5).abs() < 0.01, "{}", spring.value());
+
+        spring.tick(start + Duration::from_millis(80));
+        spring.tick(start + Duration::from_millis(110));
+        assert_eq!(spring.value(), &1.0);
+    }
+
+    /// Keyframes should wait for the delay, then play the tween with its repeats and yoyo.
+    #[test]
+    fn keyframes_play_the_tween() {
+        let tween = Tween::from_millis(100.0)
+            .with_easing(Easing::Linear)
+            .with_delay(Duration::from_millis(50))
+            .with_repeat(Repeat::Times(2))
+            .with_yoyo(true);
+        let keyframes = tween.keyframes(0.0, 1.0);
+
+        assert_eq!(keyframes.duration(), Duration::from_millis(150));
+        assert_eq!(keyframes.repeat(), Some(Repeat::Times(2)));
+        assert!(keyframes.yoyo());
+        assert_eq!(keyframes.value_at(Duration::from_millis(40)), 0.0);
+        assert!((keyframes.value_at(Duration::from_millis(100)) - 0.5).abs() < 1e-4);
+    }
+}
@@ -17,10 +17,96 @@
 //!   since [`None`] counts as a different variant.
 //! - You can disable animations by passing a [`SpringMotion`] with a duration of `0.0` to the
 //!   `motion` method, but there may be a more ergonomic way to do this in the future.
+pub mod ambient;
+pub mod animatable_catalog;
 pub mod animated_state;
+pub mod breadcrumbs;
 pub mod button;
+pub mod chips;
+pub mod clip_reveal;
+pub mod color_picker;
+pub mod command_palette;
+pub mod crossfade;
+pub mod cursor_overlay;
+pub mod date_picker;
+pub mod dock;
+pub mod drag_ghost;
+pub mod field;
+pub mod flash;
+pub mod flex;
+pub mod keyed_column;
+pub mod knob;
+pub mod minimap;
+pub mod number;
+pub mod progress_bar;
+#[cfg(feature = "canvas")]
+pub mod rating;
+pub mod resize_handle;
+pub mod resize_observer;
+#[cfg(feature = "canvas")]
+pub mod ring;
+pub mod scrollable;
+pub mod scrubber;
+pub mod segmented;
+pub mod shake;
+pub mod slider;
+pub mod steps;
 pub mod svg;
+pub mod tabs;
+pub mod text;
+pub mod text_input;
+pub mod theme_preview;
+pub mod toolbar;
+pub mod tooltip;
+pub mod transition;
+pub mod translate;
+pub mod veil;
+pub mod window_fade;
+pub mod zoom_pan;
 
-pub use animated_state::AnimatedState;
+pub use ambient::{ambient, Ambient};
+pub use animatable_catalog::AnimatableCatalog;
+pub use animated_state::{AnimatedState, Id};
+pub use breadcrumbs::{breadcrumbs, Breadcrumbs};
 pub use button::{button, Button};
+pub use chips::{chips, Chips};
+pub use clip_reveal::{clip_reveal, ClipReveal};
+pub use color_picker::{color_picker, ColorPicker};
+pub use command_palette::{command_palette, CommandPalette};
+pub use crossfade::{crossfade, Crossfade};
+pub use cursor_overlay::{cursor_overlay, CursorOverlay};
+pub use date_picker::{date_picker, DatePicker};
+pub use dock::{dock, Dock};
+pub use drag_ghost::{drag_ghost, DragGhost};
+pub use field::{field, Field};
+pub use flash::{flash_on_change, Flash};
+pub use flex::{animated_column, animated_row, Flex};
+pub use keyed_column::{animated_keyed_column, KeyedColumn};
+pub use knob::{knob, Knob};
+pub use minimap::{minimap, Minimap};
+pub use number::{number, Number};
+pub use progress_bar::{progress_bar, ProgressBar};
+#[cfg(feature = "canvas")]
+pub use rating::{rating, Rating};
+pub use resize_handle::{resize_handle, ResizeHandle};
+pub use resize_observer::{on_resize_animated, ResizeObserver};
+#[cfg(feature = "canvas")]
+pub use ring::{ring, Ring};
+pub use scrollable::{scrollable, Scrollable};
+pub use scrubber::{scrubber, Scrubber};
+pub use segmented::{segmented, Segmented};
+pub use shake::Shake;
+pub use slider::{range_slider, slider, vertical_slider, Slider};
+pub use steps::{steps, Steps};
 pub use svg::{svg, Svg};
+pub use tabs::{tabs, Tabs};
+pub use text::{text, Text};
+pub use text_input::{text_field, text_input, TextInput};
+pub use theme_preview::{theme_preview, ThemePreview};
+pub use toolbar::{toolbar, Toolbar};
+pub use tooltip::{tooltip, Tooltip};
+pub use transition::Transition;
+pub use translate::{translate, Translate};
+pub use veil::{veil, Veil};
+pub use window_fade::WindowFade;
+pub use zoom_pan::{zoom_pan, ZoomPan};
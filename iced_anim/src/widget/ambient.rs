@@ -0,0 +1,334 @@
+//! Play a looping effect while the app is idle, like a breathing glow on a call to action.
+//!
+//! An [`Ambient`] builds its content from the value of [`Keyframes`], like
+//! [`AnimationBuilder::keyframes`](crate::AnimationBuilder::keyframes). Rather than playing them
+//! right away, it waits until the app has been idle for a while and then loops the effect until
+//! the user moves the mouse, presses a key, or touches the screen, which jumps the content back
+//! to the initial value of the keyframes. Idle time is shared across the app through the
+//! [`idle`](crate::idle) module, so every ambient effect stops on any input.
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use iced::{widget::text, Color, Element};
+//! # use iced_anim::{widget::ambient, Easing, Keyframes};
+//! # #[derive(Clone)] enum Message {}
+//! fn call_to_action<'a>() -> Element<'a, Message> {
+//!     let breathe = Keyframes::new(1.0)
+//!         .then(0.6, Duration::from_millis(1500), Easing::EaseInOut)
+//!         .with_yoyo(true);
+//!
+//!     ambient(breathe, |opacity| {
+//!         text("Get started")
+//!             .color(Color::from_rgba(0.2, 0.5, 1.0, opacity))
+//!             .into()
+//!     })
+//!     .after(Duration::from_secs(10))
+//!     .into()
+//! }
+//! ```
+//!
+//! Ambient effects are never essential, so they stop while the app is over its animation budget,
+//! see the [`budget`](crate::budget) module.
+use std::time::{Duration, Instant};
+
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, mouse, window, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+use crate::{budget, budget::Priority, idle, Animate, Keyframes, Repeat};
+
+/// Loops an effect over its content while the app is idle.
+pub struct Ambient<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    T: 'static + Animate,
+{
+    /// The function that builds the element using the value of the effect.
+    builder: Box<dyn Fn(T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    /// The keyframes played while the app is idle.
+    effect: Keyframes<T>,
+    /// How long the app has to be idle before the effect plays.
+    after: Duration,
+    /// Whether the layout will be affected by the value of the effect.
+    animates_layout: bool,
+    /// The cached element built using the most recent value of the effect.
+    cached_element: Element<'a, Message, Theme, Renderer>,
+}
+
+/// The internal state of an [`Ambient`].
+struct State<T: Animate> {
+    /// The effect being played, or resting at its initial value.
+    effect: Keyframes<T>,
+    /// When the widget appeared, which counts as activity for the idle time.
+    created_at: Instant,
+    /// When the effect last started playing, so effects that don't repeat forever only play
+    /// once each time the app goes idle.
+    played_at: Option<Instant>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Ambient<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+{
+    /// The default time the app has to be idle before the effect plays.
+    pub const DEFAULT_AFTER: Duration = Duration::from_secs(5);
+
+    /// Creates a new [`Ambient`] that loops the `effect` while the app is idle, building its
+    /// element with the value of the effect.
+    ///
+    /// The effect repeats forever unless it has a [`Repeat`] of its own.
+    pub fn new(
+        effect: Keyframes<T>,
+        builder: impl Fn(T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        let effect = match effect.repeat() {
+            Some(_) => effect,
+            None => effect.with_repeat(Repeat::Forever),
+        };
+        let element = (builder)(effect.value().clone());
+
+        Self {
+            builder: Box::new(builder),
+            effect,
+            after: Self::DEFAULT_AFTER,
+            animates_layout: false,
+            cached_element: element,
+        }
+    }
+
+    /// Sets how long the app has to be idle before the effect plays.
+    pub fn after(mut self, after: Duration) -> Self {
+        self.after = after;
+        self
+    }
+
+    /// Indicates whether the value of the effect affects the layout of the content, in which
+    /// case the layout is invalidated while it plays.
+    pub fn animates_layout(mut self, animates_layout: bool) -> Self {
+        self.animates_layout = animates_layout;
+        self
+    }
+
+    /// Stops the effect, returning the content to the initial value of the keyframes.
+    fn stop(&mut self, state: &mut State<T>, shell: &mut Shell<'_, Message>) {
+        state.effect = self.effect.clone();
+        self.cached_element = (self.builder)(state.effect.value().clone());
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+        if self.animates_layout {
+            shell.invalidate_layout();
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Ambient<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.cached_element.as_widget().size()
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            effect: self.effect.clone(),
+            created_at: Instant::now(),
+            played_at: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.cached_element)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<T>>();
+        if !state.effect.has_same_frames(&self.effect) {
+            state.effect = self.effect.clone();
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.cached_element));
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.cached_element
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.cached_element
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.cached_element.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        // Any input stops the effect right away and restarts the idle time
+        if idle::is_input(&event) {
+            let now = Instant::now();
+            idle::record_input_at(now);
+            if state.effect.is_playing() {
+                self.stop(state, shell);
+            }
+            shell.request_redraw(window::RedrawRequest::At(now + self.after));
+            return status;
+        }
+
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return status;
+        };
+
+        if state.effect.is_playing() {
+            // Give the frame to essential animations while the app is over its budget
+            if budget::record_tick(now, Priority::Low) {
+                self.stop(state, shell);
+                return status;
+            }
+
+            state.effect.tick(now);
+            self.cached_element = (self.builder)(state.effect.value().clone());
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+            if self.animates_layout {
+                shell.invalidate_layout();
+            }
+            return status;
+        }
+
+        let idle_time = idle::idle_time(state.created_at, now);
+        let has_played = state
+            .played_at
+            .is_some_and(|played_at| now.saturating_duration_since(played_at) <= idle_time);
+        if idle_time < self.after {
+            shell.request_redraw(window::RedrawRequest::At(now + (self.after - idle_time)));
+        } else if crate::animations_enabled() && !has_played {
+            #[cfg(feature = "tracing")]
+            tracing::trace!("starting ambient effect");
+            state.played_at = Some(now);
+            state.effect.play_at(now);
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.cached_element.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.cached_element.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.cached_element.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Ambient<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(ambient: Ambient<'a, T, Message, Theme, Renderer>) -> Self {
+        Self::new(ambient)
+    }
+}
+
+/// Creates a new [`Ambient`] that loops the `effect` while the app is idle, building its element
+/// with the value of the effect.
+pub fn ambient<'a, T, Message, Theme, Renderer>(
+    effect: Keyframes<T>,
+    builder: impl Fn(T) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Ambient<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+{
+    Ambient::new(effect, builder)
+}
@@ -0,0 +1,141 @@
+//! Animated styles for custom themes and the styles of custom widgets.
+//!
+//! The widgets in this crate only rely on the `Catalog` trait of each widget, and every style
+//! they use already implements [`Animate`]. A custom `Theme` gets animated styles in all of them
+//! just by implementing their catalogs like it would for the widgets of iced.
+//!
+//! Custom widgets with a style of their own need that style to implement [`Animate`], either
+//! with `#[derive(Animate)]` when the `derive` feature is enabled or with
+//! [`impl_animate!`](crate::impl_animate) when it isn't. Implementing [`AnimatableCatalog`] for
+//! the theme then lets the widget get its animated style from an [`AnimatedState`] in one call
+//! with [`AnimatedState::catalog_style`]:
+//!
+//! ```rust
+//! # use iced::{Border, Color};
+//! # use iced_anim::widget::{AnimatableCatalog, AnimatedState};
+//! # use iced_anim::SpringMotion;
+//! #[derive(Debug, Clone, PartialEq)]
+//! struct Style {
+//!     background: Color,
+//!     border: Border,
+//! }
+//!
+//! iced_anim::impl_animate!(Style {
+//!     background: Color,
+//!     border: Border,
+//! });
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Status {
+//!     Active,
+//!     Hovered,
+//! }
+//!
+//! struct MyTheme {
+//!     accent: Color,
+//! }
+//!
+//! impl AnimatableCatalog<Style> for MyTheme {
+//!     type Class<'a> = ();
+//!     type Status = Status;
+//!
+//!     fn default_class<'a>() -> Self::Class<'a> {}
+//!
+//!     fn animatable_style(&self, _class: &Self::Class<'_>, status: &Status) -> Style {
+//!         let background = match status {
+//!             Status::Active => Color::WHITE,
+//!             Status::Hovered => self.accent,
+//!         };
+//!         Style {
+//!             background,
+//!             border: Border::default(),
+//!         }
+//!     }
+//! }
+//!
+//! // In the widget's `draw` function:
+//! # let animated_state: AnimatedState<Status, Style> =
+//! #     AnimatedState::new(Status::Active, SpringMotion::default());
+//! # let theme = MyTheme { accent: Color::BLACK };
+//! let style = animated_state.catalog_style(&theme, &());
+//! ```
+//!
+//! The catalogs of the widgets in this crate, along with those of iced for styles that animate,
+//! implement [`AnimatableCatalog`] for every theme that implements them, so widgets that are
+//! generic over their styles can accept any of them.
+//!
+//! [`AnimatedState`]: super::AnimatedState
+//! [`AnimatedState::catalog_style`]: super::AnimatedState::catalog_style
+use crate::Animate;
+
+/// A theme catalog whose styles can be animated.
+pub trait AnimatableCatalog<Style: Animate> {
+    /// The item class of the catalog.
+    type Class<'a>;
+
+    /// The status of the widget that the style depends on.
+    type Status;
+
+    /// The default class produced by the catalog.
+    fn default_class<'a>() -> Self::Class<'a>;
+
+    /// The style of a class with the given status.
+    fn animatable_style(&self, class: &Self::Class<'_>, status: &Self::Status) -> Style;
+}
+
+/// Implements [`AnimatableCatalog`] for every theme that implements a widget's catalog.
+macro_rules! animatable_catalog {
+    ($($widget:ident)::+, Status = ()) => {
+        impl<Theme: $($widget)::+::Catalog> AnimatableCatalog<$($widget)::+::Style> for Theme {
+            type Class<'a> = <Theme as $($widget)::+::Catalog>::Class<'a>;
+            type Status = ();
+
+            fn default_class<'a>() -> Self::Class<'a> {
+                <Theme as $($widget)::+::Catalog>::default()
+            }
+
+            fn animatable_style(
+                &self,
+                class: &Self::Class<'_>,
+                _status: &(),
+            ) -> $($widget)::+::Style {
+                <Theme as $($widget)::+::Catalog>::style(self, class)
+            }
+        }
+    };
+    ($($widget:ident)::+) => {
+        impl<Theme: $($widget)::+::Catalog> AnimatableCatalog<$($widget)::+::Style> for Theme {
+            type Class<'a> = <Theme as $($widget)::+::Catalog>::Class<'a>;
+            type Status = $($widget)::+::Status;
+
+            fn default_class<'a>() -> Self::Class<'a> {
+                <Theme as $($widget)::+::Catalog>::default()
+            }
+
+            fn animatable_style(
+                &self,
+                class: &Self::Class<'_>,
+                status: &$($widget)::+::Status,
+            ) -> $($widget)::+::Style {
+                <Theme as $($widget)::+::Catalog>::style(self, class, *status)
+            }
+        }
+    };
+}
+
+animatable_catalog!(iced::widget::button);
+animatable_catalog!(iced::widget::slider);
+animatable_catalog!(iced::widget::svg);
+animatable_catalog!(iced::widget::text, Status = ());
+animatable_catalog!(iced::widget::text_input);
+animatable_catalog!(super::breadcrumbs);
+animatable_catalog!(super::chips);
+animatable_catalog!(super::cursor_overlay);
+animatable_catalog!(super::knob);
+#[cfg(feature = "canvas")]
+animatable_catalog!(super::rating);
+animatable_catalog!(super::resize_handle);
+#[cfg(feature = "canvas")]
+animatable_catalog!(super::ring, Status = ());
+animatable_catalog!(super::scrubber);
+animatable_catalog!(super::segmented);
@@ -75,7 +75,8 @@
 //!    instead of manually calculating the style on each draw. Use [`AnimatedState::current_style`]
 //!    and pass in a callback to generate the style based on the theme and status, and it'll return
 //!    a reference to the latest animated style. The inner animated style will be updated if the
-//!    closure produces a style different from the current target.
+//!    closure produces a style different from the current target. Themes that implement
+//!    [`AnimatableCatalog`] can use [`AnimatedState::catalog_style`] instead.
 //! 7. Update [`iced::advanced::Widget::on_event`] to call [`AnimatedState::needs_redraw`] to
 //!    determine if the widget needs to redraw. If a redraw is needed, then use the shell to
 //!    request a redraw on the next frame. This may vary based on how your widget, but it will
@@ -94,12 +95,21 @@
 //! 8. Finally, ensure your widget handles [`iced::window::Event::RedrawRequested`] events by
 //!    calling [`AnimatedState::tick`] to update the animated style with the current time. This
 //!    is how the animated state can update the style over time.
+//!
+//! Widget state normally lives at the widget's position in the tree, so a widget that moves,
+//! e.g. because it's conditionally wrapped in a container, starts over from its initial style.
+//! Widgets can accept an optional [`AnimationId`] and pass it to
+//! [`AnimatedState::with_animation_id`] in their `state` function, which saves the status and
+//! animated style under that id whenever they change and picks them back up wherever the widget
+//! is created next. See the [`animation_store`](crate::animation_store) module for details.
 use std::{
     cell::{Ref, RefCell},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
-use crate::{Animate, Spring, SpringMotion};
+use super::AnimatableCatalog;
+pub use crate::animation_store::Id;
+use crate::{Animate, AnimationId, Spring, SpringMotion};
 
 /// Helps manage animating styles for widgets.
 ///
@@ -115,21 +125,65 @@ pub struct AnimatedState<Status, Style> {
     animated_style: RefCell<Option<Spring<Style>>>,
     /// The motion used by the animated style.
     motion: SpringMotion,
+    /// Motions that override the default motion when transitioning into a given status.
+    transition_motions: Vec<(Status, SpringMotion)>,
+    /// How long a style change waits before animating.
+    delay: Duration,
+    /// The id the state is saved under whenever it changes, if any.
+    animation_id: Option<AnimationId>,
+}
+
+/// The part of an [`AnimatedState`] that's saved under its [`AnimationId`].
+///
+/// The id itself isn't saved, since it refers back to the store the state is saved in.
+#[derive(Clone)]
+struct Saved<Status, Style> {
+    status: Status,
+    animated_style: Option<Spring<Style>>,
 }
 
 impl<Status, Style> AnimatedState<Status, Style>
 where
-    Status: PartialEq,
-    Style: Animate + Clone + PartialEq,
+    Status: PartialEq + Clone + Send + 'static,
+    Style: Animate + Clone + PartialEq + Send + 'static,
 {
     pub fn new(status: Status, motion: SpringMotion) -> Self {
         Self {
             status,
             animated_style: RefCell::new(None),
             motion,
+            transition_motions: Vec::new(),
+            delay: Duration::ZERO,
+            animation_id: None,
         }
     }
 
+    /// Saves the state under the given `id` whenever its status changes or its style animates,
+    /// picking up the state last saved under it instead if there is one. This keeps a widget's
+    /// style animation going when its position in the widget tree changes, rather than
+    /// resetting it.
+    pub fn with_animation_id(mut self, animation_id: Option<AnimationId>) -> Self {
+        let Some(animation_id) = animation_id else {
+            return self;
+        };
+
+        if let Some(saved) = animation_id.restore::<Saved<Status, Style>>() {
+            self.status = saved.status;
+            if let Some(mut animated_style) = saved.animated_style {
+                animated_style.set_motion(self.motion);
+                animated_style.set_delay(self.delay);
+                self.animated_style.replace(Some(animated_style));
+            }
+        }
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// The id the state is saved under, if any.
+    pub fn animation_id(&self) -> Option<&AnimationId> {
+        self.animation_id.as_ref()
+    }
+
     pub fn status(&self) -> &Status {
         &self.status
     }
@@ -145,14 +199,44 @@ where
         }
     }
 
+    /// Sets how long a style change waits before animating, e.g. to stagger several widgets.
+    pub fn set_delay(&mut self, delay: Duration) {
+        if self.delay != delay {
+            self.delay = delay;
+            let mut animated_style = self.animated_style.borrow_mut();
+            if let Some(style) = animated_style.as_mut() {
+                style.set_delay(delay);
+            }
+        }
+    }
+
+    /// Sets the `motion` used when transitioning into the given `status`, falling back to the
+    /// default motion if `None`. This is useful for transitions that should feel different than
+    /// the rest, like quickly fading a button when it becomes disabled.
+    pub fn set_transition_motion(&mut self, status: Status, motion: Option<SpringMotion>) {
+        self.transition_motions
+            .retain(|(existing, _)| *existing != status);
+        if let Some(motion) = motion {
+            self.transition_motions.push((status, motion));
+        }
+    }
+
+    /// The motion used when transitioning into the given `status`.
+    pub fn motion_for(&self, status: &Status) -> SpringMotion {
+        self.transition_motions
+            .iter()
+            .find(|(existing, _)| existing == status)
+            .map_or(self.motion, |(_, motion)| *motion)
+    }
+
     /// Determines whether the widget needs to be redrawn based on events, updating the status and
     /// animated style as necessary. Generally called in a widget's `on_event` function.
     pub fn needs_redraw(&mut self, status: Status) -> bool {
-        let animated_style = self.animated_style.borrow();
         if self.status != status {
             self.status = status;
+            self.save();
             true
-        } else if let Some(animated_style) = animated_style.as_ref() {
+        } else if let Some(animated_style) = self.animated_style.borrow().as_ref() {
             animated_style.has_energy()
         } else {
             // No animated style yet.
@@ -163,28 +247,55 @@ where
     /// Update the animated style with the current time.
     /// Call this for `RedrawRequested` events.
     pub fn tick(&mut self, now: Instant) {
-        let mut animated_style = self.animated_style.borrow_mut();
-        if let Some(animated_style) = animated_style.as_mut() {
-            animated_style.tick(now);
+        let is_animating = match self.animated_style.get_mut() {
+            Some(animated_style) if animated_style.has_energy() => {
+                animated_style.tick(now);
+                true
+            }
+            _ => false,
+        };
+
+        // Resting styles don't change, so they only need saving while they animate.
+        if is_animating {
+            self.save();
         }
     }
 
     /// Causes the animation to immediately jump to the target value.
     pub fn settle(&mut self) {
-        let mut animated_style = self.animated_style.borrow_mut();
-        if let Some(animated_style) = animated_style.as_mut() {
+        if let Some(animated_style) = self.animated_style.get_mut() {
             animated_style.settle();
+            self.save();
         }
     }
 
     /// Causes the animation to immediately jump to the given `value`.
     pub fn settle_at(&mut self, value: Style) {
-        let mut animated_style = self.animated_style.borrow_mut();
-        if let Some(animated_style) = animated_style.as_mut() {
+        if let Some(animated_style) = self.animated_style.get_mut() {
             animated_style.settle_at(value);
+            self.save();
         }
     }
 
+    /// Saves the status and animated style under the animation id, if there is one.
+    fn save(&self) {
+        if let Some(animation_id) = &self.animation_id {
+            animation_id.save(Saved {
+                status: self.status.clone(),
+                animated_style: self.animated_style.borrow().clone(),
+            });
+        }
+    }
+
+    /// Gets a reference to the animated style of the `class` in the `theme`'s catalog, like
+    /// [`AnimatedState::current_style`].
+    pub fn catalog_style<Theme>(&self, theme: &Theme, class: &Theme::Class<'_>) -> Ref<'_, Style>
+    where
+        Theme: AnimatableCatalog<Style, Status = Status>,
+    {
+        self.current_style(|status| theme.animatable_style(class, status))
+    }
+
     /// Gets a reference to the animated style to use in a widget's `draw` function,
     /// using interior mutability to update the animation as necessary.
     ///
@@ -198,13 +309,15 @@ where
         {
             let mut animated_style_ref = self.animated_style.borrow_mut();
             if let Some(animated_style) = animated_style_ref.as_mut() {
-                if animated_style.target() != &new_style {
+                if animated_style.latest_target() != &new_style {
+                    animated_style.set_motion(self.motion_for(&self.status));
                     animated_style.interrupt(new_style);
                 }
             } else {
                 // Create a new animated style if one doesn't exist.
                 let animated_style = Spring::new(new_style.clone())
                     .with_motion(self.motion)
+                    .with_delay(self.delay)
                     .with_target(new_style);
                 animated_style_ref.replace(animated_style);
             }
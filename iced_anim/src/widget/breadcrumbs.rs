@@ -0,0 +1,635 @@
+//! A navigation trail where segments slide in and out as the path changes.
+//!
+//! Breadcrumbs compare the new path to the previous one whenever the view changes. Segments
+//! after the point where the paths differ fade out while sliding back, new segments fade in while
+//! sliding forward, and the segments that stay glide to their new places with their separators,
+//! so moving between related pages reads as one continuous change.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::breadcrumbs;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     GoTo(usize),
+//! }
+//!
+//! fn trail<'a>(path: &[&str]) -> Element<'a, Message> {
+//!     breadcrumbs(path.iter().copied()).on_select(Message::GoTo).into()
+//! }
+//! ```
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    touch, window, Color, Element, Event, Length, Pixels, Point, Rectangle, Size,
+};
+
+use super::animated_state::{AnimatedState, Id};
+use crate::{Animate, AnimationId, AnimationStore, Spring, SpringMotion};
+
+pub use iced::widget::text::{LineHeight, Shaping};
+
+/// How far segments slide while entering and exiting.
+const SLIDE: f32 = 12.0;
+
+/// A trail of segments separated by a separator, which animates segments being added and removed.
+#[allow(missing_debug_implementations)]
+pub struct Breadcrumbs<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    labels: Vec<String>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    separator: String,
+    spacing: f32,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Option<Renderer::Font>,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    class: Theme::Class<'a>,
+}
+
+/// A segment of a [`Breadcrumbs`] trail.
+struct Crumb<P: Paragraph> {
+    label: String,
+    paragraph: P,
+    /// The animated position of the segment from the start of the trail.
+    x: Spring<f32>,
+    /// How present the segment is, from 0 when it has left to 1 when it's fully shown.
+    presence: Spring<f32>,
+    /// Whether the segment was removed from the path and is on its way out.
+    is_exiting: bool,
+    /// Whether the segment was just added and hasn't been placed yet.
+    is_placed: bool,
+}
+
+impl<P: Paragraph> Crumb<P> {
+    /// Creates a segment entering the trail with the given `label`.
+    fn entering(label: String, motion: SpringMotion) -> Self {
+        let mut presence = Spring::new(0.0).with_motion(motion);
+        presence.interrupt(1.0);
+
+        Self {
+            label,
+            paragraph: P::default(),
+            x: Spring::new(0.0).with_motion(motion),
+            presence,
+            is_exiting: false,
+            is_placed: false,
+        }
+    }
+
+    /// Whether the segment has finished exiting and can be dropped.
+    fn has_exited(&self) -> bool {
+        self.is_exiting && !self.presence.has_energy()
+    }
+}
+
+/// The internal state of a [`Breadcrumbs`] trail.
+struct State<P: Paragraph> {
+    /// The segments of the trail, including those on their way out.
+    crumbs: Vec<Crumb<P>>,
+    /// The laid out separator.
+    separator: P,
+    /// The segment being pressed, if any.
+    pressed: Option<usize>,
+    animated_state: AnimatedState<Status, Style>,
+}
+
+impl<P: Paragraph> State<P> {
+    /// The segments that are part of the current path, in order.
+    fn live(&self) -> impl Iterator<Item = (usize, &Crumb<P>)> {
+        self.crumbs
+            .iter()
+            .filter(|crumb| !crumb.is_exiting)
+            .enumerate()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Breadcrumbs<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// Creates a new [`Breadcrumbs`] trail of the given `labels`, from the root to the current
+    /// page.
+    pub fn new(labels: impl IntoIterator<Item = impl ToString>) -> Self {
+        Self {
+            labels: labels.into_iter().map(|label| label.to_string()).collect(),
+            on_select: None,
+            separator: String::from("/"),
+            spacing: 8.0,
+            size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            motion: SpringMotion::Smooth,
+            animation_id: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced with the index of a segment when it's clicked. The last
+    /// segment is the current page, so it can't be clicked.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the text drawn between segments.
+    pub fn separator(mut self, separator: impl ToString) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Sets the space on each side of the separators.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the segments.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the line height of the segments.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the segments.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the motion of segments and of style animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Breadcrumbs`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// Sets the style of the [`Breadcrumbs`] trail.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Breadcrumbs`] trail.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The text to lay out for the given `label`.
+    fn text<'b>(
+        &self,
+        label: &'b str,
+        renderer: &Renderer,
+    ) -> core_text::Text<&'b str, Renderer::Font> {
+        core_text::Text {
+            content: label,
+            bounds: Size::INFINITY,
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: core_text::Wrapping::None,
+        }
+    }
+
+    /// The index in the path of the segment under the `cursor` that can be selected, if any.
+    fn crumb_at(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<usize> {
+        self.on_select.as_ref()?;
+        let position = cursor.position_over(bounds)?;
+        let current = self.labels.len().checked_sub(1)?;
+
+        state.live().find_map(|(index, crumb)| {
+            let x = bounds.x + *crumb.x.value();
+            let width = crumb.paragraph.min_width();
+            (index != current && position.x >= x && position.x <= x + width).then_some(index)
+        })
+    }
+
+    /// Gets the status of the [`Breadcrumbs`] trail based on the current state.
+    fn get_status(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        cursor: Cursor,
+        layout: Layout<'_>,
+    ) -> Status {
+        if state.pressed.is_some() {
+            Status::Pressed
+        } else if self.crumb_at(state, layout.bounds(), cursor).is_some() {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Breadcrumbs<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let crumbs = self
+            .labels
+            .iter()
+            .map(|label| Crumb {
+                presence: Spring::new(1.0).with_motion(self.motion),
+                ..Crumb::entering(label.clone(), self.motion)
+            })
+            .collect();
+
+        tree::State::new(State::<Renderer::Paragraph> {
+            crumbs,
+            separator: Renderer::Paragraph::default(),
+            pressed: None,
+            animated_state: AnimatedState::new(Status::Active, self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.motion);
+        for crumb in &mut state.crumbs {
+            crumb.x.set_motion(self.motion);
+            crumb.presence.set_motion(self.motion);
+        }
+
+        // Segments stay as long as the path matches up to them.
+        let common = state
+            .live()
+            .zip(&self.labels)
+            .take_while(|((_, crumb), label)| crumb.label == **label)
+            .count();
+
+        let mut live = 0;
+        for crumb in state.crumbs.iter_mut().filter(|crumb| !crumb.is_exiting) {
+            if live >= common {
+                crumb.is_exiting = true;
+                crumb.presence.interrupt(0.0);
+                crumb.x.interrupt(*crumb.x.value() - SLIDE);
+            }
+            live += 1;
+        }
+
+        state.crumbs.extend(
+            self.labels[common..]
+                .iter()
+                .map(|label| Crumb::entering(label.clone(), self.motion)),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.separator = Renderer::Paragraph::with_text(self.text(&self.separator, renderer));
+        let step = state.separator.min_width() + self.spacing * 2.0;
+
+        let mut x = 0.0;
+        let mut extent: f32 = 0.0;
+        let mut height = state.separator.min_height();
+        for crumb in &mut state.crumbs {
+            crumb.paragraph = Renderer::Paragraph::with_text(self.text(&crumb.label, renderer));
+            let width = crumb.paragraph.min_width();
+            height = height.max(crumb.paragraph.min_height());
+
+            // Exiting segments keep their place until they're gone.
+            if crumb.is_exiting {
+                extent = extent.max(*crumb.x.target() + SLIDE + width);
+                continue;
+            }
+
+            // Segments that are entering slide forward into place, while those that were
+            // there from the start are placed right away.
+            if !crumb.is_placed {
+                crumb.is_placed = true;
+                let start = if crumb.presence.has_energy() {
+                    x - SLIDE
+                } else {
+                    x
+                };
+                crumb.x.settle_at(start);
+            }
+            if *crumb.x.target() != x {
+                crumb.x.interrupt(x);
+            }
+
+            extent = extent.max(x + width);
+            x += width + step;
+        }
+
+        layout::Node::new(limits.resolve(Length::Shrink, Length::Shrink, Size::new(extent, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let hovered = self.crumb_at(
+            tree.state.downcast_ref::<State<Renderer::Paragraph>>(),
+            bounds,
+            cursor,
+        );
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                for crumb in &mut state.crumbs {
+                    crumb.x.tick(now);
+                    crumb.presence.tick(now);
+                }
+
+                // Shrink back once the exiting segments are gone.
+                if state.crumbs.iter().any(Crumb::has_exited) {
+                    state.crumbs.retain(|crumb| !crumb.has_exited());
+                    shell.invalidate_layout();
+                }
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.pressed = hovered;
+                if state.pressed.is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => match state.pressed.take() {
+                // Only select the segment if the press ends where it started.
+                Some(pressed) if hovered == Some(pressed) => {
+                    if let Some(on_select) = &self.on_select {
+                        shell.publish(on_select(pressed));
+                    }
+                    event::Status::Captured
+                }
+                Some(_) => event::Status::Captured,
+                None => event::Status::Ignored,
+            },
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let current_status = self.get_status(state, cursor, layout);
+        let is_moving = state
+            .crumbs
+            .iter()
+            .any(|crumb| crumb.x.has_energy() || crumb.presence.has_energy());
+        if state.animated_state.needs_redraw(current_status) || is_moving {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = *state
+            .animated_state
+            .current_style(|status| theme.style(&self.class, *status));
+        let bounds = layout.bounds();
+        let hovered = self.crumb_at(state, bounds, cursor);
+        let current = self.labels.len().checked_sub(1);
+        let separator_width = state.separator.min_width();
+
+        let mut live = 0;
+        for (position, crumb) in state.crumbs.iter().enumerate() {
+            let index = (!crumb.is_exiting).then_some(live);
+            live += usize::from(!crumb.is_exiting);
+
+            let presence = crumb.presence.value().clamp(0.0, 1.0);
+            let x = bounds.x + *crumb.x.value();
+            let y = bounds.center_y();
+            let color = if index.is_some() && index == current {
+                style.current
+            } else if index.is_some() && index == hovered {
+                style.hovered
+            } else {
+                style.text
+            };
+
+            // Each segment after the first brings the separator before it along.
+            if position > 0 {
+                renderer.fill_paragraph(
+                    &state.separator,
+                    Point::new(x - self.spacing - separator_width, y),
+                    style.separator.scale_alpha(presence),
+                    *viewport,
+                );
+            }
+
+            renderer.fill_paragraph(
+                &crumb.paragraph,
+                Point::new(x, y),
+                color.scale_alpha(presence),
+                *viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        if self.crumb_at(state, layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Breadcrumbs<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(breadcrumbs: Breadcrumbs<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(breadcrumbs)
+    }
+}
+
+/// Creates a new [`Breadcrumbs`] trail of the given `labels`, from the root to the current page.
+pub fn breadcrumbs<'a, Message, Theme, Renderer>(
+    labels: impl IntoIterator<Item = impl ToString>,
+) -> Breadcrumbs<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Breadcrumbs::new(labels)
+}
+
+/// The possible status of a [`Breadcrumbs`] trail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Breadcrumbs`] trail can be interacted with.
+    Active,
+    /// A segment that can be selected is being hovered.
+    Hovered,
+    /// A segment is being pressed.
+    Pressed,
+}
+
+/// The appearance of a [`Breadcrumbs`] trail.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of segments leading to the current page.
+    pub text: Color,
+    /// The color of the hovered segment.
+    pub hovered: Color,
+    /// The color of the last segment, which is the current page.
+    pub current: Color,
+    /// The color of the separators.
+    pub separator: Color,
+}
+
+impl Animate for Style {
+    fn components() -> usize {
+        Color::components() * 4
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.text.distance_to(&end.text),
+            self.hovered.distance_to(&end.hovered),
+            self.current.distance_to(&end.current),
+            self.separator.distance_to(&end.separator),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.text.update(components);
+        self.hovered.update(components);
+        self.current.update(components);
+        self.separator.update(components);
+    }
+}
+
+/// The theme catalog of a [`Breadcrumbs`] trail.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Breadcrumbs`] trail.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Breadcrumbs`] trail, with muted segments leading to the current page.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let base = Style {
+        text: palette.background.base.text.scale_alpha(0.6),
+        hovered: palette.primary.base.color,
+        current: palette.background.base.text,
+        separator: palette.background.base.text.scale_alpha(0.35),
+    };
+
+    match status {
+        Status::Active | Status::Hovered => base,
+        Status::Pressed => Style {
+            hovered: palette.primary.strong.color,
+            ..base
+        },
+    }
+}
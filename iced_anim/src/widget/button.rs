@@ -1,6 +1,45 @@
 //! An animated button that will automatically transition between different styles.
-use super::animated_state::AnimatedState;
-use crate::SpringMotion;
+//!
+//! Buttons can also show that their action is in progress with [`Button::loading`]. While
+//! loading, the label fades out, the button contracts into a square, and a spinner fades in.
+//! Everything reverses smoothly once loading ends, and presses are ignored in the meantime.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::button;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Save,
+//! }
+//!
+//! fn save_button<'a>(is_saving: bool) -> Element<'a, Message> {
+//!     button("Save").on_press(Message::Save).loading(is_saving).into()
+//! }
+//! ```
+//!
+//! Once the action completes, [`Button::feedback`] briefly shows the outcome by blending the
+//! button into a success or danger style while a checkmark or cross draws itself over the label.
+//! The button returns to normal on its own after the [`Button::feedback_duration`].
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::button::{button, Feedback};
+//! # #[derive(Debug, Clone)] enum Message { Save }
+//! fn save_button<'a>(is_saving: bool, result: Option<Feedback>) -> Element<'a, Message> {
+//!     button("Save")
+//!         .on_press(Message::Save)
+//!         .loading(is_saving)
+//!         .feedback(result)
+//!         .into()
+//! }
+//! ```
+use std::{
+    f32::consts::TAU,
+    time::{Duration, Instant},
+};
+
+use super::animated_state::{AnimatedState, Id};
+use crate::{Animate, AnimationId, AnimationStore, Spring, SpringMotion};
 use iced::{
     advanced::{
         layout, renderer,
@@ -32,8 +71,45 @@ where
     clip: bool,
     class: Theme::Class<'a>,
     motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    disabled_motion: Option<SpringMotion>,
+    delay: Duration,
+    is_loading: bool,
+    feedback: Option<Feedback>,
+    feedback_duration: Duration,
+    feedback_style: Option<fn(&Theme, Feedback) -> Style>,
+}
+
+/// The outcome of an action shown by a [`Button`] with [`Button::feedback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    /// The action succeeded, shown with a checkmark.
+    Success,
+    /// The action failed, shown with a cross.
+    Error,
 }
 
+/// The theme catalog for the styles of [`Feedback`] shown by a [`Button`].
+pub trait FeedbackCatalog: Catalog {
+    /// The [`Style`] a button blends into while showing the `feedback`.
+    fn feedback_style(&self, feedback: Feedback) -> Style;
+}
+
+impl FeedbackCatalog for iced::Theme {
+    fn feedback_style(&self, feedback: Feedback) -> Style {
+        match feedback {
+            Feedback::Success => success(self, Status::Active),
+            Feedback::Error => danger(self, Status::Active),
+        }
+    }
+}
+
+/// The number of dots in the loading spinner.
+const SPINNER_DOTS: usize = 8;
+
+/// How long it takes the loading spinner to complete a rotation.
+const SPINNER_PERIOD: Duration = Duration::from_millis(900);
+
 enum OnPress<'a, Message> {
     Direct(Message),
     Closure(Box<dyn Fn() -> Message + 'a>),
@@ -66,6 +142,13 @@ where
             clip: false,
             class: Theme::default(),
             motion: SpringMotion::default(),
+            animation_id: None,
+            disabled_motion: None,
+            delay: Duration::ZERO,
+            is_loading: false,
+            feedback: None,
+            feedback_duration: Duration::from_millis(1500),
+            feedback_style: None,
         }
     }
 
@@ -147,6 +230,90 @@ where
         self
     }
 
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Button`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// Sets the motion used when the [`Button`] becomes disabled, which defaults to the
+    /// regular motion. Disabling a button often feels better when it's faster or subtler
+    /// than hovering or pressing it.
+    pub fn disabled_motion(mut self, motion: SpringMotion) -> Self {
+        self.disabled_motion = Some(motion);
+        self
+    }
+
+    /// Sets how long style changes wait before animating, which staggers several buttons
+    /// changing at once.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets whether the [`Button`] is loading, which replaces its content with a spinner and
+    /// ignores presses until loading ends.
+    pub fn loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
+    /// Shows the outcome of an action on the [`Button`] whenever the `feedback` changes to
+    /// `Some`, returning to normal after the [`Button::feedback_duration`].
+    ///
+    /// Set the feedback back to `None` before showing the same outcome again.
+    pub fn feedback(mut self, feedback: Option<Feedback>) -> Self
+    where
+        Theme: FeedbackCatalog,
+    {
+        self.feedback = feedback;
+        self.feedback_style = Some(<Theme as FeedbackCatalog>::feedback_style);
+        self
+    }
+
+    /// Sets how long [`Feedback`] is shown before the [`Button`] returns to normal.
+    pub fn feedback_duration(mut self, duration: Duration) -> Self {
+        self.feedback_duration = duration;
+        self
+    }
+
+    /// Starts showing new feedback requested by the app, or hides feedback the app cleared.
+    fn diff_feedback(&self, state: &mut State) {
+        state.feedback_progress.set_motion(self.motion);
+        if state.requested_feedback == self.feedback {
+            return;
+        }
+
+        state.requested_feedback = self.feedback;
+        match self.feedback {
+            Some(feedback) => {
+                state.feedback = Some(feedback);
+                state.feedback_until = Some(Instant::now() + self.feedback_duration);
+                state.feedback_progress.interrupt(1.0);
+            }
+            None => {
+                state.feedback_until = None;
+                state.feedback_progress.interrupt(0.0);
+            }
+        }
+    }
+
+    /// The target progress of the loading animation.
+    fn loading_progress(&self) -> f32 {
+        if self.is_loading {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
     /// The initial status that this widget will have based on its properties.
     ///
     /// This will be used as the initial state value.
@@ -179,6 +346,20 @@ where
 struct State {
     is_pressed: bool,
     animated_state: AnimatedState<Status, Style>,
+    /// The progress of the loading animation, from 0 when idle to 1 when loading.
+    loading: Spring<f32>,
+    /// When the loading spinner started spinning.
+    spinner_start: Instant,
+    /// The time of the latest redraw, which rotates the loading spinner.
+    now: Instant,
+    /// The latest feedback requested by the app, used to detect new feedback.
+    requested_feedback: Option<Feedback>,
+    /// The feedback being shown, which remains set while it fades out.
+    feedback: Option<Feedback>,
+    /// The progress of the feedback animation, from 0 when idle to 1 when shown.
+    feedback_progress: Spring<f32>,
+    /// When the feedback should start returning to normal.
+    feedback_until: Option<Instant>,
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -195,10 +376,22 @@ where
     fn state(&self) -> tree::State {
         let status = self.get_initial_status();
         // Initialize the state with the current style.
-        let state = State {
+        let now = Instant::now();
+        let mut animated_state =
+            AnimatedState::new(status, self.motion).with_animation_id(self.animation_id.clone());
+        animated_state.set_delay(self.delay);
+        let mut state = State {
             is_pressed: false,
-            animated_state: AnimatedState::new(status, self.motion),
+            animated_state,
+            loading: Spring::new(self.loading_progress()).with_motion(self.motion),
+            spinner_start: now,
+            now,
+            requested_feedback: None,
+            feedback: None,
+            feedback_progress: Spring::new(0.0),
+            feedback_until: None,
         };
+        self.diff_feedback(&mut state);
 
         tree::State::new(state)
     }
@@ -211,6 +404,20 @@ where
         // If the style changes from outside, then immediately update the style.
         let state = tree.state.downcast_mut::<State>();
         state.animated_state.diff(self.motion);
+        state.animated_state.set_delay(self.delay);
+        state
+            .animated_state
+            .set_transition_motion(Status::Disabled, self.disabled_motion);
+
+        state.loading.set_motion(self.motion);
+        let loading = self.loading_progress();
+        if *state.loading.target() != loading {
+            if !state.loading.has_energy() && self.is_loading {
+                state.spinner_start = Instant::now();
+            }
+            state.loading.interrupt(loading);
+        }
+        self.diff_feedback(state);
         tree.diff_children(std::slice::from_ref(&self.content));
     }
 
@@ -227,11 +434,27 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::padded(limits, self.width, self.height, self.padding, |limits| {
+        let node = layout::padded(limits, self.width, self.height, self.padding, |limits| {
             self.content
                 .as_widget()
                 .layout(&mut tree.children[0], renderer, limits)
-        })
+        });
+
+        // Contract into a square while loading, keeping the content centered as it fades out.
+        let progress = *tree.state.downcast_ref::<State>().loading.value();
+        if progress <= 0.0 {
+            return node;
+        }
+
+        let size = node.size();
+        let width = size.width - (size.width - size.height).max(0.0) * progress;
+        let offset = Vector::new((width - size.width) / 2.0, 0.0);
+        let children = node
+            .children()
+            .iter()
+            .map(|child| child.clone().translate(offset))
+            .collect();
+        layout::Node::with_children(Size::new(width, size.height), children)
     }
 
     fn operate(
@@ -280,13 +503,38 @@ where
         let status = self.get_status(state, cursor, layout);
         let needs_redraw = state.animated_state.needs_redraw(status);
 
-        if needs_redraw {
+        // Keep redrawing while the spinner is visible or the button is changing size.
+        let is_spinning = *state.loading.value() > 0.0;
+        if needs_redraw
+            || is_spinning
+            || state.loading.has_energy()
+            || state.feedback_progress.has_energy()
+        {
             shell.request_redraw(window::RedrawRequest::NextFrame);
+        } else if let Some(until) = state.feedback_until {
+            shell.request_redraw(window::RedrawRequest::At(until));
         }
 
         match event {
             Event::Window(window::Event::RedrawRequested(now)) => {
                 state.animated_state.tick(now);
+                state.now = now;
+                if state.loading.has_energy() {
+                    state.loading.tick(now);
+                    shell.invalidate_layout();
+                }
+
+                if state.feedback_until.is_some_and(|until| now >= until) {
+                    state.feedback_until = None;
+                    state.feedback_progress.interrupt(0.0);
+                }
+                state.feedback_progress.tick(now);
+                if state.feedback_until.is_none() && !state.feedback_progress.has_energy() {
+                    state.feedback = None;
+                }
+            }
+            _ if self.is_loading => {
+                state.is_pressed = false;
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -348,9 +596,24 @@ where
         let content_layout = layout.children().next().unwrap();
         let state = tree.state.downcast_ref::<State>();
 
-        let style = state
+        let mut style = state
             .animated_state
-            .current_style(|status| theme.style(&self.class, *status));
+            .current_style(|status| theme.style(&self.class, *status))
+            .clone();
+
+        // Blend into the feedback style while showing the outcome of an action.
+        let feedback = *state.feedback_progress.value();
+        let feedback_style = state
+            .feedback
+            .zip(self.feedback_style)
+            .map(|(feedback, feedback_style)| (feedback, feedback_style(theme, feedback)));
+        if let Some((_, feedback_style)) = &feedback_style {
+            let mut components = feedback_style
+                .distance_to(&style)
+                .into_iter()
+                .map(|distance| distance * feedback);
+            style.update(&mut components);
+        }
 
         if style.background.is_some() || style.border.width > 0.0 || style.shadow.color.a > 0.0 {
             renderer.fill_quad(
@@ -365,23 +628,37 @@ where
             );
         }
 
-        let viewport = if self.clip {
+        let loading = *state.loading.value();
+        let viewport = if self.clip || loading > 0.0 {
             bounds.intersection(viewport).unwrap_or(*viewport)
         } else {
             *viewport
         };
 
-        self.content.as_widget().draw(
-            &tree.children[0],
-            renderer,
-            theme,
-            &renderer::Style {
-                text_color: style.text_color,
-            },
-            content_layout,
-            cursor,
-            &viewport,
-        );
+        let content_opacity = (1.0 - loading) * (1.0 - feedback);
+        if content_opacity > 0.0 {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color.scale_alpha(content_opacity),
+                },
+                content_layout,
+                cursor,
+                &viewport,
+            );
+        }
+
+        if loading > 0.0 {
+            let elapsed = state.now.saturating_duration_since(state.spinner_start);
+            let phase = elapsed.as_secs_f32() / SPINNER_PERIOD.as_secs_f32();
+            draw_spinner(renderer, bounds, style.text_color, loading, phase);
+        }
+
+        if let Some((feedback_kind, _)) = feedback_style.filter(|_| feedback > 0.0) {
+            draw_feedback_icon(renderer, bounds, feedback_kind, style.text_color, feedback);
+        }
     }
 
     fn mouse_interaction(
@@ -394,7 +671,7 @@ where
     ) -> mouse::Interaction {
         let is_mouse_over = cursor.is_over(layout.bounds());
 
-        if is_mouse_over && self.on_press.is_some() {
+        if is_mouse_over && self.on_press.is_some() && !self.is_loading {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -429,6 +706,112 @@ where
     }
 }
 
+/// Draws a spinner of fading dots in the center of the `bounds`, where `opacity` fades the whole
+/// spinner and `phase` is the number of rotations completed so far.
+fn draw_spinner<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+    opacity: f32,
+    phase: f32,
+) where
+    Renderer: iced::advanced::Renderer,
+{
+    let center = bounds.center();
+    let radius = (bounds.width.min(bounds.height) / 2.0 - DEFAULT_PADDING.top).max(4.0);
+    let dot_radius = (radius * 0.2).max(1.0);
+    let head = phase.fract() * SPINNER_DOTS as f32;
+
+    for i in 0..SPINNER_DOTS {
+        let angle = i as f32 / SPINNER_DOTS as f32 * TAU;
+        // Dots fade out behind the head of the spinner as it rotates.
+        let trail = (head - i as f32).rem_euclid(SPINNER_DOTS as f32) / SPINNER_DOTS as f32;
+        let alpha = opacity * (1.0 - trail).max(0.2);
+        let dot = iced::Point::new(
+            center.x + (radius - dot_radius) * angle.sin(),
+            center.y - (radius - dot_radius) * angle.cos(),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: dot.x - dot_radius,
+                    y: dot.y - dot_radius,
+                    width: dot_radius * 2.0,
+                    height: dot_radius * 2.0,
+                },
+                border: iced::Border::default().rounded(dot_radius),
+                ..renderer::Quad::default()
+            },
+            color.scale_alpha(alpha),
+        );
+    }
+}
+
+/// Draws the icon for the `feedback` in the center of the `bounds`, where `progress` is how much
+/// of the icon's path has been drawn in.
+fn draw_feedback_icon<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    feedback: Feedback,
+    color: Color,
+    progress: f32,
+) where
+    Renderer: iced::advanced::Renderer,
+{
+    // The strokes of each icon, in units of the icon's radius from its center.
+    let strokes: &[&[(f32, f32)]] = match feedback {
+        Feedback::Success => &[&[(-0.6, 0.0), (-0.15, 0.45), (0.6, -0.45)]],
+        Feedback::Error => &[&[(-0.5, -0.5), (0.5, 0.5)], &[(0.5, -0.5), (-0.5, 0.5)]],
+    };
+
+    let center = bounds.center();
+    let radius = (bounds.width.min(bounds.height) / 2.0 - DEFAULT_PADDING.top).max(4.0);
+    let dot_radius = (radius * 0.12).max(1.0);
+    let point = |(x, y): (f32, f32)| iced::Point::new(center.x + x * radius, center.y + y * radius);
+
+    let segments: Vec<(iced::Point, iced::Point)> = strokes
+        .iter()
+        .flat_map(|stroke| {
+            stroke
+                .windows(2)
+                .map(|pair| (point(pair[0]), point(pair[1])))
+        })
+        .collect();
+    let total: f32 = segments.iter().map(|(a, b)| a.distance(*b)).sum();
+
+    // The path draws in as a line of overlapping dots, since quads can't be rotated.
+    let mut remaining = total * progress.clamp(0.0, 1.0);
+    for (start, end) in segments {
+        if remaining <= 0.0 {
+            break;
+        }
+
+        let length = start.distance(end);
+        let drawn = remaining.min(length);
+        remaining -= drawn;
+
+        let steps = (drawn / (dot_radius * 0.5)).ceil().max(1.0) as usize;
+        for step in 0..=steps {
+            let t = drawn / length * step as f32 / steps as f32;
+            let dot = start + (end - start) * t;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: dot.x - dot_radius,
+                        y: dot.y - dot_radius,
+                        width: dot_radius * 2.0,
+                        height: dot_radius * 2.0,
+                    },
+                    border: iced::Border::default().rounded(dot_radius),
+                    ..renderer::Quad::default()
+                },
+                color.scale_alpha(progress.min(1.0)),
+            );
+        }
+    }
+}
+
 /// The default [`Padding`] of a [`Button`].
 pub(crate) const DEFAULT_PADDING: Padding = Padding {
     top: 5.0,
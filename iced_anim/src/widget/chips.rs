@@ -0,0 +1,743 @@
+//! A wrapping group of chips that animate being added, removed, and reflowed.
+//!
+//! Chips are matched to the previous chips by their label whenever the view changes. New chips
+//! pop in where they belong, removed chips shrink away in place, and every other chip slides to
+//! its new position, including onto another row when the group rewraps.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::chips;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     RemoveTag(String),
+//! }
+//!
+//! fn tags<'a>(tags: &[String]) -> Element<'a, Message> {
+//!     chips(tags.iter().cloned()).on_remove(Message::RemoveTag).into()
+//! }
+//! ```
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size,
+    Transformation,
+};
+
+use super::animated_state::{AnimatedState, Id};
+use crate::{Animate, AnimationId, AnimationStore, Spring, SpringMotion};
+
+pub use iced::widget::text::{LineHeight, Shaping};
+
+/// The label of the button that removes a chip.
+const REMOVE_LABEL: &str = "×";
+
+/// A group of chips that wrap onto new rows, which animates chips being added and removed.
+#[allow(missing_debug_implementations)]
+pub struct Chips<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    items: Vec<T>,
+    labels: Vec<String>,
+    on_press: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_remove: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    width: Length,
+    spacing: f32,
+    padding: Padding,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Option<Renderer::Font>,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    class: Theme::Class<'a>,
+}
+
+/// A chip of a [`Chips`] group.
+struct Chip<P: Paragraph> {
+    label: String,
+    paragraph: P,
+    /// The size of the chip from the latest layout.
+    size: Size,
+    /// The animated position of the chip relative to the group.
+    position: Spring<(f32, f32)>,
+    /// The animated scale of the chip, which pops from 0 to 1 and shrinks back when removed.
+    scale: Spring<f32>,
+    /// Whether the chip was removed and is on its way out.
+    is_exiting: bool,
+    /// Whether the chip was just added and hasn't been placed yet.
+    is_placed: bool,
+}
+
+impl<P: Paragraph> Chip<P> {
+    /// Creates a chip with the given `label` that is already shown.
+    fn new(label: String, motion: SpringMotion) -> Self {
+        Self {
+            label,
+            paragraph: P::default(),
+            size: Size::ZERO,
+            position: Spring::new((0.0, 0.0)).with_motion(motion),
+            scale: Spring::new(1.0).with_motion(motion),
+            is_exiting: false,
+            is_placed: false,
+        }
+    }
+
+    /// Creates a chip with the given `label` that pops into view.
+    fn entering(label: String, motion: SpringMotion) -> Self {
+        let mut chip = Self::new(label, motion);
+        chip.scale = Spring::new(0.0).with_motion(SpringMotion::Bouncy);
+        chip.scale.interrupt(1.0);
+        chip
+    }
+
+    /// The bounds of the chip, without its scale, within the `bounds` of the group.
+    fn bounds(&self, bounds: Rectangle) -> Rectangle {
+        let (x, y) = *self.position.value();
+        Rectangle::new(Point::new(bounds.x + x, bounds.y + y), self.size)
+    }
+
+    /// Whether the chip has finished exiting and can be dropped.
+    fn has_exited(&self) -> bool {
+        self.is_exiting && !self.scale.has_energy()
+    }
+}
+
+/// The internal state of a [`Chips`] group.
+struct State<P: Paragraph> {
+    /// The chips of the group, including those on their way out.
+    chips: Vec<Chip<P>>,
+    /// The laid out label of the remove buttons.
+    remove: P,
+    /// The chip being pressed and whether its remove button was pressed, if any.
+    pressed: Option<(usize, bool)>,
+    animated_state: AnimatedState<Status, Style>,
+}
+
+impl<P: Paragraph> State<P> {
+    /// The chips that are part of the group, in order.
+    fn live(&self) -> impl Iterator<Item = (usize, &Chip<P>)> {
+        self.chips
+            .iter()
+            .filter(|chip| !chip.is_exiting)
+            .enumerate()
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Chips<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// The default padding of each chip.
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        bottom: 4.0,
+        left: 10.0,
+        right: 10.0,
+    };
+
+    /// Creates a new [`Chips`] group of the given `items`, labeled by their [`ToString`] impl.
+    ///
+    /// Chips are matched across views by their labels, so labels should be unique.
+    pub fn new(items: impl IntoIterator<Item = T>) -> Self {
+        let items: Vec<T> = items.into_iter().collect();
+        let labels = items.iter().map(ToString::to_string).collect();
+
+        Self {
+            items,
+            labels,
+            on_press: None,
+            on_remove: None,
+            width: Length::Fill,
+            spacing: 6.0,
+            padding: Self::DEFAULT_PADDING,
+            size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            motion: SpringMotion::Snappy,
+            animation_id: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when a chip is pressed.
+    pub fn on_press(mut self, on_press: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_press = Some(Box::new(on_press));
+        self
+    }
+
+    /// Sets the message produced when the remove button of a chip is pressed, which also adds a
+    /// remove button to every chip.
+    pub fn on_remove(mut self, on_remove: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_remove = Some(Box::new(on_remove));
+        self
+    }
+
+    /// Sets the width of the [`Chips`] group, which chips wrap within.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the space between chips and between rows.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the padding of each chip.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the line height of the labels.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the labels.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the motion of chips sliding and shrinking away, and of style animations.
+    ///
+    /// New chips always pop in with a [`SpringMotion::Bouncy`] motion.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Chips`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// Sets the style of the [`Chips`] group.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Chips`] group.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The text to lay out for the given `label`.
+    fn text<'b>(
+        &self,
+        label: &'b str,
+        renderer: &Renderer,
+    ) -> core_text::Text<&'b str, Renderer::Font> {
+        core_text::Text {
+            content: label,
+            bounds: Size::INFINITY,
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: core_text::Wrapping::None,
+        }
+    }
+
+    /// The bounds of the remove button of a chip with the given `bounds`.
+    fn remove_bounds(&self, state: &State<Renderer::Paragraph>, bounds: Rectangle) -> Rectangle {
+        let width = state.remove.min_width() + self.padding.right;
+        Rectangle {
+            x: bounds.x + bounds.width - width,
+            width,
+            ..bounds
+        }
+    }
+
+    /// The chip under the `cursor` and whether its remove button is under the cursor, if any.
+    fn chip_at(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<(usize, bool)> {
+        let position = cursor.position()?;
+        state.live().find_map(|(index, chip)| {
+            let chip_bounds = chip.bounds(bounds);
+            if !chip_bounds.contains(position) {
+                return None;
+            }
+
+            let is_remove = self.on_remove.is_some()
+                && self.remove_bounds(state, chip_bounds).contains(position);
+            (is_remove || self.on_press.is_some()).then_some((index, is_remove))
+        })
+    }
+
+    /// Gets the status of the [`Chips`] group based on the current state.
+    fn get_status(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        cursor: Cursor,
+        layout: Layout<'_>,
+    ) -> Status {
+        if state.pressed.is_some() {
+            Status::Pressed
+        } else if self.chip_at(state, layout.bounds(), cursor).is_some() {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Chips<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            chips: self
+                .labels
+                .iter()
+                .map(|label| Chip::new(label.clone(), self.motion))
+                .collect(),
+            remove: Renderer::Paragraph::default(),
+            pressed: None,
+            animated_state: AnimatedState::new(Status::Active, self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.motion);
+
+        // Keep the chips that are still around in their new order, add the new ones, and send
+        // the rest on their way out.
+        let mut previous = std::mem::take(&mut state.chips);
+        let mut chips: Vec<_> = self
+            .labels
+            .iter()
+            .map(|label| {
+                match previous
+                    .iter()
+                    .position(|chip| !chip.is_exiting && chip.label == *label)
+                {
+                    Some(index) => previous.remove(index),
+                    None => Chip::entering(label.clone(), self.motion),
+                }
+            })
+            .collect();
+
+        for chip in &mut previous {
+            if !chip.is_exiting {
+                chip.is_exiting = true;
+                chip.scale.set_motion(self.motion);
+                chip.scale.interrupt(0.0);
+            }
+        }
+        chips.extend(previous);
+
+        for chip in &mut chips {
+            chip.position.set_motion(self.motion);
+        }
+        state.chips = chips;
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.remove = Renderer::Paragraph::with_text(self.text(REMOVE_LABEL, renderer));
+        let remove_width = if self.on_remove.is_some() {
+            state.remove.min_width() + self.padding.left / 2.0
+        } else {
+            0.0
+        };
+
+        let max_width = limits.width(self.width).max().width;
+        let (mut x, mut y) = (0.0, 0.0);
+        let mut row_height: f32 = 0.0;
+        let mut extent: f32 = 0.0;
+
+        for chip in &mut state.chips {
+            chip.paragraph = Renderer::Paragraph::with_text(self.text(&chip.label, renderer));
+
+            // Removed chips shrink away where they were.
+            if chip.is_exiting {
+                continue;
+            }
+
+            let text = chip.paragraph.min_bounds();
+            chip.size = Size::new(
+                text.width + remove_width + self.padding.horizontal(),
+                text.height + self.padding.vertical(),
+            );
+            if x > 0.0 && x + chip.size.width > max_width {
+                x = 0.0;
+                y += row_height + self.spacing;
+                row_height = 0.0;
+            }
+
+            // New chips pop in right where they belong.
+            let target = (x, y);
+            if !chip.is_placed {
+                chip.is_placed = true;
+                chip.position.settle_at(target);
+            } else if *chip.position.target() != target {
+                chip.position.interrupt(target);
+            }
+
+            row_height = row_height.max(chip.size.height);
+            extent = extent.max(x + chip.size.width);
+            x += chip.size.width + self.spacing;
+        }
+
+        let size = limits.width(self.width).height(Length::Shrink).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(extent, y + row_height),
+        );
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                for chip in &mut state.chips {
+                    chip.position.tick(now);
+                    chip.scale.tick(now);
+                }
+                state.chips.retain(|chip| !chip.has_exited());
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.pressed = self.chip_at(state, bounds, cursor);
+                if state.pressed.is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let hovered = self.chip_at(state, bounds, cursor);
+                match state.pressed.take() {
+                    // Only act on the chip if the press ends where it started.
+                    Some(pressed) if hovered == Some(pressed) => {
+                        let (index, is_remove) = pressed;
+                        let on_click = if is_remove {
+                            &self.on_remove
+                        } else {
+                            &self.on_press
+                        };
+                        if let Some(on_click) = on_click {
+                            shell.publish(on_click(self.items[index].clone()));
+                        }
+                        event::Status::Captured
+                    }
+                    Some(_) => event::Status::Captured,
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let current_status = self.get_status(state, cursor, layout);
+        let is_moving = state
+            .chips
+            .iter()
+            .any(|chip| chip.position.has_energy() || chip.scale.has_energy());
+        if state.animated_state.needs_redraw(current_status) || is_moving {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = *state
+            .animated_state
+            .current_style(|status| theme.style(&self.class, *status));
+        let bounds = layout.bounds();
+        let hovered = self.chip_at(state, bounds, cursor);
+
+        let mut live = 0;
+        for chip in &state.chips {
+            let index = (!chip.is_exiting).then_some(live);
+            live += usize::from(!chip.is_exiting);
+
+            let scale = chip.scale.value().max(0.0);
+            if scale <= 0.0 {
+                continue;
+            }
+
+            let chip_bounds = chip.bounds(bounds);
+            let is_hovered = index.is_some() && index == hovered.map(|(index, _)| index);
+            let background = if is_hovered {
+                style.hovered_background
+            } else {
+                style.background
+            };
+            let opacity = scale.min(1.0);
+
+            // Chips scale around their center as they pop in and shrink away.
+            let center = chip_bounds.center();
+            let transformation = Transformation::translate(center.x, center.y)
+                * Transformation::scale(scale)
+                * Transformation::translate(-center.x, -center.y);
+
+            renderer.with_transformation(transformation, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: chip_bounds,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    background.scale_alpha(opacity),
+                );
+                renderer.fill_paragraph(
+                    &chip.paragraph,
+                    Point::new(chip_bounds.x + self.padding.left, center.y),
+                    style.text.scale_alpha(opacity),
+                    *viewport,
+                );
+
+                if self.on_remove.is_some() {
+                    let remove = self.remove_bounds(state, chip_bounds);
+                    renderer.fill_paragraph(
+                        &state.remove,
+                        Point::new(remove.x, center.y),
+                        style.remove.scale_alpha(opacity),
+                        *viewport,
+                    );
+                }
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        if self.chip_at(state, layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Chips<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: ToString + Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(chips: Chips<'a, T, Message, Theme, Renderer>) -> Self {
+        Self::new(chips)
+    }
+}
+
+/// Creates a new [`Chips`] group of the given `items`, labeled by their [`ToString`] impl.
+pub fn chips<'a, T, Message, Theme, Renderer>(
+    items: impl IntoIterator<Item = T>,
+) -> Chips<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Chips::new(items)
+}
+
+/// The possible status of a [`Chips`] group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Chips`] group can be interacted with.
+    Active,
+    /// A chip is being hovered.
+    Hovered,
+    /// A chip is being pressed.
+    Pressed,
+}
+
+/// The appearance of a [`Chips`] group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background color of chips.
+    pub background: Color,
+    /// The background color of the hovered chip.
+    pub hovered_background: Color,
+    /// The border around chips.
+    pub border: Border,
+    /// The color of the labels.
+    pub text: Color,
+    /// The color of the remove buttons.
+    pub remove: Color,
+}
+
+impl Animate for Style {
+    fn components() -> usize {
+        Color::components() * 4 + Border::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.hovered_background.distance_to(&end.hovered_background),
+            self.border.distance_to(&end.border),
+            self.text.distance_to(&end.text),
+            self.remove.distance_to(&end.remove),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.hovered_background.update(components);
+        self.border.update(components);
+        self.text.update(components);
+        self.remove.update(components);
+    }
+}
+
+/// The theme catalog of a [`Chips`] group.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Chips`] group.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Chips`] group, with rounded chips on a weak background.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let base = Style {
+        background: palette.background.weak.color,
+        hovered_background: palette.background.strong.color,
+        border: Border::default().rounded(12.0),
+        text: palette.background.weak.text,
+        remove: palette.background.weak.text.scale_alpha(0.6),
+    };
+
+    match status {
+        Status::Active | Status::Hovered => base,
+        Status::Pressed => Style {
+            hovered_background: palette.background.strong.color.scale_alpha(0.8),
+            ..base
+        },
+    }
+}
+
+/// A style with chips in the primary color of the theme.
+pub fn primary(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    Style {
+        background: palette.primary.weak.color,
+        hovered_background: palette.primary.base.color,
+        text: palette.primary.weak.text,
+        remove: palette.primary.weak.text.scale_alpha(0.7),
+        ..default(theme, status)
+    }
+}
@@ -0,0 +1,329 @@
+//! Reveal and hide content by animating the area it's clipped to.
+//!
+//! A clip reveal wipes content into view from one of its edges or grows it out from its center,
+//! which is a common transition for banners, images, and panels that appear in place. The
+//! content keeps its full layout the whole time, so revealing it doesn't push anything around.
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::widget::clip_reveal::{clip_reveal, Origin};
+//! # #[derive(Clone)] enum Message {}
+//! fn banner<'a>(is_visible: bool) -> Element<'a, Message> {
+//!     clip_reveal(text("Saved!"), is_visible)
+//!         .origin(Origin::Left)
+//!         .into()
+//! }
+//! ```
+//!
+//! Iced layers can only clip to rectangles, so reveals are rectangular. Circular reveals would
+//! need support from the renderer.
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    overlay, window, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+/// Where the revealed area of a [`ClipReveal`] grows from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Origin {
+    /// Wipes in from the left edge.
+    #[default]
+    Left,
+    /// Wipes in from the right edge.
+    Right,
+    /// Wipes in from the top edge.
+    Top,
+    /// Wipes in from the bottom edge.
+    Bottom,
+    /// Grows out from the center in both directions.
+    Center,
+}
+
+impl Origin {
+    /// The part of the `bounds` that is revealed at the given `progress`, from 0 to 1.
+    pub fn clip(self, bounds: Rectangle, progress: f32) -> Rectangle {
+        let progress = progress.clamp(0.0, 1.0);
+        let width = bounds.width * progress;
+        let height = bounds.height * progress;
+
+        match self {
+            Self::Left => Rectangle { width, ..bounds },
+            Self::Right => Rectangle {
+                x: bounds.x + bounds.width - width,
+                width,
+                ..bounds
+            },
+            Self::Top => Rectangle { height, ..bounds },
+            Self::Bottom => Rectangle {
+                y: bounds.y + bounds.height - height,
+                height,
+                ..bounds
+            },
+            Self::Center => Rectangle {
+                x: bounds.center_x() - width / 2.0,
+                y: bounds.center_y() - height / 2.0,
+                width,
+                height,
+            },
+        }
+    }
+}
+
+/// Reveals and hides its content by animating the area it's clipped to.
+pub struct ClipReveal<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    is_revealed: bool,
+    origin: Origin,
+    motion: SpringMotion,
+}
+
+/// The internal state of a [`ClipReveal`].
+struct State {
+    /// How much of the content is revealed, from 0 to 1.
+    progress: Spring<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> ClipReveal<'a, Message, Theme, Renderer> {
+    /// Creates a new [`ClipReveal`] that shows the `content` while `is_revealed` is true.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        is_revealed: bool,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            is_revealed,
+            origin: Origin::default(),
+            motion: SpringMotion::default(),
+        }
+    }
+
+    /// Sets where the revealed area grows from.
+    pub fn origin(mut self, origin: Origin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// The target progress of the reveal.
+    fn target(&self) -> f32 {
+        if self.is_revealed {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// The part of the content that is currently revealed.
+    fn clip(&self, state: &State, bounds: Rectangle) -> Rectangle {
+        self.origin.clip(bounds, *state.progress.value())
+    }
+
+    /// The cursor, which is only available over the revealed part of the content.
+    fn cursor(&self, state: &State, bounds: Rectangle, cursor: Cursor) -> Cursor {
+        match cursor.position() {
+            Some(position) if self.clip(state, bounds).contains(position) => cursor,
+            _ => Cursor::Unavailable,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ClipReveal<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            progress: Spring::new(self.target()).with_motion(self.motion),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.progress.set_motion(self.motion);
+        if *state.progress.target() != self.target() {
+            state.progress.interrupt(self.target());
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            if state.progress.has_energy() {
+                state.progress.tick(now);
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let cursor = self.cursor(state, layout.bounds(), cursor);
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let clip = self.clip(state, bounds);
+        if clip.width <= 0.0 || clip.height <= 0.0 {
+            return;
+        }
+
+        let cursor = self.cursor(state, bounds, cursor);
+        let draw = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        };
+
+        // Skip the extra layer once the content is fully revealed.
+        if clip == bounds {
+            draw(renderer);
+        } else {
+            renderer.with_layer(clip, draw);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let cursor = self.cursor(state, layout.bounds(), cursor);
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        // Overlays can't be clipped, so they only show once the content is fully revealed.
+        let state = tree.state.downcast_ref::<State>();
+        if *state.progress.value() < 1.0 {
+            return None;
+        }
+
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ClipReveal<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(reveal: ClipReveal<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(reveal)
+    }
+}
+
+/// Creates a new [`ClipReveal`] that shows the `content` while `is_revealed` is true.
+pub fn clip_reveal<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    is_revealed: bool,
+) -> ClipReveal<'a, Message, Theme, Renderer> {
+    ClipReveal::new(content, is_revealed)
+}
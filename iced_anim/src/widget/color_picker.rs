@@ -0,0 +1,888 @@
+//! A color picker whose swatch, hue thumb, and saturation cursor spring toward the selection.
+//!
+//! The picker opens in an overlay below its underlay, usually a button showing the color. It
+//! grows and fades into place when opened and shrinks away when closed. Inside, a field picks the
+//! saturation and value, a bar below it picks the hue, and a swatch previews the color. Dragging
+//! either of them, or selecting a color from outside of the picker, springs the cursor, the
+//! thumb, and the swatch toward the new color rather than jumping.
+//!
+//! ```rust
+//! # use iced::{Color, Element, widget::{button, text}};
+//! # use iced_anim::widget::color_picker;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Open,
+//!     Close,
+//!     Pick(Color),
+//! }
+//!
+//! fn accent<'a>(is_open: bool, color: Color) -> Element<'a, Message> {
+//!     let underlay = button(text("Accent")).on_press(Message::Open);
+//!     color_picker(is_open, color, underlay, Message::Pick)
+//!         .on_close(Message::Close)
+//!         .into()
+//! }
+//! ```
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, gradient,
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Length, Point, Radians, Rectangle, Shadow, Size,
+    Transformation, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+/// The space between the picker and its edges, and between its parts.
+const PADDING: f32 = 8.0;
+
+/// The gap between the underlay and the picker.
+const GAP: f32 = 4.0;
+
+/// The height of the hue bar.
+const HUE_HEIGHT: f32 = 12.0;
+
+/// The height of the swatch previewing the color.
+const SWATCH_HEIGHT: f32 = 24.0;
+
+/// The radius of the cursor in the saturation and value field.
+const CURSOR_RADIUS: f32 = 6.0;
+
+/// How much smaller the picker is when it starts opening.
+const OPEN_SCALE: f32 = 0.9;
+
+/// A color described by its hue, saturation, and value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hsv {
+    /// The hue in degrees, from 0 to 360.
+    hue: f32,
+    /// The saturation, from 0 to 1.
+    saturation: f32,
+    /// The value, from 0 to 1.
+    value: f32,
+}
+
+impl Hsv {
+    /// The hue, saturation, and value of the `color`, keeping the `hue` for grays, which don't
+    /// have one of their own.
+    fn from_color(color: Color, hue: f32) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let delta = max - min;
+
+        let hue = if delta <= f32::EPSILON {
+            hue
+        } else if max == color.r {
+            60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+        } else if max == color.g {
+            60.0 * ((color.b - color.r) / delta + 2.0)
+        } else {
+            60.0 * ((color.r - color.g) / delta + 4.0)
+        };
+        let saturation = if max <= 0.0 { 0.0 } else { delta / max };
+
+        Self {
+            hue,
+            saturation,
+            value: max,
+        }
+    }
+
+    /// The color with this hue, saturation, and value, and the given `alpha`.
+    fn to_color(self, alpha: f32) -> Color {
+        let chroma = self.value * self.saturation;
+        let sector = (self.hue.rem_euclid(360.0)) / 60.0;
+        let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let min = self.value - chroma;
+        Color::from_rgba(r + min, g + min, b + min, alpha)
+    }
+}
+
+/// A color picker that shows an animated popover below its underlay while open.
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    underlay: Element<'a, Message, Theme, Renderer>,
+    is_open: bool,
+    selected: Color,
+    on_select: Box<dyn Fn(Color) -> Message + 'a>,
+    on_close: Option<Message>,
+    size: f32,
+    motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`ColorPicker`].
+#[derive(Debug)]
+struct State {
+    /// How open the picker is, from 0 to 1.
+    openness: Spring<f32>,
+    /// The selected color during the latest diff.
+    selected: Color,
+    /// The color being edited, which keeps its hue while it's gray.
+    hsv: Hsv,
+    /// The animated position of the thumb on the hue bar, in degrees.
+    hue: Spring<f32>,
+    /// The animated position of the cursor in the field, as saturation and value.
+    cursor: Spring<(f32, f32)>,
+    /// The animated color of the swatch.
+    swatch: Spring<Color>,
+    /// The part of the picker being dragged, if any.
+    dragging: Option<Part>,
+}
+
+impl State {
+    /// Edits the `hsv` color, springing the thumb, cursor, and swatch toward it.
+    fn select(&mut self, hsv: Hsv, color: Color) {
+        self.hsv = hsv;
+        if *self.hue.target() != hsv.hue {
+            self.hue.interrupt(hsv.hue);
+        }
+        if *self.cursor.target() != (hsv.saturation, hsv.value) {
+            self.cursor.interrupt((hsv.saturation, hsv.value));
+        }
+        if *self.swatch.target() != color {
+            self.swatch.interrupt(color);
+        }
+    }
+
+    /// Jumps the thumb, cursor, and swatch straight to the color being edited.
+    fn settle(&mut self) {
+        self.hue.settle_at(self.hsv.hue);
+        self.cursor.settle_at((self.hsv.saturation, self.hsv.value));
+        self.swatch.settle_at(self.selected);
+    }
+
+    /// Whether any part of the picker is still animating.
+    fn has_energy(&self) -> bool {
+        self.openness.has_energy()
+            || self.hue.has_energy()
+            || self.cursor.has_energy()
+            || self.swatch.has_energy()
+    }
+}
+
+/// A part of the picker that can be dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    /// The field picking the saturation and value.
+    Field,
+    /// The bar picking the hue.
+    Hue,
+}
+
+impl<'a, Message, Theme, Renderer> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`ColorPicker`] that shows a popover below the `underlay` while `is_open`
+    /// is true, editing the `selected` color and producing `on_select` as it changes.
+    pub fn new(
+        is_open: bool,
+        selected: Color,
+        underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_select: impl Fn(Color) -> Message + 'a,
+    ) -> Self {
+        Self {
+            underlay: underlay.into(),
+            is_open,
+            selected,
+            on_select: Box::new(on_select),
+            on_close: None,
+            size: 200.0,
+            motion: SpringMotion::Snappy,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when the popover is dismissed by clicking outside of it.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Sets the width of the popover, which is also the size of the saturation and value field.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the motion of the picker's animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ColorPicker`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorPicker<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let openness = if self.is_open { 1.0 } else { 0.0 };
+        let hsv = Hsv::from_color(self.selected, 0.0);
+
+        tree::State::new(State {
+            openness: Spring::new(openness).with_motion(self.motion),
+            selected: self.selected,
+            hsv,
+            hue: Spring::new(hsv.hue).with_motion(self.motion),
+            cursor: Spring::new((hsv.saturation, hsv.value)).with_motion(self.motion),
+            swatch: Spring::new(self.selected).with_motion(self.motion),
+            dragging: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.openness.set_motion(self.motion);
+        state.hue.set_motion(self.motion);
+        state.cursor.set_motion(self.motion);
+        state.swatch.set_motion(self.motion);
+
+        // Follow colors selected outside of the picker, keeping the hue of grays.
+        if state.selected != self.selected {
+            state.selected = self.selected;
+            if state.hsv.to_color(self.selected.a) != self.selected {
+                let hsv = Hsv::from_color(self.selected, state.hsv.hue);
+                state.select(hsv, self.selected);
+            } else {
+                state.select(state.hsv, self.selected);
+            }
+        }
+
+        let openness = if self.is_open { 1.0 } else { 0.0 };
+        if *state.openness.target() != openness {
+            // Pickers open showing the selected color rather than animating toward it.
+            if self.is_open && *state.openness.value() <= 0.0 {
+                state.settle();
+            }
+            state.dragging = None;
+            state.openness.interrupt(openness);
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.underlay));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.underlay.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // The popover ticks the springs itself, but nothing else wakes it up when it opens.
+        let state = tree.state.downcast_ref::<State>();
+        if state.openness.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.underlay.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The popover stays around while it's closing.
+        if !self.is_open && *state.openness.value() <= 0.0 && !state.openness.has_energy() {
+            return self.underlay.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        let bounds = layout.bounds();
+        Some(overlay::Element::new(Box::new(Popover {
+            state,
+            anchor: Rectangle {
+                x: bounds.x + translation.x,
+                y: bounds.y + translation.y,
+                ..bounds
+            },
+            is_open: self.is_open,
+            selected: self.selected,
+            on_select: self.on_select.as_ref(),
+            on_close: self.on_close.as_ref(),
+            size: self.size,
+            class: &self.class,
+        })))
+    }
+}
+
+/// The popover of a [`ColorPicker`].
+struct Popover<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    state: &'b mut State,
+    /// The bounds of the underlay, which the popover opens below.
+    anchor: Rectangle,
+    is_open: bool,
+    selected: Color,
+    on_select: &'b dyn Fn(Color) -> Message,
+    on_close: Option<&'b Message>,
+    size: f32,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<'a, 'b, Message, Theme> Popover<'a, 'b, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The size of the whole popover.
+    fn popover_size(&self) -> Size {
+        Size::new(
+            self.size + PADDING * 2.0,
+            self.size + HUE_HEIGHT + SWATCH_HEIGHT + PADDING * 4.0,
+        )
+    }
+
+    /// The bounds of the field picking the saturation and value.
+    fn field(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING,
+            width: self.size,
+            height: self.size,
+        }
+    }
+
+    /// The bounds of the bar picking the hue.
+    fn hue_bar(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING * 2.0 + self.size,
+            width: self.size,
+            height: HUE_HEIGHT,
+        }
+    }
+
+    /// The bounds of the swatch previewing the color.
+    fn swatch(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING * 3.0 + self.size + HUE_HEIGHT,
+            width: self.size,
+            height: SWATCH_HEIGHT,
+        }
+    }
+
+    /// The part of the popover under the `cursor`, if any.
+    fn part_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<Part> {
+        if cursor.is_over(self.field(bounds)) {
+            Some(Part::Field)
+        } else if cursor.is_over(self.hue_bar(bounds)) {
+            Some(Part::Hue)
+        } else {
+            None
+        }
+    }
+
+    /// Edits the color for the `part` dragged to the `position`, producing a message if it
+    /// changed.
+    fn drag(
+        &mut self,
+        part: Part,
+        position: Point,
+        bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let hsv = match part {
+            Part::Field => {
+                let field = self.field(bounds);
+                Hsv {
+                    saturation: ((position.x - field.x) / field.width).clamp(0.0, 1.0),
+                    value: 1.0 - ((position.y - field.y) / field.height).clamp(0.0, 1.0),
+                    ..self.state.hsv
+                }
+            }
+            Part::Hue => {
+                let bar = self.hue_bar(bounds);
+                Hsv {
+                    hue: ((position.x - bar.x) / bar.width).clamp(0.0, 1.0) * 360.0,
+                    ..self.state.hsv
+                }
+            }
+        };
+
+        let color = hsv.to_color(self.selected.a);
+        self.state.select(hsv, color);
+        if color != self.selected {
+            shell.publish((self.on_select)(color));
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Popover<'a, 'b, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = self.popover_size();
+
+        // Open below the underlay, or above it if there isn't enough room.
+        let below = self.anchor.y + self.anchor.height + GAP;
+        let y = if below + size.height > bounds.height {
+            (self.anchor.y - GAP - size.height).max(0.0)
+        } else {
+            below
+        };
+        let x = self.anchor.x.min(bounds.width - size.width).max(0.0);
+
+        layout::Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                self.state.openness.tick(now);
+                self.state.hue.tick(now);
+                self.state.cursor.tick(now);
+                self.state.swatch.tick(now);
+                event::Status::Ignored
+            }
+            // A closing popover can't be interacted with.
+            _ if !self.is_open => event::Status::Ignored,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(bounds) {
+                    self.state.dragging = self.part_at(bounds, cursor);
+                    if let (Some(part), Some(position)) = (self.state.dragging, cursor.position()) {
+                        self.drag(part, position, bounds, shell);
+                    }
+                    event::Status::Captured
+                } else {
+                    if let Some(on_close) = self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                match self.state.dragging {
+                    Some(part) => {
+                        self.drag(part, position, bounds, shell);
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if self.state.dragging.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        };
+
+        if self.state.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let bounds = layout.bounds();
+        let openness = self.state.openness.value().clamp(0.0, 1.0);
+        if openness <= 0.0 {
+            return;
+        }
+
+        // Grow from the top edge, next to the underlay, while opening.
+        let scale = OPEN_SCALE + (1.0 - OPEN_SCALE) * openness;
+        let origin = Point::new(bounds.center_x(), bounds.y);
+        let transformation = Transformation::translate(origin.x, origin.y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-origin.x, -origin.y);
+        let fade = |color: Color| color.scale_alpha(openness);
+        let rounded = Border::default().rounded(style.radius);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_transformation(transformation, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        shadow: Shadow {
+                            color: fade(style.shadow.color),
+                            ..style.shadow
+                        },
+                    },
+                    fade(style.background),
+                );
+
+                // The field is the pure hue, whitened to the left and darkened to the bottom.
+                let hue = *self.state.hue.value();
+                let field = self.field(bounds);
+                let pure = Hsv {
+                    hue,
+                    saturation: 1.0,
+                    value: 1.0,
+                };
+                let field_quad = renderer::Quad {
+                    bounds: field,
+                    border: rounded,
+                    ..renderer::Quad::default()
+                };
+                renderer.fill_quad(field_quad, fade(pure.to_color(1.0)));
+                renderer.fill_quad(
+                    field_quad,
+                    gradient::Linear::new(Radians(FRAC_PI_2))
+                        .add_stop(0.0, fade(Color::WHITE))
+                        .add_stop(1.0, Color::TRANSPARENT),
+                );
+                renderer.fill_quad(
+                    field_quad,
+                    gradient::Linear::new(Radians(PI))
+                        .add_stop(0.0, Color::TRANSPARENT)
+                        .add_stop(1.0, fade(Color::BLACK)),
+                );
+
+                let (saturation, value) = *self.state.cursor.value();
+                let center = Point::new(
+                    field.x + saturation.clamp(0.0, 1.0) * field.width,
+                    field.y + (1.0 - value.clamp(0.0, 1.0)) * field.height,
+                );
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center.x - CURSOR_RADIUS,
+                            y: center.y - CURSOR_RADIUS,
+                            width: CURSOR_RADIUS * 2.0,
+                            height: CURSOR_RADIUS * 2.0,
+                        },
+                        border: Border {
+                            color: fade(style.handle),
+                            width: 2.0,
+                            radius: CURSOR_RADIUS.into(),
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+
+                // The hue bar runs through every hue, back around to red.
+                let bar = self.hue_bar(bounds);
+                let spectrum = (0..=6).fold(
+                    gradient::Linear::new(Radians(FRAC_PI_2)),
+                    |spectrum, sector| {
+                        let hue = Hsv {
+                            hue: sector as f32 * 60.0,
+                            saturation: 1.0,
+                            value: 1.0,
+                        };
+                        spectrum.add_stop(sector as f32 / 6.0, fade(hue.to_color(1.0)))
+                    },
+                );
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: bar,
+                        border: Border::default().rounded(HUE_HEIGHT / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    spectrum,
+                );
+
+                let thumb_x = bar.x + (hue / 360.0).clamp(0.0, 1.0) * bar.width;
+                let thumb = HUE_HEIGHT + 4.0;
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: thumb_x - thumb / 2.0,
+                            y: bar.center_y() - thumb / 2.0,
+                            width: thumb,
+                            height: thumb,
+                        },
+                        border: Border {
+                            color: fade(style.handle),
+                            width: 2.0,
+                            radius: (thumb / 2.0).into(),
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    fade(pure.to_color(1.0)),
+                );
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: self.swatch(bounds),
+                        border: Border {
+                            color: fade(style.border.color),
+                            width: 1.0,
+                            radius: style.radius.into(),
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    fade(*self.state.swatch.value()),
+                );
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.is_open {
+            mouse::Interaction::default()
+        } else if self.state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if self.part_at(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ColorPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(color_picker: ColorPicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(color_picker)
+    }
+}
+
+/// Creates a new [`ColorPicker`] that shows a popover below the `underlay` while `is_open` is
+/// true, editing the `selected` color and producing `on_select` as it changes.
+pub fn color_picker<'a, Message, Theme, Renderer>(
+    is_open: bool,
+    selected: Color,
+    underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_select: impl Fn(Color) -> Message + 'a,
+) -> ColorPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    ColorPicker::new(is_open, selected, underlay, on_select)
+}
+
+/// The appearance of the popover of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background color of the popover.
+    pub background: Color,
+    /// The border around the popover.
+    pub border: Border,
+    /// The shadow of the popover.
+    pub shadow: Shadow,
+    /// The radius of the corners of the field and the swatch.
+    pub radius: f32,
+    /// The color of the cursor in the field and the outline of the hue thumb.
+    pub handle: Color,
+}
+
+/// The theme catalog of a [`ColorPicker`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`ColorPicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`ColorPicker`], a popover in the background color with white handles.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color,
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 8.0.into(),
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+        },
+        radius: 4.0,
+        handle: Color::WHITE,
+    }
+}
@@ -0,0 +1,1014 @@
+//! A command palette that opens over the app and animates its results as the query changes.
+//!
+//! The palette hosts an input, usually a text input for the query, above a list of results that
+//! the app filters and ranks itself. Results are matched to the previous results by their label
+//! whenever the view changes, so typing never rebuilds the list: results that still match slide
+//! to their new rank, new results fade in where they belong, and results that no longer match
+//! fade away in place. The panel springs to fit the results and the highlight of the selected
+//! result springs between rows.
+//!
+//! The palette opens by growing out of the top of its panel over a translucent backdrop, and
+//! closes the same way in reverse. The arrow keys move the selection, enter picks the selected
+//! result, and escape or clicking the backdrop produces the close message.
+//!
+//! ```rust
+//! # use iced::{Element, widget::{button, text_input}};
+//! # use iced_anim::widget::command_palette;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Open,
+//!     Close,
+//!     Query(String),
+//!     Run(String),
+//! }
+//!
+//! fn view<'a>(is_open: bool, query: &str, commands: &[String]) -> Element<'a, Message> {
+//!     let results = commands
+//!         .iter()
+//!         .filter(|command| command.to_lowercase().contains(&query.to_lowercase()))
+//!         .cloned();
+//!
+//!     command_palette(
+//!         is_open,
+//!         button("Commands").on_press(Message::Open),
+//!         text_input("Type a command", query).on_input(Message::Query),
+//!         results,
+//!         Message::Run,
+//!     )
+//!     .on_close(Message::Close)
+//!     .into()
+//! }
+//! ```
+//!
+//! The input isn't focused automatically, so focus it with a task like
+//! [`text_input::focus`](iced::widget::text_input::focus) when opening the palette.
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    keyboard::{self, key::Named, Key},
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle,
+    Shadow, Size, Transformation, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+pub use iced::widget::text::{LineHeight, Shaping};
+
+/// How far new results slide down into place as they fade in.
+const SLIDE: f32 = 8.0;
+
+/// How far down the window the panel opens, as a fraction of the window height.
+const TOP: f32 = 0.15;
+
+/// The space kept between the panel and the edges of the window.
+const MARGIN: f32 = 16.0;
+
+/// How much smaller the panel is when it starts opening.
+const OPEN_SCALE: f32 = 0.96;
+
+/// A command palette that shows an input and animated results over its underlay while open.
+#[allow(missing_debug_implementations)]
+pub struct CommandPalette<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    underlay: Element<'a, Message, Theme, Renderer>,
+    input: Element<'a, Message, Theme, Renderer>,
+    is_open: bool,
+    items: Vec<T>,
+    labels: Vec<String>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    on_close: Option<Message>,
+    width: f32,
+    padding: Padding,
+    max_results: usize,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Option<Renderer::Font>,
+    motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// A result row of a [`CommandPalette`].
+struct Row<P: Paragraph> {
+    label: String,
+    paragraph: P,
+    /// The animated position of the row from the top of the panel.
+    y: Spring<f32>,
+    /// How present the row is, from 0 when it has left to 1 when it's fully shown.
+    presence: Spring<f32>,
+    /// Whether the row no longer matches the query and is on its way out.
+    is_exiting: bool,
+    /// Whether the row was just added and hasn't been placed yet.
+    is_placed: bool,
+}
+
+impl<P: Paragraph> Row<P> {
+    /// Creates a row with the given `label` that is already shown.
+    fn new(label: String, motion: SpringMotion) -> Self {
+        Self {
+            label,
+            paragraph: P::default(),
+            y: Spring::new(0.0).with_motion(motion),
+            presence: Spring::new(1.0).with_motion(motion),
+            is_exiting: false,
+            is_placed: false,
+        }
+    }
+
+    /// Creates a row with the given `label` that fades into view.
+    fn entering(label: String, motion: SpringMotion) -> Self {
+        let mut row = Self::new(label, motion);
+        row.presence.settle_at(0.0);
+        row.presence.interrupt(1.0);
+        row
+    }
+
+    /// Whether the row has finished exiting and can be dropped.
+    fn has_exited(&self) -> bool {
+        self.is_exiting && !self.presence.has_energy()
+    }
+}
+
+/// The internal state of a [`CommandPalette`].
+struct State<P: Paragraph> {
+    /// How open the palette is, from 0 to 1.
+    openness: Spring<f32>,
+    /// The result rows, including those on their way out.
+    rows: Vec<Row<P>>,
+    /// The animated height of the panel.
+    height: Spring<f32>,
+    /// The index of the selected result among the results that are still shown.
+    selected: usize,
+    /// The animated position of the highlight from the top of the panel.
+    highlight: Spring<f32>,
+    /// The index of the result being pressed, if any.
+    pressed: Option<usize>,
+}
+
+impl<P: Paragraph> State<P> {
+    /// The rows of the results, in order, without those on their way out.
+    fn live(&self) -> impl Iterator<Item = &Row<P>> {
+        self.rows.iter().filter(|row| !row.is_exiting)
+    }
+
+    /// Selects the result at the given `index` and moves the highlight to it.
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        let target = self.live().nth(index).map(|row| *row.y.target());
+        if let Some(target) = target {
+            // The highlight starts on the first selection rather than sliding down to it.
+            if !self.highlight.has_energy() && *self.highlight.value() == 0.0 {
+                self.highlight.settle_at(target);
+            } else if *self.highlight.target() != target {
+                self.highlight.interrupt(target);
+            }
+        }
+    }
+
+    /// Whether any animation of the palette is still moving.
+    fn has_energy(&self) -> bool {
+        self.openness.has_energy()
+            || self.height.has_energy()
+            || self.highlight.has_energy()
+            || self
+                .rows
+                .iter()
+                .any(|row| row.y.has_energy() || row.presence.has_energy())
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> CommandPalette<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// Creates a new [`CommandPalette`] that shows the `input` and `results` over the
+    /// `underlay` while `is_open` is true, producing `on_select` when a result is picked.
+    pub fn new(
+        is_open: bool,
+        underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+        input: impl Into<Element<'a, Message, Theme, Renderer>>,
+        results: impl IntoIterator<Item = T>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        let items: Vec<T> = results.into_iter().collect();
+
+        Self {
+            underlay: underlay.into(),
+            input: input.into(),
+            is_open,
+            labels: items.iter().map(ToString::to_string).collect(),
+            items,
+            on_select: Box::new(on_select),
+            on_close: None,
+            width: 480.0,
+            padding: Padding::new(8.0),
+            max_results: 8,
+            size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            motion: SpringMotion::Snappy,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when the palette is dismissed with escape or by clicking
+    /// outside of it.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Sets the width of the panel.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding around the input and within each result.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the largest number of results shown at once.
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.max_results = max_results;
+        self
+    }
+
+    /// Sets the text size of the results.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the line height of the results.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the results.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the motion of the palette's animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the style of the [`CommandPalette`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`CommandPalette`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The labels of the results that are shown.
+    fn shown(&self) -> &[String] {
+        &self.labels[..self.labels.len().min(self.max_results)]
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CommandPalette<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let openness = if self.is_open { 1.0 } else { 0.0 };
+
+        tree::State::new(State::<Renderer::Paragraph> {
+            openness: Spring::new(openness).with_motion(self.motion),
+            rows: self
+                .shown()
+                .iter()
+                .map(|label| Row::new(label.clone(), self.motion))
+                .collect(),
+            height: Spring::new(0.0).with_motion(self.motion),
+            selected: 0,
+            highlight: Spring::new(0.0).with_motion(self.motion),
+            pressed: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&self.input)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.openness.set_motion(self.motion);
+        state.height.set_motion(self.motion);
+        state.highlight.set_motion(self.motion);
+
+        let openness = if self.is_open { 1.0 } else { 0.0 };
+        let is_opening = self.is_open && *state.openness.target() != openness;
+        if *state.openness.target() != openness {
+            state.openness.interrupt(openness);
+        }
+
+        let selected = state
+            .live()
+            .nth(state.selected)
+            .map(|row| row.label.clone());
+
+        if is_opening && *state.openness.value() <= 0.0 {
+            // A palette opening from scratch shows its results right away.
+            state.rows = self
+                .shown()
+                .iter()
+                .map(|label| Row::new(label.clone(), self.motion))
+                .collect();
+            state.height.settle_at(0.0);
+            state.highlight.settle_at(0.0);
+        } else {
+            // Keep the results that still match in their new order, add the new ones, and
+            // send the rest on their way out.
+            let mut previous = std::mem::take(&mut state.rows);
+            let mut rows: Vec<_> = self
+                .shown()
+                .iter()
+                .map(|label| {
+                    match previous
+                        .iter()
+                        .position(|row| !row.is_exiting && row.label == *label)
+                    {
+                        Some(index) => previous.remove(index),
+                        None => Row::entering(label.clone(), self.motion),
+                    }
+                })
+                .collect();
+
+            for row in &mut previous {
+                if !row.is_exiting {
+                    row.is_exiting = true;
+                    row.presence.interrupt(0.0);
+                }
+            }
+            rows.extend(previous);
+            state.rows = rows;
+        }
+
+        for row in &mut state.rows {
+            row.y.set_motion(self.motion);
+            row.presence.set_motion(self.motion);
+        }
+
+        // The selection follows its result, or goes back to the best match once it's gone.
+        state.selected = selected
+            .and_then(|selected| self.shown().iter().position(|label| *label == selected))
+            .unwrap_or(0);
+
+        tree.diff_children(&[&self.underlay, &self.input]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.underlay.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // The panel ticks the springs itself, but nothing else wakes it up when it opens.
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        if state.openness.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.underlay.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        // The palette stays around while it's closing.
+        if !self.is_open && *state.openness.value() <= 0.0 && !state.openness.has_energy() {
+            return self.underlay.as_widget_mut().overlay(
+                &mut children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        Some(overlay::Element::new(Box::new(Panel {
+            palette: self,
+            state,
+            input: &mut children[1],
+        })))
+    }
+}
+
+/// The overlay of a [`CommandPalette`] with the backdrop, the input, and the results.
+struct Panel<'a, 'b, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    palette: &'b mut CommandPalette<'a, T, Message, Theme, Renderer>,
+    state: &'b mut State<Renderer::Paragraph>,
+    /// The tree of the input.
+    input: &'b mut Tree,
+}
+
+impl<'a, 'b, T, Message, Theme, Renderer> Panel<'a, 'b, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// The text of a result with the given `label`.
+    fn text<'c>(
+        &self,
+        label: &'c str,
+        renderer: &Renderer,
+    ) -> core_text::Text<&'c str, Renderer::Font> {
+        core_text::Text {
+            content: label,
+            bounds: Size::INFINITY,
+            size: self.palette.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.palette.line_height,
+            font: self.palette.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: core_text::Wrapping::None,
+        }
+    }
+
+    /// The height of each result.
+    fn row_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.palette.size.unwrap_or_else(|| renderer.default_size());
+        self.palette.line_height.to_absolute(size).0 + self.palette.padding.vertical()
+    }
+
+    /// The index of the result under the `cursor` among the results that are shown, if any.
+    fn row_at(&self, panel: Layout<'_>, cursor: Cursor) -> Option<usize> {
+        let bounds = panel.bounds();
+        let position = cursor.position_over(bounds)?;
+        let input = panel.children().next()?.bounds();
+        let top = input.y + input.height + self.palette.padding.bottom;
+        if position.y < top {
+            return None;
+        }
+
+        // Rows are hit where they're headed so clicks don't miss rows that are still sliding.
+        let height = self
+            .state
+            .live()
+            .nth(1)
+            .zip(self.state.live().next())
+            .map(|(second, first)| second.y.target() - first.y.target());
+        self.state.live().position(|row| {
+            let y = bounds.y + row.y.target();
+            position.y >= y && height.map_or(true, |height| position.y < y + height)
+        })
+    }
+}
+
+impl<'a, 'b, T, Message, Theme, Renderer> Panel<'a, 'b, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// Handles the keys that move the selection, pick the selected result, and close the palette.
+    fn key_pressed(&mut self, event: &Event, shell: &mut Shell<'_, Message>) -> event::Status {
+        let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event else {
+            return event::Status::Ignored;
+        };
+
+        let count = self.state.live().count();
+        match key {
+            Key::Named(Named::ArrowDown) if count > 0 => {
+                self.state.select((self.state.selected + 1) % count);
+            }
+            Key::Named(Named::ArrowUp) if count > 0 => {
+                self.state.select((self.state.selected + count - 1) % count);
+            }
+            Key::Named(Named::Enter) if count > 0 => {
+                let item = self.palette.items[self.state.selected].clone();
+                shell.publish((self.palette.on_select)(item));
+            }
+            Key::Named(Named::Escape) => {
+                if let Some(on_close) = &self.palette.on_close {
+                    shell.publish(on_close.clone());
+                }
+            }
+            _ => return event::Status::Ignored,
+        }
+
+        event::Status::Captured
+    }
+}
+
+impl<'a, 'b, T, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Panel<'a, 'b, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let padding = self.palette.padding;
+        let width = self.palette.width.min(bounds.width - MARGIN * 2.0).max(0.0);
+        let input = self
+            .palette
+            .input
+            .as_widget()
+            .layout(
+                self.input,
+                renderer,
+                &layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(width - padding.horizontal(), f32::INFINITY),
+                ),
+            )
+            .move_to(Point::new(padding.left, padding.top));
+
+        let top = padding.vertical() + input.size().height;
+        let row_height = self.row_height(renderer);
+        let mut y = top;
+        for index in 0..self.state.rows.len() {
+            let paragraph =
+                Renderer::Paragraph::with_text(self.text(&self.state.rows[index].label, renderer));
+            let row = &mut self.state.rows[index];
+            row.paragraph = paragraph;
+
+            // Results that no longer match fade away where they were.
+            if row.is_exiting {
+                continue;
+            }
+
+            // New results slide down into place while fading in.
+            if !row.is_placed {
+                row.is_placed = true;
+                if row.presence.has_energy() {
+                    row.y.settle_at(y - SLIDE);
+                    row.y.interrupt(y);
+                } else {
+                    row.y.settle_at(y);
+                }
+            } else if *row.y.target() != y {
+                row.y.interrupt(y);
+            }
+            y += row_height;
+        }
+
+        // The panel starts at its first height rather than growing into it.
+        let height = if y > top { y + padding.bottom } else { top };
+        if *self.state.height.value() == 0.0 {
+            self.state.height.settle_at(height);
+        } else if *self.state.height.target() != height {
+            self.state.height.interrupt(height);
+        }
+        let selected = self.state.selected;
+        self.state.select(selected);
+
+        let panel = layout::Node::with_children(Size::new(width, height), vec![input]).move_to(
+            Point::new((bounds.width - width) / 2.0, bounds.height * TOP),
+        );
+        layout::Node::with_children(bounds, vec![panel])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let panel = layout.children().next().unwrap();
+        let input = panel.children().next().unwrap();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            self.state.openness.tick(now);
+            self.state.height.tick(now);
+            self.state.highlight.tick(now);
+            for row in &mut self.state.rows {
+                row.y.tick(now);
+                row.presence.tick(now);
+            }
+            self.state.rows.retain(|row| !row.has_exited());
+        }
+
+        // A closing palette can't be interacted with. Keys for the results come first, since
+        // inputs capture keys like enter and escape for themselves.
+        let status = if !self.palette.is_open {
+            event::Status::Ignored
+        } else if self.key_pressed(&event, shell) == event::Status::Captured {
+            event::Status::Captured
+        } else if self.palette.input.as_widget_mut().on_event(
+            self.input,
+            event.clone(),
+            input,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        ) == event::Status::Captured
+        {
+            event::Status::Captured
+        } else {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    if let Some(index) = self.row_at(panel, cursor) {
+                        if index != self.state.selected {
+                            self.state.select(index);
+                        }
+                    }
+                    event::Status::Ignored
+                }
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                    if cursor.is_over(panel.bounds()) {
+                        self.state.pressed = self.row_at(panel, cursor);
+                    } else if let Some(on_close) = &self.palette.on_close {
+                        shell.publish(on_close.clone());
+                    }
+                    event::Status::Captured
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                    let hovered = self.row_at(panel, cursor);
+                    match self.state.pressed.take() {
+                        // Only pick the result if the press ends where it started.
+                        Some(index) if hovered == Some(index) => {
+                            let item = self.palette.items[index].clone();
+                            shell.publish((self.palette.on_select)(item));
+                            event::Status::Captured
+                        }
+                        Some(_) => event::Status::Captured,
+                        None => event::Status::Ignored,
+                    }
+                }
+                _ => event::Status::Ignored,
+            }
+        };
+
+        if self.state.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let appearance = theme.style(&self.palette.class);
+        let openness = self.state.openness.value().clamp(0.0, 1.0);
+        if openness <= 0.0 {
+            return;
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                ..renderer::Quad::default()
+            },
+            appearance.backdrop.scale_alpha(openness),
+        );
+
+        let panel = layout.children().next().unwrap();
+        let input = panel.children().next().unwrap();
+        let bounds = panel.bounds();
+
+        // The panel grows out of its top edge as it opens and is cut off while it does.
+        let height = self.state.height.value().max(0.0);
+        let visible = Rectangle {
+            height: height * openness,
+            ..bounds
+        };
+        let scale = OPEN_SCALE + (1.0 - OPEN_SCALE) * openness;
+        let transformation = Transformation::translate(bounds.center_x(), bounds.y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-bounds.center_x(), -bounds.y);
+
+        renderer.with_transformation(transformation, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: visible,
+                    border: appearance.border,
+                    shadow: Shadow {
+                        color: appearance.shadow.color.scale_alpha(openness),
+                        ..appearance.shadow
+                    },
+                },
+                appearance.background,
+            );
+
+            renderer.with_layer(visible, |renderer| {
+                self.palette
+                    .input
+                    .as_widget()
+                    .draw(self.input, renderer, theme, style, input, cursor, &visible);
+
+                let mut live = self.state.live();
+                if live.next().is_some() {
+                    let row_height = self.row_height(renderer);
+                    let inset = self.palette.padding.left / 2.0;
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x + inset,
+                                y: bounds.y + self.state.highlight.value(),
+                                width: bounds.width - inset * 2.0,
+                                height: row_height,
+                            },
+                            border: Border::default().rounded(appearance.border.radius),
+                            ..renderer::Quad::default()
+                        },
+                        appearance.highlight,
+                    );
+                }
+
+                let selected = self
+                    .state
+                    .live()
+                    .nth(self.state.selected)
+                    .map(|row| &row.label);
+                for row in &self.state.rows {
+                    let color = if !row.is_exiting && Some(&row.label) == selected {
+                        appearance.highlight_text
+                    } else {
+                        appearance.text
+                    };
+
+                    renderer.fill_paragraph(
+                        &row.paragraph,
+                        Point::new(
+                            bounds.x + self.palette.padding.left,
+                            bounds.y + row.y.value() + self.palette.padding.top,
+                        ),
+                        color.scale_alpha(row.presence.value().clamp(0.0, 1.0)),
+                        visible,
+                    );
+                }
+            });
+        });
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        // Operations like focusing the input reach it through the panel.
+        let input = layout.children().next().unwrap().children().next().unwrap();
+        self.palette
+            .input
+            .as_widget()
+            .operate(self.input, input, renderer, operation);
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.palette.is_open {
+            return mouse::Interaction::default();
+        }
+
+        let panel = layout.children().next().unwrap();
+        if self.row_at(panel, cursor).is_some() {
+            return mouse::Interaction::Pointer;
+        }
+
+        let input = panel.children().next().unwrap();
+        self.palette
+            .input
+            .as_widget()
+            .mouse_interaction(self.input, input, cursor, viewport, renderer)
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<CommandPalette<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: ToString + Clone + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(command_palette: CommandPalette<'a, T, Message, Theme, Renderer>) -> Self {
+        Self::new(command_palette)
+    }
+}
+
+/// Creates a new [`CommandPalette`] that shows the `input` and `results` over the `underlay`
+/// while `is_open` is true, producing `on_select` when a result is picked.
+pub fn command_palette<'a, T, Message, Theme, Renderer>(
+    is_open: bool,
+    underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+    input: impl Into<Element<'a, Message, Theme, Renderer>>,
+    results: impl IntoIterator<Item = T>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> CommandPalette<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    CommandPalette::new(is_open, underlay, input, results, on_select)
+}
+
+/// The appearance of a [`CommandPalette`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color covering the app behind the panel.
+    pub backdrop: Color,
+    /// The background color of the panel.
+    pub background: Color,
+    /// The border around the panel.
+    pub border: Border,
+    /// The shadow of the panel.
+    pub shadow: Shadow,
+    /// The color of the results.
+    pub text: Color,
+    /// The color of the highlight behind the selected result.
+    pub highlight: Color,
+    /// The color of the selected result.
+    pub highlight_text: Color,
+}
+
+/// The theme catalog of a [`CommandPalette`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`CommandPalette`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`CommandPalette`], with the selected result in the primary color.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.3),
+        background: palette.background.base.color,
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 8.0.into(),
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.25),
+            offset: Vector::new(0.0, 8.0),
+            blur_radius: 24.0,
+        },
+        text: palette.background.base.text,
+        highlight: palette.primary.weak.color,
+        highlight_text: palette.primary.weak.text,
+    }
+}
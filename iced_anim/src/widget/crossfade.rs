@@ -0,0 +1,412 @@
+//! Crossfade between elements whenever a key changes.
+//!
+//! This is useful for swapping content that isn't a full page transition, like an icon that
+//! changes based on some state or the body of a panel. The outgoing content fades out into a
+//! backdrop color before the incoming content fades in from it.
+//!
+//! Since elements can't outlive the `view` that created them, a [`Crossfade`] takes a builder
+//! closure just like [`AnimationBuilder`](crate::AnimationBuilder). The closure is called with
+//! the current key as well as the previous key while the outgoing content is still visible.
+//!
+//! Content can also be swapped with one of the preset transitions, like sliding or zooming, with
+//! [`Crossfade::transition`].
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::widget::crossfade;
+//! # #[derive(Clone)] enum Message {}
+//! fn play_icon<'a>(is_playing: bool) -> Element<'a, Message> {
+//!     crossfade(is_playing, |is_playing| {
+//!         text(if *is_playing { "Pause" } else { "Play" }).into()
+//!     })
+//!     .into()
+//! }
+//! ```
+use std::cell::RefCell;
+
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    overlay, window, Element, Event, Length, Rectangle, Size, Transformation, Vector,
+};
+
+use super::{
+    transition::{Frame, Transition},
+    veil,
+};
+use crate::{Spring, SpringMotion};
+
+// The crossfade fades through the same backdrop as a veil.
+pub use super::veil::{default, Catalog, Style, StyleFn};
+
+/// Crossfades between elements whenever its key changes.
+pub struct Crossfade<'a, Key, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Key: 'static + Clone + PartialEq,
+    Theme: Catalog,
+{
+    /// The key identifying the current content.
+    key: Key,
+    /// Builds the content for a given key.
+    builder: Box<dyn Fn(&Key) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    /// The content built from the current key.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The content built from the previous key while it's fading out.
+    /// This is lazily created in `diff` since only the tree state knows the previous key.
+    outgoing: RefCell<Option<Element<'a, Message, Theme, Renderer>>>,
+    transition: Transition,
+    motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`Crossfade`].
+struct State<Key> {
+    /// The key of the content that is currently visible or fading in.
+    key: Key,
+    /// The key of the content fading out, if any.
+    previous: Option<Key>,
+    /// The progress of the transition from `previous` to `key`, from 0 to 1.
+    progress: Spring<f32>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Crossfade<'a, Key, Message, Theme, Renderer>
+where
+    Key: 'static + Clone + PartialEq,
+    Theme: Catalog,
+{
+    /// Creates a new [`Crossfade`] with the given `key` and `builder`.
+    pub fn new(
+        key: Key,
+        builder: impl Fn(&Key) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        let content = builder(&key);
+        Self {
+            key,
+            builder: Box::new(builder),
+            content,
+            outgoing: RefCell::new(None),
+            transition: Transition::default(),
+            motion: SpringMotion::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the [`Transition`] between contents along with its tuned motion, which can still be
+    /// overridden by calling [`Crossfade::motion`] afterward.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self.motion = transition.motion();
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the style of the [`Crossfade`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Crossfade`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Crossfade<'a, Key, Message, Theme, Renderer>
+where
+    Key: 'static + Clone + PartialEq,
+    Renderer: iced::advanced::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            key: self.key.clone(),
+            previous: None,
+            progress: Spring::new(1.0).with_motion(self.motion),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        state.progress.set_motion(self.motion);
+
+        if state.key != self.key {
+            let previous = std::mem::replace(&mut state.key, self.key.clone());
+            state.previous = Some(previous);
+            state.progress.settle_at(0.0);
+            state.progress.interrupt(1.0);
+
+            // The existing content tree now belongs to the outgoing content.
+            let outgoing_tree = tree.children.swap_remove(0);
+            tree.children = vec![Tree::new(&self.content), outgoing_tree];
+        } else {
+            tree.children[0].diff(&self.content);
+        }
+
+        let outgoing = state
+            .previous
+            .as_ref()
+            .map(|previous| (self.builder)(previous));
+        if let Some(outgoing) = &outgoing {
+            tree.children[1].diff(outgoing);
+        }
+        self.outgoing.replace(outgoing);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        let size = content.size();
+
+        let mut children = vec![content];
+        if let (Some(outgoing), Some(outgoing_tree)) =
+            (self.outgoing.borrow().as_ref(), tree.children.get_mut(1))
+        {
+            children.push(outgoing.as_widget().layout(outgoing_tree, renderer, limits));
+        }
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Only the incoming content is interactive.
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return status;
+        };
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+        if state.progress.has_energy() {
+            state.progress.tick(now);
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        } else if state.previous.is_some() {
+            // The transition finished, so the outgoing content can be dropped.
+            state.previous = None;
+            self.outgoing.replace(None);
+            tree.children.truncate(1);
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let progress = *state.progress.value();
+        let backdrop = theme.style(&self.class).backdrop;
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let content_layout = children.next().unwrap();
+
+        let outgoing = self.outgoing.borrow();
+        if let (Some(outgoing), Some(outgoing_tree), Some(outgoing_layout)) =
+            (outgoing.as_ref(), tree.children.get(1), children.next())
+        {
+            if let Some(frame) = self.transition.outgoing(progress, bounds.size()) {
+                draw_frame(renderer, bounds, backdrop, frame, |renderer| {
+                    outgoing.as_widget().draw(
+                        outgoing_tree,
+                        renderer,
+                        theme,
+                        style,
+                        outgoing_layout,
+                        cursor,
+                        viewport,
+                    );
+                });
+            }
+
+            let Some(frame) = self.transition.incoming(progress, bounds.size()) else {
+                return;
+            };
+            draw_frame(renderer, bounds, backdrop, frame, |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    content_layout,
+                    cursor,
+                    viewport,
+                );
+            });
+        } else {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<Crossfade<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: 'static + Clone + PartialEq,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(crossfade: Crossfade<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(crossfade)
+    }
+}
+
+/// Creates a new [`Crossfade`] with the given `key` and `builder`.
+pub fn crossfade<'a, Key, Message, Theme, Renderer>(
+    key: Key,
+    builder: impl Fn(&Key) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Crossfade<'a, Key, Message, Theme, Renderer>
+where
+    Key: 'static + Clone + PartialEq,
+    Theme: Catalog,
+{
+    Crossfade::new(key, builder)
+}
+
+/// Draws content with the `frame` of a transition, clipped to the `bounds` and covered by the
+/// `backdrop` by the frame's veil.
+fn draw_frame<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    backdrop: iced::Color,
+    frame: Frame,
+    draw: impl FnOnce(&mut Renderer),
+) where
+    Renderer: iced::advanced::Renderer,
+{
+    // Scale around the center of the bounds rather than the origin of the window.
+    let center = bounds.center();
+    let transformation =
+        Transformation::translate(center.x + frame.offset.x, center.y + frame.offset.y)
+            * Transformation::scale(frame.scale)
+            * Transformation::translate(-center.x, -center.y);
+
+    renderer.with_layer(bounds, |renderer| {
+        renderer.with_transformation(transformation, |renderer| {
+            draw(renderer);
+            veil::draw(renderer, bounds, backdrop, frame.veil);
+        });
+    });
+}
@@ -0,0 +1,541 @@
+//! Draw the cursor in the app, animating it between states like hovering and grabbing.
+//!
+//! The system cursor jumps between shapes as the content under it changes its interaction, like
+//! a hand appearing over a draggable card. A [`CursorOverlay`] wraps the root of the window and
+//! hides the system cursor for the interactions it knows how to draw, drawing its own cursor on
+//! top of everything else in the window instead. The cursor follows the pointer directly, while
+//! its size and colors spring between the [`Status`] of the content under it.
+//!
+//! ```rust
+//! # use iced::{widget::{column, text}, Element};
+//! # use iced_anim::widget::cursor_overlay;
+//! # #[derive(Debug, Clone)] enum Message {}
+//! fn view<'a>() -> Element<'a, Message> {
+//!     cursor_overlay(column![text("Board"), text("Cards")]).into()
+//! }
+//! ```
+//!
+//! Interactions without an animated counterpart, like resizing or selecting text, keep the
+//! system cursor so platform conventions still apply. The drawn cursor also fades out when the
+//! pointer leaves the window or the user switches to touch input, where there's no pointer to
+//! draw. Content in overlays of its own, like open menus, may show the system cursor as well.
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    border, event,
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+use super::animated_state::{AnimatedState, Id};
+use crate::{Animate, AnimationId, AnimationStore, Spring, SpringMotion};
+
+/// Draws an animated cursor over its content in place of the system cursor.
+#[allow(missing_debug_implementations)]
+pub struct CursorOverlay<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`CursorOverlay`].
+#[derive(Debug)]
+struct State {
+    /// The latest position of the pointer over the content, where the cursor is drawn.
+    position: Option<Point>,
+    /// Whether the pointer is over the content.
+    is_over: bool,
+    /// Whether the latest input came from touch rather than a pointer.
+    is_touch: bool,
+    /// How visible the drawn cursor is, from 0 when hidden to 1 when fully shown.
+    visibility: Spring<f32>,
+    animated_state: AnimatedState<Status, Style>,
+}
+
+impl State {
+    /// Whether the drawn cursor is visible at all.
+    fn is_visible(&self) -> bool {
+        self.position.is_some() && *self.visibility.value() > 0.0
+    }
+}
+
+impl<'a, Message, Theme, Renderer> CursorOverlay<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`CursorOverlay`] that draws the cursor over the `content`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            motion: SpringMotion::Snappy,
+            animation_id: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the motion of the cursor changing between states and fading in or out.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`CursorOverlay`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// Sets the style of the [`CursorOverlay`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`CursorOverlay`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CursorOverlay<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            position: None,
+            is_over: false,
+            is_touch: false,
+            visibility: Spring::new(0.0).with_motion(self.motion),
+            animated_state: AnimatedState::new(Status::Idle, self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.visibility.set_motion(self.motion);
+        state.animated_state.diff(self.motion);
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let event_status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.visibility.tick(now);
+                state.animated_state.tick(now);
+            }
+            Event::Touch(touch::Event::FingerPressed { .. }) => state.is_touch = true,
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => state.is_touch = false,
+            _ => {}
+        }
+
+        // Stay where the pointer was last seen while fading out.
+        let previous = state.position;
+        let position = cursor
+            .position_over(layout.bounds())
+            .filter(|_| !state.is_touch);
+        state.is_over = position.is_some();
+        if position.is_some() {
+            state.position = position;
+        }
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+        let status = Status::from_interaction(interaction);
+
+        let visibility = match status {
+            Some(_) if state.is_over => 1.0,
+            _ => 0.0,
+        };
+        if *state.visibility.target() != visibility {
+            state.visibility.interrupt(visibility);
+        }
+
+        // Keep the previous look while the cursor fades out.
+        let needs_redraw = match status {
+            Some(status) => state.animated_state.needs_redraw(status),
+            None => false,
+        };
+        if needs_redraw
+            || state.visibility.has_energy()
+            || (state.is_visible() && state.position != previous)
+        {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event_status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        match Status::from_interaction(interaction) {
+            Some(_) if state.is_over => mouse::Interaction::Hidden,
+            _ => interaction,
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_ref::<State>();
+
+        let mut overlays: Vec<_> = self
+            .content
+            .as_widget_mut()
+            .overlay(&mut children[0], layout, renderer, translation)
+            .into_iter()
+            .collect();
+
+        // The cursor is drawn last so it stays on top of the content's own overlays.
+        if state.is_visible() {
+            overlays.push(overlay::Element::new(Box::new(Pointer {
+                state,
+                translation,
+                class: &self.class,
+            })));
+        }
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+/// The overlay of a [`CursorOverlay`], which draws the cursor at the pointer.
+struct Pointer<'a, 'b, Theme>
+where
+    Theme: Catalog,
+{
+    state: &'b State,
+    /// The translation from the layout of the content to the overlay.
+    translation: Vector,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Pointer<'a, 'b, Theme>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        layout::Node::new(bounds)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let Some(position) = self.state.position else {
+            return;
+        };
+
+        let visibility = self.state.visibility.value().clamp(0.0, 1.0);
+        let style = self
+            .state
+            .animated_state
+            .current_style(|status| theme.style(self.class, *status));
+
+        // Shrink while fading so the cursor appears to pop in and out of the pointer.
+        let size = style.size * (0.5 + visibility / 2.0);
+        let center = position + self.translation;
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(
+                    Point::new(center.x - size / 2.0, center.y - size / 2.0),
+                    Size::new(size, size),
+                ),
+                border: Border {
+                    color: style.border_color.scale_alpha(visibility),
+                    width: style.border_width,
+                    radius: border::radius(size / 2.0),
+                },
+                ..renderer::Quad::default()
+            },
+            style.color.scale_alpha(visibility),
+        );
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        // The cursor never gets in the way of what it's pointing at.
+        false
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CursorOverlay<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(overlay: CursorOverlay<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(overlay)
+    }
+}
+
+/// Creates a new [`CursorOverlay`] that draws the cursor over the `content`.
+pub fn cursor_overlay<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> CursorOverlay<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    CursorOverlay::new(content)
+}
+
+/// The interaction of the content under a [`CursorOverlay`] that the cursor is drawn for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The content has no particular interaction.
+    Idle,
+    /// The content can be clicked.
+    Pointer,
+    /// The content can be dragged.
+    Grab,
+    /// The content is being dragged.
+    Grabbing,
+}
+
+impl Status {
+    /// The status for the given interaction, or `None` if the system cursor should be kept.
+    fn from_interaction(interaction: mouse::Interaction) -> Option<Self> {
+        match interaction {
+            mouse::Interaction::None | mouse::Interaction::Idle => Some(Self::Idle),
+            mouse::Interaction::Pointer => Some(Self::Pointer),
+            mouse::Interaction::Grab => Some(Self::Grab),
+            mouse::Interaction::Grabbing => Some(Self::Grabbing),
+            _ => None,
+        }
+    }
+}
+
+/// The appearance of the cursor drawn by a [`CursorOverlay`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The diameter of the cursor.
+    pub size: f32,
+    /// The color filling the cursor.
+    pub color: Color,
+    /// The color of the ring around the cursor.
+    pub border_color: Color,
+    /// The width of the ring around the cursor.
+    pub border_width: f32,
+}
+
+impl Animate for Style {
+    fn components() -> usize {
+        f32::components() + Color::components() * 2 + f32::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.size.distance_to(&end.size),
+            self.color.distance_to(&end.color),
+            self.border_color.distance_to(&end.border_color),
+            self.border_width.distance_to(&end.border_width),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.size.update(components);
+        self.color.update(components);
+        self.border_color.update(components);
+        self.border_width.update(components);
+    }
+}
+
+/// The theme catalog of a [`CursorOverlay`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`CursorOverlay`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`CursorOverlay`], a small dot that grows into a ring over content
+/// that can be clicked or dragged, and shrinks into a solid grip while dragging.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let text = palette.background.base.text;
+    let primary = palette.primary.base.color;
+    match status {
+        Status::Idle => Style {
+            size: 10.0,
+            color: text,
+            border_color: palette.background.base.color,
+            border_width: 1.5,
+        },
+        Status::Pointer => Style {
+            size: 28.0,
+            color: primary.scale_alpha(0.15),
+            border_color: primary,
+            border_width: 1.5,
+        },
+        Status::Grab => Style {
+            size: 32.0,
+            color: primary.scale_alpha(0.1),
+            border_color: primary,
+            border_width: 2.0,
+        },
+        Status::Grabbing => Style {
+            size: 20.0,
+            color: primary.scale_alpha(0.6),
+            border_color: primary,
+            border_width: 2.0,
+        },
+    }
+}
@@ -0,0 +1,1076 @@
+//! A date picker whose calendar opens, slides between months, and highlights days with springs.
+//!
+//! The calendar opens in an overlay below its underlay, usually a button showing the date. It
+//! grows into place when opened and shrinks away when closed, the month grid slides sideways
+//! when moving to another month, and the highlight behind the selected day springs from cell to
+//! cell as the selection changes.
+//!
+//! ```rust
+//! # use iced::{Element, widget::{button, text}};
+//! # use iced_anim::widget::date_picker::{date_picker, Date};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Open,
+//!     Close,
+//!     Pick(Date),
+//! }
+//!
+//! fn due_date<'a>(is_open: bool, date: Date) -> Element<'a, Message> {
+//!     let underlay = button(text(date.to_string())).on_press(Message::Open);
+//!     date_picker(is_open, date, underlay, Message::Pick)
+//!         .on_close(Message::Close)
+//!         .into()
+//! }
+//! ```
+use std::fmt;
+
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        text::{self as core_text, Text},
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow, Size,
+    Transformation, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+pub use iced::widget::text::{LineHeight, Shaping};
+
+/// The names of the months, starting with January.
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The labels of the days of the week, starting with Monday.
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// The space between the calendar and its edges.
+const PADDING: f32 = 8.0;
+
+/// The gap between the underlay and the calendar.
+const GAP: f32 = 4.0;
+
+/// How much smaller the calendar is when it starts opening.
+const OPEN_SCALE: f32 = 0.95;
+
+/// A day of the proleptic Gregorian calendar.
+///
+/// Dates are created with [`Date::new`], which makes sure they exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a new [`Date`], clamping the month and the day to ones that exist.
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        let month = month.clamp(1, 12);
+        let day = day.clamp(1, Self::days_in_month(year, month));
+        Self { year, month, day }
+    }
+
+    /// The year, like 2024.
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// The month, from 1 for January to 12 for December.
+    pub fn month(self) -> u32 {
+        self.month
+    }
+
+    /// The day of the month, starting at 1.
+    pub fn day(self) -> u32 {
+        self.day
+    }
+
+    /// Whether the given `year` is a leap year.
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// The number of days in the given `month` of a `year`, clamping the month to one that exists.
+    pub fn days_in_month(year: i32, month: u32) -> u32 {
+        match month.clamp(1, 12) {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// The day of the week, from 0 for Monday to 6 for Sunday.
+    pub fn weekday(self) -> u32 {
+        // Sakamoto's method, which counts from Sunday.
+        const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let year = if self.month < 3 {
+            self.year - 1
+        } else {
+            self.year
+        };
+        let sunday_based = (year + year.div_euclid(4) - year.div_euclid(100)
+            + year.div_euclid(400)
+            + OFFSETS[self.month as usize - 1]
+            + self.day as i32)
+            .rem_euclid(7);
+        ((sunday_based + 6) % 7) as u32
+    }
+
+    /// The same day moved by a number of `months`, clamped to the last day of the new month.
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        Self::new(
+            index.div_euclid(12),
+            index.rem_euclid(12) as u32 + 1,
+            self.day,
+        )
+    }
+
+    /// The first day of the month of this date.
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// The number of months from this date to the `other` date, ignoring days.
+    fn months_until(self, other: Self) -> i32 {
+        (other.year - self.year) * 12 + other.month as i32 - self.month as i32
+    }
+
+    /// The column and row of this date in the grid of its month.
+    fn cell(self) -> (f32, f32) {
+        let index = self.first_of_month().weekday() + self.day - 1;
+        ((index % 7) as f32, (index / 7) as f32)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A date picker that shows an animated calendar below its underlay while open.
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    underlay: Element<'a, Message, Theme, Renderer>,
+    is_open: bool,
+    selected: Date,
+    on_select: Box<dyn Fn(Date) -> Message + 'a>,
+    on_close: Option<Message>,
+    cell_size: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`DatePicker`].
+#[derive(Debug)]
+struct State {
+    /// How open the calendar is, from 0 to 1.
+    openness: Spring<f32>,
+    /// The first day of the month shown in the calendar.
+    month: Date,
+    /// The first day of the month sliding out of view, if any.
+    previous: Option<Date>,
+    /// The side the shown month slides in from, 1 for the right and -1 for the left.
+    direction: f32,
+    /// How far the shown month is from its resting place, in grid widths.
+    slide: Spring<f32>,
+    /// The selected date during the latest diff.
+    selected: Date,
+    /// The column and row of the highlight, and how visible it is.
+    highlight: Spring<(f32, f32, f32)>,
+    /// The part of the calendar being pressed, if any.
+    pressed: Option<Target>,
+}
+
+impl State {
+    /// Shows the month of the given `date`, sliding toward it if another month is shown.
+    fn show(&mut self, date: Date) {
+        let month = date.first_of_month();
+        let months = self.month.months_until(month);
+        if months == 0 {
+            return;
+        }
+
+        self.previous = Some(self.month);
+        self.month = month;
+        self.direction = months.signum() as f32;
+        self.slide.settle_at(self.direction);
+        self.slide.interrupt(0.0);
+    }
+
+    /// Moves the highlight to the `selected` date, fading it out if it's in another month.
+    fn highlight(&mut self, selected: Date) {
+        let (column, row, _) = *self.highlight.target();
+        let target = if selected.first_of_month() == self.month {
+            let (column, row) = selected.cell();
+            (column, row, 1.0)
+        } else {
+            (column, row, 0.0)
+        };
+
+        if *self.highlight.target() != target {
+            self.highlight.interrupt(target);
+        }
+    }
+}
+
+/// A part of the calendar that can be pressed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    /// The button moving to the previous month.
+    Previous,
+    /// The button moving to the next month.
+    Next,
+    /// A day of the shown month.
+    Day(Date),
+}
+
+impl<'a, Message, Theme, Renderer> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// Creates a new [`DatePicker`] that shows a calendar below the `underlay` while `is_open`
+    /// is true, highlighting the `selected` date and producing `on_select` when a day is picked.
+    pub fn new(
+        is_open: bool,
+        selected: Date,
+        underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_select: impl Fn(Date) -> Message + 'a,
+    ) -> Self {
+        Self {
+            underlay: underlay.into(),
+            is_open,
+            selected,
+            on_select: Box::new(on_select),
+            on_close: None,
+            cell_size: 32.0,
+            text_size: None,
+            font: None,
+            motion: SpringMotion::Snappy,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when the calendar is dismissed by clicking outside of it.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Sets the size of each day in the calendar.
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Sets the text size of the calendar.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the calendar.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the motion of the calendar's animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the style of the [`DatePicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DatePicker`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DatePicker<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let openness = if self.is_open { 1.0 } else { 0.0 };
+        let (column, row) = self.selected.cell();
+
+        tree::State::new(State {
+            openness: Spring::new(openness).with_motion(self.motion),
+            month: self.selected.first_of_month(),
+            previous: None,
+            direction: 1.0,
+            slide: Spring::new(0.0).with_motion(self.motion),
+            selected: self.selected,
+            highlight: Spring::new((column, row, 1.0)).with_motion(self.motion),
+            pressed: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.openness.set_motion(self.motion);
+        state.slide.set_motion(self.motion);
+        state.highlight.set_motion(self.motion);
+
+        let openness = if self.is_open { 1.0 } else { 0.0 };
+        if *state.openness.target() != openness {
+            // Calendars always open on the month of the selected date.
+            if self.is_open && *state.openness.value() <= 0.0 {
+                let (column, row) = self.selected.cell();
+                state.month = self.selected.first_of_month();
+                state.previous = None;
+                state.slide.settle_at(0.0);
+                state.highlight.settle_at((column, row, 1.0));
+            }
+            state.openness.interrupt(openness);
+        }
+
+        // Follow new selections to their month, like ones made outside of the calendar.
+        if state.selected != self.selected {
+            state.selected = self.selected;
+            if self.is_open {
+                state.show(self.selected);
+            }
+        }
+        state.highlight(self.selected);
+
+        tree.diff_children(std::slice::from_ref(&self.underlay));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.underlay.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // The calendar ticks the springs itself, but nothing else wakes it up when it opens.
+        let state = tree.state.downcast_ref::<State>();
+        if state.openness.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.underlay.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The calendar stays around while it's closing.
+        if !self.is_open && *state.openness.value() <= 0.0 && !state.openness.has_energy() {
+            return self.underlay.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        let bounds = layout.bounds();
+        Some(overlay::Element::new(Box::new(Calendar {
+            state,
+            anchor: Rectangle {
+                x: bounds.x + translation.x,
+                y: bounds.y + translation.y,
+                ..bounds
+            },
+            is_open: self.is_open,
+            selected: self.selected,
+            on_select: self.on_select.as_ref(),
+            on_close: self.on_close.as_ref(),
+            cell_size: self.cell_size,
+            text_size: self.text_size,
+            font: self.font,
+            class: &self.class,
+        })))
+    }
+}
+
+/// The calendar overlay of a [`DatePicker`].
+struct Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    state: &'b mut State,
+    /// The bounds of the underlay, which the calendar opens below.
+    anchor: Rectangle,
+    is_open: bool,
+    selected: Date,
+    on_select: &'b dyn Fn(Date) -> Message,
+    on_close: Option<&'b Message>,
+    cell_size: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// The size of the whole calendar.
+    fn size(&self) -> Size {
+        Size::new(
+            self.cell_size * 7.0 + PADDING * 2.0,
+            self.cell_size * 8.0 + PADDING * 2.0,
+        )
+    }
+
+    /// The bounds of the header with the month and the buttons to change it.
+    fn header(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING,
+            width: bounds.width - PADDING * 2.0,
+            height: self.cell_size,
+        }
+    }
+
+    /// The bounds of the grid of days.
+    fn grid(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING + self.cell_size * 2.0,
+            width: self.cell_size * 7.0,
+            height: self.cell_size * 6.0,
+        }
+    }
+
+    /// The bounds of the cell at a `column` and `row` of a grid offset by `offset` grid widths.
+    fn cell(&self, grid: Rectangle, column: f32, row: f32, offset: f32) -> Rectangle {
+        Rectangle {
+            x: grid.x + (column + offset * 7.0) * self.cell_size,
+            y: grid.y + row * self.cell_size,
+            width: self.cell_size,
+            height: self.cell_size,
+        }
+    }
+
+    /// The part of the calendar under the `cursor`, if any.
+    fn target_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<Target> {
+        let position = cursor.position_over(bounds)?;
+        let header = self.header(bounds);
+        if header.contains(position) {
+            if position.x < header.x + self.cell_size {
+                return Some(Target::Previous);
+            } else if position.x > header.x + header.width - self.cell_size {
+                return Some(Target::Next);
+            }
+            return None;
+        }
+
+        let grid = self.grid(bounds);
+        if !grid.contains(position) {
+            return None;
+        }
+
+        let column = ((position.x - grid.x) / self.cell_size) as u32;
+        let row = ((position.y - grid.y) / self.cell_size) as u32;
+        let month = self.state.month;
+        let day = (row * 7 + column + 1).checked_sub(month.weekday())?;
+        (day >= 1 && day <= Date::days_in_month(month.year, month.month))
+            .then(|| Target::Day(Date { day, ..month }))
+    }
+
+    /// Draws `content` centered at a `position` with the given `color`.
+    fn text(
+        &self,
+        renderer: &mut Renderer,
+        content: String,
+        position: Point,
+        color: Color,
+        clip: Rectangle,
+    ) {
+        let text = Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: Shaping::Basic,
+            wrapping: core_text::Wrapping::None,
+        };
+        renderer.fill_text(text, position, color, clip);
+    }
+
+    /// Draws the days of the `month` into the `grid`, offset by `offset` grid widths.
+    fn draw_month(
+        &self,
+        renderer: &mut Renderer,
+        style: &Style,
+        grid: Rectangle,
+        month: Date,
+        offset: f32,
+        hovered: Option<Target>,
+        opacity: f32,
+    ) {
+        for day in 1..=Date::days_in_month(month.year, month.month) {
+            let date = Date { day, ..month };
+            let (column, row) = date.cell();
+            let cell = self.cell(grid, column, row, offset);
+
+            if hovered == Some(Target::Day(date)) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: cell,
+                        border: Border::default().rounded(self.cell_size / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered_day.scale_alpha(opacity),
+                );
+            }
+
+            // Days take on the selected text color as the highlight passes over them.
+            let (highlight_column, highlight_row, visibility) = *self.state.highlight.value();
+            let coverage = if month == self.state.month {
+                let distance = (highlight_column - column)
+                    .abs()
+                    .max((highlight_row - row).abs());
+                (1.0 - distance).clamp(0.0, 1.0) * visibility.clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let color = mix(style.text, style.selected_text, coverage);
+            self.text(
+                renderer,
+                day.to_string(),
+                cell.center(),
+                color.scale_alpha(opacity),
+                grid,
+            );
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Calendar<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = self.size();
+
+        // Open below the underlay, or above it if there isn't enough room.
+        let below = self.anchor.y + self.anchor.height + GAP;
+        let y = if below + size.height > bounds.height {
+            (self.anchor.y - GAP - size.height).max(0.0)
+        } else {
+            below
+        };
+        let x = self.anchor.x.min(bounds.width - size.width).max(0.0);
+
+        layout::Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                self.state.openness.tick(now);
+                self.state.slide.tick(now);
+                self.state.highlight.tick(now);
+                if !self.state.slide.has_energy() {
+                    self.state.previous = None;
+                }
+                event::Status::Ignored
+            }
+            // A closing calendar can't be interacted with.
+            _ if !self.is_open => event::Status::Ignored,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(bounds) {
+                    self.state.pressed = self.target_at(bounds, cursor);
+                    event::Status::Captured
+                } else {
+                    if let Some(on_close) = self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let target = self.target_at(bounds, cursor);
+                match self.state.pressed.take() {
+                    Some(pressed) if target == Some(pressed) => {
+                        match pressed {
+                            Target::Previous => {
+                                let month = self.state.month.add_months(-1);
+                                self.state.show(month);
+                                self.state.highlight(self.selected);
+                            }
+                            Target::Next => {
+                                let month = self.state.month.add_months(1);
+                                self.state.show(month);
+                                self.state.highlight(self.selected);
+                            }
+                            Target::Day(date) => shell.publish((self.on_select)(date)),
+                        }
+                        event::Status::Captured
+                    }
+                    Some(_) => event::Status::Captured,
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let state = &self.state;
+        if state.openness.has_energy() || state.slide.has_energy() || state.highlight.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let bounds = layout.bounds();
+        let openness = self.state.openness.value().clamp(0.0, 1.0);
+        if openness <= 0.0 {
+            return;
+        }
+
+        // Grow from the top edge, next to the underlay, while opening.
+        let scale = OPEN_SCALE + (1.0 - OPEN_SCALE) * openness;
+        let origin = Point::new(bounds.center_x(), bounds.y);
+        let transformation = Transformation::translate(origin.x, origin.y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-origin.x, -origin.y);
+        let hovered = self
+            .is_open
+            .then(|| self.target_at(bounds, cursor))
+            .flatten();
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_transformation(transformation, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        shadow: Shadow {
+                            color: style.shadow.color.scale_alpha(openness),
+                            ..style.shadow
+                        },
+                    },
+                    style.background.scale_alpha(openness),
+                );
+
+                // The header with the month and the buttons to move between months.
+                let header = self.header(bounds);
+                let month = self.state.month;
+                self.text(
+                    renderer,
+                    format!("{} {}", MONTHS[month.month as usize - 1], month.year),
+                    header.center(),
+                    style.text.scale_alpha(openness),
+                    bounds,
+                );
+                for (target, label, x) in [
+                    (Target::Previous, "‹", header.x),
+                    (Target::Next, "›", header.x + header.width - self.cell_size),
+                ] {
+                    let button = Rectangle {
+                        x,
+                        width: self.cell_size,
+                        ..header
+                    };
+                    if hovered == Some(target) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: button,
+                                border: Border::default().rounded(self.cell_size / 2.0),
+                                ..renderer::Quad::default()
+                            },
+                            style.hovered_day.scale_alpha(openness),
+                        );
+                    }
+                    self.text(
+                        renderer,
+                        label.to_string(),
+                        button.center(),
+                        style.text.scale_alpha(openness),
+                        bounds,
+                    );
+                }
+
+                let grid = self.grid(bounds);
+                for (column, weekday) in WEEKDAYS.iter().enumerate() {
+                    let cell = self.cell(grid, column as f32, -1.0, 0.0);
+                    self.text(
+                        renderer,
+                        weekday.to_string(),
+                        cell.center(),
+                        style.weekday.scale_alpha(openness),
+                        bounds,
+                    );
+                }
+
+                // The grid is clipped so months slide in and out of view.
+                renderer.with_layer(grid, |renderer| {
+                    let slide = *self.state.slide.value();
+                    let (column, row, visibility) = *self.state.highlight.value();
+                    if visibility > 0.0 {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: self.cell(grid, column, row, slide),
+                                border: Border::default().rounded(self.cell_size / 2.0),
+                                ..renderer::Quad::default()
+                            },
+                            style
+                                .highlight
+                                .scale_alpha(visibility.clamp(0.0, 1.0) * openness),
+                        );
+                    }
+
+                    self.draw_month(renderer, &style, grid, month, slide, hovered, openness);
+                    if let Some(previous) = self.state.previous {
+                        let offset = slide - self.state.direction;
+                        self.draw_month(renderer, &style, grid, previous, offset, None, openness);
+                    }
+                });
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_open && self.target_at(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Mixes the colors `from` and `to` by the given `amount`, from 0 to 1.
+fn mix(from: Color, to: Color, amount: f32) -> Color {
+    Color {
+        r: from.r + (to.r - from.r) * amount,
+        g: from.g + (to.g - from.g) * amount,
+        b: from.b + (to.b - from.b) * amount,
+        a: from.a + (to.a - from.a) * amount,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DatePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(date_picker: DatePicker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(date_picker)
+    }
+}
+
+/// Creates a new [`DatePicker`] that shows a calendar below the `underlay` while `is_open` is
+/// true, highlighting the `selected` date and producing `on_select` when a day is picked.
+pub fn date_picker<'a, Message, Theme, Renderer>(
+    is_open: bool,
+    selected: Date,
+    underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_select: impl Fn(Date) -> Message + 'a,
+) -> DatePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    DatePicker::new(is_open, selected, underlay, on_select)
+}
+
+/// The appearance of the calendar of a [`DatePicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background color of the calendar.
+    pub background: Color,
+    /// The border around the calendar.
+    pub border: Border,
+    /// The shadow of the calendar.
+    pub shadow: Shadow,
+    /// The color of the month and the days.
+    pub text: Color,
+    /// The color of the labels of the days of the week.
+    pub weekday: Color,
+    /// The color of the highlight behind the selected day.
+    pub highlight: Color,
+    /// The color of the selected day.
+    pub selected_text: Color,
+    /// The color behind a hovered day or button.
+    pub hovered_day: Color,
+}
+
+/// The theme catalog of a [`DatePicker`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`DatePicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`DatePicker`], with the selected day highlighted in the primary color.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color,
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 8.0.into(),
+        },
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+        },
+        text: palette.background.base.text,
+        weekday: palette.background.base.text.scale_alpha(0.5),
+        highlight: palette.primary.base.color,
+        selected_text: palette.primary.base.text,
+        hovered_day: palette.background.weak.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Weekdays should match the calendar, including in leap years and before the year 1.
+    #[test]
+    fn weekdays() {
+        assert_eq!(Date::new(2024, 1, 1).weekday(), 0);
+        assert_eq!(Date::new(2024, 2, 29).weekday(), 3);
+        assert_eq!(Date::new(2024, 3, 1).weekday(), 4);
+        assert_eq!(Date::new(2000, 1, 1).weekday(), 5);
+        assert_eq!(Date::new(1900, 3, 1).weekday(), 3);
+        assert_eq!(Date::new(1970, 1, 1).weekday(), 3);
+        assert_eq!(Date::new(0, 1, 1).weekday(), 5);
+        assert_eq!(Date::new(-1, 12, 31).weekday(), 4);
+    }
+
+    /// February should have 29 days in leap years, which skip centuries not divisible by 400.
+    #[test]
+    fn days_in_month() {
+        assert_eq!(Date::days_in_month(2023, 1), 31);
+        assert_eq!(Date::days_in_month(2023, 4), 30);
+        assert_eq!(Date::days_in_month(2023, 2), 28);
+        assert_eq!(Date::days_in_month(2024, 2), 29);
+        assert_eq!(Date::days_in_month(1900, 2), 28);
+        assert_eq!(Date::days_in_month(2000, 2), 29);
+        assert_eq!(Date::days_in_month(2100, 2), 28);
+        assert_eq!(Date::days_in_month(2023, 0), 31);
+        assert_eq!(Date::days_in_month(2023, 13), 31);
+    }
+
+    /// Dates should only be created with months and days that exist.
+    #[test]
+    fn clamps_new_dates() {
+        assert_eq!(Date::new(2024, 0, 0), Date::new(2024, 1, 1));
+        assert_eq!(Date::new(2024, 13, 40), Date::new(2024, 12, 31));
+        assert_eq!(Date::new(2023, 2, 29).day(), 28);
+        assert_eq!(Date::new(2023, 13, 1).weekday(), 4);
+    }
+
+    /// Adding months should roll over years and clamp to the last day of shorter months.
+    #[test]
+    fn adds_months() {
+        let date = Date::new(2024, 1, 31);
+        assert_eq!(date.add_months(1), Date::new(2024, 2, 29));
+        assert_eq!(Date::new(2023, 1, 31).add_months(1), Date::new(2023, 2, 28));
+        assert_eq!(date.add_months(2), Date::new(2024, 3, 31));
+        assert_eq!(date.add_months(3), Date::new(2024, 4, 30));
+        assert_eq!(date.add_months(12), Date::new(2025, 1, 31));
+        assert_eq!(date.add_months(-1), Date::new(2023, 12, 31));
+        assert_eq!(
+            Date::new(2024, 11, 15).add_months(3),
+            Date::new(2025, 2, 15)
+        );
+        assert_eq!(date.add_months(-25), Date::new(2021, 12, 31));
+    }
+}
@@ -0,0 +1,376 @@
+//! A row of items that magnify as the cursor moves over them, like the macOS dock.
+//!
+//! Each item has its own spring that animates its scale based on its distance from the cursor,
+//! so neighboring items grow with a smooth falloff. Items are laid out along the bottom of the
+//! dock, which is tall enough to fit a fully magnified item, and should generally fill the space
+//! they're given so they scale with the dock.
+//!
+//! ```rust
+//! # use iced::{Element, Length, widget::container};
+//! # use iced_anim::widget::dock;
+//! # #[derive(Clone)] enum Message {}
+//! fn apps<'a>() -> Element<'a, Message> {
+//!     dock((0..5).map(|_| container("App").center(Length::Fill).into()))
+//!         .item_size(48.0)
+//!         .magnification(1.8)
+//!         .into()
+//! }
+//! ```
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    window, Element, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+/// A row of items that magnify as the cursor moves over them.
+pub struct Dock<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// The size of each item when it isn't magnified.
+    item_size: f32,
+    /// The largest scale of an item directly under the cursor.
+    magnification: f32,
+    /// The distance from the cursor at which items stop being magnified.
+    range: f32,
+    spacing: f32,
+    motion: SpringMotion,
+}
+
+/// The internal state of a [`Dock`].
+struct State {
+    /// The animated scale of each item.
+    scales: Vec<Spring<f32>>,
+}
+
+impl<'a, Message, Theme, Renderer> Dock<'a, Message, Theme, Renderer> {
+    /// Creates an empty [`Dock`].
+    pub fn new() -> Self {
+        Self::with_children(std::iter::empty())
+    }
+
+    /// Creates a [`Dock`] with the given `children`.
+    pub fn with_children(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            children: children.into_iter().collect(),
+            item_size: 48.0,
+            magnification: 2.0,
+            range: 150.0,
+            spacing: 4.0,
+            motion: SpringMotion::Snappy,
+        }
+    }
+
+    /// Adds an item to the [`Dock`].
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Sets the size of each item when it isn't magnified.
+    pub fn item_size(mut self, item_size: f32) -> Self {
+        self.item_size = item_size;
+        self
+    }
+
+    /// Sets the scale of an item directly under the cursor, e.g. `2.0` for double the size.
+    pub fn magnification(mut self, magnification: f32) -> Self {
+        self.magnification = magnification.max(1.0);
+        self
+    }
+
+    /// Sets the distance from the cursor at which items stop being magnified.
+    pub fn range(mut self, range: f32) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets the spacing between items.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// The height of the dock, which fits a fully magnified item.
+    fn height(&self) -> f32 {
+        self.item_size * self.magnification
+    }
+
+    /// The scale that each item should have based on the cursor position.
+    ///
+    /// Distances are measured against the unmagnified item positions so that magnifying
+    /// the items doesn't feed back into the scales.
+    fn target_scales(&self, bounds: Rectangle, cursor: Cursor) -> impl Iterator<Item = f32> + '_ {
+        let unmagnified_width =
+            self.children.len() as f32 * (self.item_size + self.spacing) - self.spacing;
+        let position = cursor
+            .position()
+            .filter(|position| position.y >= bounds.y && position.y <= bounds.y + bounds.height)
+            .map(|position| position.x - bounds.center_x() + unmagnified_width / 2.0);
+
+        (0..self.children.len()).map(move |index| {
+            let center = index as f32 * (self.item_size + self.spacing) + self.item_size / 2.0;
+            match position {
+                Some(x) => magnification((x - center).abs(), self.range, self.magnification),
+                None => 1.0,
+            }
+        })
+    }
+}
+
+/// The scale of an item at the given `distance` from the cursor,
+/// falling off smoothly from `max` to 1 over the `range`.
+fn magnification(distance: f32, range: f32, max: f32) -> f32 {
+    if range <= 0.0 || distance >= range {
+        return 1.0;
+    }
+
+    let falloff = 0.5 + 0.5 * (std::f32::consts::PI * distance / range).cos();
+    1.0 + (max - 1.0) * falloff
+}
+
+impl<'a, Message, Theme, Renderer> Default for Dock<'a, Message, Theme, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Dock<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            scales: self
+                .children
+                .iter()
+                .map(|_| Spring::new(1.0).with_motion(self.motion))
+                .collect(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state
+            .scales
+            .resize_with(self.children.len(), || Spring::new(1.0));
+        for scale in &mut state.scales {
+            scale.set_motion(self.motion);
+        }
+
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Fixed(self.height()))
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let height = self.height();
+        let scales: Vec<f32> = tree
+            .state
+            .downcast_ref::<State>()
+            .scales
+            .iter()
+            .map(|scale| *scale.value())
+            .collect();
+
+        let mut x = 0.0;
+        let children = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .zip(scales)
+            .map(|((child, tree), scale)| {
+                let size = self.item_size * scale;
+                let limits = layout::Limits::new(Size::ZERO, Size::new(size, size));
+                let node = child
+                    .as_widget()
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(x, height - size));
+                x += size + self.spacing;
+                node
+            })
+            .collect();
+
+        let width = (x - self.spacing).max(0.0);
+        let size = limits.resolve(Length::Shrink, height, Size::new(width, height));
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let targets: Vec<f32> = self.target_scales(layout.bounds(), cursor).collect();
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                for (scale, target) in state.scales.iter_mut().zip(targets) {
+                    if *scale.target() != target {
+                        scale.interrupt(target);
+                    }
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                for scale in &mut state.scales {
+                    scale.tick(now);
+                }
+            }
+            _ => {}
+        }
+
+        if state.scales.iter().any(Spring::has_energy) {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Dock<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(dock: Dock<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(dock)
+    }
+}
+
+/// Creates a new [`Dock`] with the given `children`.
+pub fn dock<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Dock<'a, Message, Theme, Renderer> {
+    Dock::with_children(children)
+}
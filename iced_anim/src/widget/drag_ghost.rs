@@ -0,0 +1,547 @@
+//! Drag a translucent copy of content around, snapping it into place when it's dropped.
+//!
+//! A [`DragGhost`] wraps an element that can be dragged, like a card on a board. Dragging it
+//! lifts a ghost of the content that trails behind the cursor on a spring, while the content
+//! itself stays behind as a placeholder. Releasing the ghost springs it to wherever the content
+//! is laid out next, so it snaps into the new slot when the app moves the content in response to
+//! [`DragGhost::on_drop`], or springs back to where it came from when the app leaves it in place
+//! or the drag is cancelled with the escape key.
+//!
+//! ```rust
+//! # use iced::{widget::text, Element, Point};
+//! # use iced_anim::widget::drag_ghost;
+//! # #[derive(Debug, Clone)] enum Message { Hover(Point), Drop(Point) }
+//! fn card<'a>(title: &'a str) -> Element<'a, Message> {
+//!     drag_ghost(text(title))
+//!         .on_drag(Message::Hover)
+//!         .on_drop(Message::Drop)
+//!         .into()
+//! }
+//! ```
+//!
+//! Since the ghost follows the state of the widget, the content should keep its widget state
+//! when it moves to another slot, e.g. by placing it in an
+//! [`animated_keyed_column`](super::animated_keyed_column) rather than a plain column.
+//!
+//! Iced can't draw arbitrary content with transparency, so the ghost and placeholder are
+//! covered by a [`veil`](super::veil) of the backdrop color instead. This works best when the
+//! backdrop matches the background behind the content.
+use std::time::Duration;
+
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch, window, Color, Element, Event, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+
+use super::veil;
+use crate::{Spring, SpringMotion};
+
+/// How far the cursor has to move after pressing the content before a drag starts.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// Drags a ghost of its content that springs into place when dropped.
+#[allow(missing_debug_implementations)]
+pub struct DragGhost<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drag: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_drop: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_cancel: Option<Message>,
+    motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`DragGhost`].
+#[derive(Debug)]
+struct State {
+    /// Where the content was pressed, until the cursor moves far enough to start a drag.
+    pressed_at: Option<Point>,
+    /// The offset of the cursor from the top left of the ghost while dragging.
+    grab: Option<Vector>,
+    /// The top left of the ghost, which trails the cursor while dragging.
+    ghost: Spring<Point>,
+    /// Whether the ghost is springing into place after being dropped or cancelled.
+    is_landing: bool,
+}
+
+impl State {
+    /// Whether the ghost is being dragged or is still landing.
+    fn is_visible(&self) -> bool {
+        self.grab.is_some() || self.is_landing
+    }
+}
+
+impl<'a, Message, Theme, Renderer> DragGhost<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// The default motion of the ghost following the cursor and landing.
+    pub const MOTION: SpringMotion = SpringMotion::Custom {
+        response: Duration::from_millis(200),
+        damping: 0.8,
+    };
+
+    /// Creates a new [`DragGhost`] that can drag the `content`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_drag: None,
+            on_drop: None,
+            on_cancel: None,
+            motion: Self::MOTION,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced with the cursor position as the ghost is dragged, e.g. to
+    /// highlight the slot under it.
+    pub fn on_drag(mut self, on_drag: impl Fn(Point) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    /// Sets the message produced with the cursor position when the ghost is dropped.
+    ///
+    /// Moving the content to another slot in response makes the ghost snap into it, while
+    /// leaving it in place makes the ghost spring back.
+    pub fn on_drop(mut self, on_drop: impl Fn(Point) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the message produced when the drag is cancelled with the escape key.
+    pub fn on_cancel(mut self, on_cancel: Message) -> Self {
+        self.on_cancel = Some(on_cancel);
+        self
+    }
+
+    /// Sets the motion of the ghost trailing the cursor and landing. A longer response makes
+    /// the ghost lag further behind.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the style of the [`DragGhost`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DragGhost`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragGhost<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            pressed_at: None,
+            grab: None,
+            ghost: Spring::new(Point::ORIGIN).with_motion(self.motion),
+            is_landing: false,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.ghost.set_motion(self.motion);
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let slot = bounds.position();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                // Follow the content if it moved to another slot after being dropped
+                if state.is_landing && *state.ghost.target() != slot {
+                    state.ghost.interrupt(slot);
+                }
+                state.ghost.tick(now);
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.pressed_at = cursor.position_over(bounds);
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(pressed_at) = state.pressed_at {
+                    if state.grab.is_none() && pressed_at.distance(position) > DRAG_THRESHOLD {
+                        // Lift the ghost right where the content is
+                        state.grab = Some(pressed_at - slot);
+                        state.is_landing = false;
+                        state.ghost.settle_at(slot);
+                    }
+                }
+
+                match state.grab {
+                    Some(grab) => {
+                        state.ghost.interrupt(position - grab);
+                        if let Some(on_drag) = &self.on_drag {
+                            shell.publish(on_drag(position));
+                        }
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                state.pressed_at = None;
+                if state.grab.take().is_some() {
+                    state.is_landing = true;
+                    state.ghost.interrupt(slot);
+                    if let (Some(on_drop), Some(position)) = (&self.on_drop, cursor.position()) {
+                        shell.publish(on_drop(position));
+                    }
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) if state.grab.is_some() => {
+                state.pressed_at = None;
+                state.grab = None;
+                state.is_landing = true;
+                state.ghost.interrupt(slot);
+                if let Some(on_cancel) = &self.on_cancel {
+                    shell.publish(on_cancel.clone());
+                }
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
+
+        if state.ghost.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        } else if state.is_landing {
+            state.is_landing = false;
+        }
+
+        // The content doesn't see the drag, so it doesn't react to it like a press.
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        // Fade the content left behind while its ghost is away.
+        let state = tree.state.downcast_ref::<State>();
+        if state.is_visible() {
+            let appearance = theme.style(&self.class);
+            veil::draw(
+                renderer,
+                layout.bounds(),
+                appearance.backdrop,
+                appearance.placeholder_veil,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        if state.grab.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_ref::<State>();
+
+        if !state.is_visible() {
+            return self.content.as_widget_mut().overlay(
+                &mut children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        Some(overlay::Element::new(Box::new(Ghost {
+            content: &self.content,
+            tree: &mut children[0],
+            state,
+            size: layout.bounds().size(),
+            translation,
+            class: &self.class,
+        })))
+    }
+}
+
+/// The overlay of a [`DragGhost`], which draws the ghost of the content above everything else.
+struct Ghost<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    state: &'b State,
+    /// The size of the content the ghost copies.
+    size: Size,
+    /// The translation from the layout of the content to the overlay.
+    translation: Vector,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Ghost<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, _bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(self.size, self.size);
+        let content = self
+            .content
+            .as_widget()
+            .layout(self.tree, renderer, &limits);
+
+        layout::Node::with_children(self.size, vec![content])
+            .move_to(*self.state.ghost.value() + self.translation)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let appearance = theme.style(self.class);
+        let bounds = layout.bounds();
+
+        renderer.with_layer(bounds.expand(appearance.shadow.blur_radius), |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    shadow: appearance.shadow,
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+
+            if let Some(content) = layout.children().next() {
+                self.content
+                    .as_widget()
+                    .draw(self.tree, renderer, theme, style, content, cursor, &bounds);
+            }
+        });
+        veil::draw(renderer, bounds, appearance.backdrop, appearance.ghost_veil);
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        // The ghost never gets in the way of the slots it's dragged over.
+        false
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DragGhost<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(ghost: DragGhost<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(ghost)
+    }
+}
+
+/// Creates a new [`DragGhost`] that can drag the `content`.
+pub fn drag_ghost<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DragGhost<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    DragGhost::new(content)
+}
+
+/// The appearance of a [`DragGhost`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color covering the ghost and the content left behind to make them translucent.
+    /// This should generally match the background behind the content.
+    pub backdrop: Color,
+    /// How much of the backdrop covers the ghost, from 0 to 1.
+    pub ghost_veil: f32,
+    /// How much of the backdrop covers the content left behind while dragging, from 0 to 1.
+    pub placeholder_veil: f32,
+    /// The shadow under the ghost.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`DragGhost`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`DragGhost`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`DragGhost`], a lightly veiled ghost with a soft shadow over a
+/// faded placeholder.
+pub fn default(theme: &iced::Theme) -> Style {
+    Style {
+        backdrop: theme.palette().background,
+        ghost_veil: 0.3,
+        placeholder_veil: 0.6,
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.25),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+        },
+    }
+}
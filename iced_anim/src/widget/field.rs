@@ -0,0 +1,487 @@
+//! Form fields pair an input with a validation message that slides open underneath it.
+//!
+//! A [`Field`] wraps any input, usually a text input, and shows the error message it's given
+//! below the input. The message area grows open when an error appears, pushing the rest of the
+//! form down smoothly, and closes again once the error is gone. Giving the field a count of
+//! submit attempts with [`Field::submitted`] shakes the input whenever a submit fails, so form
+//! builders get the whole validation experience from a single widget without keeping any
+//! animation state themselves.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::{field, text_input};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     EmailChanged(String),
+//!     Submit,
+//! }
+//!
+//! struct Form {
+//!     email: String,
+//!     error: Option<String>,
+//!     attempts: u32,
+//! }
+//!
+//! impl Form {
+//!     fn view(&self) -> Element<Message> {
+//!         field(
+//!             text_input("Email", &self.email)
+//!                 .on_input(Message::EmailChanged)
+//!                 .on_submit(Message::Submit),
+//!             self.error.clone(),
+//!         )
+//!         .submitted(self.attempts)
+//!         .into()
+//!     }
+//! }
+//! ```
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    overlay, window, Color, Element, Event, Length, Pixels, Point, Rectangle, Size, Vector,
+};
+
+use super::shake::Shake;
+use crate::{Spring, SpringMotion};
+
+pub use iced::widget::text::{LineHeight, Shaping};
+
+/// An input with a validation message that slides open underneath it.
+#[allow(missing_debug_implementations)]
+pub struct Field<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    input: Element<'a, Message, Theme, Renderer>,
+    error: Option<String>,
+    /// A hash of the submit attempts, which shakes the input when it changes with an error.
+    submitted: Option<u64>,
+    spacing: f32,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Option<Renderer::Font>,
+    motion: SpringMotion,
+    shake_amplitude: f32,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`Field`].
+struct State<P: Paragraph> {
+    /// The latest error message, which stays shown while the message area closes.
+    message: Option<String>,
+    /// The laid out error message.
+    paragraph: P,
+    /// How open the message area is, from 0 when closed to 1 when open.
+    openness: Spring<f32>,
+    /// The horizontal offset of the input while it shakes.
+    shake: Spring<f32>,
+    /// The hash of the latest submit attempts.
+    submitted: Option<u64>,
+}
+
+impl<'a, Message, Theme, Renderer> Field<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// Creates a new [`Field`] around the `input`, showing the `error` message below it if
+    /// there is one.
+    pub fn new(
+        input: impl Into<Element<'a, Message, Theme, Renderer>>,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            input: input.into(),
+            error,
+            submitted: None,
+            spacing: 4.0,
+            size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            motion: SpringMotion::Smooth,
+            shake_amplitude: 8.0,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the submit attempts of the form, like a count of them. Whenever the attempts change
+    /// while the field has an error, the input shakes.
+    pub fn submitted(mut self, attempts: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        attempts.hash(&mut hasher);
+        self.submitted = Some(hasher.finish());
+        self
+    }
+
+    /// Sets the space between the input and the error message.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the error message.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the line height of the error message.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the error message.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the motion of the message area opening and closing.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets roughly how far the input moves on the first swing of a shake.
+    pub fn shake_amplitude(mut self, amplitude: f32) -> Self {
+        self.shake_amplitude = amplitude;
+        self
+    }
+
+    /// Sets the style of the [`Field`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Field`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The text to lay out for the error `message` in a field of the given `width`.
+    fn text<'b>(
+        &self,
+        message: &'b str,
+        width: f32,
+        renderer: &Renderer,
+    ) -> core_text::Text<&'b str, Renderer::Font> {
+        core_text::Text {
+            content: message,
+            bounds: Size::new(width, f32::INFINITY),
+            size: self
+                .size
+                .unwrap_or_else(|| Pixels(renderer.default_size().0 * 0.85)),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: core_text::Wrapping::Word,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Field<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let openness = if self.error.is_some() { 1.0 } else { 0.0 };
+        tree::State::new(State::<Renderer::Paragraph> {
+            message: self.error.clone(),
+            paragraph: Renderer::Paragraph::default(),
+            openness: Spring::new(openness).with_motion(self.motion),
+            shake: Spring::new(0.0).with_motion(Shake::MOTION),
+            submitted: self.submitted,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.input)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.openness.set_motion(self.motion);
+
+        if self.error.is_some() {
+            state.message.clone_from(&self.error);
+        }
+        let openness = if self.error.is_some() { 1.0 } else { 0.0 };
+        if *state.openness.target() != openness {
+            state.openness.interrupt(openness);
+        }
+
+        // Failed submits shake the input, like a `Shake` kicked at rest.
+        if state.submitted != self.submitted {
+            state.submitted = self.submitted;
+            let response = state.shake.motion().duration().as_secs_f32();
+            if self.error.is_some() && response > 0.0 {
+                let velocity = self.shake_amplitude * std::f32::consts::TAU / response;
+                state.shake.impulse(vec![velocity]);
+            }
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.input));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.input.as_widget().size().width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State<Renderer::Paragraph>>();
+        let input = self
+            .input
+            .as_widget()
+            .layout(&mut children[0], renderer, limits);
+        let input_size = input.size();
+
+        let message_height = match &state.message {
+            Some(message) => {
+                state.paragraph =
+                    Renderer::Paragraph::with_text(self.text(message, input_size.width, renderer));
+                self.spacing + state.paragraph.min_height()
+            }
+            None => 0.0,
+        };
+
+        let openness = state.openness.value().max(0.0);
+        let size = Size::new(
+            input_size.width,
+            input_size.height + message_height * openness,
+        );
+        layout::Node::with_children(size, vec![input])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.input.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if state.openness.has_energy() {
+                state.openness.tick(now);
+                shell.invalidate_layout();
+
+                // Forget the message once it's hidden, so the field takes no extra space.
+                if self.error.is_none() && !state.openness.has_energy() {
+                    state.message = None;
+                }
+            }
+            state.shake.tick(now);
+        }
+        if state.openness.has_energy() || state.shake.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.input.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let input = layout.children().next().unwrap();
+        let input_bounds = input.bounds();
+
+        // The message slides down out from under the input as its area opens.
+        if state.message.is_some() {
+            let area = Rectangle {
+                y: input_bounds.y + input_bounds.height,
+                height: bounds.y + bounds.height - input_bounds.y - input_bounds.height,
+                ..bounds
+            };
+            if area.height > 0.0 {
+                let appearance = theme.style(&self.class);
+                let height = self.spacing + state.paragraph.min_height();
+                let position = Point::new(area.x, area.y + self.spacing + area.height - height);
+
+                renderer.with_layer(area, |renderer| {
+                    renderer.fill_paragraph(&state.paragraph, position, appearance.text, area);
+                });
+            }
+        }
+
+        let offset = Vector::new(*state.shake.value(), 0.0);
+        renderer.with_translation(offset, |renderer| {
+            self.input.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                input,
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.input.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.input.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Field<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(field: Field<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(field)
+    }
+}
+
+/// Creates a new [`Field`] around the `input`, showing the `error` message below it if there
+/// is one.
+pub fn field<'a, Message, Theme, Renderer>(
+    input: impl Into<Element<'a, Message, Theme, Renderer>>,
+    error: Option<String>,
+) -> Field<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Field::new(input, error)
+}
+
+/// The appearance of a [`Field`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the error message.
+    pub text: Color,
+}
+
+/// The theme catalog of a [`Field`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Field`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Field`], with the error message in the danger color.
+pub fn default(theme: &iced::Theme) -> Style {
+    Style {
+        text: theme.extended_palette().danger.base.color,
+    }
+}
@@ -0,0 +1,345 @@
+//! Briefly highlight content when the value it displays changes.
+//!
+//! This is the classic stock-ticker effect, where a cell flashes a highlight color behind its
+//! content when its value changes and then fades back to its normal background. The widget only
+//! keeps a hash of the value and a single spring in its state, and it only requests redraws while
+//! the highlight is fading, so it's cheap enough to use for hundreds of cells in a table.
+//!
+//! ```rust
+//! # use iced::{widget::text, Element};
+//! # use iced_anim::widget::flash_on_change;
+//! # #[derive(Clone)] enum Message {}
+//! fn price<'a>(price: f32) -> Element<'a, Message> {
+//!     flash_on_change(price.to_bits(), text(format!("{price:.2}"))).into()
+//! }
+//! ```
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    border, event,
+    mouse::{self, Cursor},
+    overlay, window, Color, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+/// Highlights its content whenever the value it displays changes.
+pub struct Flash<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    /// A hash of the displayed value, which flashes the content when it changes.
+    key: u64,
+    content: Element<'a, Message, Theme, Renderer>,
+    motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`Flash`].
+#[derive(Debug)]
+struct State {
+    /// The hash of the value that was last displayed.
+    key: u64,
+    /// The strength of the highlight, which jumps to 1 and decays to 0 on change.
+    highlight: Spring<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> Flash<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Flash`] that highlights the `content` when the `value` changes.
+    pub fn new(
+        value: impl Hash,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+
+        Self {
+            key: hasher.finish(),
+            content: content.into(),
+            motion: SpringMotion::Custom {
+                response: std::time::Duration::from_millis(800),
+                damping: 1.0,
+            },
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the motion of the highlight fading back to the normal background.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the style of the [`Flash`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Flash`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Flash<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            key: self.key,
+            highlight: Spring::new(0.0).with_motion(self.motion),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.highlight.set_motion(self.motion);
+
+        if state.key != self.key {
+            state.key = self.key;
+            state.highlight.settle_at(1.0);
+            state.highlight.interrupt(0.0);
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        if state.highlight.has_energy() {
+            if let Event::Window(window::Event::RedrawRequested(now)) = event {
+                state.highlight.tick(now);
+            }
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let amount = state.highlight.value().clamp(0.0, 1.0);
+
+        if amount > 0.0 {
+            let appearance = theme.style(&self.class);
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border: border::rounded(appearance.radius),
+                    ..Default::default()
+                },
+                Color {
+                    a: appearance.highlight.a * amount,
+                    ..appearance.highlight
+                },
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Flash<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(flash: Flash<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(flash)
+    }
+}
+
+/// Creates a new [`Flash`] that highlights the `content` when the `value` changes.
+pub fn flash_on_change<'a, Message, Theme, Renderer>(
+    value: impl Hash,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Flash<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    Flash::new(value, content)
+}
+
+/// The appearance of a [`Flash`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color drawn behind the content when its value changes.
+    pub highlight: Color,
+    /// The border radius of the highlight.
+    pub radius: f32,
+}
+
+/// The theme catalog of a [`Flash`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Flash`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(primary)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// A highlight using the theme's primary color.
+pub fn primary(theme: &iced::Theme) -> Style {
+    Style {
+        highlight: theme.extended_palette().primary.weak.color,
+        radius: 2.0,
+    }
+}
+
+/// A highlight using the theme's success color, e.g. for values that went up.
+pub fn success(theme: &iced::Theme) -> Style {
+    Style {
+        highlight: theme.extended_palette().success.weak.color,
+        radius: 2.0,
+    }
+}
+
+/// A highlight using the theme's danger color, e.g. for values that went down.
+pub fn danger(theme: &iced::Theme) -> Style {
+    Style {
+        highlight: theme.extended_palette().danger.weak.color,
+        radius: 2.0,
+    }
+}
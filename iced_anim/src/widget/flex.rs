@@ -0,0 +1,450 @@
+//! Rows and columns that share their space between children by animated weights.
+//!
+//! Each child of an [`animated_row`] or [`animated_column`] gets a portion of the space along the
+//! row or column by its weight, like [`Length::FillPortion`] in the rows and columns of iced. The
+//! weights are animated though, so shifting the emphasis between children, like expanding one
+//! panel while the others shrink, animates every child together while they keep filling the same
+//! space.
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::widget::animated_row;
+//! # #[derive(Clone)] enum Message {}
+//! fn panels<'a>(expanded: usize) -> Element<'a, Message> {
+//!     let weight = |panel| if panel == expanded { 3.0 } else { 1.0 };
+//!     animated_row([
+//!         (text("Files").into(), weight(0)),
+//!         (text("Editor").into(), weight(1)),
+//!         (text("Preview").into(), weight(2)),
+//!     ])
+//!     .spacing(8)
+//!     .into()
+//! }
+//! ```
+//!
+//! Children added to the row or column grow from a weight of zero, and children with a weight of
+//! zero collapse entirely. Children are clipped to their portion while their weights animate, so
+//! content that doesn't fit a shrinking child doesn't spill over its neighbors.
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    window, Alignment, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+/// The direction a [`Flex`] lays out its children in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    /// Children are laid out from left to right.
+    Horizontal,
+    /// Children are laid out from top to bottom.
+    Vertical,
+}
+
+impl Axis {
+    /// The main and cross lengths of the `size`, in that order.
+    fn split(self, size: Size) -> (f32, f32) {
+        match self {
+            Self::Horizontal => (size.width, size.height),
+            Self::Vertical => (size.height, size.width),
+        }
+    }
+
+    /// The size with the given `main` and `cross` lengths.
+    fn pack(self, main: f32, cross: f32) -> Size {
+        match self {
+            Self::Horizontal => Size::new(main, cross),
+            Self::Vertical => Size::new(cross, main),
+        }
+    }
+}
+
+/// A row or column that shares its space between children by animated weights.
+pub struct Flex<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    axis: Axis,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    weights: Vec<f32>,
+    spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    align: Alignment,
+    motion: SpringMotion,
+}
+
+/// The internal state of a [`Flex`].
+struct State {
+    /// The animated weight of each child.
+    weights: Vec<Spring<f32>>,
+}
+
+impl<'a, Message, Theme, Renderer> Flex<'a, Message, Theme, Renderer> {
+    /// Creates a row or column along the `axis` with the given `children` and their weights.
+    fn with_children(
+        axis: Axis,
+        children: impl IntoIterator<Item = (Element<'a, Message, Theme, Renderer>, f32)>,
+    ) -> Self {
+        let (children, weights) = children.into_iter().unzip();
+        let (width, height) = match axis {
+            Axis::Horizontal => (Length::Fill, Length::Shrink),
+            Axis::Vertical => (Length::Shrink, Length::Fill),
+        };
+
+        Self {
+            axis,
+            children,
+            weights,
+            spacing: 0.0,
+            padding: Padding::ZERO,
+            width,
+            height,
+            align: Alignment::Start,
+            motion: SpringMotion::default(),
+        }
+    }
+
+    /// Creates an empty row.
+    pub fn row() -> Self {
+        Self::with_children(Axis::Horizontal, std::iter::empty())
+    }
+
+    /// Creates an empty column.
+    pub fn column() -> Self {
+        Self::with_children(Axis::Vertical, std::iter::empty())
+    }
+
+    /// Adds a `child` that gets a portion of the space by its `weight`.
+    ///
+    /// Negative weights are treated as zero.
+    pub fn push(
+        mut self,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+        weight: f32,
+    ) -> Self {
+        self.children.push(child.into());
+        self.weights.push(weight.max(0.0));
+        self
+    }
+
+    /// Sets the spacing between children.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the padding around the children.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Flex`], which fills the available space in a row by default.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Flex`], which fills the available space in a column by default.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets how children are aligned across the row or column, e.g. vertically in a row.
+    pub fn align(mut self, align: impl Into<Alignment>) -> Self {
+        self.align = align.into();
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Flex<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            weights: self
+                .weights
+                .iter()
+                .map(|weight| Spring::new(weight.max(0.0)).with_motion(self.motion))
+                .collect(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // New children grow from nothing rather than pushing their neighbors aside at once.
+        state
+            .weights
+            .resize_with(self.weights.len(), || Spring::new(0.0));
+        for (spring, weight) in state.weights.iter_mut().zip(&self.weights) {
+            spring.set_motion(self.motion);
+            let weight = weight.max(0.0);
+            if *spring.target() != weight {
+                spring.interrupt(weight);
+            }
+        }
+
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let weights: Vec<f32> = tree
+            .state
+            .downcast_ref::<State>()
+            .weights
+            .iter()
+            .map(|weight| weight.value().max(0.0))
+            .collect();
+        let total: f32 = weights.iter().sum();
+
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .shrink(self.padding);
+        let (max_main, max_cross) = self.axis.split(limits.max());
+        let spacing = self.spacing * self.children.len().saturating_sub(1) as f32;
+        let available = (max_main - spacing).max(0.0);
+
+        // Each child is given exactly its portion along the axis.
+        let mut main = 0.0;
+        let mut cross: f32 = 0.0;
+        let nodes: Vec<(layout::Node, f32)> = self
+            .children
+            .iter()
+            .zip(&mut tree.children)
+            .zip(weights)
+            .map(|((child, tree), weight)| {
+                let length = if total > 0.0 {
+                    available * weight / total
+                } else {
+                    0.0
+                };
+                let limits = layout::Limits::new(
+                    self.axis.pack(length, 0.0),
+                    self.axis.pack(length, max_cross),
+                );
+                let node = child.as_widget().layout(tree, renderer, &limits);
+                cross = cross.max(self.axis.split(node.size()).1);
+
+                let offset = main;
+                main += length + self.spacing;
+                (node, offset)
+            })
+            .collect();
+
+        let main = (main - self.spacing).max(0.0);
+        let size = limits.resolve(self.width, self.height, self.axis.pack(main, cross));
+        let cross_space = self.axis.split(size).1;
+
+        let children = nodes
+            .into_iter()
+            .map(|(node, main)| {
+                let space = cross_space - self.axis.split(node.size()).1;
+                let cross = match self.align {
+                    Alignment::Start => 0.0,
+                    Alignment::Center => space / 2.0,
+                    Alignment::End => space,
+                };
+                let offset = self.axis.pack(main, cross);
+                node.move_to(Point::new(
+                    self.padding.left + offset.width,
+                    self.padding.top + offset.height,
+                ))
+            })
+            .collect();
+
+        layout::Node::with_children(size.expand(self.padding), children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            if state.weights.iter().any(Spring::has_energy) {
+                for weight in &mut state.weights {
+                    weight.tick(now);
+                }
+
+                // The portions follow the weights, so every frame needs a new layout.
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                shell.invalidate_layout();
+            }
+        }
+
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        for (((child, state), layout), weight) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .zip(&state.weights)
+        {
+            let bounds = layout.bounds();
+            if bounds.width <= 0.0 || bounds.height <= 0.0 {
+                continue;
+            }
+
+            let draw = |renderer: &mut Renderer| {
+                child
+                    .as_widget()
+                    .draw(state, renderer, theme, style, layout, cursor, viewport);
+            };
+
+            // Content can be larger than a child that's still shrinking, so keep it in its portion.
+            if weight.has_energy() {
+                renderer.with_layer(bounds, draw);
+            } else {
+                draw(renderer);
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Flex<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(flex: Flex<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(flex)
+    }
+}
+
+/// Creates a new row with the given `children`, each sharing the width of the row by its weight.
+pub fn animated_row<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = (Element<'a, Message, Theme, Renderer>, f32)>,
+) -> Flex<'a, Message, Theme, Renderer> {
+    Flex::with_children(Axis::Horizontal, children)
+}
+
+/// Creates a new column with the given `children`, each sharing the height of the column by its
+/// weight.
+pub fn animated_column<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = (Element<'a, Message, Theme, Renderer>, f32)>,
+) -> Flex<'a, Message, Theme, Renderer> {
+    Flex::with_children(Axis::Vertical, children)
+}
@@ -0,0 +1,487 @@
+//! A column of keyed items that animates items being inserted, removed, and moved.
+//!
+//! Items are matched between views by a key with a [`KeyedList`], so the column knows which
+//! items are new, which are gone, and which moved. Inserted items grow out of their top edge,
+//! removed items collapse into theirs while the items below slide up to fill the gap, and moved
+//! items slide from their old bounds to their new ones, scaling along if their size changed.
+//! Each item keeps its widget state through moves, so a text input that moves to the top of the
+//! list stays focused. Items can have their own motion with [`KeyedColumn::item_motion`].
+//!
+//! Removed items are still drawn while they collapse, even though they're no longer part of the
+//! app's list, by keeping a clone of each item and building its element again with the view
+//! function. That's why items must be [`Clone`] and `'static`.
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::widget::animated_keyed_column;
+//! # #[derive(Clone)] enum Message {}
+//! #[derive(Debug, Clone)]
+//! struct Task {
+//!     id: u64,
+//!     title: String,
+//! }
+//!
+//! fn tasks<'a>(tasks: &[Task]) -> Element<'a, Message> {
+//!     animated_keyed_column(
+//!         tasks.iter().cloned(),
+//!         |task| task.id,
+//!         |task| text(task.title.clone()).into(),
+//!     )
+//!     .spacing(8)
+//!     .into()
+//! }
+//! ```
+use std::cell::RefCell;
+
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    window, Element, Event, Length, Pixels, Point, Rectangle, Size, Vector,
+};
+
+use crate::{keyed_list::KeyedList, SpringMotion};
+
+/// A column of keyed items that animates insertions, removals, and moves.
+#[allow(missing_debug_implementations)]
+pub struct KeyedColumn<'a, T, K, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    items: Vec<T>,
+    key: Box<dyn Fn(&T) -> K + 'a>,
+    view: Box<dyn Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// The elements of removed items for every entry of the list, which are built during diffs
+    /// since removed items aren't part of the view anymore.
+    exiting: RefCell<Vec<Option<Element<'a, Message, Theme, Renderer>>>>,
+    spacing: f32,
+    width: Length,
+    motion: SpringMotion,
+    item_motion: Option<Box<dyn Fn(&T) -> SpringMotion + 'a>>,
+}
+
+impl<'a, T, K, Message, Theme, Renderer> KeyedColumn<'a, T, K, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    K: PartialEq + 'static,
+{
+    /// Creates a new [`KeyedColumn`] of the `items`, matched between views by their `key` and
+    /// shown with the `view` function.
+    pub fn new(
+        items: impl IntoIterator<Item = T>,
+        key: impl Fn(&T) -> K + 'a,
+        view: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        let items: Vec<T> = items.into_iter().collect();
+        let children = items.iter().map(&view).collect();
+
+        Self {
+            items,
+            key: Box::new(key),
+            view: Box::new(view),
+            children,
+            exiting: RefCell::new(Vec::new()),
+            spacing: 0.0,
+            width: Length::Shrink,
+            motion: SpringMotion::Smooth,
+            item_motion: None,
+        }
+    }
+
+    /// Sets the vertical spacing between items.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the width of the [`KeyedColumn`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the motion of the items as they're inserted, removed, and moved.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the motion of each item by the item, instead of the motion of the column.
+    pub fn item_motion(mut self, item_motion: impl Fn(&T) -> SpringMotion + 'a) -> Self {
+        self.item_motion = Some(Box::new(item_motion));
+        self
+    }
+
+    /// The element of every entry of the list, in order.
+    fn elements<'b>(
+        &'b self,
+        exiting: &'b [Option<Element<'a, Message, Theme, Renderer>>],
+    ) -> Vec<&'b Element<'a, Message, Theme, Renderer>> {
+        let mut live = self.children.iter();
+        exiting
+            .iter()
+            .filter_map(|element| element.as_ref().or_else(|| live.next()))
+            .collect()
+    }
+}
+
+impl<'a, T, K, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for KeyedColumn<'a, T, K, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    K: PartialEq + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<KeyedList<K, T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(KeyedList::new(
+            self.items.iter().cloned(),
+            &self.key,
+            self.motion,
+        ))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let list = state.downcast_mut::<KeyedList<K, T>>();
+        list.set_motion(self.motion);
+        let sources = list.diff(self.items.iter().cloned(), &self.key);
+        if let Some(item_motion) = &self.item_motion {
+            for entry in list.entries_mut() {
+                let motion = item_motion(&entry.item);
+                entry.set_motion(motion);
+            }
+        }
+
+        // Reorder the widget states to match the entries, so every item keeps its own state.
+        let mut previous: Vec<Option<Tree>> =
+            std::mem::take(children).into_iter().map(Some).collect();
+        let mut exiting = self.exiting.borrow_mut();
+        exiting.clear();
+        let mut live = self.children.iter();
+
+        for (entry, source) in list.entries().iter().zip(sources) {
+            let state = source.and_then(|source| previous.get_mut(source)?.take());
+            if entry.is_exiting() {
+                let element = (self.view)(&entry.item);
+                children.push(diffed(state, &element));
+                exiting.push(Some(element));
+            } else if let Some(element) = live.next() {
+                children.push(diffed(state, element));
+                exiting.push(None);
+            }
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let list = state.downcast_mut::<KeyedList<K, T>>();
+        let max_width = limits.width(self.width).max().width;
+        let child_limits = layout::Limits::new(Size::ZERO, Size::new(max_width, f32::INFINITY));
+
+        let exiting = self.exiting.borrow();
+        let elements = self.elements(&exiting);
+
+        let mut y = 0.0;
+        let mut width: f32 = 0.0;
+        let mut nodes = Vec::with_capacity(elements.len());
+        for ((entry, element), state) in list
+            .entries_mut()
+            .iter_mut()
+            .zip(elements)
+            .zip(children.iter_mut())
+        {
+            let node = element.as_widget().layout(state, renderer, &child_limits);
+
+            // Removed items stay where they were while the others close the gap.
+            if !entry.is_exiting() {
+                entry.place(Rectangle::new(Point::new(0.0, y), node.size()));
+                y += node.size().height + self.spacing;
+                width = width.max(node.size().width);
+            }
+            nodes.push(node.move_to(entry.target_bounds().position()));
+        }
+
+        let height = (y - self.spacing).max(0.0);
+        let size = limits.width(self.width).height(Length::Shrink).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(width, height),
+        );
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let list = tree.state.downcast_ref::<KeyedList<K, T>>();
+        let mut live = self.children.iter();
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((entry, state), layout) in list
+                .entries()
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                if entry.is_exiting() {
+                    continue;
+                }
+                if let Some(child) = live.next() {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let list = state.downcast_mut::<KeyedList<K, T>>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            list.tick(now);
+        }
+
+        // Removed items can't be interacted with.
+        let mut live = self.children.iter_mut();
+        let status = list
+            .entries()
+            .iter()
+            .zip(children.iter_mut())
+            .zip(layout.children())
+            .filter(|((entry, _), _)| !entry.is_exiting())
+            .zip(&mut live)
+            .map(|(((_, state), layout), child)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let exited = list.take_exited();
+        if !exited.is_empty() {
+            let exiting = self.exiting.get_mut();
+            for index in exited {
+                children.remove(index);
+                exiting.remove(index);
+            }
+            shell.invalidate_layout();
+        }
+
+        if list.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let list = tree.state.downcast_ref::<KeyedList<K, T>>();
+        let bounds = layout.bounds();
+        let exiting = self.exiting.borrow();
+        let elements = self.elements(&exiting);
+
+        for (((entry, element), state), child_layout) in list
+            .entries()
+            .iter()
+            .zip(elements)
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            let presence = entry.presence().clamp(0.0, 1.0);
+            if presence <= 0.0 {
+                continue;
+            }
+
+            // Items are laid out where they're headed and drawn where they are.
+            let transformation = entry.transformation(bounds.position());
+            let shown = entry.bounds();
+            let cursor = if entry.is_exiting() {
+                Cursor::Unavailable
+            } else {
+                cursor
+            };
+            let draw = |renderer: &mut Renderer| {
+                renderer.with_transformation(transformation, |renderer| {
+                    element.as_widget().draw(
+                        state,
+                        renderer,
+                        theme,
+                        style,
+                        child_layout,
+                        cursor,
+                        viewport,
+                    );
+                });
+            };
+
+            // Items grow out of their top edge as they appear and collapse into it as they leave.
+            if presence < 1.0 {
+                let clip = Rectangle {
+                    x: bounds.x,
+                    y: bounds.y + shown.y,
+                    width: bounds.width,
+                    height: shown.height * presence,
+                };
+                renderer.with_layer(clip, draw);
+            } else {
+                draw(renderer);
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let list = tree.state.downcast_ref::<KeyedList<K, T>>();
+
+        list.entries()
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|((entry, _), _)| !entry.is_exiting())
+            .zip(&self.children)
+            .map(|(((_, state), layout), child)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let list = state.downcast_ref::<KeyedList<K, T>>();
+
+        let overlays: Vec<_> = list
+            .entries()
+            .iter()
+            .zip(children.iter_mut())
+            .zip(layout.children())
+            .filter(|((entry, _), _)| !entry.is_exiting())
+            .zip(self.children.iter_mut())
+            .filter_map(|(((_, state), layout), child)| {
+                child
+                    .as_widget_mut()
+                    .overlay(state, layout, renderer, translation)
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+/// The widget state of an `element`, reusing and diffing the previous `state` if there is one.
+fn diffed<Message, Theme, Renderer>(
+    state: Option<Tree>,
+    element: &Element<'_, Message, Theme, Renderer>,
+) -> Tree
+where
+    Renderer: iced::advanced::Renderer,
+{
+    match state {
+        Some(mut state) => {
+            state.diff(element);
+            state
+        }
+        None => Tree::new(element),
+    }
+}
+
+impl<'a, T, K, Message, Theme, Renderer> From<KeyedColumn<'a, T, K, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    K: PartialEq + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(column: KeyedColumn<'a, T, K, Message, Theme, Renderer>) -> Self {
+        Self::new(column)
+    }
+}
+
+/// Creates a new [`KeyedColumn`] of the `items`, matched between views by their `key` and shown
+/// with the `view` function.
+pub fn animated_keyed_column<'a, T, K, Message, Theme, Renderer>(
+    items: impl IntoIterator<Item = T>,
+    key: impl Fn(&T) -> K + 'a,
+    view: impl Fn(&T) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> KeyedColumn<'a, T, K, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    K: PartialEq + 'static,
+{
+    KeyedColumn::new(items, key, view)
+}
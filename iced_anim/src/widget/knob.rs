@@ -0,0 +1,550 @@
+//! Rotary knobs pick a value from a range by dragging up and down, like in audio software.
+//!
+//! The indicator of an animated knob springs toward the angle of its value, so both dragging and
+//! changes made from outside the knob, like loading a preset, turn it smoothly. The style
+//! animates when the knob is hovered or dragged. Holding shift while dragging adjusts the value
+//! in finer increments for precise tweaks.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::knob;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     GainChanged(f32),
+//! }
+//!
+//! fn gain<'a>(gain: f32) -> Element<'a, Message> {
+//!     knob(-24.0..=24.0, gain, Message::GainChanged).size(48.0).into()
+//! }
+//! ```
+use std::{f32::consts::PI, ops::RangeInclusive};
+
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Length, Point, Radians, Rectangle, Size,
+};
+
+use super::animated_state::{AnimatedState, Id};
+use crate::{Animate, AnimationId, AnimationStore, Spring, SpringMotion};
+
+/// The angle of the indicator at the start of the range, measured clockwise from the top.
+const START_ANGLE: f32 = -0.75 * PI;
+
+/// The total angle swept by the indicator across the range.
+const SWEEP_ANGLE: f32 = 1.5 * PI;
+
+/// A rotary knob whose indicator springs toward its value.
+#[allow(missing_debug_implementations)]
+pub struct Knob<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    value: f32,
+    step: Option<f32>,
+    on_change: Box<dyn Fn(f32) -> Message + 'a>,
+    on_release: Option<Message>,
+    size: f32,
+    sensitivity: f32,
+    fine_sensitivity: f32,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    class: Theme::Class<'a>,
+}
+
+/// A drag in progress on a [`Knob`].
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// The position where the drag was last anchored.
+    origin: Point,
+    /// The value of the knob when the drag was anchored.
+    value: f32,
+}
+
+/// The internal state of a [`Knob`].
+#[derive(Debug)]
+struct State {
+    /// The animated angle of the indicator.
+    angle: Spring<Radians>,
+    /// The drag in progress, if any.
+    drag: Option<Drag>,
+    /// The latest keyboard modifiers, used for fine dragging.
+    modifiers: keyboard::Modifiers,
+    animated_state: AnimatedState<Status, Style>,
+}
+
+impl<'a, Message, Theme> Knob<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default size of a [`Knob`].
+    pub const DEFAULT_SIZE: f32 = 40.0;
+
+    /// Creates a new [`Knob`] for the `value` in the `range`.
+    pub fn new(
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: impl Fn(f32) -> Message + 'a,
+    ) -> Self {
+        Self {
+            value: value.clamp(*range.start(), *range.end()),
+            range,
+            step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            size: Self::DEFAULT_SIZE,
+            sensitivity: 200.0,
+            fine_sensitivity: 2000.0,
+            motion: SpringMotion::default(),
+            animation_id: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the step size of the [`Knob`]. Values are continuous by default.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = (step > 0.0).then_some(step);
+        self
+    }
+
+    /// Sets the message produced when the user stops dragging the [`Knob`].
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the diameter of the [`Knob`].
+    pub fn size(mut self, size: impl Into<iced::Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets how many pixels the cursor needs to move to drag across the whole range,
+    /// both normally and while holding shift for fine adjustments.
+    pub fn sensitivity(mut self, normal: f32, fine: f32) -> Self {
+        self.sensitivity = normal.max(1.0);
+        self.fine_sensitivity = fine.max(1.0);
+        self
+    }
+
+    /// Sets the style of the [`Knob`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Knob`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Knob`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// The angle of the indicator for the current value.
+    fn angle(&self) -> Radians {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let fraction = if end > start {
+            (self.value - start) / (end - start)
+        } else {
+            0.0
+        };
+        Radians(START_ANGLE + fraction * SWEEP_ANGLE)
+    }
+
+    /// The value after dragging from the `drag` origin to the `position`.
+    fn dragged_value(&self, drag: Drag, position: Point, is_fine: bool) -> f32 {
+        let sensitivity = if is_fine {
+            self.fine_sensitivity
+        } else {
+            self.sensitivity
+        };
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let value = drag.value + (drag.origin.y - position.y) / sensitivity * (end - start);
+        match self.step {
+            Some(step) => (start + ((value - start) / step).round() * step).clamp(start, end),
+            None => value.clamp(start, end),
+        }
+    }
+
+    /// The initial status that this widget will have based on its properties.
+    fn get_initial_status(&self) -> Status {
+        Status::Active
+    }
+
+    /// Gets the status of the [`Knob`] based on the current [`State`].
+    fn get_status(&self, state: &State, cursor: Cursor, layout: Layout<'_>) -> Status {
+        if state.drag.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Knob<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            angle: Spring::new(self.angle()).with_motion(self.motion),
+            drag: None,
+            modifiers: keyboard::Modifiers::default(),
+            animated_state: AnimatedState::new(self.get_initial_status(), self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.animated_state.diff(self.motion);
+        state.angle.set_motion(self.motion);
+
+        let angle = self.angle();
+        if *state.angle.target() != angle {
+            state.angle.interrupt(angle);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                state.angle.tick(now);
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                // Re-anchor the drag so switching to fine adjustments doesn't jump the value.
+                if state.modifiers.shift() != modifiers.shift() {
+                    if let (Some(drag), Some(position)) = (state.drag.as_mut(), cursor.position()) {
+                        *drag = Drag {
+                            origin: position,
+                            value: self.value,
+                        };
+                    }
+                }
+                state.modifiers = modifiers;
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match cursor.position_over(layout.bounds()) {
+                    Some(origin) => {
+                        state.drag = Some(Drag {
+                            origin,
+                            value: self.value,
+                        });
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                match (state.drag, cursor.position()) {
+                    (Some(drag), Some(position)) => {
+                        let value = self.dragged_value(drag, position, state.modifiers.shift());
+                        if value != self.value {
+                            shell.publish((self.on_change)(value));
+                        }
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.drag.take().is_some() {
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        };
+
+        // Redraw anytime the status changes or the indicator is still turning.
+        let state = tree.state.downcast_mut::<State>();
+        let status_changed = state
+            .animated_state
+            .needs_redraw(self.get_status(state, cursor, layout));
+        if status_changed || state.angle.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let style = state
+            .animated_state
+            .current_style(|status| theme.style(&self.class, *status));
+
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let center = bounds.center();
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x - radius,
+                    y: center.y - radius,
+                    width: radius * 2.0,
+                    height: radius * 2.0,
+                },
+                border: Border {
+                    radius: radius.into(),
+                    width: style.border_width,
+                    color: style.border_color,
+                },
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        // The indicator is a dot placed around the knob at the animated angle.
+        let angle = state.angle.value().0;
+        let dot_radius = (radius * 0.15).max(1.5);
+        let distance = radius * 0.65;
+        let dot = Point::new(
+            center.x + distance * angle.sin(),
+            center.y - distance * angle.cos(),
+        );
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: dot.x - dot_radius,
+                    y: dot.y - dot_radius,
+                    width: dot_radius * 2.0,
+                    height: dot_radius * 2.0,
+                },
+                border: Border::default().rounded(dot_radius),
+                ..renderer::Quad::default()
+            },
+            style.indicator,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        if state.drag.is_some() || cursor.is_over(layout.bounds()) {
+            mouse::Interaction::ResizingVertically
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Knob<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(knob: Knob<'a, Message, Theme>) -> Self {
+        Self::new(knob)
+    }
+}
+
+/// Creates a new [`Knob`] for the `value` in the `range`.
+pub fn knob<'a, Message, Theme>(
+    range: RangeInclusive<f32>,
+    value: f32,
+    on_change: impl Fn(f32) -> Message + 'a,
+) -> Knob<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Knob::new(range, value, on_change)
+}
+
+/// The possible status of a [`Knob`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Knob`] can be interacted with.
+    Active,
+    /// The [`Knob`] is being hovered.
+    Hovered,
+    /// The [`Knob`] is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`Knob`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the body of the knob.
+    pub background: Color,
+    /// The width of the border around the body.
+    pub border_width: f32,
+    /// The color of the border around the body.
+    pub border_color: Color,
+    /// The color of the indicator showing the value.
+    pub indicator: Color,
+}
+
+impl Animate for Style {
+    fn components() -> usize {
+        Color::components() * 3 + f32::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.border_width.distance_to(&end.border_width),
+            self.border_color.distance_to(&end.border_color),
+            self.indicator.distance_to(&end.indicator),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.border_width.update(components);
+        self.border_color.update(components);
+        self.indicator.update(components);
+    }
+}
+
+/// The theme catalog of a [`Knob`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Knob`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(primary)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The primary style of a [`Knob`], whose indicator uses the primary color.
+pub fn primary(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let base = Style {
+        background: palette.background.weak.color,
+        border_width: 1.0,
+        border_color: palette.background.strong.color,
+        indicator: palette.primary.base.color,
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered => Style {
+            border_color: palette.primary.weak.color,
+            ..base
+        },
+        Status::Dragged => Style {
+            border_width: 2.0,
+            border_color: palette.primary.strong.color,
+            indicator: palette.primary.strong.color,
+            ..base
+        },
+    }
+}
+
+/// The secondary style of a [`Knob`], whose indicator uses the text color.
+pub fn secondary(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    Style {
+        indicator: palette.background.base.text,
+        ..primary(theme, status)
+    }
+}
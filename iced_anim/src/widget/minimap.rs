@@ -0,0 +1,399 @@
+//! A small overview of the content of a [`ZoomPan`](super::ZoomPan) showing what's in view.
+//!
+//! A minimap draws its own copy of the content scaled down to fit, with an indicator over the
+//! part that's visible in the zoom and pan container. Both widgets read the same animated view
+//! from a shared [`Viewport`], so the indicator glides along with every zoom and fling of the
+//! main view rather than jumping to where it ends up. Clicking or dragging on the minimap moves
+//! the main view there, animating both at once.
+//!
+//! ```rust
+//! # use iced::{Element, widget::{column, text}};
+//! # use iced_anim::widget::{minimap, zoom_pan::{zoom_pan, Viewport}};
+//! # #[derive(Clone)] enum Message {}
+//! fn view<'a>(viewport: &Viewport) -> Element<'a, Message> {
+//!     column![
+//!         zoom_pan(text("A large diagram")).viewport(viewport),
+//!         minimap(viewport, text("A large diagram")),
+//!     ]
+//!     .into()
+//! }
+//! ```
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Length, Point, Rectangle, Size, Transformation,
+    Vector,
+};
+
+use super::zoom_pan::Viewport;
+
+/// A small overview of content with an indicator over the part visible in a shared [`Viewport`].
+#[allow(missing_debug_implementations)]
+pub struct Minimap<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    viewport: Viewport,
+    content: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`Minimap`].
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the main view is being moved by dragging on the minimap.
+    is_dragging: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Minimap<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Minimap`] of the `content`, showing what's visible in the `viewport`.
+    ///
+    /// The `content` should be a copy of the content of the [`ZoomPan`](super::ZoomPan) given
+    /// the same viewport, since elements can't be drawn by two widgets.
+    pub fn new(
+        viewport: &Viewport,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            viewport: viewport.clone(),
+            content: content.into(),
+            width: Length::Fixed(160.0),
+            height: Length::Fixed(120.0),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Minimap`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Minimap`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Minimap`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Minimap`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The scale of the content in the minimap and its offset from the top-left corner of the
+    /// minimap, which centers the content.
+    fn fit(&self, bounds: Rectangle, content: Size) -> (f32, Vector) {
+        if content.width <= 0.0 || content.height <= 0.0 {
+            return (1.0, Vector::new(0.0, 0.0));
+        }
+
+        let scale = (bounds.width / content.width).min(bounds.height / content.height);
+        let offset = Vector::new(
+            (bounds.width - content.width * scale) / 2.0,
+            (bounds.height - content.height * scale) / 2.0,
+        );
+        (scale, offset)
+    }
+
+    /// Centers the main view on the point of the content under the `position` in the minimap.
+    fn move_view(&self, layout: Layout<'_>, position: Point) {
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap().bounds().size();
+        let (scale, offset) = self.fit(bounds, content);
+        let local = position - Vector::new(bounds.x, bounds.y) - offset;
+        self.viewport
+            .center_on(Point::new(local.x / scale, local.y / scale));
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Minimap<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::ZERO,
+        );
+
+        // Lay the content out at the size it has in the zoom and pan container so the
+        // indicator lines up with it.
+        let content_size = self.viewport.content_size();
+        let content_limits = if content_size == Size::ZERO {
+            layout::Limits::new(Size::ZERO, size)
+        } else {
+            layout::Limits::new(Size::ZERO, content_size)
+        };
+        let content =
+            self.content
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, &content_limits);
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                self.viewport.tick(now);
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match cursor.position_over(layout.bounds()) {
+                    Some(position) => {
+                        state.is_dragging = true;
+                        self.move_view(layout, position);
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.is_dragging =>
+            {
+                self.move_view(layout, position);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if std::mem::take(&mut state.is_dragging) {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        };
+
+        if self.viewport.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let appearance = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let content_bounds = content_layout.bounds();
+        let (scale, offset) = self.fit(bounds, content_bounds.size());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                ..renderer::Quad::default()
+            },
+            appearance.background,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            let transformation =
+                Transformation::translate(bounds.x + offset.x, bounds.y + offset.y)
+                    * Transformation::scale(scale)
+                    * Transformation::translate(-content_bounds.x, -content_bounds.y);
+
+            // The copy of the content isn't interactive, so it never sees the cursor.
+            renderer.with_transformation(transformation, |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    content_layout,
+                    Cursor::Unavailable,
+                    &content_bounds,
+                );
+            });
+
+            let area = self.viewport.visible_area();
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + offset.x + area.x * scale,
+                        y: bounds.y + offset.y + area.y * scale,
+                        width: area.width * scale,
+                        height: area.height * scale,
+                    },
+                    border: Border {
+                        color: appearance.indicator_border,
+                        width: 1.0,
+                        radius: 2.0.into(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                appearance.indicator,
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().is_dragging {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Minimap<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(minimap: Minimap<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(minimap)
+    }
+}
+
+/// Creates a new [`Minimap`] of the `content`, showing what's visible in the `viewport`.
+pub fn minimap<'a, Message, Theme, Renderer>(
+    viewport: &Viewport,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Minimap<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    Minimap::new(viewport, content)
+}
+
+/// The appearance of a [`Minimap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color behind the content.
+    pub background: Color,
+    /// The border around the minimap.
+    pub border: Border,
+    /// The color covering the visible part of the content.
+    pub indicator: Color,
+    /// The color of the border around the visible part of the content.
+    pub indicator_border: Color,
+}
+
+/// The theme catalog of a [`Minimap`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Minimap`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Minimap`], with an indicator in the primary color of the theme.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color,
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        indicator: palette.primary.base.color.scale_alpha(0.15),
+        indicator_border: palette.primary.base.color,
+    }
+}
@@ -0,0 +1,100 @@
+//! Animated numbers that count toward their value.
+//!
+//! A [`Number`] animates changes to its value and shows the interpolated value as text on every
+//! frame. The text is produced by a formatter, like the ones in [`crate::format`], so that
+//! intermediate values stay readable while counting, e.g. `1.2 MB` instead of `1234567.89`.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::{format, widget::number};
+//! # #[derive(Clone)] enum Message {}
+//! fn downloaded<'a>(bytes: f32) -> Element<'a, Message> {
+//!     number(bytes).format(format::si(1, "B")).size(24).into()
+//! }
+//! ```
+use iced::{advanced::text as core_text, Element, Pixels};
+
+use super::text::{self, text};
+use crate::{format, AnimationBuilder, SpringMotion};
+
+/// A formatter that turns an animated value into text.
+pub type Formatter<'a> = Box<dyn Fn(f32) -> String + 'a>;
+
+/// A number whose text counts toward its value, formatted on every frame.
+pub struct Number<'a> {
+    value: f32,
+    format: Formatter<'a>,
+    size: Option<Pixels>,
+    motion: SpringMotion,
+    animates_layout: bool,
+}
+
+impl<'a> Number<'a> {
+    /// Creates a new [`Number`] with the given `value`, shown with two decimal places by default.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            format: Box::new(format::decimal(2)),
+            size: None,
+            motion: SpringMotion::default(),
+            animates_layout: false,
+        }
+    }
+
+    /// Sets the formatter applied to the animated value on every frame.
+    pub fn format(mut self, format: impl Fn(f32) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets the size of the text.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Indicates whether the layout should update as the width of the text changes.
+    pub fn animates_layout(mut self, animates_layout: bool) -> Self {
+        self.animates_layout = animates_layout;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Number<'a>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: text::Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(number: Number<'a>) -> Self {
+        let Number {
+            value,
+            format,
+            size,
+            motion,
+            animates_layout,
+        } = number;
+
+        AnimationBuilder::new(value, move |value| {
+            let text = text(format(value));
+            match size {
+                Some(size) => text.size(size).into(),
+                None => text.into(),
+            }
+        })
+        .motion(motion)
+        .animates_layout(animates_layout)
+        .into()
+    }
+}
+
+/// Creates a new [`Number`] with the given `value`.
+pub fn number<'a>(value: f32) -> Number<'a> {
+    Number::new(value)
+}
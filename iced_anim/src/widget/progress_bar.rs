@@ -0,0 +1,326 @@
+//! Progress bars visualize the progression of an extended computer operation.
+//!
+//! Changes to the value of an animated progress bar smoothly fill or drain the bar. When the
+//! progress isn't known yet, the bar can be made indeterminate to show a segment sweeping across
+//! it in a loop. The sweeping segment smoothly grows into the real progress once the bar becomes
+//! determinate again, so a loading bar can start before the first fraction arrives.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::progress_bar;
+//! # #[derive(Clone)] enum Message {}
+//! fn loading<'a>(progress: Option<f32>) -> Element<'a, Message> {
+//!     progress_bar(0.0..=1.0, progress.unwrap_or_default())
+//!         .indeterminate(progress.is_none())
+//!         .into()
+//! }
+//! ```
+use std::{
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::Cursor,
+    window, Border, Color, Element, Event, Length, Rectangle, Size,
+};
+
+use crate::{Spring, SpringMotion};
+
+// Re-export the widget types for convenience
+pub use iced::widget::progress_bar::{
+    danger, primary, secondary, success, Catalog, Style, StyleFn,
+};
+
+/// The fraction of the bar covered by the sweeping segment of an indeterminate bar.
+const SWEEP_LENGTH: f32 = 0.3;
+
+/// A bar that displays progress, which animates changes to its value.
+#[allow(missing_debug_implementations)]
+pub struct ProgressBar<'a, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    value: f32,
+    is_indeterminate: bool,
+    width: Length,
+    height: Length,
+    period: Duration,
+    motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`ProgressBar`].
+#[derive(Debug)]
+struct State {
+    /// The animated fraction of the bar that is filled.
+    fraction: Spring<f32>,
+    /// How determinate the bar is, from 0 while sweeping to 1 while showing the fraction.
+    determinacy: Spring<f32>,
+    /// When the current sweep loop started.
+    sweep_start: Instant,
+    /// The time of the latest redraw, which positions the sweeping segment.
+    now: Instant,
+}
+
+impl<'a, Theme> ProgressBar<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default height of a [`ProgressBar`].
+    pub const DEFAULT_HEIGHT: f32 = 30.0;
+
+    /// Creates a new [`ProgressBar`] with the given `range` and current `value`.
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self {
+            value: value.clamp(*range.start(), *range.end()),
+            range,
+            is_indeterminate: false,
+            width: Length::Fill,
+            height: Length::from(Self::DEFAULT_HEIGHT),
+            period: Duration::from_millis(1500),
+            motion: SpringMotion::default(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets whether the [`ProgressBar`] is indeterminate, sweeping a segment across the bar
+    /// instead of showing its value.
+    pub fn indeterminate(mut self, is_indeterminate: bool) -> Self {
+        self.is_indeterminate = is_indeterminate;
+        self
+    }
+
+    /// Sets how long it takes the segment of an indeterminate [`ProgressBar`] to sweep across.
+    pub fn period(mut self, period: Duration) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Sets the width of the [`ProgressBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`ProgressBar`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`ProgressBar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ProgressBar`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// The fraction of the range covered by the value.
+    fn fraction(&self) -> f32 {
+        let (start, end) = self.range.clone().into_inner();
+        if end > start {
+            (self.value - start) / (end - start)
+        } else {
+            1.0
+        }
+    }
+
+    /// The target determinacy of the bar.
+    fn determinacy(&self) -> f32 {
+        if self.is_indeterminate {
+            0.0
+        } else {
+            1.0
+        }
+    }
+}
+
+/// The start and end of the filled segment of the bar as fractions of its width.
+///
+/// The segment blends between the sweeping segment at the given `phase` and the determinate
+/// segment from the start of the bar to the `fraction` based on the `determinacy`.
+fn segment(phase: f32, fraction: f32, determinacy: f32) -> (f32, f32) {
+    let sweep_start = (phase * (1.0 + SWEEP_LENGTH) - SWEEP_LENGTH).clamp(0.0, 1.0);
+    let sweep_end = (phase * (1.0 + SWEEP_LENGTH)).clamp(0.0, 1.0);
+
+    let start = sweep_start * (1.0 - determinacy);
+    let end = sweep_end + (fraction - sweep_end) * determinacy;
+    (start, end.max(start))
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ProgressBar<'a, Theme>
+where
+    Renderer: iced::advanced::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let now = Instant::now();
+        tree::State::new(State {
+            fraction: Spring::new(self.fraction()).with_motion(self.motion),
+            determinacy: Spring::new(self.determinacy()).with_motion(self.motion),
+            sweep_start: now,
+            now,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.fraction.set_motion(self.motion);
+        state.determinacy.set_motion(self.motion);
+
+        let fraction = self.fraction();
+        if *state.fraction.target() != fraction {
+            state.fraction.interrupt(fraction);
+        }
+
+        let determinacy = self.determinacy();
+        if *state.determinacy.target() != determinacy {
+            // Restart the sweep from the beginning when a determinate bar becomes indeterminate.
+            if !state.determinacy.has_energy() {
+                state.sweep_start = Instant::now();
+            }
+            state.determinacy.interrupt(determinacy);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = now;
+            state.fraction.tick(now);
+            state.determinacy.tick(now);
+        }
+
+        let is_sweeping = *state.determinacy.value() < 1.0;
+        if is_sweeping || state.fraction.has_energy() || state.determinacy.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let elapsed = state.now.saturating_duration_since(state.sweep_start);
+        let phase = match self.period.as_secs_f32() {
+            period if period > 0.0 => elapsed.as_secs_f32() % period / period,
+            _ => 0.0,
+        };
+        let (start, end) = segment(
+            phase,
+            *state.fraction.value(),
+            state.determinacy.value().clamp(0.0, 1.0),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        if end > start {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + start * bounds.width,
+                        width: (end - start) * bounds.width,
+                        ..bounds
+                    },
+                    border: Border {
+                        color: Color::TRANSPARENT,
+                        ..style.border
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.bar,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ProgressBar<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(progress_bar: ProgressBar<'a, Theme>) -> Self {
+        Self::new(progress_bar)
+    }
+}
+
+/// Creates a new [`ProgressBar`] with the given `range` and current `value`.
+pub fn progress_bar<'a, Theme>(range: RangeInclusive<f32>, value: f32) -> ProgressBar<'a, Theme>
+where
+    Theme: Catalog,
+{
+    ProgressBar::new(range, value)
+}
@@ -0,0 +1,576 @@
+//! A row of stars for showing and picking a rating.
+//!
+//! When the rating changes, the stars fill one after another with a short stagger, and each
+//! newly filled star pops slightly before settling. Hovering an interactive rating previews the
+//! rating under the cursor with the same animation, and the style animates while hovered.
+//!
+//! > Note: this module is only available when the `canvas` feature is enabled.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::rating;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Rate(u8),
+//! }
+//!
+//! fn review<'a>(stars: u8) -> Element<'a, Message> {
+//!     rating(stars).on_rate(Message::Rate).into()
+//! }
+//! ```
+use std::{
+    f32::consts::{FRAC_PI_2, PI},
+    time::{Duration, Instant},
+};
+
+use iced::{
+    advanced::{
+        graphics::geometry,
+        layout, renderer,
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::canvas::{self, Path},
+    window, Color, Element, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+use super::{AnimatedState, Id};
+use crate::{Animate, AnimationId, AnimationStore, Spring, SpringMotion};
+
+/// The radius of the inner corners of a star relative to its outer points.
+const INNER_RADIUS: f32 = 0.45;
+
+/// How much a star grows when it pops after being filled, relative to its size.
+const POP: f32 = 0.35;
+
+/// A row of stars that fill with a staggered pop when the rating changes.
+#[allow(missing_debug_implementations)]
+pub struct Rating<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    value: u8,
+    max: u8,
+    on_rate: Option<Box<dyn Fn(u8) -> Message + 'a>>,
+    size: f32,
+    spacing: f32,
+    stagger: Duration,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    class: Theme::Class<'a>,
+}
+
+/// A star of a [`Rating`].
+#[derive(Debug)]
+struct Star {
+    /// How filled the star is, from 0 to 1.
+    fill: Spring<f32>,
+    /// How much the star is popping out beyond its size.
+    pop: Spring<f32>,
+    /// A change to the fill that waits for its turn in the stagger, with when it starts,
+    /// whether the star becomes filled, and whether it pops.
+    pending: Option<(Instant, bool, bool)>,
+}
+
+impl Star {
+    /// Whether the star is filled or about to be.
+    fn is_filled(&self) -> bool {
+        match self.pending {
+            Some((_, is_filled, _)) => is_filled,
+            None => *self.fill.target() >= 0.5,
+        }
+    }
+
+    /// Whether the star is still animating or waiting to.
+    fn has_energy(&self) -> bool {
+        self.pending.is_some() || self.fill.has_energy() || self.pop.has_energy()
+    }
+}
+
+/// The internal state of a [`Rating`].
+#[derive(Debug)]
+struct State {
+    stars: Vec<Star>,
+    /// The latest rating given to the widget.
+    value: u8,
+    /// The rating previewed by hovering, if any.
+    hovered: Option<u8>,
+    /// Whether a star is being pressed.
+    is_pressed: bool,
+    animated_state: AnimatedState<Status, Style>,
+}
+
+impl State {
+    /// Fills the first `count` stars one after another, starting with the star nearest to the
+    /// stars that already match, so filling goes forward and emptying goes backward.
+    fn show(&mut self, count: u8, pops: bool, stagger: Duration) {
+        let now = Instant::now();
+        let count = usize::from(count);
+        let mut changed: Vec<usize> = self
+            .stars
+            .iter()
+            .enumerate()
+            .filter(|(index, star)| star.is_filled() != (*index < count))
+            .map(|(index, _)| index)
+            .collect();
+        if changed.first().is_some_and(|first| *first >= count) {
+            changed.reverse();
+        }
+
+        for (order, index) in changed.into_iter().enumerate() {
+            let is_filled = index < count;
+            let start = now + stagger * order as u32;
+            self.stars[index].pending = Some((start, is_filled, pops && is_filled));
+        }
+    }
+
+    /// The number of stars shown, which is the hovered preview if there is one.
+    fn shown(&self) -> u8 {
+        self.hovered.unwrap_or(self.value)
+    }
+}
+
+impl<'a, Message, Theme> Rating<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Rating`] showing the given number of filled stars out of 5.
+    pub fn new(value: u8) -> Self {
+        Self {
+            value,
+            max: 5,
+            on_rate: None,
+            size: 24.0,
+            spacing: 4.0,
+            stagger: Duration::from_millis(40),
+            motion: SpringMotion::Bouncy,
+            animation_id: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced with the rating when a star is clicked, which also makes the
+    /// [`Rating`] preview ratings on hover.
+    pub fn on_rate(mut self, on_rate: impl Fn(u8) -> Message + 'a) -> Self {
+        self.on_rate = Some(Box::new(on_rate));
+        self
+    }
+
+    /// Sets the number of stars.
+    pub fn max(mut self, max: u8) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the size of each star.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the spacing between stars.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the delay between each star filling when the rating changes.
+    pub fn stagger(mut self, stagger: Duration) -> Self {
+        self.stagger = stagger;
+        self
+    }
+
+    /// Sets the motion of the stars and of style animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Rating`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// Sets the style of the [`Rating`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Rating`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The rating previewed by a cursor over the given `bounds`, if any.
+    fn rating_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<u8> {
+        self.on_rate.as_ref()?;
+        let position = cursor.position_over(bounds)?;
+        let star = ((position.x - bounds.x) / (self.size + self.spacing)) as u8;
+        Some(star.min(self.max.saturating_sub(1)) + 1)
+    }
+
+    /// Gets the status of the [`Rating`] based on the current state.
+    fn get_status(&self, state: &State) -> Status {
+        if state.is_pressed {
+            Status::Pressed
+        } else if state.hovered.is_some() {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Rating<'a, Message, Theme>
+where
+    Renderer: geometry::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let stars = (0..self.max)
+            .map(|index| Star {
+                fill: Spring::new(if index < self.value { 1.0 } else { 0.0 })
+                    .with_motion(self.motion),
+                pop: Spring::new(0.0).with_motion(self.motion),
+                pending: None,
+            })
+            .collect();
+
+        tree::State::new(State {
+            stars,
+            value: self.value,
+            hovered: None,
+            is_pressed: false,
+            animated_state: AnimatedState::new(Status::Active, self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.animated_state.diff(self.motion);
+        for star in &mut state.stars {
+            star.fill.set_motion(self.motion);
+            star.pop.set_motion(self.motion);
+        }
+
+        if state.stars.len() != usize::from(self.max) {
+            state.stars.resize_with(usize::from(self.max), || Star {
+                fill: Spring::new(0.0).with_motion(self.motion),
+                pop: Spring::new(0.0).with_motion(self.motion),
+                pending: None,
+            });
+        }
+
+        if state.value != self.value {
+            state.value = self.value;
+            if state.hovered.is_none() {
+                state.show(self.value, true, self.stagger);
+            }
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let count = f32::from(self.max);
+        let width = self.size * count + self.spacing * (count - 1.0).max(0.0);
+        layout::atomic(limits, width, self.size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                for star in &mut state.stars {
+                    if let Some((start, is_filled, pops)) = star.pending {
+                        if start <= now {
+                            star.pending = None;
+                            star.fill.interrupt(if is_filled { 1.0 } else { 0.0 });
+                            if pops {
+                                star.pop.settle_at(POP);
+                                star.pop.interrupt(0.0);
+                            }
+                        }
+                    }
+                    star.fill.tick(now);
+                    star.pop.tick(now);
+                }
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_pressed = self.rating_at(bounds, cursor).is_some();
+                if state.is_pressed {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let was_pressed = std::mem::take(&mut state.is_pressed);
+                match (self.rating_at(bounds, cursor), &self.on_rate) {
+                    (Some(rating), Some(on_rate)) if was_pressed => {
+                        shell.publish(on_rate(rating));
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        };
+
+        // Preview the rating under the cursor, or go back to the value once it leaves.
+        let hovered = self.rating_at(bounds, cursor);
+        if state.hovered != hovered {
+            state.hovered = hovered;
+            state.show(state.shown(), false, self.stagger);
+        }
+
+        let needs_redraw = state.animated_state.needs_redraw(self.get_status(state));
+        if needs_redraw || state.stars.iter().any(Star::has_energy) {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = *state
+            .animated_state
+            .current_style(|status| theme.style(&self.class, *status));
+
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let radius = self.size / 2.0;
+        for (index, star) in state.stars.iter().enumerate() {
+            let center = Point::new(radius + (self.size + self.spacing) * index as f32, radius);
+            let scale = 1.0 + *star.pop.value();
+            frame.fill(&star_path(center, radius * scale), style.empty);
+
+            // Filled stars grow from the center of the empty star.
+            let fill = star.fill.value().max(0.0);
+            if fill > 0.0 {
+                frame.fill(&star_path(center, radius * scale * fill), style.filled);
+            }
+        }
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_geometry(frame.into_geometry());
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.rating_at(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// A five-pointed star centered at `center` whose points reach the given `radius`.
+fn star_path(center: Point, radius: f32) -> Path {
+    Path::new(|builder| {
+        for corner in 0..10 {
+            let angle = PI / 5.0 * corner as f32 - FRAC_PI_2;
+            let distance = if corner % 2 == 0 {
+                radius
+            } else {
+                radius * INNER_RADIUS
+            };
+            let point = Point::new(
+                center.x + distance * angle.cos(),
+                center.y + distance * angle.sin(),
+            );
+            if corner == 0 {
+                builder.move_to(point);
+            } else {
+                builder.line_to(point);
+            }
+        }
+        builder.close();
+    })
+}
+
+impl<'a, Message, Theme, Renderer> From<Rating<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'a,
+{
+    fn from(rating: Rating<'a, Message, Theme>) -> Self {
+        Self::new(rating)
+    }
+}
+
+/// Creates a new [`Rating`] showing the given number of filled stars out of 5.
+pub fn rating<'a, Message, Theme>(value: u8) -> Rating<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Rating::new(value)
+}
+
+/// The possible status of a [`Rating`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Rating`] is showing its value.
+    Active,
+    /// The [`Rating`] is previewing the rating under the cursor.
+    Hovered,
+    /// A star of the [`Rating`] is being pressed.
+    Pressed,
+}
+
+/// The appearance of a [`Rating`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of filled stars.
+    pub filled: Color,
+    /// The color of empty stars.
+    pub empty: Color,
+}
+
+impl Animate for Style {
+    fn components() -> usize {
+        Color::components() * 2
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.filled.distance_to(&end.filled),
+            self.empty.distance_to(&end.empty),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.filled.update(components);
+        self.empty.update(components);
+    }
+}
+
+/// The theme catalog of a [`Rating`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Rating`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Rating`], with golden stars that lighten while previewing.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let gold = Color::from_rgb8(0xF5, 0xB0, 0x1A);
+    let empty = palette.background.strong.color;
+
+    match status {
+        Status::Active => Style {
+            filled: gold,
+            empty,
+        },
+        Status::Hovered => Style {
+            filled: gold.scale_alpha(0.7),
+            empty,
+        },
+        Status::Pressed => Style {
+            filled: gold.scale_alpha(0.85),
+            empty,
+        },
+    }
+}
+
+/// A style with stars in the primary color of the theme.
+pub fn primary(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let filled = match status {
+        Status::Active => palette.primary.base.color,
+        Status::Hovered | Status::Pressed => palette.primary.weak.color,
+    };
+
+    Style {
+        filled,
+        empty: palette.background.strong.color,
+    }
+}
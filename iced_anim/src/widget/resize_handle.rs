@@ -0,0 +1,544 @@
+//! Resize handles let users drag the edge of a panel to resize it with a physical feel.
+//!
+//! Dragging an animated resize handle changes the size directly, with some resistance past the
+//! minimum and maximum sizes. Letting go flings the size with the velocity of the drag before it
+//! springs to rest within the allowed range. The animated size is reported through messages on
+//! every frame, so the app can store it and size its panel with it.
+//!
+//! ```rust
+//! # use iced::{widget::{container, row, text}, Element, Length};
+//! # use iced_anim::widget::resize_handle::{resize_handle, Direction};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ResizeSidebar(f32),
+//! }
+//!
+//! fn view<'a>(sidebar_width: f32) -> Element<'a, Message> {
+//!     row![
+//!         container(text("Sidebar")).width(sidebar_width),
+//!         resize_handle(sidebar_width, 120.0..=400.0, Message::ResizeSidebar)
+//!             .direction(Direction::Right),
+//!         container(text("Content")).width(Length::Fill),
+//!     ]
+//!     .into()
+//! }
+//! ```
+use std::{
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch, window, Color, Element, Event, Length, Point, Rectangle, Size,
+};
+
+use super::animated_state::{AnimatedState, Id};
+use crate::{Animate, AnimationId, AnimationStore, Spring, SpringMotion};
+
+/// How much of the drag past the minimum or maximum size is applied to the size.
+const RESISTANCE: f32 = 0.3;
+
+/// How far ahead the velocity of a fling is projected to pick where the size comes to rest.
+const FLING_PROJECTION: Duration = Duration::from_millis(150);
+
+/// How quickly the measured drag velocity follows the latest movement, from 0 to 1.
+const VELOCITY_SMOOTHING: f32 = 0.4;
+
+/// The direction in which dragging a [`ResizeHandle`] grows the size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Dragging right grows the size, e.g. for a panel on the left.
+    Right,
+    /// Dragging left grows the size, e.g. for a panel on the right.
+    Left,
+    /// Dragging down grows the size, e.g. for a panel at the top.
+    Down,
+    /// Dragging up grows the size, e.g. for a panel at the bottom.
+    Up,
+}
+
+impl Direction {
+    /// Whether the handle is dragged horizontally.
+    fn is_horizontal(self) -> bool {
+        matches!(self, Self::Right | Self::Left)
+    }
+
+    /// How far the `position` grows the size from the `origin`.
+    fn delta(self, origin: Point, position: Point) -> f32 {
+        match self {
+            Self::Right => position.x - origin.x,
+            Self::Left => origin.x - position.x,
+            Self::Down => position.y - origin.y,
+            Self::Up => origin.y - position.y,
+        }
+    }
+}
+
+/// A handle that resizes a panel by dragging, with inertia and springy limits.
+#[allow(missing_debug_implementations)]
+pub struct ResizeHandle<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    size: f32,
+    range: RangeInclusive<f32>,
+    on_resize: Box<dyn Fn(f32) -> Message + 'a>,
+    on_release: Option<Message>,
+    direction: Direction,
+    thickness: f32,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    class: Theme::Class<'a>,
+}
+
+/// A drag in progress on a [`ResizeHandle`].
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// The position where the drag started.
+    origin: Point,
+    /// The size when the drag started.
+    size: f32,
+    /// The latest position of the drag.
+    position: Point,
+    /// When the drag last moved.
+    moved_at: Instant,
+    /// The smoothed velocity of the drag in pixels per second along the direction.
+    velocity: f32,
+}
+
+/// The internal state of a [`ResizeHandle`].
+#[derive(Debug)]
+struct State {
+    /// The animated size, which follows the drag directly and springs after a fling.
+    size: Spring<f32>,
+    /// The drag in progress, if any.
+    drag: Option<Drag>,
+    animated_state: AnimatedState<Status, Style>,
+}
+
+impl<'a, Message, Theme> ResizeHandle<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default thickness of a [`ResizeHandle`].
+    pub const DEFAULT_THICKNESS: f32 = 6.0;
+
+    /// Creates a new [`ResizeHandle`] for the current `size`, limited to the `range`.
+    pub fn new(
+        size: f32,
+        range: RangeInclusive<f32>,
+        on_resize: impl Fn(f32) -> Message + 'a,
+    ) -> Self {
+        Self {
+            size,
+            range,
+            on_resize: Box::new(on_resize),
+            on_release: None,
+            direction: Direction::Right,
+            thickness: Self::DEFAULT_THICKNESS,
+            motion: SpringMotion::Snappy,
+            animation_id: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when the user stops dragging the [`ResizeHandle`].
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the [`Direction`] in which dragging grows the size.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the thickness of the [`ResizeHandle`] along the direction it is dragged.
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the style of the [`ResizeHandle`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ResizeHandle`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the motion used when the size springs back into range and for style animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`ResizeHandle`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// Clamps the `size` to the allowed range.
+    fn clamp(&self, size: f32) -> f32 {
+        size.clamp(*self.range.start(), *self.range.end())
+    }
+
+    /// Applies resistance to the part of the `size` that is past the allowed range.
+    fn resist(&self, size: f32) -> f32 {
+        let clamped = self.clamp(size);
+        clamped + (size - clamped) * RESISTANCE
+    }
+
+    /// Gets the status of the [`ResizeHandle`] based on the current [`State`].
+    fn get_status(&self, state: &State, cursor: Cursor, layout: Layout<'_>) -> Status {
+        if state.drag.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ResizeHandle<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            size: Spring::new(self.size).with_motion(self.motion),
+            drag: None,
+            animated_state: AnimatedState::new(Status::Active, self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.animated_state.diff(self.motion);
+        state.size.set_motion(self.motion);
+
+        // The app normally stores the reported size, so only a different size means it was
+        // changed from outside the handle, like collapsing the panel with a button.
+        if state.drag.is_none() && *state.size.value() != self.size {
+            let size = self.clamp(self.size);
+            if *state.size.target() != size {
+                state.size.interrupt(size);
+            }
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        if self.direction.is_horizontal() {
+            Size::new(Length::Fixed(self.thickness), Length::Fill)
+        } else {
+            Size::new(Length::Fill, Length::Fixed(self.thickness))
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = Widget::<Message, Theme, Renderer>::size(self);
+        layout::atomic(limits, size.width, size.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                if state.drag.is_none() && state.size.has_energy() {
+                    state.size.tick(now);
+                    shell.publish((self.on_resize)(*state.size.value()));
+                }
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    return event::Status::Ignored;
+                };
+
+                // Catch the size where it is, even in the middle of a fling.
+                let size = *state.size.value();
+                state.size.settle_at(size);
+                state.drag = Some(Drag {
+                    origin: position,
+                    size,
+                    position,
+                    moved_at: Instant::now(),
+                    velocity: 0.0,
+                });
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(drag) = state.drag.as_mut() else {
+                    return event::Status::Ignored;
+                };
+
+                let now = Instant::now();
+                let elapsed = now.duration_since(drag.moved_at).as_secs_f32();
+                if elapsed > 0.0 {
+                    let velocity = self.direction.delta(drag.position, position) / elapsed;
+                    drag.velocity += (velocity - drag.velocity) * VELOCITY_SMOOTHING;
+                }
+                drag.position = position;
+                drag.moved_at = now;
+
+                let size = self.resist(drag.size + self.direction.delta(drag.origin, position));
+                state.size.settle_at(size);
+                shell.publish((self.on_resize)(size));
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take() else {
+                    return event::Status::Ignored;
+                };
+
+                // A drag that stopped before letting go shouldn't fling.
+                let velocity = if drag.moved_at.elapsed() < Duration::from_millis(100) {
+                    drag.velocity
+                } else {
+                    0.0
+                };
+
+                let size = *state.size.value();
+                let target = self.clamp(size + velocity * FLING_PROJECTION.as_secs_f32());
+                state.size.interrupt(target);
+                state.size.impulse(vec![velocity]);
+
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let current_status = self.get_status(state, cursor, layout);
+        let needs_redraw = state.animated_state.needs_redraw(current_status);
+        if needs_redraw || (state.drag.is_none() && state.size.has_energy()) {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = state
+            .animated_state
+            .current_style(|status| theme.style(&self.class, *status));
+        let bounds = layout.bounds();
+
+        // Draw a line through the middle of the handle, thickening as it is hovered or dragged.
+        let line = if self.direction.is_horizontal() {
+            Rectangle {
+                x: bounds.center_x() - style.width / 2.0,
+                width: style.width,
+                ..bounds
+            }
+        } else {
+            Rectangle {
+                y: bounds.center_y() - style.width / 2.0,
+                height: style.width,
+                ..bounds
+            }
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: line,
+                ..renderer::Quad::default()
+            },
+            style.color,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        if state.drag.is_none() && !cursor.is_over(layout.bounds()) {
+            mouse::Interaction::default()
+        } else if self.direction.is_horizontal() {
+            mouse::Interaction::ResizingHorizontally
+        } else {
+            mouse::Interaction::ResizingVertically
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ResizeHandle<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(handle: ResizeHandle<'a, Message, Theme>) -> Self {
+        Self::new(handle)
+    }
+}
+
+/// Creates a new [`ResizeHandle`] for the current `size`, limited to the `range`.
+pub fn resize_handle<'a, Message, Theme>(
+    size: f32,
+    range: RangeInclusive<f32>,
+    on_resize: impl Fn(f32) -> Message + 'a,
+) -> ResizeHandle<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    ResizeHandle::new(size, range, on_resize)
+}
+
+/// The possible status of a [`ResizeHandle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`ResizeHandle`] can be interacted with.
+    Active,
+    /// The [`ResizeHandle`] is being hovered.
+    Hovered,
+    /// The [`ResizeHandle`] is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`ResizeHandle`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the line through the handle.
+    pub color: Color,
+    /// The width of the line through the handle.
+    pub width: f32,
+}
+
+impl Animate for Style {
+    fn components() -> usize {
+        Color::components() + f32::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.color.distance_to(&end.color),
+            self.width.distance_to(&end.width),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.color.update(components);
+        self.width.update(components);
+    }
+}
+
+/// The theme catalog of a [`ResizeHandle`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`ResizeHandle`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`ResizeHandle`], a thin divider that highlights with the primary
+/// color while hovered or dragged.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    match status {
+        Status::Active => Style {
+            color: palette.background.strong.color,
+            width: 1.0,
+        },
+        Status::Hovered => Style {
+            color: palette.primary.weak.color,
+            width: 3.0,
+        },
+        Status::Dragged => Style {
+            color: palette.primary.base.color,
+            width: 3.0,
+        },
+    }
+}
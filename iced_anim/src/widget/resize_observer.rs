@@ -0,0 +1,253 @@
+//! Report the size of content as it animates between layouts.
+//!
+//! Some UI depends on the size of other content without being laid out along with it, like a
+//! canvas overlay drawing connectors between cards or a highlight tracking a panel. Listening to
+//! the raw layout size makes that UI jump whenever the content is resized. [`on_resize_animated`]
+//! instead smooths the size with a spring and produces a message with the animated size on every
+//! frame it changes, so dependent UI follows along smoothly.
+//!
+//! ```rust
+//! # use iced::{widget::text, Element, Size};
+//! # use iced_anim::widget::on_resize_animated;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     PanelResized(Size),
+//! }
+//!
+//! fn panel<'a>(content: &'a str) -> Element<'a, Message> {
+//!     on_resize_animated(text(content), Message::PanelResized).into()
+//! }
+//! ```
+//!
+//! The first size is reported right away without animating, so dependent UI starts out in place.
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    overlay, window, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+/// Produces a message with the animated size of its content whenever the content is resized.
+#[allow(missing_debug_implementations)]
+pub struct ResizeObserver<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_resize: Box<dyn Fn(Size) -> Message + 'a>,
+    motion: SpringMotion,
+}
+
+/// The internal state of a [`ResizeObserver`].
+#[derive(Debug)]
+struct State {
+    /// The animated size of the content, headed to the size from the latest layout.
+    size: Spring<Size>,
+    /// Whether the content has been laid out yet.
+    is_measured: bool,
+    /// The size in the latest message, if any.
+    reported: Option<Size>,
+}
+
+impl<'a, Message, Theme, Renderer> ResizeObserver<'a, Message, Theme, Renderer> {
+    /// Creates a new [`ResizeObserver`] producing the `on_resize` message with the animated size
+    /// of the `content`.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_resize: impl Fn(Size) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_resize: Box::new(on_resize),
+            motion: SpringMotion::Smooth,
+        }
+    }
+
+    /// Sets the motion of the reported size.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ResizeObserver<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            size: Spring::new(Size::ZERO).with_motion(self.motion),
+            is_measured: false,
+            reported: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.size.set_motion(self.motion);
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        let state = tree.state.downcast_mut::<State>();
+        let size = node.size();
+        if !state.is_measured {
+            state.is_measured = true;
+            state.size.settle_at(size);
+        } else if *state.size.target() != size {
+            state.size.interrupt(size);
+        }
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.size.tick(now);
+
+            let size = *state.size.value();
+            if state.is_measured && state.reported != Some(size) {
+                state.reported = Some(size);
+                shell.publish((self.on_resize)(size));
+            }
+        }
+        if state.size.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ResizeObserver<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(observer: ResizeObserver<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(observer)
+    }
+}
+
+/// Creates a new [`ResizeObserver`] producing the `on_resize` message with the animated size of
+/// the `content` whenever it's resized.
+pub fn on_resize_animated<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_resize: impl Fn(Size) -> Message + 'a,
+) -> ResizeObserver<'a, Message, Theme, Renderer> {
+    ResizeObserver::new(content, on_resize)
+}
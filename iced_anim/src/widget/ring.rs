@@ -0,0 +1,434 @@
+//! A circular progress indicator that fills a ring as progress is made.
+//!
+//! The sweep of the ring and its colors are animated, so changes to the value smoothly fill or
+//! drain the ring. Like the [`ProgressBar`](super::ProgressBar), a ring can be indeterminate to
+//! show an arc spinning around it until the real progress is known.
+//!
+//! > Note: this module is only available when the `canvas` feature is enabled.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::ring;
+//! # #[derive(Clone)] enum Message {}
+//! fn gauge<'a>(cpu: f32) -> Element<'a, Message> {
+//!     ring(0.0..=100.0, cpu).size(64.0).thickness(6.0).into()
+//! }
+//! ```
+use std::{
+    f32::consts::{FRAC_PI_2, TAU},
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+use iced::{
+    advanced::{
+        graphics::geometry,
+        layout, renderer,
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::Cursor,
+    widget::canvas::{self, path::Arc, LineCap, Path, Stroke},
+    window, Color, Element, Event, Length, Radians, Rectangle, Size, Vector,
+};
+
+use super::{AnimatedState, Id};
+use crate::{Animate, AnimationId, AnimationStore, Spring, SpringMotion};
+
+/// The fraction of the ring covered by the spinning arc of an indeterminate ring.
+const SPIN_LENGTH: f32 = 0.25;
+
+/// A circular indicator that displays progress, which animates changes to its value.
+#[allow(missing_debug_implementations)]
+pub struct Ring<'a, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    value: f32,
+    is_indeterminate: bool,
+    size: f32,
+    thickness: f32,
+    period: Duration,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`Ring`].
+#[derive(Debug)]
+struct State {
+    /// The animated fraction of the ring that is filled.
+    fraction: Spring<f32>,
+    /// How determinate the ring is, from 0 while spinning to 1 while showing the fraction.
+    determinacy: Spring<f32>,
+    /// When the ring started spinning.
+    spin_start: Instant,
+    /// The time of the latest redraw, which positions the spinning arc.
+    now: Instant,
+    animated_state: AnimatedState<(), Style>,
+}
+
+impl<'a, Theme> Ring<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Ring`] with the given `range` and current `value`.
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self {
+            value: value.clamp(*range.start(), *range.end()),
+            range,
+            is_indeterminate: false,
+            size: 40.0,
+            thickness: 4.0,
+            period: Duration::from_secs(1),
+            motion: SpringMotion::default(),
+            animation_id: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets whether the [`Ring`] is indeterminate, spinning an arc around the ring
+    /// instead of showing its value.
+    pub fn indeterminate(mut self, is_indeterminate: bool) -> Self {
+        self.is_indeterminate = is_indeterminate;
+        self
+    }
+
+    /// Sets how long it takes the arc of an indeterminate [`Ring`] to spin around once.
+    pub fn period(mut self, period: Duration) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Sets the diameter of the [`Ring`].
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the thickness of the [`Ring`]'s stroke.
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the style of the [`Ring`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Ring`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Ring`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// The fraction of the range covered by the value.
+    fn fraction(&self) -> f32 {
+        let (start, end) = self.range.clone().into_inner();
+        if end > start {
+            (self.value - start) / (end - start)
+        } else {
+            1.0
+        }
+    }
+
+    /// The target determinacy of the ring.
+    fn determinacy(&self) -> f32 {
+        if self.is_indeterminate {
+            0.0
+        } else {
+            1.0
+        }
+    }
+}
+
+/// The start and length of the filled arc as fractions of a full turn, starting from the top.
+///
+/// The arc blends between the spinning arc at the given `phase` and the determinate arc
+/// from the top of the ring to the `fraction` based on the `determinacy`.
+fn arc(phase: f32, fraction: f32, determinacy: f32) -> (f32, f32) {
+    let start = phase * (1.0 - determinacy);
+    let length = SPIN_LENGTH + (fraction - SPIN_LENGTH) * determinacy;
+    (start, length.clamp(0.0, 1.0))
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Ring<'a, Theme>
+where
+    Renderer: geometry::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let now = Instant::now();
+        tree::State::new(State {
+            fraction: Spring::new(self.fraction()).with_motion(self.motion),
+            determinacy: Spring::new(self.determinacy()).with_motion(self.motion),
+            spin_start: now,
+            now,
+            animated_state: AnimatedState::new((), self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.fraction.set_motion(self.motion);
+        state.determinacy.set_motion(self.motion);
+        state.animated_state.diff(self.motion);
+
+        let fraction = self.fraction();
+        if *state.fraction.target() != fraction {
+            state.fraction.interrupt(fraction);
+        }
+
+        let determinacy = self.determinacy();
+        if *state.determinacy.target() != determinacy {
+            // Restart the spin from the top when a determinate ring becomes indeterminate.
+            if !state.determinacy.has_energy() {
+                state.spin_start = Instant::now();
+            }
+            state.determinacy.interrupt(determinacy);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.size, self.size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = now;
+            state.fraction.tick(now);
+            state.determinacy.tick(now);
+            state.animated_state.tick(now);
+        }
+
+        let is_spinning = *state.determinacy.value() < 1.0;
+        let needs_redraw = state.animated_state.needs_redraw(());
+        if is_spinning
+            || needs_redraw
+            || state.fraction.has_energy()
+            || state.determinacy.has_energy()
+        {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = *state
+            .animated_state
+            .current_style(|_| theme.style(&self.class));
+
+        let elapsed = state.now.saturating_duration_since(state.spin_start);
+        let phase = match self.period.as_secs_f32() {
+            period if period > 0.0 => elapsed.as_secs_f32() % period / period,
+            _ => 0.0,
+        };
+        let (start, length) = arc(
+            phase,
+            *state.fraction.value(),
+            state.determinacy.value().clamp(0.0, 1.0),
+        );
+
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let center = frame.center();
+        let radius = ((bounds.width.min(bounds.height) - self.thickness) / 2.0).max(0.0);
+
+        frame.stroke(
+            &Path::circle(center, radius),
+            Stroke::default()
+                .with_color(style.track)
+                .with_width(self.thickness),
+        );
+
+        if length > 0.0 {
+            let start_angle = TAU * start - FRAC_PI_2;
+            let bar = Path::new(|builder| {
+                builder.arc(Arc {
+                    center,
+                    radius,
+                    start_angle: Radians(start_angle),
+                    end_angle: Radians(start_angle + TAU * length),
+                });
+            });
+
+            frame.stroke(
+                &bar,
+                Stroke::default()
+                    .with_color(style.bar)
+                    .with_width(self.thickness)
+                    .with_line_cap(LineCap::Round),
+            );
+        }
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_geometry(frame.into_geometry());
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Ring<'a, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'a,
+{
+    fn from(ring: Ring<'a, Theme>) -> Self {
+        Self::new(ring)
+    }
+}
+
+/// Creates a new [`Ring`] with the given `range` and current `value`.
+pub fn ring<'a, Theme>(range: RangeInclusive<f32>, value: f32) -> Ring<'a, Theme>
+where
+    Theme: Catalog,
+{
+    Ring::new(range, value)
+}
+
+/// The appearance of a [`Ring`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the unfilled part of the ring.
+    pub track: Color,
+    /// The color of the filled arc.
+    pub bar: Color,
+}
+
+impl Animate for Style {
+    fn components() -> usize {
+        Color::components() * 2
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.track.distance_to(&end.track),
+            self.bar.distance_to(&end.bar),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.track.update(components);
+        self.bar.update(components);
+    }
+}
+
+/// The theme catalog of a [`Ring`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Ring`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(primary)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The primary style of a [`Ring`].
+pub fn primary(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+    Style {
+        track: palette.background.strong.color,
+        bar: palette.primary.base.color,
+    }
+}
+
+/// The success style of a [`Ring`].
+pub fn success(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+    Style {
+        track: palette.background.strong.color,
+        bar: palette.success.base.color,
+    }
+}
+
+/// The danger style of a [`Ring`].
+pub fn danger(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+    Style {
+        track: palette.background.strong.color,
+        bar: palette.danger.base.color,
+    }
+}
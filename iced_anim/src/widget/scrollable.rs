@@ -0,0 +1,647 @@
+//! A scrollable that can animate back to a saved scroll position.
+//!
+//! Going back from a detail page to a long list usually means building the list again, which
+//! loses its scroll position. Save the offset of the [`Scrollable`] with
+//! [`on_scroll`](Scrollable::on_scroll) and restore it with [`restore_offset_animated`] when the
+//! list is shown again, and the list scrolls smoothly back to where the user left off instead of
+//! jumping there or starting over at the top.
+//!
+//! ```rust
+//! # use iced::{Element, Task, widget::{column, text}};
+//! # use iced_anim::widget::scrollable::{self, scrollable, AbsoluteOffset, Viewport};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Scrolled(Viewport),
+//!     Back,
+//! }
+//!
+//! struct App {
+//!     saved: AbsoluteOffset,
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) -> Task<Message> {
+//!         match message {
+//!             Message::Scrolled(viewport) => {
+//!                 self.saved = viewport.absolute_offset();
+//!                 Task::none()
+//!             }
+//!             Message::Back => {
+//!                 scrollable::restore_offset_animated(scrollable::Id::new("list"), self.saved)
+//!             }
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<Message> {
+//!         scrollable(column((0..100).map(|i| text(i.to_string()).into())))
+//!             .id(scrollable::Id::new("list"))
+//!             .on_scroll(Message::Scrolled)
+//!             .into()
+//!     }
+//! }
+//! ```
+//!
+//! Scrolling while the scrollable is animating stops the animation, so the user is never fought
+//! over the scroll position.
+//!
+//! # Auto-scrolling
+//!
+//! Dragging something inside a long list, like an item being reordered, often needs to reach
+//! past what's visible. With [`Scrollable::auto_scroll`], holding a drag near an edge scrolls
+//! toward it, faster the closer the cursor gets. The speed follows a spring, so scrolling eases
+//! in as the cursor approaches the edge and eases out once it leaves or the drag ends.
+//!
+//! ```rust
+//! # use iced::{Element, widget::{column, text}};
+//! # use iced_anim::widget::scrollable::{scrollable, AutoScroll};
+//! # #[derive(Debug, Clone)]
+//! # enum Message {}
+//! fn list<'a>() -> Element<'a, Message> {
+//!     scrollable(column((0..100).map(|i| text(i.to_string()).into())))
+//!         .auto_scroll(AutoScroll {
+//!             threshold: 64.0,
+//!             ..AutoScroll::default()
+//!         })
+//!         .into()
+//! }
+//! ```
+use std::{
+    any::Any,
+    time::{Duration, Instant},
+};
+
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{self, operation, tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::scrollable::{Catalog, Direction, Status, Style, StyleFn},
+    window, Element, Event, Length, Point, Rectangle, Size, Task, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+pub use iced::widget::scrollable::{AbsoluteOffset, Viewport};
+pub use widget::Id;
+
+/// A scrollable that can animate back to a saved scroll position.
+#[allow(missing_debug_implementations)]
+pub struct Scrollable<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    scrollable: iced::widget::Scrollable<'a, Message, Theme, Renderer>,
+    id: Option<Id>,
+    motion: SpringMotion,
+    auto_scroll: Option<AutoScroll>,
+}
+
+/// How a [`Scrollable`] scrolls on its own while something is dragged near its edges.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoScroll {
+    /// How close to an edge, in pixels, a drag needs to be to start scrolling.
+    pub threshold: f32,
+    /// The fastest the scrollable scrolls, in pixels per second, once a drag reaches an edge.
+    pub max_speed: f32,
+    /// The motion of the scroll speed as it speeds up and slows down.
+    pub motion: SpringMotion,
+}
+
+impl Default for AutoScroll {
+    fn default() -> Self {
+        Self {
+            threshold: 48.0,
+            max_speed: 1200.0,
+            motion: SpringMotion::Smooth.with_duration(Duration::from_millis(250)),
+        }
+    }
+}
+
+impl AutoScroll {
+    /// The scroll speed for a drag at the `position` within the `bounds`, only along the axes
+    /// that can scroll up to the `max` offset.
+    fn speed(&self, position: Point, bounds: Rectangle, max: Vector) -> Vector {
+        let threshold = self.threshold.max(1.0);
+        let axis = |position: f32, start: f32, length: f32, max: f32| {
+            if max <= 0.0 {
+                return 0.0;
+            }
+            let before = (start + threshold - position) / threshold;
+            let after = (position - (start + length - threshold)) / threshold;
+            (after.clamp(0.0, 1.0) - before.clamp(0.0, 1.0)) * self.max_speed
+        };
+
+        Vector::new(
+            axis(position.x, bounds.x, bounds.width, max.x),
+            axis(position.y, bounds.y, bounds.height, max.y),
+        )
+    }
+
+    /// Whether the `position` is close enough to an edge of the `bounds` to be on a scrollbar.
+    fn is_near_edge(&self, position: Point, bounds: Rectangle) -> bool {
+        position.x < bounds.x + self.threshold
+            || position.y < bounds.y + self.threshold
+            || position.x > bounds.x + bounds.width - self.threshold
+            || position.y > bounds.y + bounds.height - self.threshold
+    }
+}
+
+/// The internal state of a [`Scrollable`].
+#[derive(Debug)]
+struct State {
+    /// The animated offset while restoring a scroll position.
+    offset: Spring<Vector>,
+    /// The offset requested by a task, which is restored once the scrollable gets an event.
+    requested: Option<AbsoluteOffset>,
+    /// Whether the scrollable is animating to a restored offset.
+    is_restoring: bool,
+    /// Whether a drag that can auto-scroll is in progress.
+    is_dragging: bool,
+    /// The animated speed of auto-scrolling, in pixels per second.
+    speed: Spring<Vector>,
+    /// When the scrollable last auto-scrolled, if it's auto-scrolling.
+    scrolled_at: Option<Instant>,
+}
+
+impl State {
+    fn new(motion: SpringMotion, auto_scroll: Option<AutoScroll>) -> Self {
+        let speed_motion = auto_scroll.unwrap_or_default().motion;
+        Self {
+            offset: Spring::new(Vector::ZERO).with_motion(motion),
+            requested: None,
+            is_restoring: false,
+            is_dragging: false,
+            speed: Spring::new(Vector::ZERO).with_motion(speed_motion),
+            scrolled_at: None,
+        }
+    }
+
+    /// Whether the scrollable is scrolling on its own during a drag.
+    fn is_auto_scrolling(&self) -> bool {
+        self.speed.has_energy() || *self.speed.value() != Vector::ZERO
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Scrollable<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new vertical [`Scrollable`] showing the given `content`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            scrollable: iced::widget::Scrollable::new(content),
+            id: None,
+            motion: SpringMotion::Smooth,
+            auto_scroll: None,
+        }
+    }
+
+    /// Sets the [`Id`] of the [`Scrollable`], which [`restore_offset_animated`] uses to find it.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`Scrollable`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.scrollable = self.scrollable.width(width);
+        self
+    }
+
+    /// Sets the height of the [`Scrollable`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.scrollable = self.scrollable.height(height);
+        self
+    }
+
+    /// Sets the [`Direction`] the [`Scrollable`] scrolls in.
+    pub fn direction(mut self, direction: impl Into<Direction>) -> Self {
+        self.scrollable = self.scrollable.direction(direction);
+        self
+    }
+
+    /// Sets the message produced when the [`Scrollable`] is scrolled, whose [`Viewport`] has the
+    /// offset to save for restoring later.
+    pub fn on_scroll(mut self, on_scroll: impl Fn(Viewport) -> Message + 'a) -> Self {
+        self.scrollable = self.scrollable.on_scroll(on_scroll);
+        self
+    }
+
+    /// Sets the motion used when restoring a scroll position.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Scrolls the [`Scrollable`] on its own while something is dragged near its edges, as
+    /// configured by the [`AutoScroll`].
+    ///
+    /// Drags that start near an edge, like those on a scrollbar, don't auto-scroll.
+    pub fn auto_scroll(mut self, auto_scroll: AutoScroll) -> Self {
+        self.auto_scroll = Some(auto_scroll);
+        self
+    }
+
+    /// Sets the style of the [`Scrollable`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.scrollable = self.scrollable.style(style);
+        self
+    }
+
+    /// Sets the style class of the [`Scrollable`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.scrollable = self.scrollable.class(class);
+        self
+    }
+
+    /// Scrolls toward the edge that a drag is held near, easing the speed in and out.
+    fn drag_scroll(
+        &self,
+        state: &mut State,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+    ) {
+        let Some(auto_scroll) = self.auto_scroll else {
+            return;
+        };
+
+        let bounds = layout.bounds();
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.is_dragging = cursor
+                    .position_over(bounds)
+                    .is_some_and(|position| !auto_scroll.is_near_edge(position, bounds));
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_dragging = false;
+                state.speed.interrupt(Vector::ZERO);
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.is_dragging =>
+            {
+                if let Some(position) = cursor.position() {
+                    let max = self.drive(tree, layout, renderer, None).max;
+                    let speed = auto_scroll.speed(position, bounds, max);
+                    if *state.speed.latest_target() != speed {
+                        state.speed.interrupt(speed);
+                    }
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if !state.is_auto_scrolling() {
+                    state.scrolled_at = None;
+                    return;
+                }
+
+                state.speed.tick(*now);
+                let dt = state
+                    .scrolled_at
+                    .map_or(Duration::ZERO, |scrolled_at| {
+                        now.saturating_duration_since(scrolled_at)
+                    })
+                    .min(crate::spring::MAX_DURATION)
+                    .as_secs_f32();
+                state.scrolled_at = Some(*now);
+
+                let measured = self.drive(tree, layout, renderer, None);
+                let speed = *state.speed.value();
+                self.drive(
+                    tree,
+                    layout,
+                    renderer,
+                    Some(AbsoluteOffset {
+                        x: (measured.translation.x + speed.x * dt).clamp(0.0, measured.max.x),
+                        y: (measured.translation.y + speed.y * dt).clamp(0.0, measured.max.y),
+                    }),
+                );
+                state.is_restoring = false;
+            }
+            _ => {}
+        }
+    }
+
+    /// The inner scrollable as a widget.
+    fn widget(&self) -> &dyn Widget<Message, Theme, Renderer> {
+        &self.scrollable
+    }
+
+    /// Measures the inner scrollable, scrolling it to the given `offset` first if there is one.
+    fn drive(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        offset: Option<AbsoluteOffset>,
+    ) -> Drive {
+        let mut drive = Drive {
+            offset,
+            translation: Vector::ZERO,
+            max: Vector::ZERO,
+        };
+        self.scrollable.operate(tree, layout, renderer, &mut drive);
+        drive
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Scrollable<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.motion, self.auto_scroll))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(self.widget())]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.offset.set_motion(self.motion);
+        if let Some(auto_scroll) = self.auto_scroll {
+            state.speed.set_motion(auto_scroll.motion);
+        } else {
+            state.is_dragging = false;
+            state.speed.settle_at(Vector::ZERO);
+        }
+        tree.children[0].diff(self.widget());
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.scrollable.size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.scrollable.size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.scrollable
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.custom(tree.state.downcast_mut::<State>(), self.id.as_ref());
+        self.scrollable
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+
+        // The user takes over as soon as they scroll.
+        if state.is_restoring && is_scroll(&event, cursor, layout.bounds()) {
+            state.is_restoring = false;
+        }
+
+        let status = self.scrollable.on_event(
+            &mut children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let Some(offset) = state.requested.take() {
+            let measured = self.drive(&mut children[0], layout, renderer, None);
+            let target = Vector::new(
+                offset.x.clamp(0.0, measured.max.x),
+                offset.y.clamp(0.0, measured.max.y),
+            );
+            state.offset.settle_at(measured.translation);
+            state.offset.interrupt(target);
+            state.is_restoring = true;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if state.is_restoring {
+                state.offset.tick(now);
+                let offset = *state.offset.value();
+                self.drive(
+                    &mut children[0],
+                    layout,
+                    renderer,
+                    Some(AbsoluteOffset {
+                        x: offset.x,
+                        y: offset.y,
+                    }),
+                );
+                state.is_restoring = state.offset.has_energy();
+            }
+        }
+
+        self.drag_scroll(state, &mut children[0], &event, layout, cursor, renderer);
+
+        if state.is_restoring || state.is_auto_scrolling() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.scrollable.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.scrollable
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.scrollable
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+/// Whether the `event` scrolls the scrollable laid out in `bounds` by hand.
+fn is_scroll(event: &Event, cursor: Cursor, bounds: Rectangle) -> bool {
+    match event {
+        Event::Mouse(mouse::Event::WheelScrolled { .. })
+        | Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => cursor.is_over(bounds),
+        _ => false,
+    }
+}
+
+/// An operation scrolling the inner scrollable of a [`Scrollable`] and measuring its offset.
+struct Drive {
+    /// The offset to scroll to, if any.
+    offset: Option<AbsoluteOffset>,
+    /// The offset of the scrollable before scrolling.
+    translation: Vector,
+    /// The largest offset the scrollable can scroll to.
+    max: Vector,
+}
+
+impl Operation for Drive {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        // Only the inner scrollable is driven, not the scrollables in its content.
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.translation = translation;
+        self.max = Vector::new(
+            (content_bounds.width - bounds.width).max(0.0),
+            (content_bounds.height - bounds.height).max(0.0),
+        );
+        if let Some(offset) = self.offset {
+            state.scroll_to(offset);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Scrollable<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(scrollable: Scrollable<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(scrollable)
+    }
+}
+
+/// Creates a new vertical [`Scrollable`] showing the given `content`.
+pub fn scrollable<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Scrollable<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    Scrollable::new(content)
+}
+
+/// Animates the [`Scrollable`] with the given [`Id`] to a saved `offset`, starting from
+/// wherever it's scrolled to right now.
+///
+/// The offset is clamped to what the scrollable can scroll to, so restoring an offset saved
+/// before the content shrunk scrolls as far as it can.
+pub fn restore_offset_animated<T>(id: Id, offset: AbsoluteOffset) -> Task<T>
+where
+    T: Send + 'static,
+{
+    widget::operate(Restore { id, offset })
+}
+
+/// An operation restoring the offset of the [`Scrollable`] with the given [`Id`].
+struct Restore {
+    id: Id,
+    offset: AbsoluteOffset,
+}
+
+impl<T> Operation<T> for Restore {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if id != Some(&self.id) {
+            return;
+        }
+
+        if let Some(state) = state.downcast_mut::<State>() {
+            state.requested = Some(self.offset);
+        }
+    }
+}
@@ -0,0 +1,642 @@
+//! Numeric inputs that change their value by dragging sideways, like in creative tools.
+//!
+//! Dragging a [`Scrubber`] left or right changes its value, and dragging faster changes it
+//! faster, so large and small adjustments both take a short drag. Holding shift scrubs in finer
+//! increments. The displayed value springs toward the value instead of jumping, and releasing the
+//! drag snaps the value to the nearest step, which the displayed value springs into.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::{format, widget::scrubber};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     OpacityChanged(f32),
+//! }
+//!
+//! fn opacity<'a>(opacity: f32) -> Element<'a, Message> {
+//!     scrubber(opacity, Message::OpacityChanged)
+//!         .range(0.0..=100.0)
+//!         .step(5.0)
+//!         .format(format::decimal(0))
+//!         .into()
+//! }
+//! ```
+use std::{ops::RangeInclusive, time::Instant};
+
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Text},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event, keyboard,
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size,
+};
+
+use super::{
+    animated_state::{AnimatedState, Id},
+    number::Formatter,
+};
+use crate::{format, Animate, AnimationId, AnimationStore, Spring, SpringMotion};
+
+pub use iced::widget::text::{LineHeight, Shaping};
+
+/// How much slower the value changes while holding shift.
+const FINE_FACTOR: f32 = 0.1;
+
+/// A numeric input whose value changes by dragging sideways and whose text springs toward it.
+#[allow(missing_debug_implementations)]
+pub struct Scrubber<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    value: f32,
+    range: Option<RangeInclusive<f32>>,
+    step: Option<f32>,
+    on_change: Box<dyn Fn(f32) -> Message + 'a>,
+    on_release: Option<Message>,
+    format: Formatter<'a>,
+    sensitivity: f32,
+    acceleration: f32,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Option<Renderer::Font>,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    class: Theme::Class<'a>,
+}
+
+/// A drag in progress on a [`Scrubber`].
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// Where the cursor was last seen during the drag.
+    position: Point,
+    /// When the cursor was last seen during the drag, to measure its speed.
+    at: Instant,
+    /// The value scrubbed to so far, before snapping to a step.
+    value: f32,
+}
+
+/// The internal state of a [`Scrubber`].
+#[derive(Debug)]
+struct State {
+    /// The animated value shown as text.
+    value: Spring<f32>,
+    /// The drag in progress, if any.
+    drag: Option<Drag>,
+    /// The latest keyboard modifiers, used for fine scrubbing.
+    modifiers: keyboard::Modifiers,
+    animated_state: AnimatedState<Status, Style>,
+}
+
+impl<'a, Message, Theme, Renderer> Scrubber<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// The default width of a [`Scrubber`].
+    pub const DEFAULT_WIDTH: f32 = 96.0;
+
+    /// The default padding around the text of a [`Scrubber`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        right: 8.0,
+        bottom: 4.0,
+        left: 8.0,
+    };
+
+    /// Creates a new [`Scrubber`] for the `value`, producing a message whenever it's dragged.
+    pub fn new(value: f32, on_change: impl Fn(f32) -> Message + 'a) -> Self {
+        Self {
+            value,
+            range: None,
+            step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            format: Box::new(format::decimal(2)),
+            sensitivity: 0.1,
+            acceleration: 1.0,
+            width: Length::Fixed(Self::DEFAULT_WIDTH),
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            motion: SpringMotion::Snappy,
+            animation_id: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Limits the value to the `range`, which is also shown as a fill behind the text.
+    /// Values are unbounded by default.
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.value = self.value.clamp(*range.start(), *range.end());
+        self.range = Some(range);
+        self
+    }
+
+    /// Sets the step the value snaps to when the drag is released. Values are continuous by
+    /// default.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = (step > 0.0).then_some(step);
+        self
+    }
+
+    /// Sets the message produced when the user stops dragging the [`Scrubber`].
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the formatter applied to the animated value on every frame, like the ones in
+    /// [`crate::format`].
+    pub fn format(mut self, format: impl Fn(f32) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// Sets how much the value changes for each pixel dragged slowly, and how much faster it
+    /// changes for each 1000 pixels per second the cursor moves.
+    ///
+    /// An `acceleration` of zero changes the value at the same rate no matter the speed.
+    pub fn sensitivity(mut self, per_pixel: f32, acceleration: f32) -> Self {
+        self.sensitivity = per_pixel.max(0.0);
+        self.acceleration = acceleration.max(0.0);
+        self
+    }
+
+    /// Sets the width of the [`Scrubber`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding around the text of the [`Scrubber`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the size of the text.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the line height of the text.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the text.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Scrubber`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// Sets the style of the [`Scrubber`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Scrubber`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Limits the `value` to the range, if there is one.
+    fn clamp(&self, value: f32) -> f32 {
+        match &self.range {
+            Some(range) => value.clamp(*range.start(), *range.end()),
+            None => value,
+        }
+    }
+
+    /// Snaps the `value` to the nearest step, counting steps from the start of the range.
+    fn snap(&self, value: f32) -> f32 {
+        let Some(step) = self.step else {
+            return value;
+        };
+
+        let start = self.range.as_ref().map_or(0.0, |range| *range.start());
+        self.clamp(start + ((value - start) / step).round() * step)
+    }
+
+    /// The value after dragging from the `drag` to the `position` at `now`, changing faster the
+    /// faster the cursor moves.
+    fn scrubbed_value(&self, drag: Drag, position: Point, now: Instant, is_fine: bool) -> f32 {
+        let delta = position.x - drag.position.x;
+        let elapsed = now.saturating_duration_since(drag.at).as_secs_f32();
+        let speed = if elapsed > 0.0 {
+            delta.abs() / elapsed
+        } else {
+            0.0
+        };
+
+        let factor = if is_fine { FINE_FACTOR } else { 1.0 };
+        let rate = self.sensitivity * (1.0 + self.acceleration * speed / 1000.0);
+        self.clamp(drag.value + delta * rate * factor)
+    }
+
+    /// The height of the text inside the padding.
+    fn text_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        self.line_height.to_absolute(size).0
+    }
+
+    /// The initial status that this widget will have based on its properties.
+    fn get_initial_status(&self) -> Status {
+        Status::Active
+    }
+
+    /// Gets the status of the [`Scrubber`] based on the current [`State`].
+    fn get_status(&self, state: &State, cursor: Cursor, layout: Layout<'_>) -> Status {
+        if state.drag.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Scrubber<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            value: Spring::new(self.value).with_motion(self.motion),
+            drag: None,
+            modifiers: keyboard::Modifiers::default(),
+            animated_state: AnimatedState::new(self.get_initial_status(), self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.animated_state.diff(self.motion);
+        state.value.set_motion(self.motion);
+
+        if *state.value.target() != self.value {
+            state.value.interrupt(self.value);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let height = self.text_height(renderer) + self.padding.vertical();
+        layout::atomic(limits, self.width, height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                state.value.tick(now);
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match cursor.position_over(layout.bounds()) {
+                    Some(position) => {
+                        state.drag = Some(Drag {
+                            position,
+                            at: Instant::now(),
+                            value: self.value,
+                        });
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => match state.drag {
+                Some(drag) => {
+                    let now = Instant::now();
+                    let value = self.scrubbed_value(drag, position, now, state.modifiers.shift());
+                    state.drag = Some(Drag {
+                        position,
+                        at: now,
+                        value,
+                    });
+                    if value != self.value {
+                        shell.publish((self.on_change)(value));
+                    }
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            },
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => match state.drag.take() {
+                Some(drag) => {
+                    // The displayed value springs into the step when the app applies it.
+                    let value = self.snap(drag.value);
+                    if value != self.value {
+                        shell.publish((self.on_change)(value));
+                    }
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+                    event::Status::Captured
+                }
+                None => event::Status::Ignored,
+            },
+            _ => event::Status::Ignored,
+        };
+
+        // Redraw anytime the status changes or the displayed value is still moving.
+        let status_changed = state
+            .animated_state
+            .needs_redraw(self.get_status(state, cursor, layout));
+        if status_changed || state.value.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let style = state
+            .animated_state
+            .current_style(|status| theme.style(&self.class, *status));
+        let value = *state.value.value();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: style.border_radius.into(),
+                    width: style.border_width,
+                    color: style.border_color,
+                },
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        // The fill shows where the value sits in the range, overshoot aside.
+        if let Some(range) = &self.range {
+            let (start, end) = (*range.start(), *range.end());
+            if end > start {
+                let fraction = ((value - start) / (end - start)).clamp(0.0, 1.0);
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            width: bounds.width * fraction,
+                            ..bounds
+                        },
+                        border: Border::default().rounded(style.border_radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.fill,
+                );
+            }
+        }
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        renderer.fill_text(
+            Text {
+                content: (self.format)(value),
+                bounds: Size::new(bounds.width - self.padding.horizontal(), bounds.height),
+                size,
+                line_height: self.line_height,
+                font,
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: Shaping::Advanced,
+                wrapping: core_text::Wrapping::None,
+            },
+            bounds.center(),
+            style.text_color,
+            *viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        if state.drag.is_some() || cursor.is_over(layout.bounds()) {
+            mouse::Interaction::ResizingHorizontally
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Scrubber<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(scrubber: Scrubber<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(scrubber)
+    }
+}
+
+/// Creates a new [`Scrubber`] for the `value`, producing a message whenever it's dragged.
+pub fn scrubber<'a, Message, Theme, Renderer>(
+    value: f32,
+    on_change: impl Fn(f32) -> Message + 'a,
+) -> Scrubber<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Scrubber::new(value, on_change)
+}
+
+/// The possible status of a [`Scrubber`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Scrubber`] can be interacted with.
+    Active,
+    /// The [`Scrubber`] is being hovered.
+    Hovered,
+    /// The [`Scrubber`] is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`Scrubber`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background color of the [`Scrubber`].
+    pub background: Color,
+    /// The color of the fill showing where the value sits in the range.
+    pub fill: Color,
+    /// The color of the text.
+    pub text_color: Color,
+    /// The radius of the corners.
+    pub border_radius: f32,
+    /// The width of the border.
+    pub border_width: f32,
+    /// The color of the border.
+    pub border_color: Color,
+}
+
+impl Animate for Style {
+    fn components() -> usize {
+        Color::components() * 4 + f32::components() * 2
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.fill.distance_to(&end.fill),
+            self.text_color.distance_to(&end.text_color),
+            self.border_radius.distance_to(&end.border_radius),
+            self.border_width.distance_to(&end.border_width),
+            self.border_color.distance_to(&end.border_color),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.fill.update(components);
+        self.text_color.update(components);
+        self.border_radius.update(components);
+        self.border_width.update(components);
+        self.border_color.update(components);
+    }
+}
+
+/// The theme catalog of a [`Scrubber`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Scrubber`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Scrubber`], a field whose fill and border take the primary color
+/// while it's dragged.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let base = Style {
+        background: palette.background.weak.color,
+        fill: palette.primary.weak.color.scale_alpha(0.4),
+        text_color: palette.background.base.text,
+        border_radius: 4.0,
+        border_width: 1.0,
+        border_color: palette.background.strong.color,
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered => Style {
+            border_color: palette.primary.weak.color,
+            ..base
+        },
+        Status::Dragged => Style {
+            fill: palette.primary.base.color.scale_alpha(0.5),
+            border_color: palette.primary.strong.color,
+            ..base
+        },
+    }
+}
@@ -0,0 +1,634 @@
+//! Segmented controls pick one of a few options laid out side by side, like in iOS.
+//!
+//! The pill behind the selected segment slides between segments with a spring when the
+//! selection changes, and each label crossfades to the selected color as the pill passes under
+//! it. The style of the whole control animates when it is hovered.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::segmented;
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum View {
+//!     Day,
+//!     Week,
+//!     Month,
+//! }
+//!
+//! impl std::fmt::Display for View {
+//!     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+//!         write!(f, "{self:?}")
+//!     }
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     ViewSelected(View),
+//! }
+//!
+//! fn view_picker<'a>(view: View) -> Element<'a, Message> {
+//!     segmented([View::Day, View::Week, View::Month], Some(view), Message::ViewSelected).into()
+//! }
+//! ```
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Length, Padding, Pixels, Rectangle, Size,
+};
+
+use super::animated_state::{AnimatedState, Id};
+use crate::{Animate, AnimationId, AnimationStore, Spring, SpringMotion};
+
+pub use iced::widget::text::{LineHeight, Shaping};
+
+/// The space between the edge of the control and the pill.
+const PILL_INSET: f32 = 2.0;
+
+/// A row of segments where the pill behind the selected segment slides between them.
+#[allow(missing_debug_implementations)]
+pub struct Segmented<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    options: Vec<T>,
+    labels: Vec<String>,
+    selected: Option<usize>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    width: Length,
+    padding: Padding,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Option<Renderer::Font>,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`Segmented`] control.
+struct State<P: Paragraph> {
+    /// The laid out label of each segment.
+    paragraphs: Vec<P>,
+    /// The animated position of the pill in segments from the start, and how visible it is.
+    pill: Spring<(f32, f32)>,
+    /// The segment being pressed, if any.
+    pressed: Option<usize>,
+    animated_state: AnimatedState<Status, Style>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Segmented<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// The default padding of each segment.
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 6.0,
+        bottom: 6.0,
+        left: 14.0,
+        right: 14.0,
+    };
+
+    /// Creates a new [`Segmented`] control with the given `options`, highlighting the
+    /// `selected` option and producing `on_select` when an option is picked.
+    pub fn new(
+        options: impl IntoIterator<Item = T>,
+        selected: Option<T>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        let options: Vec<T> = options.into_iter().collect();
+        let labels = options.iter().map(ToString::to_string).collect();
+        let selected = selected.and_then(|selected| options.iter().position(|o| *o == selected));
+
+        Self {
+            options,
+            labels,
+            selected,
+            on_select: Box::new(on_select),
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            motion: SpringMotion::Snappy,
+            animation_id: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Segmented`] control. Segments always share the width equally.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of each segment.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the line height of the labels.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the labels.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the motion of the pill and of style animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Segmented`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// Sets the style of the [`Segmented`] control.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Segmented`] control.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The target position and visibility of the pill.
+    fn pill_target(&self, state: &State<Renderer::Paragraph>) -> (f32, f32) {
+        match self.selected {
+            Some(selected) => (selected as f32, 1.0),
+            // Fade out in place when nothing is selected.
+            None => (state.pill.target().0, 0.0),
+        }
+    }
+
+    /// The text to lay out for the given `label`.
+    fn text<'b>(
+        &self,
+        label: &'b str,
+        renderer: &Renderer,
+    ) -> core_text::Text<&'b str, Renderer::Font> {
+        core_text::Text {
+            content: label,
+            bounds: Size::INFINITY,
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: core_text::Wrapping::None,
+        }
+    }
+
+    /// The bounds of the segment at `index` within the `bounds` of the control.
+    fn segment_bounds(&self, bounds: Rectangle, index: f32) -> Rectangle {
+        let width = bounds.width / self.options.len().max(1) as f32;
+        Rectangle {
+            x: bounds.x + width * index,
+            width,
+            ..bounds
+        }
+    }
+
+    /// The index of the segment under the `cursor`, if any.
+    fn segment_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        let position = cursor.position_over(bounds)?;
+        let width = bounds.width / self.options.len().max(1) as f32;
+        let index = ((position.x - bounds.x) / width) as usize;
+        (index < self.options.len()).then_some(index)
+    }
+
+    /// Gets the status of the [`Segmented`] control based on the current state.
+    fn get_status(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        cursor: Cursor,
+        layout: Layout<'_>,
+    ) -> Status {
+        if state.pressed.is_some() {
+            Status::Pressed
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Segmented<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let pill = match self.selected {
+            Some(selected) => (selected as f32, 1.0),
+            None => (0.0, 0.0),
+        };
+
+        tree::State::new(State::<Renderer::Paragraph> {
+            paragraphs: Vec::new(),
+            pill: Spring::new(pill).with_motion(self.motion),
+            pressed: None,
+            animated_state: AnimatedState::new(Status::Active, self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.motion);
+        state.pill.set_motion(self.motion);
+
+        let target = self.pill_target(state);
+        if *state.pill.target() != target {
+            state.pill.interrupt(target);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.paragraphs = self
+            .labels
+            .iter()
+            .map(|label| Renderer::Paragraph::with_text(self.text(label, renderer)))
+            .collect();
+
+        // Every segment is as wide as the widest label so the pill keeps the same size.
+        let label_size = state
+            .paragraphs
+            .iter()
+            .map(Paragraph::min_bounds)
+            .fold(Size::ZERO, |max, size| {
+                Size::new(max.width.max(size.width), max.height.max(size.height))
+            });
+        let segment = Size::new(
+            label_size.width + self.padding.horizontal(),
+            label_size.height + self.padding.vertical(),
+        );
+        let intrinsic = Size::new(segment.width * self.options.len() as f32, segment.height);
+
+        let size = limits.width(self.width).height(Length::Shrink).resolve(
+            self.width,
+            Length::Shrink,
+            intrinsic,
+        );
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                state.pill.tick(now);
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.pressed = self.segment_at(bounds, cursor);
+                if state.pressed.is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                match state.pressed.take() {
+                    // Only select the segment if the press ends where it started.
+                    Some(pressed) if self.segment_at(bounds, cursor) == Some(pressed) => {
+                        if self.selected != Some(pressed) {
+                            shell.publish((self.on_select)(self.options[pressed].clone()));
+                        }
+                        event::Status::Captured
+                    }
+                    Some(_) => event::Status::Captured,
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        };
+
+        let current_status = self.get_status(state, cursor, layout);
+        if state.animated_state.needs_redraw(current_status) || state.pill.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = state
+            .animated_state
+            .current_style(|status| theme.style(&self.class, *status));
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let inset = |segment: Rectangle| Rectangle {
+            x: segment.x + PILL_INSET,
+            y: segment.y + PILL_INSET,
+            width: segment.width - PILL_INSET * 2.0,
+            height: segment.height - PILL_INSET * 2.0,
+        };
+        let radius = (style.border.radius.top_left - PILL_INSET).max(0.0);
+
+        // Hint at the hovered segment unless the pill is already there.
+        if let Some(hovered) = self.segment_at(bounds, cursor) {
+            if self.selected != Some(hovered) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: inset(self.segment_bounds(bounds, hovered as f32)),
+                        border: Border::default().rounded(radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered_segment,
+                );
+            }
+        }
+
+        let (position, visibility) = *state.pill.value();
+        if visibility > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: inset(self.segment_bounds(bounds, position)),
+                    border: Border::default().rounded(radius),
+                    shadow: style.pill_shadow,
+                },
+                Color {
+                    a: style.pill.a * visibility.clamp(0.0, 1.0),
+                    ..style.pill
+                },
+            );
+        }
+
+        // Labels take on the selected color as the pill passes under them.
+        for (index, paragraph) in state.paragraphs.iter().enumerate() {
+            let segment = self.segment_bounds(bounds, index as f32);
+            let coverage = (1.0 - (position - index as f32).abs()).clamp(0.0, 1.0) * visibility;
+            let mut color = style.text;
+            let mut components = style
+                .selected_text
+                .distance_to(&style.text)
+                .into_iter()
+                .map(|distance| distance * coverage.clamp(0.0, 1.0));
+            color.update(&mut components);
+
+            renderer.fill_paragraph(paragraph, segment.center(), color, *viewport);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.segment_at(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Segmented<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(segmented: Segmented<'a, T, Message, Theme, Renderer>) -> Self {
+        Self::new(segmented)
+    }
+}
+
+/// Creates a new [`Segmented`] control with the given `options`, highlighting the `selected`
+/// option and producing `on_select` when an option is picked.
+pub fn segmented<'a, T, Message, Theme, Renderer>(
+    options: impl IntoIterator<Item = T>,
+    selected: Option<T>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> Segmented<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Segmented::new(options, selected, on_select)
+}
+
+/// The possible status of a [`Segmented`] control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Segmented`] control can be interacted with.
+    Active,
+    /// The [`Segmented`] control is being hovered.
+    Hovered,
+    /// A segment is being pressed.
+    Pressed,
+}
+
+/// The appearance of a [`Segmented`] control.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background color of the control.
+    pub background: Color,
+    /// The border around the control.
+    pub border: Border,
+    /// The color of the pill behind the selected segment.
+    pub pill: Color,
+    /// The shadow of the pill.
+    pub pill_shadow: iced::Shadow,
+    /// The color of the highlight behind a hovered segment.
+    pub hovered_segment: Color,
+    /// The color of the labels of unselected segments.
+    pub text: Color,
+    /// The color of the label of the selected segment.
+    pub selected_text: Color,
+}
+
+impl Animate for Style {
+    fn components() -> usize {
+        Color::components() * 4 + Border::components() + iced::Shadow::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.border.distance_to(&end.border),
+            self.pill.distance_to(&end.pill),
+            self.pill_shadow.distance_to(&end.pill_shadow),
+            self.hovered_segment.distance_to(&end.hovered_segment),
+            self.text.distance_to(&end.text),
+            self.selected_text.distance_to(&end.selected_text),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.border.update(components);
+        self.pill.update(components);
+        self.pill_shadow.update(components);
+        self.hovered_segment.update(components);
+        self.text.update(components);
+        self.selected_text.update(components);
+    }
+}
+
+/// The theme catalog of a [`Segmented`] control.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Segmented`] control.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Segmented`] control, with a light pill on a weak background.
+pub fn default(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let base = Style {
+        background: palette.background.weak.color,
+        border: Border::default().rounded(8.0),
+        pill: palette.background.base.color,
+        pill_shadow: iced::Shadow {
+            color: Color::BLACK.scale_alpha(0.15),
+            offset: iced::Vector::new(0.0, 1.0),
+            blur_radius: 3.0,
+        },
+        hovered_segment: Color::TRANSPARENT,
+        text: palette.background.weak.text.scale_alpha(0.7),
+        selected_text: palette.background.base.text,
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered => Style {
+            hovered_segment: palette.background.strong.color.scale_alpha(0.4),
+            ..base
+        },
+        Status::Pressed => Style {
+            hovered_segment: palette.background.strong.color.scale_alpha(0.7),
+            ..base
+        },
+    }
+}
+
+/// A style with a primary-colored pill, for segmented controls that stand out.
+pub fn primary(theme: &iced::Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    Style {
+        pill: palette.primary.base.color,
+        selected_text: palette.primary.base.text,
+        ..default(theme, status)
+    }
+}
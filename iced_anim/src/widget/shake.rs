@@ -0,0 +1,129 @@
+//! Shake content horizontally, like a login form after entering the wrong password.
+//!
+//! A [`Shake`] lives in your app state and wraps content in [`Shake::view`], which is usually
+//! the root of the window. Calling [`Shake::shake`] kicks a spring that's resting at zero with
+//! an [impulse](crate::Spring::impulse), so the content swings side to side with a damped
+//! oscillation before coming to rest where it started.
+//!
+//! ```rust
+//! # use iced::{widget::text, Element};
+//! # use iced_anim::{SpringEvent, widget::shake::Shake};
+//! #[derive(Default)]
+//! struct State {
+//!     shake: Shake,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     InvalidPassword,
+//!     Shake(SpringEvent<f32>),
+//! }
+//!
+//! impl State {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::InvalidPassword => self.shake.shake(),
+//!             Message::Shake(event) => self.shake.update(event),
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<Message> {
+//!         self.shake.view(text("Password"), Message::Shake)
+//!     }
+//! }
+//! ```
+use std::time::Duration;
+
+use iced::{Element, Vector};
+
+use super::translate;
+use crate::{Animation, Spring, SpringEvent, SpringMotion};
+
+/// Shakes content horizontally with a damped oscillation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shake {
+    /// The horizontal offset of the content, which rests at zero.
+    offset: Spring<f32>,
+    /// Roughly how far the content moves on its first swing.
+    amplitude: f32,
+}
+
+impl Shake {
+    /// The default motion of a [`Shake`], which oscillates quickly and several times.
+    pub const MOTION: SpringMotion = SpringMotion::Custom {
+        response: Duration::from_millis(150),
+        damping: 0.2,
+    };
+
+    /// Creates a new [`Shake`] that isn't shaking.
+    pub fn new() -> Self {
+        Self {
+            offset: Spring::new(0.0).with_motion(Self::MOTION),
+            amplitude: 12.0,
+        }
+    }
+
+    /// Returns an updated shake that moves the content roughly `amplitude` pixels
+    /// on its first swing.
+    pub fn with_amplitude(mut self, amplitude: f32) -> Self {
+        self.amplitude = amplitude;
+        self
+    }
+
+    /// Returns an updated shake with the given `motion`. The lower the damping,
+    /// the more times the content swings before coming to rest.
+    pub fn with_motion(mut self, motion: SpringMotion) -> Self {
+        self.offset.set_motion(motion);
+        self
+    }
+
+    /// The current horizontal offset of the content.
+    pub fn offset(&self) -> f32 {
+        *self.offset.value()
+    }
+
+    /// Whether the content is currently shaking.
+    pub fn is_shaking(&self) -> bool {
+        self.offset.has_energy()
+    }
+
+    /// Starts shaking the content. Shaking while already shaking adds to the current motion.
+    pub fn shake(&mut self) {
+        // The peak of an underdamped spring is roughly its initial velocity divided
+        // by its angular frequency, so scale the impulse to reach the amplitude.
+        let response = self.offset.motion().duration().as_secs_f32();
+        if response > 0.0 {
+            let velocity = self.amplitude * std::f32::consts::TAU / response;
+            self.offset.impulse(vec![velocity]);
+        }
+    }
+
+    /// Updates the shake with the given `event`.
+    pub fn update(&mut self, event: SpringEvent<f32>) {
+        self.offset.update(event);
+    }
+
+    /// Wraps the `content` so that it shakes, emitting messages created by `on_update`
+    /// that should be passed to [`Shake::update`].
+    pub fn view<'a, Message, Theme, Renderer>(
+        &'a self,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_update: impl Fn(SpringEvent<f32>) -> Message + 'static,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Message: 'a + Clone,
+        Theme: 'a,
+        Renderer: 'a + iced::advanced::Renderer,
+    {
+        let offset = Vector::new(self.offset(), 0.0);
+        Animation::new(&self.offset, translate(content, offset))
+            .on_update(on_update)
+            .into()
+    }
+}
+
+impl Default for Shake {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,562 @@
+//! Sliders let users pick values from a range by dragging a handle.
+//!
+//! The handles of an animated slider spring toward their values, so changes made from outside
+//! the slider, like resetting a filter or snapping to a step, glide into place along with the
+//! filled part of the rail. The style animates between the active, hovered, and dragged states.
+//!
+//! There are three flavors that share the same widget:
+//! - [`slider`] for a single value on a horizontal rail.
+//! - [`vertical_slider`] for a single value on a vertical rail, filling from the bottom.
+//! - [`range_slider`] for a pair of values with a handle for each end of the selected range.
+//!
+//! ```rust
+//! # use iced::{widget::row, Element};
+//! # use iced_anim::widget::slider::{range_slider, vertical_slider};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     VolumeChanged(f32),
+//!     BandChanged((f32, f32)),
+//! }
+//!
+//! fn view<'a>(volume: f32, band: (f32, f32)) -> Element<'a, Message> {
+//!     row![
+//!         vertical_slider(0.0..=1.0, volume, Message::VolumeChanged).length(120),
+//!         range_slider(20.0..=20_000.0, band, Message::BandChanged).step(10.0),
+//!     ]
+//!     .into()
+//! }
+//! ```
+use std::ops::RangeInclusive;
+
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch, window, Border, Element, Event, Length, Point, Rectangle, Size,
+};
+
+use super::animated_state::{AnimatedState, Id};
+use crate::{AnimationId, AnimationStore, Spring, SpringMotion};
+
+// Re-export the widget types for convenience
+pub use iced::widget::slider::{
+    default, Catalog, Handle, HandleShape, Rail, Status, Style, StyleFn,
+};
+
+/// The direction along which a [`Slider`] moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Values increase from left to right.
+    Horizontal,
+    /// Values increase from bottom to top.
+    Vertical,
+}
+
+/// A slider with animated handles for picking a value or a range of values.
+#[allow(missing_debug_implementations)]
+pub struct Slider<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    /// The start and end of the selected values. Single value sliders start at the range start.
+    values: (f32, f32),
+    is_range: bool,
+    step: Option<f32>,
+    on_change: Box<dyn Fn((f32, f32)) -> Message + 'a>,
+    on_release: Option<Message>,
+    axis: Axis,
+    length: Length,
+    thickness: f32,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`Slider`].
+#[derive(Debug)]
+struct State {
+    /// The animated fractions of the range where the selected values start and end.
+    fractions: Spring<(f32, f32)>,
+    /// The index of the handle being dragged, if any.
+    dragging: Option<usize>,
+    animated_state: AnimatedState<Status, Style>,
+}
+
+impl<'a, Message, Theme> Slider<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default thickness of a [`Slider`].
+    pub const DEFAULT_THICKNESS: f32 = 16.0;
+
+    /// Creates a new horizontal [`Slider`] for a single `value` in the `range`.
+    pub fn new(
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: impl Fn(f32) -> Message + 'a,
+    ) -> Self {
+        let start = *range.start();
+        Self::with_values(range, (start, value), false, move |(_, value)| {
+            on_change(value)
+        })
+    }
+
+    /// Creates a new horizontal [`Slider`] for the range of `values` between `low` and `high`.
+    pub fn range(
+        range: RangeInclusive<f32>,
+        (low, high): (f32, f32),
+        on_change: impl Fn((f32, f32)) -> Message + 'a,
+    ) -> Self {
+        Self::with_values(range, (low.min(high), high.max(low)), true, on_change)
+    }
+
+    fn with_values(
+        range: RangeInclusive<f32>,
+        values: (f32, f32),
+        is_range: bool,
+        on_change: impl Fn((f32, f32)) -> Message + 'a,
+    ) -> Self {
+        let clamp = |value: f32| value.clamp(*range.start(), *range.end());
+        Self {
+            values: (clamp(values.0), clamp(values.1)),
+            range,
+            is_range,
+            step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            axis: Axis::Horizontal,
+            length: Length::Fill,
+            thickness: Self::DEFAULT_THICKNESS,
+            motion: SpringMotion::default(),
+            animation_id: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the step size of the [`Slider`]. Values are continuous by default.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = (step > 0.0).then_some(step);
+        self
+    }
+
+    /// Sets the message produced when the user stops dragging a handle.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the [`Axis`] of the [`Slider`].
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets the length of the [`Slider`] along its axis.
+    pub fn length(mut self, length: impl Into<Length>) -> Self {
+        self.length = length.into();
+        self
+    }
+
+    /// Sets the thickness of the [`Slider`] across its axis.
+    pub fn thickness(mut self, thickness: impl Into<iced::Pixels>) -> Self {
+        self.thickness = thickness.into().0;
+        self
+    }
+
+    /// Sets the style of the [`Slider`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Slider`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Slider`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// The fraction of the range covered by the given `value`.
+    fn fraction(&self, value: f32) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        if end > start {
+            (value - start) / (end - start)
+        } else {
+            0.0
+        }
+    }
+
+    /// The fractions of the range where the selected values start and end.
+    fn fractions(&self) -> (f32, f32) {
+        (self.fraction(self.values.0), self.fraction(self.values.1))
+    }
+
+    /// The value of the range at the `cursor` position within the `bounds`.
+    fn value_at(&self, cursor: Point, bounds: Rectangle) -> f32 {
+        let fraction = match self.axis {
+            Axis::Horizontal => (cursor.x - bounds.x) / bounds.width,
+            Axis::Vertical => 1.0 - (cursor.y - bounds.y) / bounds.height,
+        };
+
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let value = start + fraction.clamp(0.0, 1.0) * (end - start);
+        match self.step {
+            Some(step) => (start + ((value - start) / step).round() * step).clamp(start, end),
+            None => value,
+        }
+    }
+
+    /// The index of the handle closest to the given `value`.
+    fn closest_handle(&self, value: f32) -> usize {
+        if self.is_range && (value - self.values.0).abs() < (value - self.values.1).abs() {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Moves the handle at `index` to the `cursor` position, publishing any change.
+    fn drag(&self, index: usize, cursor: Point, bounds: Rectangle, shell: &mut Shell<'_, Message>) {
+        let value = self.value_at(cursor, bounds);
+        let values = match index {
+            0 => (value.min(self.values.1), self.values.1),
+            _ if self.is_range => (self.values.0, value.max(self.values.0)),
+            _ => (self.values.0, value),
+        };
+
+        if values != self.values {
+            shell.publish((self.on_change)(values));
+        }
+    }
+
+    /// The initial status that this widget will have based on its properties.
+    fn get_initial_status(&self) -> Status {
+        Status::Active
+    }
+
+    /// Gets the status of the [`Slider`] based on the current [`State`].
+    fn get_status(&self, state: &State, cursor: Cursor, layout: Layout<'_>) -> Status {
+        if state.dragging.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Slider<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            fractions: Spring::new(self.fractions()).with_motion(self.motion),
+            dragging: None,
+            animated_state: AnimatedState::new(self.get_initial_status(), self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.animated_state.diff(self.motion);
+        state.fractions.set_motion(self.motion);
+
+        let fractions = self.fractions();
+        if *state.fractions.target() != fractions {
+            state.fractions.interrupt(fractions);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        match self.axis {
+            Axis::Horizontal => Size::new(self.length, Length::Fixed(self.thickness)),
+            Axis::Vertical => Size::new(Length::Fixed(self.thickness), self.length),
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.size();
+        layout::atomic(limits, size.width, size.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                state.fractions.tick(now);
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match cursor.position_over(bounds) {
+                    Some(position) => {
+                        let index = self.closest_handle(self.value_at(position, bounds));
+                        state.dragging = Some(index);
+                        self.drag(index, position, bounds, shell);
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                match (state.dragging, cursor.position()) {
+                    (Some(index), Some(position)) => {
+                        self.drag(index, position, bounds, shell);
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.dragging.take().is_some() {
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        };
+
+        // Redraw anytime the status changes or the handles are still moving.
+        let state = tree.state.downcast_mut::<State>();
+        let status_changed = state
+            .animated_state
+            .needs_redraw(self.get_status(state, cursor, layout));
+        if status_changed || state.fractions.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let style = state
+            .animated_state
+            .current_style(|status| theme.style(&self.class, *status));
+
+        // The handle size along the axis and across it.
+        let (handle_length, handle_thickness, handle_radius) = match style.handle.shape {
+            HandleShape::Circle { radius } => (radius * 2.0, radius * 2.0, radius.into()),
+            HandleShape::Rectangle {
+                width,
+                border_radius,
+            } => (f32::from(width), self.thickness, border_radius),
+        };
+
+        // Handles travel along the rail without overflowing the bounds.
+        let (along, across, travel) = match self.axis {
+            Axis::Horizontal => (bounds.x, bounds.center_y(), bounds.width - handle_length),
+            Axis::Vertical => (bounds.y, bounds.center_x(), bounds.height - handle_length),
+        };
+        let offset = |fraction: f32| match self.axis {
+            Axis::Horizontal => along + fraction.clamp(0.0, 1.0) * travel,
+            Axis::Vertical => along + (1.0 - fraction.clamp(0.0, 1.0)) * travel,
+        };
+        let segment = |start: f32, end: f32, thickness: f32| match self.axis {
+            Axis::Horizontal => Rectangle {
+                x: start,
+                y: across - thickness / 2.0,
+                width: (end - start).max(0.0),
+                height: thickness,
+            },
+            Axis::Vertical => Rectangle {
+                x: across - thickness / 2.0,
+                y: start,
+                width: thickness,
+                height: (end - start).max(0.0),
+            },
+        };
+
+        let (low, high) = *state.fractions.value();
+        let handles: &[f32] = if self.is_range { &[low, high] } else { &[high] };
+
+        // The rail is filled between the handle centers, from the start of the rail when only
+        // a single value is selected.
+        let (rail_start, rail_end) = match self.axis {
+            Axis::Horizontal => (bounds.x, bounds.x + bounds.width),
+            Axis::Vertical => (bounds.y, bounds.y + bounds.height),
+        };
+        let (fill_start, fill_end) = {
+            let low = if self.is_range {
+                offset(low) + handle_length / 2.0
+            } else if self.axis == Axis::Horizontal {
+                rail_start
+            } else {
+                rail_end
+            };
+            let high = offset(high) + handle_length / 2.0;
+            (low.min(high), low.max(high))
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: segment(rail_start, rail_end, style.rail.width),
+                border: style.rail.border,
+                ..renderer::Quad::default()
+            },
+            style.rail.backgrounds.1,
+        );
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: segment(fill_start, fill_end, style.rail.width),
+                border: style.rail.border,
+                ..renderer::Quad::default()
+            },
+            style.rail.backgrounds.0,
+        );
+
+        for fraction in handles {
+            let start = offset(*fraction);
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: segment(start, start + handle_length, handle_thickness),
+                    border: Border {
+                        radius: handle_radius,
+                        width: style.handle.border_width,
+                        color: style.handle.border_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.handle.background,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Slider<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(slider: Slider<'a, Message, Theme>) -> Self {
+        Self::new(slider)
+    }
+}
+
+/// Creates a new horizontal [`Slider`] for a single `value` in the `range`.
+pub fn slider<'a, Message, Theme>(
+    range: RangeInclusive<f32>,
+    value: f32,
+    on_change: impl Fn(f32) -> Message + 'a,
+) -> Slider<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Slider::new(range, value, on_change)
+}
+
+/// Creates a new vertical [`Slider`] for a single `value` in the `range`.
+pub fn vertical_slider<'a, Message, Theme>(
+    range: RangeInclusive<f32>,
+    value: f32,
+    on_change: impl Fn(f32) -> Message + 'a,
+) -> Slider<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Slider::new(range, value, on_change).axis(Axis::Vertical)
+}
+
+/// Creates a new horizontal [`Slider`] with two handles selecting the `values` in the `range`.
+pub fn range_slider<'a, Message, Theme>(
+    range: RangeInclusive<f32>,
+    values: (f32, f32),
+    on_change: impl Fn((f32, f32)) -> Message + 'a,
+) -> Slider<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    Slider::range(range, values, on_change)
+}
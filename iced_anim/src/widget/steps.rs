@@ -0,0 +1,465 @@
+//! A progress indicator for the steps of a wizard, which springs forward as steps are completed.
+//!
+//! The current step is given as an integer and animated as a [`Step`] through the fractions
+//! between steps. The connector line fills progressively toward the current step, the markers
+//! of the steps fill as the line reaches them, and the ring around the active step springs along
+//! the line to its new marker.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::steps;
+//! # #[derive(Clone)] enum Message {}
+//! fn checkout<'a>(step: usize) -> Element<'a, Message> {
+//!     steps(["Cart", "Shipping", "Payment", "Review"], step).into()
+//! }
+//! ```
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Text},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::Cursor,
+    window, Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Size,
+};
+
+use crate::{animate::Step, Spring, SpringMotion};
+
+pub use iced::widget::text::{LineHeight, Shaping};
+
+/// A row of step markers joined by a line that fills up to the current step.
+#[allow(missing_debug_implementations)]
+pub struct Steps<'a, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    labels: Vec<String>,
+    current: usize,
+    width: Length,
+    marker_size: f32,
+    line_width: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Option<Renderer::Font>,
+    motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`Steps`] indicator.
+#[derive(Debug)]
+struct State {
+    /// The animated position of the active step.
+    step: Spring<Step>,
+}
+
+impl<'a, Theme, Renderer> Steps<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// Creates a new [`Steps`] indicator with a step for each of the `labels`, where the step
+    /// at the `current` index is active and the ones before it are complete.
+    pub fn new(labels: impl IntoIterator<Item = impl ToString>, current: usize) -> Self {
+        Self {
+            labels: labels.into_iter().map(|label| label.to_string()).collect(),
+            current,
+            width: Length::Fill,
+            marker_size: 24.0,
+            line_width: 4.0,
+            spacing: 6.0,
+            text_size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            motion: SpringMotion::Bouncy,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Steps`] indicator.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the diameter of the step markers.
+    pub fn marker_size(mut self, marker_size: f32) -> Self {
+        self.marker_size = marker_size;
+        self
+    }
+
+    /// Sets the thickness of the line connecting the step markers.
+    pub fn line_width(mut self, line_width: f32) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    /// Sets the space between the step markers and their labels.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the line height of the labels.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the labels.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the motion of the active step as it moves between steps.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the style of the [`Steps`] indicator.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Steps`] indicator.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The current step, limited to the steps that exist.
+    fn step(&self) -> Step {
+        Step::new(self.current.min(self.labels.len().saturating_sub(1)))
+    }
+
+    /// The height of the labels below the markers, if there are any labels to show.
+    fn label_height(&self, renderer: &Renderer) -> f32 {
+        if self.labels.iter().all(String::is_empty) {
+            return 0.0;
+        }
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        self.spacing + self.line_height.to_absolute(size).0
+    }
+
+    /// The horizontal center of the marker at a fractional `position` between steps.
+    fn marker_x(&self, bounds: Rectangle, position: f32) -> f32 {
+        // Markers are inset by the ring around the active step so it isn't cut off.
+        let inset = self.marker_size / 2.0 + self.ring_width();
+        match self.labels.len() {
+            0 | 1 => bounds.center_x(),
+            count => {
+                let gap = (bounds.width - inset * 2.0) / (count - 1) as f32;
+                bounds.x + inset + gap * position
+            }
+        }
+    }
+
+    /// The width of the ring around the active step.
+    fn ring_width(&self) -> f32 {
+        self.line_width / 2.0
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Steps<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            step: Spring::new(self.step()).with_motion(self.motion),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.step.set_motion(self.motion);
+
+        let step = self.step();
+        if *state.step.target() != step {
+            state.step.interrupt(step);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let height = self.marker_size + self.ring_width() * 2.0 + self.label_height(renderer);
+        layout::atomic(limits, self.width, height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.step.tick(now);
+        }
+
+        if state.step.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let last = self.labels.len().saturating_sub(1) as f32;
+        // Bouncy motions overshoot, but the line can't fill past its ends.
+        let position = state.step.value().position().clamp(0.0, last);
+        let radius = self.marker_size / 2.0;
+        let ring = self.ring_width();
+        let top = bounds.y + ring;
+        let center_y = top + radius;
+
+        if self.labels.len() > 1 {
+            let line = Rectangle {
+                x: self.marker_x(bounds, 0.0),
+                y: center_y - self.line_width / 2.0,
+                width: self.marker_x(bounds, last) - self.marker_x(bounds, 0.0),
+                height: self.line_width,
+            };
+            let rounded = Border::default().rounded(self.line_width / 2.0);
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: line,
+                    border: rounded,
+                    ..renderer::Quad::default()
+                },
+                style.line,
+            );
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: self.marker_x(bounds, position) - line.x,
+                        ..line
+                    },
+                    border: rounded,
+                    ..renderer::Quad::default()
+                },
+                style.line_filled,
+            );
+        }
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        for (index, label) in self.labels.iter().enumerate() {
+            let x = self.marker_x(bounds, index as f32);
+
+            // Markers fill in as the active step approaches them.
+            let completion = (position - index as f32 + 1.0).clamp(0.0, 1.0);
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - radius,
+                        y: top,
+                        width: self.marker_size,
+                        height: self.marker_size,
+                    },
+                    border: Border::default().rounded(radius),
+                    ..renderer::Quad::default()
+                },
+                mix(style.marker, style.marker_complete, completion),
+            );
+
+            if label.is_empty() {
+                continue;
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: label.clone(),
+                    bounds: Size::new(f32::INFINITY, bounds.height),
+                    size,
+                    line_height: self.line_height,
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: Shaping::Advanced,
+                    wrapping: core_text::Wrapping::None,
+                },
+                Point::new(x, top + self.marker_size + ring + self.spacing),
+                mix(style.label, style.label_complete, completion),
+                *viewport,
+            );
+        }
+
+        // The ring around the active step follows the animated position, overshoot included.
+        let active_x = self.marker_x(bounds, state.step.value().position());
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: active_x - radius - ring,
+                    y: top - ring,
+                    width: self.marker_size + ring * 2.0,
+                    height: self.marker_size + ring * 2.0,
+                },
+                border: Border {
+                    color: style.active,
+                    width: ring,
+                    radius: (radius + ring).into(),
+                },
+                ..renderer::Quad::default()
+            },
+            Color::TRANSPARENT,
+        );
+    }
+}
+
+/// Mixes the colors `from` and `to` by the given `amount`, from 0 to 1.
+fn mix(from: Color, to: Color, amount: f32) -> Color {
+    Color {
+        r: from.r + (to.r - from.r) * amount,
+        g: from.g + (to.g - from.g) * amount,
+        b: from.b + (to.b - from.b) * amount,
+        a: from.a + (to.a - from.a) * amount,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Steps<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(steps: Steps<'a, Theme, Renderer>) -> Self {
+        Self::new(steps)
+    }
+}
+
+/// Creates a new [`Steps`] indicator with a step for each of the `labels`, where the step at
+/// the `current` index is active and the ones before it are complete.
+pub fn steps<'a, Theme, Renderer>(
+    labels: impl IntoIterator<Item = impl ToString>,
+    current: usize,
+) -> Steps<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Steps::new(labels, current)
+}
+
+/// The appearance of a [`Steps`] indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color of the connector line.
+    pub line: Color,
+    /// The color of the part of the connector line up to the active step.
+    pub line_filled: Color,
+    /// The color of the markers of upcoming steps.
+    pub marker: Color,
+    /// The color of the markers of completed and active steps.
+    pub marker_complete: Color,
+    /// The color of the ring around the active step.
+    pub active: Color,
+    /// The color of the labels of upcoming steps.
+    pub label: Color,
+    /// The color of the labels of completed and active steps.
+    pub label_complete: Color,
+}
+
+/// The theme catalog of a [`Steps`] indicator.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Steps`] indicator.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Steps`] indicator, which fills completed steps in the primary color.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        line: palette.background.strong.color,
+        line_filled: palette.primary.base.color,
+        marker: palette.background.strong.color,
+        marker_complete: palette.primary.base.color,
+        active: palette.primary.strong.color,
+        label: palette.background.strong.text.scale_alpha(0.6),
+        label_complete: palette.background.base.text,
+    }
+}
+
+/// A style that fills completed steps in the success color, like a finished checkout.
+pub fn success(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        line_filled: palette.success.base.color,
+        marker_complete: palette.success.base.color,
+        active: palette.success.strong.color,
+        ..default(theme)
+    }
+}
@@ -1,6 +1,6 @@
 //! Svg widgets display vector graphics in your application.
-use super::AnimatedState;
-use crate::SpringMotion;
+use super::{AnimatedState, Id};
+use crate::{AnimationId, AnimationStore, SpringMotion};
 use iced::advanced::{
     layout, renderer,
     widget::{tree, Tree},
@@ -34,6 +34,7 @@ where
     rotation: Rotation,
     opacity: f32,
     motion: SpringMotion,
+    animation_id: Option<AnimationId>,
 }
 
 #[derive(Debug)]
@@ -56,6 +57,7 @@ where
             rotation: Rotation::default(),
             opacity: 1.0,
             motion: SpringMotion::default(),
+            animation_id: None,
         }
     }
 
@@ -129,6 +131,19 @@ where
         self
     }
 
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Svg`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
     /// The initial status that this widget will have based on its properties.
     ///
     /// This will be used as the initial state value.
@@ -159,7 +174,8 @@ where
     fn state(&self) -> tree::State {
         let status = self.get_initial_status();
         let state = State {
-            animated_state: AnimatedState::new(status, self.motion),
+            animated_state: AnimatedState::new(status, self.motion)
+                .with_animation_id(self.animation_id.clone()),
         };
 
         tree::State::new(state)
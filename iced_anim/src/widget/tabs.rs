@@ -0,0 +1,833 @@
+//! Tab bars switch between views with a row of labeled tabs.
+//!
+//! The indicator under the selected tab slides and resizes to the next tab with a spring, and
+//! each label takes on the selected color as the indicator passes under it.
+//!
+//! Tabs that don't fit in the bar overflow and can be scrolled horizontally with the mouse wheel,
+//! a touchpad, or by dragging them. Drags fling the tabs with momentum when they're released, and
+//! scrolling past either end stretches like a rubber band before springing back. The edges fade
+//! out whenever there are more tabs to scroll to in that direction, and selecting a tab that's
+//! partly hidden scrolls it into view.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::tabs;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     FileSelected(String),
+//! }
+//!
+//! fn files<'a>(files: &[String], open: &str) -> Element<'a, Message> {
+//!     tabs(files.iter().cloned(), Some(open.to_string()), Message::FileSelected).into()
+//! }
+//! ```
+//!
+//! The edges fade to the background of the tab bar, so the fade is only visible with an opaque
+//! background.
+use std::{
+    f32::consts::FRAC_PI_2,
+    time::{Duration, Instant},
+};
+
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event, gradient,
+    mouse::{self, Cursor, ScrollDelta},
+    touch, window, Border, Color, Element, Event, Length, Padding, Pixels, Radians, Rectangle,
+    Size,
+};
+
+use crate::{Animate, Spring, SpringMotion};
+
+pub use iced::widget::text::{LineHeight, Shaping};
+
+/// The height of the indicator under the selected tab.
+const INDICATOR_HEIGHT: f32 = 2.0;
+
+/// The width of the fade at each edge of the tab bar when there are more tabs to scroll to.
+const FADE_WIDTH: f32 = 32.0;
+
+/// The number of pixels scrolled by a single line of scrolling.
+const PIXELS_PER_LINE: f32 = 60.0;
+
+/// How far the cursor has to move while pressed before the tabs are dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// How far ahead the velocity of a fling is projected to pick where the tabs come to rest.
+const FLING_PROJECTION: Duration = Duration::from_millis(300);
+
+/// How quickly the measured drag velocity follows the latest movement, from 0 to 1.
+const VELOCITY_SMOOTHING: f32 = 0.4;
+
+/// Drags that stopped moving for longer than this before being released don't fling the tabs.
+const FLING_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// How stiff the rubber band is when scrolling past either end, where smaller is stiffer.
+const RUBBER_BAND: f32 = 0.55;
+
+/// How hard scrolling past either end with the wheel kicks the tabs, per pixel scrolled past it.
+const EDGE_KICK: f32 = 8.0;
+
+/// A row of tabs where the indicator under the selected tab slides between them.
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    options: Vec<T>,
+    labels: Vec<String>,
+    selected: Option<usize>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    width: Length,
+    padding: Padding,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Option<Renderer::Font>,
+    motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`Tabs`] bar.
+struct State<P: Paragraph> {
+    /// The laid out label of each tab.
+    paragraphs: Vec<P>,
+    /// The width of each tab.
+    widths: Vec<f32>,
+    /// The width of the bar from the latest layout.
+    viewport: f32,
+    /// The animated distance the tabs are scrolled from the start.
+    scroll: Spring<f32>,
+    /// The animated position and width of the indicator from the start of the tabs.
+    indicator: Spring<(f32, f32)>,
+    /// How visible the fades at the start and end of the bar are.
+    fades: Spring<(f32, f32)>,
+    /// The tab that was selected during the latest layout.
+    selected: Option<usize>,
+    /// Whether the tabs have been laid out yet.
+    is_laid_out: bool,
+    /// The tab being pressed, if any.
+    pressed: Option<usize>,
+    /// The press or drag in progress, if any.
+    drag: Option<Drag>,
+}
+
+/// A press on a [`Tabs`] bar, which scrolls the tabs once it moves far enough.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// Where the press started.
+    origin: f32,
+    /// The scroll position when the press started.
+    start: f32,
+    /// The latest position of the cursor.
+    position: f32,
+    /// When the cursor last moved.
+    moved_at: Instant,
+    /// The smoothed velocity of the scroll position in pixels per second.
+    velocity: f32,
+    /// Whether the press moved far enough to scroll the tabs.
+    is_dragging: bool,
+}
+
+impl<P: Paragraph> State<P> {
+    /// The width of all the tabs together.
+    fn content(&self) -> f32 {
+        self.widths.iter().sum()
+    }
+
+    /// The farthest the tabs can be scrolled.
+    fn max_scroll(&self) -> f32 {
+        (self.content() - self.viewport).max(0.0)
+    }
+
+    /// The distance from the start of the tabs to the tab at `index`.
+    fn tab_x(&self, index: usize) -> f32 {
+        self.widths[..index].iter().sum()
+    }
+
+    /// The index of the tab at the distance `x` from the start of the tabs, if any.
+    fn tab_at(&self, x: f32) -> Option<usize> {
+        let mut start = 0.0;
+        self.widths.iter().position(|width| {
+            let end = start + width;
+            let is_inside = x >= start && x < end;
+            start = end;
+            is_inside
+        })
+    }
+
+    /// Scrolls the tab at `index` into view, clear of the fades, if it's partly hidden.
+    fn reveal(&mut self, index: usize, animate: bool) {
+        let Some(width) = self.widths.get(index) else {
+            return;
+        };
+
+        let x = self.tab_x(index);
+        let current = *self.scroll.target();
+        let target = if x - FADE_WIDTH < current {
+            x - FADE_WIDTH
+        } else if x + width + FADE_WIDTH > current + self.viewport {
+            x + width + FADE_WIDTH - self.viewport
+        } else {
+            current
+        }
+        .clamp(0.0, self.max_scroll());
+
+        if !animate {
+            self.scroll.settle_at(target);
+        } else if target != current {
+            self.scroll.interrupt(target);
+        }
+    }
+
+    /// Scrolls the tabs by `delta`, kicking them past the end when scrolling beyond it.
+    fn scroll_by(&mut self, delta: f32) {
+        let max = self.max_scroll();
+        let target = *self.scroll.target() + delta;
+        let clamped = target.clamp(0.0, max);
+        self.scroll.interrupt(clamped);
+
+        let excess = target - clamped;
+        if excess != 0.0 {
+            self.scroll.impulse(vec![excess * EDGE_KICK]);
+        }
+    }
+
+    /// Ends the drag at `now`, flinging the tabs with the velocity of the drag.
+    fn release(&mut self, now: Instant) {
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+        if !drag.is_dragging {
+            return;
+        }
+
+        let max = self.max_scroll();
+        let scroll = *self.scroll.value();
+        let is_flung = now.saturating_duration_since(drag.moved_at) <= FLING_TIMEOUT;
+        let velocity = if is_flung { drag.velocity } else { 0.0 };
+        let target = (scroll + velocity * FLING_PROJECTION.as_secs_f32()).clamp(0.0, max);
+
+        self.scroll.interrupt(target);
+        if velocity != 0.0 {
+            self.scroll.impulse(vec![velocity]);
+        }
+    }
+
+    /// Fades each edge in while there are more tabs to scroll to past it.
+    fn update_fades(&mut self) {
+        let scroll = *self.scroll.value();
+        let start = if scroll > 0.5 { 1.0 } else { 0.0 };
+        let end = if scroll < self.max_scroll() - 0.5 {
+            1.0
+        } else {
+            0.0
+        };
+
+        if *self.fades.target() != (start, end) {
+            self.fades.interrupt((start, end));
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Tabs<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// The default padding of each tab.
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 10.0,
+        bottom: 10.0,
+        left: 16.0,
+        right: 16.0,
+    };
+
+    /// Creates a new [`Tabs`] bar with the given `options`, selecting the `selected` option and
+    /// producing `on_select` when a tab is picked.
+    pub fn new(
+        options: impl IntoIterator<Item = T>,
+        selected: Option<T>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        let options: Vec<T> = options.into_iter().collect();
+        let labels = options.iter().map(ToString::to_string).collect();
+        let selected = selected.and_then(|selected| options.iter().position(|o| *o == selected));
+
+        Self {
+            options,
+            labels,
+            selected,
+            on_select: Box::new(on_select),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            motion: SpringMotion::Snappy,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the width of the [`Tabs`] bar. Tabs that don't fit can be scrolled to.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of each tab.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the line height of the labels.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the labels.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the motion of the indicator and of scrolling.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the style of the [`Tabs`] bar.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tabs`] bar.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The text to lay out for the given `label`.
+    fn text<'b>(
+        &self,
+        label: &'b str,
+        renderer: &Renderer,
+    ) -> core_text::Text<&'b str, Renderer::Font> {
+        core_text::Text {
+            content: label,
+            bounds: Size::INFINITY,
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: core_text::Wrapping::None,
+        }
+    }
+
+    /// The index of the tab under the `cursor` within the `bounds` of the bar, if any.
+    fn tab_under(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<usize> {
+        let position = cursor.position_over(bounds)?;
+        state.tab_at(position.x - bounds.x + *state.scroll.value())
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabs<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            paragraphs: Vec::new(),
+            widths: Vec::new(),
+            viewport: 0.0,
+            scroll: Spring::new(0.0).with_motion(self.motion),
+            indicator: Spring::new((0.0, 0.0)).with_motion(self.motion),
+            fades: Spring::new((0.0, 0.0)).with_motion(self.motion),
+            selected: self.selected,
+            is_laid_out: false,
+            pressed: None,
+            drag: None,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.scroll.set_motion(self.motion);
+        state.indicator.set_motion(self.motion);
+        state.fades.set_motion(self.motion);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.paragraphs = self
+            .labels
+            .iter()
+            .map(|label| Renderer::Paragraph::with_text(self.text(label, renderer)))
+            .collect();
+        state.widths = state
+            .paragraphs
+            .iter()
+            .map(|paragraph| paragraph.min_width() + self.padding.horizontal())
+            .collect();
+
+        let label_height = state
+            .paragraphs
+            .iter()
+            .map(|paragraph| paragraph.min_height())
+            .fold(0.0, f32::max);
+        let intrinsic = Size::new(state.content(), label_height + self.padding.vertical());
+        let size = limits.width(self.width).height(Length::Shrink).resolve(
+            self.width,
+            Length::Shrink,
+            intrinsic,
+        );
+        state.viewport = size.width;
+
+        let indicator = match self.selected {
+            Some(selected) => (state.tab_x(selected), state.widths[selected]),
+            // Shrink into the middle when nothing is selected.
+            None => {
+                let (x, width) = *state.indicator.target();
+                (x + width / 2.0, 0.0)
+            }
+        };
+
+        if !state.is_laid_out {
+            state.is_laid_out = true;
+            state.indicator.settle_at(indicator);
+            if let Some(selected) = self.selected {
+                state.reveal(selected, false);
+            }
+        } else {
+            if *state.indicator.target() != indicator {
+                state.indicator.interrupt(indicator);
+            }
+            if state.selected != self.selected {
+                if let Some(selected) = self.selected {
+                    state.reveal(selected, true);
+                }
+            }
+
+            // Keep the tabs in range when the bar grows or tabs are removed.
+            let max = state.max_scroll();
+            if state.drag.is_none() && *state.scroll.target() > max {
+                state.scroll.interrupt(max);
+            }
+        }
+
+        state.selected = self.selected;
+        state.update_fades();
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.scroll.tick(now);
+                state.indicator.tick(now);
+                state.fades.tick(now);
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(bounds) || state.max_scroll() <= 0.0 {
+                    return event::Status::Ignored;
+                }
+
+                // Vertical wheels scroll the tabs too, since most mice only have those.
+                let (x, y) = match delta {
+                    ScrollDelta::Lines { x, y } => (x * PIXELS_PER_LINE, y * PIXELS_PER_LINE),
+                    ScrollDelta::Pixels { x, y } => (x, y),
+                };
+                state.scroll_by(-if x != 0.0 { x } else { y });
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                state.pressed = self.tab_under(state, bounds, cursor);
+                state.drag = Some(Drag {
+                    origin: position.x,
+                    start: *state.scroll.value(),
+                    position: position.x,
+                    moved_at: Instant::now(),
+                    velocity: 0.0,
+                    is_dragging: false,
+                });
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(mut drag) = state.drag else {
+                    return event::Status::Ignored;
+                };
+
+                let max = state.max_scroll();
+                if !drag.is_dragging {
+                    if (position.x - drag.origin).abs() < DRAG_THRESHOLD || max <= 0.0 {
+                        return event::Status::Ignored;
+                    }
+                    drag.is_dragging = true;
+                    state.pressed = None;
+                }
+
+                let now = Instant::now();
+                let elapsed = now.saturating_duration_since(drag.moved_at).as_secs_f32();
+                if elapsed > 0.0 {
+                    let velocity = (drag.position - position.x) / elapsed;
+                    drag.velocity += (velocity - drag.velocity) * VELOCITY_SMOOTHING;
+                }
+                drag.position = position.x;
+                drag.moved_at = now;
+                state.drag = Some(drag);
+
+                let scroll = drag.start + drag.origin - position.x;
+                state
+                    .scroll
+                    .settle_at(rubber_band(scroll, max, state.viewport));
+                state.update_fades();
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if state.drag.is_none() {
+                    return event::Status::Ignored;
+                }
+                state.release(Instant::now());
+
+                // Only select the tab if the press ends where it started.
+                match state.pressed.take() {
+                    Some(pressed) if self.tab_under(state, bounds, cursor) == Some(pressed) => {
+                        if self.selected != Some(pressed) {
+                            shell.publish((self.on_select)(self.options[pressed].clone()));
+                        }
+                    }
+                    _ => {}
+                }
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+                state.release(Instant::now());
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        };
+
+        state.update_fades();
+        if state.scroll.has_energy() || state.indicator.has_energy() || state.fades.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let scroll = *state.scroll.value();
+        let (indicator_x, indicator_width) = *state.indicator.value();
+        let is_dragging = state.drag.is_some_and(|drag| drag.is_dragging);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: bounds.y + bounds.height - 1.0,
+                    height: 1.0,
+                    ..bounds
+                },
+                ..renderer::Quad::default()
+            },
+            style.divider,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            let hovered = (!is_dragging)
+                .then(|| self.tab_under(state, bounds, cursor))
+                .flatten();
+
+            let mut x = bounds.x - scroll;
+            for (index, (paragraph, width)) in
+                state.paragraphs.iter().zip(&state.widths).enumerate()
+            {
+                let tab = Rectangle {
+                    x,
+                    width: *width,
+                    ..bounds
+                };
+                x += width;
+                if tab.x + tab.width < bounds.x || tab.x > bounds.x + bounds.width {
+                    continue;
+                }
+
+                if hovered == Some(index) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: tab,
+                            border: Border::default().rounded(4.0),
+                            ..renderer::Quad::default()
+                        },
+                        style.hovered_tab,
+                    );
+                }
+
+                // Labels take on the selected color as the indicator passes under them.
+                let start = tab.x - bounds.x + scroll;
+                let overlap =
+                    (start + tab.width).min(indicator_x + indicator_width) - start.max(indicator_x);
+                let coverage = (overlap / tab.width).clamp(0.0, 1.0);
+                let mut color = style.text;
+                let mut components = style
+                    .selected_text
+                    .distance_to(&style.text)
+                    .into_iter()
+                    .map(|distance| distance * coverage);
+                color.update(&mut components);
+
+                renderer.fill_paragraph(paragraph, tab.center(), color, *viewport);
+            }
+
+            if indicator_width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + indicator_x - scroll,
+                            y: bounds.y + bounds.height - INDICATOR_HEIGHT,
+                            width: indicator_width,
+                            height: INDICATOR_HEIGHT,
+                        },
+                        border: Border::default().rounded(INDICATOR_HEIGHT / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style.indicator,
+                );
+            }
+        });
+
+        // Fade out the edges when there are more tabs to scroll to.
+        let (start, end) = *state.fades.value();
+        let fade = |renderer: &mut Renderer, x: f32, from: f32, to: f32| {
+            let color = |alpha: f32| Color {
+                a: style.background.a * alpha.clamp(0.0, 1.0),
+                ..style.background
+            };
+            let gradient = gradient::Linear::new(Radians(FRAC_PI_2))
+                .add_stop(0.0, color(from))
+                .add_stop(1.0, color(to));
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x,
+                        width: FADE_WIDTH.min(bounds.width / 2.0),
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                gradient,
+            );
+        };
+        if start > 0.0 {
+            fade(renderer, bounds.x, start, 0.0);
+        }
+        if end > 0.0 {
+            let width = FADE_WIDTH.min(bounds.width / 2.0);
+            fade(renderer, bounds.x + bounds.width - width, 0.0, end);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            mouse::Interaction::Grabbing
+        } else if self.tab_under(state, layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Stretches a scroll position past either end of the range from 0 to `max` like a rubber band,
+/// resisting more the farther it's pulled relative to the `dimension` of the bar.
+fn rubber_band(scroll: f32, max: f32, dimension: f32) -> f32 {
+    let band = |excess: f32| {
+        let dimension = dimension.max(1.0);
+        (1.0 - 1.0 / (excess * RUBBER_BAND / dimension + 1.0)) * dimension
+    };
+
+    if scroll < 0.0 {
+        -band(-scroll)
+    } else if scroll > max {
+        max + band(scroll - max)
+    } else {
+        scroll
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Tabs<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(tabs: Tabs<'a, T, Message, Theme, Renderer>) -> Self {
+        Self::new(tabs)
+    }
+}
+
+/// Creates a new [`Tabs`] bar with the given `options`, selecting the `selected` option and
+/// producing `on_select` when a tab is picked.
+pub fn tabs<'a, T, Message, Theme, Renderer>(
+    options: impl IntoIterator<Item = T>,
+    selected: Option<T>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> Tabs<'a, T, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Tabs::new(options, selected, on_select)
+}
+
+/// The appearance of a [`Tabs`] bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background color of the bar, which the edges fade to.
+    pub background: Color,
+    /// The color of the line along the bottom of the bar.
+    pub divider: Color,
+    /// The color of the indicator under the selected tab.
+    pub indicator: Color,
+    /// The color of the highlight behind a hovered tab.
+    pub hovered_tab: Color,
+    /// The color of the labels of unselected tabs.
+    pub text: Color,
+    /// The color of the label of the selected tab.
+    pub selected_text: Color,
+}
+
+/// The theme catalog of a [`Tabs`] bar.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Tabs`] bar.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Tabs`] bar, with a primary indicator on the background.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+    Style {
+        background: palette.background.base.color,
+        divider: palette.background.strong.color,
+        indicator: palette.primary.base.color,
+        hovered_tab: palette.background.weak.color,
+        text: palette.background.base.text.scale_alpha(0.7),
+        selected_text: palette.primary.strong.color,
+    }
+}
@@ -0,0 +1,405 @@
+//! Text widgets display information through writing.
+//!
+//! This animated text will transition between colors and sizes, and can optionally animate its
+//! layout size when its content changes so that surrounding elements don't jump around.
+//!
+//! Changing the size of the text animates the font size and updates the layout as it grows or
+//! shrinks. Font weights can't be interpolated since the text renderer only supports the discrete
+//! weights of the loaded fonts, so changes to the font are applied immediately.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::text;
+//! # #[derive(Clone)] enum Message {}
+//! fn play_label<'a>(is_playing: bool) -> Element<'a, Message> {
+//!     text(if is_playing { "Pause" } else { "Play" })
+//!         .animates_layout(true)
+//!         .into()
+//! }
+//! ```
+use std::time::Duration;
+
+use super::{AnimatedState, Id};
+use crate::{animate::FadingColor, AnimationId, AnimationStore, Spring, SpringMotion};
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Fragment, IntoFragment, Paragraph},
+        widget::{self, tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    window, Color, Element, Event, Length, Pixels, Rectangle, Size,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::text::{
+    danger, primary, secondary, success, Catalog, LineHeight, Shaping, Style, StyleFn, Wrapping,
+};
+
+/// A bunch of text whose color, size, and layout size can be animated.
+pub struct Text<'a, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fragment: Fragment<'a>,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    font: Option<Renderer::Font>,
+    shaping: Shaping,
+    wrapping: Wrapping,
+    class: Theme::Class<'a>,
+    motion: SpringMotion,
+    animation_id: Option<AnimationId>,
+    delay: Duration,
+    animates_layout: bool,
+}
+
+/// The internal state of a [`Text`] widget.
+struct State<P: Paragraph> {
+    /// The state of the underlying text paragraph.
+    text: widget::text::State<P>,
+    /// The animated layout size, which is lazily created on the first layout.
+    size: Option<Spring<Size>>,
+    /// The animated font size, which is lazily created on the first layout.
+    font_size: Option<Spring<f32>>,
+    /// The animated text color, which fades into the default text color while it's `None`.
+    animated_state: AnimatedState<(), FadingColor>,
+}
+
+impl<'a, Theme, Renderer> Text<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// Creates a new [`Text`] with the given contents.
+    pub fn new(fragment: impl IntoFragment<'a>) -> Self {
+        Self {
+            fragment: fragment.into_fragment(),
+            size: None,
+            line_height: LineHeight::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            font: None,
+            shaping: Shaping::default(),
+            wrapping: Wrapping::default(),
+            class: Theme::default(),
+            motion: SpringMotion::default(),
+            animation_id: None,
+            delay: Duration::ZERO,
+            animates_layout: false,
+        }
+    }
+
+    /// Sets the size of the [`Text`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Text`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the [`Text`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the width of the [`Text`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Text`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Centers the [`Text`], both horizontally and vertically.
+    pub fn center(self) -> Self {
+        self.align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center)
+    }
+
+    /// Sets the [`alignment::Horizontal`] of the [`Text`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.horizontal_alignment = alignment.into();
+        self
+    }
+
+    /// Sets the [`alignment::Vertical`] of the [`Text`].
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.vertical_alignment = alignment.into();
+        self
+    }
+
+    /// Sets the [`Shaping`] strategy of the [`Text`].
+    pub fn shaping(mut self, shaping: Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// Sets the [`Wrapping`] strategy of the [`Text`].
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the style of the [`Text`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Text`].
+    pub fn color(self, color: impl Into<Color>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.color_maybe(Some(color))
+    }
+
+    /// Sets the [`Color`] of the [`Text`], if `Some`.
+    pub fn color_maybe(self, color: Option<impl Into<Color>>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        let color = color.map(Into::into);
+        self.style(move |_theme| Style { color })
+    }
+
+    /// Sets the style class of the [`Text`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`Text`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// Sets how long changes to the style and font size wait before animating, which staggers
+    /// several texts changing at once.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Whether the size reported to the layout should animate when the content changes.
+    ///
+    /// This is set to `false` by default, but you may want to set this to `true` for labels
+    /// whose content changes length (e.g. "Play" and "Pause") to avoid surrounding elements
+    /// jumping to their new positions.
+    pub fn animates_layout(mut self, animates_layout: bool) -> Self {
+        self.animates_layout = animates_layout;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Text<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let mut animated_state =
+            AnimatedState::new((), self.motion).with_animation_id(self.animation_id.clone());
+        animated_state.set_delay(self.delay);
+        tree::State::new(State::<Renderer::Paragraph> {
+            text: Default::default(),
+            size: None,
+            font_size: None,
+            animated_state,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.motion);
+        state.animated_state.set_delay(self.delay);
+        if let Some(size) = state.size.as_mut() {
+            size.set_motion(self.motion);
+        }
+        if let Some(font_size) = state.font_size.as_mut() {
+            font_size.set_motion(self.motion);
+            font_size.set_delay(self.delay);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        // Animate toward the font size given to the text, or the renderer's default size.
+        let font_size = self.size.unwrap_or_else(|| renderer.default_size()).0;
+        let animated_font_size = state.font_size.get_or_insert_with(|| {
+            Spring::new(font_size)
+                .with_motion(self.motion)
+                .with_delay(self.delay)
+        });
+        if *animated_font_size.latest_target() != font_size {
+            animated_font_size.interrupt(font_size);
+        }
+        let font_size = Pixels(*animated_font_size.value());
+
+        let node = widget::text::layout(
+            &mut state.text,
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            &self.fragment,
+            self.line_height,
+            Some(font_size),
+            self.font,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            self.shaping,
+            self.wrapping,
+        );
+
+        if !self.animates_layout {
+            return node;
+        }
+
+        // Animate toward the size that the text would normally take up.
+        let size = node.size();
+        let animated_size = state
+            .size
+            .get_or_insert_with(|| Spring::new(size).with_motion(self.motion));
+        if animated_size.target() != &size {
+            animated_size.interrupt(size);
+        }
+
+        layout::Node::new(*animated_size.value())
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let size_has_energy = state.size.as_ref().is_some_and(Spring::has_energy)
+            || state.font_size.as_ref().is_some_and(Spring::has_energy);
+
+        if state.animated_state.needs_redraw(()) || size_has_energy {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.animated_state.tick(now);
+            if let Some(size) = state.size.as_mut().filter(|size| size.has_energy()) {
+                size.tick(now);
+                shell.invalidate_layout();
+            }
+            if let Some(font_size) = state.font_size.as_mut().filter(|size| size.has_energy()) {
+                font_size.tick(now);
+                shell.invalidate_layout();
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let color = state.animated_state.current_style(|_| {
+            // Hidden colors start from the default color so that setting an explicit color
+            // fades from it rather than from transparent black.
+            match theme.style(&self.class).color {
+                Some(color) => FadingColor::from(color),
+                None => FadingColor::hidden(defaults.text_color),
+            }
+        });
+        let style = Style {
+            color: Some(color.over(defaults.text_color)),
+        };
+
+        widget::text::draw(renderer, defaults, layout, &state.text, style, viewport);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Text<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(text: Text<'a, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(text)
+    }
+}
+
+/// Creates a new [`Text`] widget with the provided content.
+pub fn text<'a, Theme, Renderer>(text: impl IntoFragment<'a>) -> Text<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Text::new(text)
+}
@@ -0,0 +1,942 @@
+//! Text inputs let users type a single line of text.
+//!
+//! The caret of an animated text input glides between positions on a quick spring while typing
+//! or moving with the arrow keys, and selections grow and shrink smoothly from the caret as they
+//! are extended with the mouse or keyboard. The style animates between the active, hovered,
+//! focused, and disabled states.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::text_input;
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     NameChanged(String),
+//!     Submit,
+//! }
+//!
+//! fn name<'a>(name: &str) -> Element<'a, Message> {
+//!     text_input("Your name", name)
+//!         .on_input(Message::NameChanged)
+//!         .on_submit(Message::Submit)
+//!         .into()
+//! }
+//! ```
+//!
+//! [`text_field`] creates a material-style variant where the placeholder acts as a label. The
+//! label rests inside the field like a placeholder, then floats up and shrinks above the value
+//! once the field is focused or filled in, taking on the color of the border while focused.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::widget::text_input::text_field;
+//! # #[derive(Debug, Clone)] enum Message { EmailChanged(String) }
+//! fn email<'a>(email: &str) -> Element<'a, Message> {
+//!     text_field("Email", email).on_input(Message::EmailChanged).into()
+//! }
+//! ```
+//!
+//! Positions within the value are measured in characters, so clusters made of several
+//! characters, like some emoji, take several presses of the arrow keys to move across.
+use std::time::{Duration, Instant};
+
+use iced::{
+    advanced::{
+        clipboard, layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event, keyboard,
+    mouse::{self, Cursor},
+    touch, window, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size, Transformation,
+};
+
+use super::animated_state::{AnimatedState, Id};
+use crate::{Animate, AnimationId, AnimationStore, Spring, SpringMotion};
+
+// Re-export the widget types for convenience
+pub use iced::widget::text::{LineHeight, Shaping};
+pub use iced::widget::text_input::{default, Catalog, Status, Style, StyleFn};
+
+/// How long the caret stays visible or hidden while blinking.
+const CARET_BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The width of the caret.
+const CARET_WIDTH: f32 = 1.0;
+
+/// The scale of a floating label once it has floated above the value.
+const FLOATED_LABEL_SCALE: f32 = 0.75;
+
+/// A single line text input whose caret and selection animate between positions.
+#[allow(missing_debug_implementations)]
+pub struct TextInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    placeholder: String,
+    value: String,
+    on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_submit: Option<Message>,
+    font: Option<Renderer::Font>,
+    width: Length,
+    padding: Padding,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    motion: SpringMotion,
+    disabled_motion: Option<SpringMotion>,
+    animation_id: Option<AnimationId>,
+    caret_motion: SpringMotion,
+    floating_label: bool,
+    class: Theme::Class<'a>,
+}
+
+/// The range of characters selected in a [`TextInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Selection {
+    /// Where the selection started, which stays put while the selection is extended.
+    anchor: usize,
+    /// Where the caret is, which moves as the selection is extended.
+    head: usize,
+}
+
+impl Selection {
+    /// A selection without any characters, with the caret at the `index`.
+    fn caret(index: usize) -> Self {
+        Self {
+            anchor: index,
+            head: index,
+        }
+    }
+
+    /// The start and end of the selected characters.
+    fn range(self) -> (usize, usize) {
+        (self.anchor.min(self.head), self.anchor.max(self.head))
+    }
+
+    fn is_empty(self) -> bool {
+        self.anchor == self.head
+    }
+
+    /// Moves the caret to the `index`, extending the selection if `extend` is true.
+    fn move_to(self, index: usize, extend: bool) -> Self {
+        if extend {
+            Self {
+                head: index,
+                ..self
+            }
+        } else {
+            Self::caret(index)
+        }
+    }
+}
+
+/// The focus of a [`TextInput`], used to blink the caret.
+#[derive(Debug, Clone, Copy)]
+struct Focus {
+    /// When the caret last moved, which restarts the blinking.
+    updated_at: Instant,
+    /// The time of the latest redraw.
+    now: Instant,
+}
+
+impl Focus {
+    fn new(now: Instant) -> Self {
+        Self {
+            updated_at: now,
+            now,
+        }
+    }
+
+    /// Whether the caret is in the visible half of its blink.
+    fn is_caret_visible(&self) -> bool {
+        let elapsed = self.now.saturating_duration_since(self.updated_at);
+        (elapsed.as_millis() / CARET_BLINK_INTERVAL.as_millis()) % 2 == 0
+    }
+
+    /// When the caret should next appear or disappear.
+    fn next_blink(&self) -> Instant {
+        let elapsed = self
+            .now
+            .saturating_duration_since(self.updated_at)
+            .as_millis();
+        let interval = CARET_BLINK_INTERVAL.as_millis();
+        let remaining = interval - elapsed % interval;
+        self.now + Duration::from_millis(remaining as u64)
+    }
+}
+
+/// The internal state of a [`TextInput`].
+struct State<P: Paragraph> {
+    /// The laid out value, used to measure caret positions.
+    value: P,
+    /// The laid out placeholder, shown while the value is empty.
+    placeholder: P,
+    /// The number of characters in the latest value.
+    len: usize,
+    selection: Selection,
+    focus: Option<Focus>,
+    is_dragging: bool,
+    modifiers: keyboard::Modifiers,
+    /// The animated horizontal positions of the selection's anchor and head, relative to the
+    /// start of the text. The caret is drawn at the head when the selection is empty.
+    caret: Spring<(f32, f32)>,
+    /// How far a floating label has floated above the value, and how much it is highlighted
+    /// by the focus, from 0 to 1.
+    label: Spring<(f32, f32)>,
+    animated_state: AnimatedState<Status, Style>,
+}
+
+impl<P: Paragraph> State<P> {
+    /// The horizontal position of the caret when placed before the character at the `index`.
+    fn offset_of(&self, index: usize) -> f32 {
+        if index >= self.len {
+            return self.value.min_width();
+        }
+
+        self.value
+            .grapheme_position(0, index)
+            .map_or(0.0, |position| position.x)
+    }
+
+    /// Animates the caret toward the current selection.
+    fn update_caret(&mut self) {
+        let Selection { anchor, head } = self.selection;
+        let target = (self.offset_of(anchor), self.offset_of(head));
+        if *self.caret.target() != target {
+            self.caret.interrupt(target);
+        }
+    }
+
+    /// Moves the selection, restarting the caret's blink so it stays visible while moving.
+    fn select(&mut self, selection: Selection) {
+        self.selection = selection;
+        self.update_caret();
+        if let Some(focus) = self.focus.as_mut() {
+            focus.updated_at = focus.now.max(Instant::now());
+        }
+    }
+
+    /// How far the text is scrolled so that the caret stays within the visible `width`.
+    fn scroll_offset(&self, width: f32) -> f32 {
+        if self.focus.is_none() {
+            return 0.0;
+        }
+
+        let (_, head) = *self.caret.value();
+        (head + CARET_WIDTH - width).max(0.0)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> TextInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// The default [`Padding`] of a [`TextInput`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`TextInput`] with the given `placeholder` and `value`.
+    pub fn new(placeholder: &str, value: &str) -> Self {
+        Self {
+            placeholder: placeholder.to_owned(),
+            value: value.to_owned(),
+            on_input: None,
+            on_submit: None,
+            font: None,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            size: None,
+            line_height: LineHeight::default(),
+            motion: SpringMotion::default(),
+            disabled_motion: None,
+            animation_id: None,
+            caret_motion: SpringMotion::Custom {
+                response: Duration::from_millis(120),
+                damping: 0.75,
+            },
+            floating_label: false,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when the value is edited. The [`TextInput`] is disabled
+    /// until this is set.
+    pub fn on_input(mut self, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Sets the message produced when enter is pressed while the [`TextInput`] is focused.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the font of the [`TextInput`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the width of the [`TextInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TextInput`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TextInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`TextInput`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the style of the [`TextInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TextInput`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the motion that will be used by style animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the motion used when the [`TextInput`] becomes disabled, which defaults to the
+    /// regular motion. Disabling an input often feels better when it's faster or subtler than
+    /// focusing or hovering it.
+    pub fn disabled_motion(mut self, motion: SpringMotion) -> Self {
+        self.disabled_motion = Some(motion);
+        self
+    }
+
+    /// Sets the [`Id`] the style animation is saved under, so it carries on rather than
+    /// restarting when the [`TextInput`] moves to a different place in the widget tree.
+    pub fn id(self, id: Id) -> Self {
+        self.animation_id(AnimationStore::global().id(id))
+    }
+
+    /// Saves the style animation under an [`AnimationId`] of an [`AnimationStore`] kept by the
+    /// app, so it carries on even when the view is restructured.
+    pub fn animation_id(mut self, animation_id: AnimationId) -> Self {
+        self.animation_id = Some(animation_id);
+        self
+    }
+
+    /// Sets the motion of the caret and selection, which defaults to a quick, slightly elastic
+    /// spring so that typing doesn't feel sluggish.
+    pub fn caret_motion(mut self, motion: SpringMotion) -> Self {
+        self.caret_motion = motion;
+        self
+    }
+
+    /// Shows the placeholder as a label that floats above the value when the [`TextInput`] is
+    /// focused or filled in, rather than hiding it.
+    pub fn floating_label(mut self, floating_label: bool) -> Self {
+        self.floating_label = floating_label;
+        self
+    }
+
+    /// The current status of the [`TextInput`].
+    fn status(&self, state: &State<Renderer::Paragraph>, is_hovered: bool) -> Status {
+        if self.on_input.is_none() {
+            Status::Disabled
+        } else if state.focus.is_some() {
+            Status::Focused
+        } else if is_hovered {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+
+    /// How far the floating label should float above the value, and how much it should be
+    /// highlighted by the focus.
+    fn label_target(&self, state: &State<Renderer::Paragraph>) -> (f32, f32) {
+        let is_focused = state.focus.is_some();
+        let float = if is_focused || !self.value.is_empty() {
+            1.0
+        } else {
+            0.0
+        };
+        (float, if is_focused { 1.0 } else { 0.0 })
+    }
+
+    /// The height reserved above the value for a floating label.
+    fn label_height(&self, renderer: &Renderer) -> f32 {
+        if !self.floating_label {
+            return 0.0;
+        }
+
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        self.line_height
+            .to_absolute(Pixels(size.0 * FLOATED_LABEL_SCALE))
+            .0
+    }
+
+    /// The text to lay out for the given `content`.
+    fn text<'b>(
+        &self,
+        content: &'b str,
+        bounds: Size,
+        renderer: &Renderer,
+    ) -> core_text::Text<&'b str, Renderer::Font> {
+        core_text::Text {
+            content,
+            bounds: Size::new(f32::INFINITY, bounds.height),
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: Shaping::Advanced,
+            wrapping: core_text::Wrapping::None,
+        }
+    }
+
+    /// The index of the character closest to the `position` within the text `bounds`.
+    fn hit_index(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        bounds: Rectangle,
+        position: Point,
+    ) -> usize {
+        let x = position.x - bounds.x + state.scroll_offset(bounds.width);
+        if x <= 0.0 {
+            return 0;
+        }
+
+        state
+            .value
+            .hit_test(Point::new(x, bounds.height / 2.0))
+            .map_or(state.len, |hit| hit.cursor().min(state.len))
+    }
+
+    /// Replaces the selected characters with the `insert`, publishing the edited value.
+    fn replace_selection(
+        &self,
+        state: &mut State<Renderer::Paragraph>,
+        insert: &str,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(on_input) = self.on_input.as_ref() else {
+            return;
+        };
+
+        let (start, end) = state.selection.range();
+        let mut value = self.value.clone();
+        value.replace_range(byte_index(&value, start)..byte_index(&value, end), insert);
+
+        state.len = value.chars().count();
+        state.select(Selection::caret(start + insert.chars().count()));
+        shell.publish(on_input(value));
+    }
+
+    /// The selected text, if any.
+    fn selected_text(&self, selection: Selection) -> Option<String> {
+        let (start, end) = selection.range();
+        (start < end).then(|| {
+            self.value[byte_index(&self.value, start)..byte_index(&self.value, end)].to_owned()
+        })
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TextInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let status = if self.on_input.is_some() {
+            Status::Active
+        } else {
+            Status::Disabled
+        };
+
+        tree::State::new(State::<Renderer::Paragraph> {
+            value: Default::default(),
+            placeholder: Default::default(),
+            len: self.value.chars().count(),
+            selection: Selection::default(),
+            focus: None,
+            is_dragging: false,
+            modifiers: keyboard::Modifiers::default(),
+            caret: Spring::new((0.0, 0.0)).with_motion(self.caret_motion),
+            label: Spring::new((if self.value.is_empty() { 0.0 } else { 1.0 }, 0.0))
+                .with_motion(self.motion),
+            animated_state: AnimatedState::new(status, self.motion)
+                .with_animation_id(self.animation_id.clone()),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.motion);
+        state
+            .animated_state
+            .set_transition_motion(Status::Disabled, self.disabled_motion);
+        state.caret.set_motion(self.caret_motion);
+        state.label.set_motion(self.motion);
+
+        // Keep the selection within the value in case it was changed from outside.
+        state.len = self.value.chars().count();
+        let Selection { anchor, head } = state.selection;
+        state.selection = Selection {
+            anchor: anchor.min(state.len),
+            head: head.min(state.len),
+        };
+        if self.on_input.is_none() {
+            state.focus = None;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let text_height = self.line_height.to_absolute(size).0;
+
+        // Floating labels rest above the value, so make room for them within the padding.
+        let padding = Padding {
+            top: self.padding.top + self.label_height(renderer),
+            ..self.padding
+        };
+        let node = layout::padded(limits, self.width, Length::Shrink, padding, |limits| {
+            layout::Node::new(Size::new(limits.max().width, text_height))
+        });
+
+        let bounds = node.size();
+        state.value = Renderer::Paragraph::with_text(self.text(&self.value, bounds, renderer));
+        state.placeholder =
+            Renderer::Paragraph::with_text(self.text(&self.placeholder, bounds, renderer));
+        state.update_caret();
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let status = self.status(state, cursor.is_over(bounds));
+        if self.floating_label {
+            let target = self.label_target(state);
+            if *state.label.target() != target {
+                state.label.interrupt(target);
+            }
+        }
+
+        if state.animated_state.needs_redraw(status)
+            || state.caret.has_energy()
+            || state.label.has_energy()
+        {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        } else if let Some(focus) = state.focus {
+            shell.request_redraw(window::RedrawRequest::At(focus.next_blink()));
+        }
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                state.caret.tick(now);
+                state.label.tick(now);
+                if let Some(focus) = state.focus.as_mut() {
+                    focus.now = now;
+                }
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.focus = None;
+                    state.is_dragging = false;
+                    return event::Status::Ignored;
+                };
+                if self.on_input.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                let index = self.hit_index(state, text_bounds, position);
+                if state.focus.is_none() {
+                    // Jump straight to the first caret position rather than gliding in.
+                    state.focus = Some(Focus::new(Instant::now()));
+                    state.selection = Selection::caret(index);
+                    let offset = state.offset_of(index);
+                    state.caret.settle_at((offset, offset));
+                } else {
+                    let extend = state.modifiers.shift();
+                    state.select(state.selection.move_to(index, extend));
+                }
+
+                state.is_dragging = true;
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if !state.is_dragging {
+                    return event::Status::Ignored;
+                }
+
+                let index = self.hit_index(state, text_bounds, position);
+                state.select(state.selection.move_to(index, true));
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                state.is_dragging = false;
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) if state.focus.is_some() => {
+                let selection = state.selection;
+                let (start, end) = selection.range();
+                let extend = modifiers.shift();
+
+                match key.as_ref() {
+                    keyboard::Key::Character("a") if modifiers.command() => {
+                        state.select(Selection {
+                            anchor: 0,
+                            head: state.len,
+                        });
+                    }
+                    keyboard::Key::Character("c") if modifiers.command() => {
+                        if let Some(selected) = self.selected_text(selection) {
+                            clipboard.write(clipboard::Kind::Standard, selected);
+                        }
+                    }
+                    keyboard::Key::Character("x") if modifiers.command() => {
+                        if let Some(selected) = self.selected_text(selection) {
+                            clipboard.write(clipboard::Kind::Standard, selected);
+                            self.replace_selection(state, "", shell);
+                        }
+                    }
+                    keyboard::Key::Character("v") if modifiers.command() => {
+                        if let Some(pasted) = clipboard.read(clipboard::Kind::Standard) {
+                            let pasted: String =
+                                pasted.chars().filter(|c| !c.is_control()).collect();
+                            self.replace_selection(state, &pasted, shell);
+                        }
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                        if selection.is_empty() && start > 0 {
+                            state.selection = Selection {
+                                anchor: start - 1,
+                                head: start,
+                            };
+                        }
+                        self.replace_selection(state, "", shell);
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Delete) => {
+                        if selection.is_empty() && end < state.len {
+                            state.selection = Selection {
+                                anchor: start,
+                                head: end + 1,
+                            };
+                        }
+                        self.replace_selection(state, "", shell);
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
+                        let index = if !extend && !selection.is_empty() {
+                            start
+                        } else {
+                            selection.head.saturating_sub(1)
+                        };
+                        state.select(selection.move_to(index, extend));
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
+                        let index = if !extend && !selection.is_empty() {
+                            end
+                        } else {
+                            (selection.head + 1).min(state.len)
+                        };
+                        state.select(selection.move_to(index, extend));
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Home) => {
+                        state.select(selection.move_to(0, extend));
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::End) => {
+                        state.select(selection.move_to(state.len, extend));
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                        if let Some(on_submit) = self.on_submit.clone() {
+                            shell.publish(on_submit);
+                        }
+                    }
+                    keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                        state.focus = None;
+                        state.is_dragging = false;
+                    }
+                    _ => {
+                        let Some(text) = text.filter(|_| !modifiers.command()) else {
+                            return event::Status::Ignored;
+                        };
+                        let text: String = text.chars().filter(|c| !c.is_control()).collect();
+                        if text.is_empty() {
+                            return event::Status::Ignored;
+                        }
+                        self.replace_selection(state, &text, shell);
+                    }
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = state
+            .animated_state
+            .current_style(|status| theme.style(&self.class, *status));
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let Some(clip_bounds) = text_bounds.intersection(viewport) else {
+            return;
+        };
+        let offset = state.scroll_offset(text_bounds.width);
+        let (anchor, head) = *state.caret.value();
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            // The selection and caret are drawn behind the text so they don't cover it.
+            if let Some(focus) = state.focus {
+                let (left, right) = (anchor.min(head), anchor.max(head));
+                if right - left >= CARET_WIDTH {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: text_bounds.x + left - offset,
+                                y: text_bounds.y,
+                                width: right - left,
+                                height: text_bounds.height,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.selection,
+                    );
+                } else if focus.is_caret_visible() || state.caret.has_energy() {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: text_bounds.x + head - offset,
+                                y: text_bounds.y,
+                                width: CARET_WIDTH,
+                                height: text_bounds.height,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.value,
+                    );
+                }
+            }
+
+            // Floating labels replace the placeholder and are drawn separately below.
+            let (paragraph, color) = if !self.value.is_empty() {
+                (&state.value, style.value)
+            } else if !self.floating_label {
+                (&state.placeholder, style.placeholder)
+            } else {
+                return;
+            };
+            renderer.fill_paragraph(
+                paragraph,
+                Point::new(text_bounds.x - offset, text_bounds.center_y()),
+                color,
+                clip_bounds,
+            );
+        });
+
+        if self.floating_label {
+            let Some(clip_bounds) = bounds.intersection(viewport) else {
+                return;
+            };
+            let (float, highlight) = *state.label.value();
+
+            // Move the label from where the placeholder would be up into the space above the
+            // value, shrinking it from its left edge.
+            let resting = Point::new(text_bounds.x, text_bounds.center_y());
+            let label_height = self.label_height(renderer);
+            let floated = Point::new(
+                text_bounds.x,
+                bounds.y + self.padding.top + label_height / 2.0,
+            );
+            let position = resting + (floated - resting) * float;
+            let scale = 1.0 - (1.0 - FLOATED_LABEL_SCALE) * float;
+
+            let mut color = style.placeholder;
+            let mut components = style
+                .border
+                .color
+                .distance_to(&style.placeholder)
+                .into_iter()
+                .map(|distance| distance * highlight);
+            color.update(&mut components);
+
+            renderer.with_layer(clip_bounds, |renderer| {
+                renderer.with_transformation(
+                    Transformation::translate(position.x, position.y)
+                        * Transformation::scale(scale),
+                    |renderer| {
+                        renderer.fill_paragraph(
+                            &state.placeholder,
+                            Point::ORIGIN,
+                            color,
+                            Rectangle::with_size(Size::INFINITY),
+                        );
+                    },
+                );
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            if self.on_input.is_none() {
+                mouse::Interaction::NotAllowed
+            } else {
+                mouse::Interaction::Text
+            }
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// The byte index of the character at the `index`, or the length of the `value` past its end.
+fn byte_index(value: &str, index: usize) -> usize {
+    value
+        .char_indices()
+        .nth(index)
+        .map_or(value.len(), |(byte, _)| byte)
+}
+
+impl<'a, Message, Theme, Renderer> From<TextInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(text_input: TextInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(text_input)
+    }
+}
+
+/// Creates a new [`TextInput`] with the given `placeholder` and `value`.
+pub fn text_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    value: &str,
+) -> TextInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    TextInput::new(placeholder, value)
+}
+
+/// Creates a new material-style [`TextInput`] whose `label` floats above the `value` when it is
+/// focused or filled in.
+pub fn text_field<'a, Message, Theme, Renderer>(
+    label: &str,
+    value: &str,
+) -> TextInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    TextInput::new(label, value).floating_label(true)
+}
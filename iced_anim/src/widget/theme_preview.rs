@@ -0,0 +1,214 @@
+//! A grid of theme swatches that previews themes on hover and picks one on click.
+//!
+//! Each swatch shows the background, primary, success, and danger colors of a theme along with
+//! its name. Hovering a swatch reports the theme through [`ThemePreview::on_preview`] so the app
+//! can animate toward it, and leaving the grid reports `None` so the app can animate back to the
+//! theme that was picked. Clicking a swatch picks its theme through the `on_select` message.
+//!
+//! ```rust
+//! # use iced::{Element, Theme};
+//! # use iced_anim::{widget::theme_preview, Animation, Spring, SpringEvent};
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Preview(Option<Theme>),
+//!     Select(Theme),
+//!     Animate(SpringEvent<Theme>),
+//! }
+//!
+//! struct State {
+//!     selected: Theme,
+//!     theme: Spring<Theme>,
+//! }
+//!
+//! impl State {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Preview(theme) => {
+//!                 let theme = theme.unwrap_or_else(|| self.selected.clone());
+//!                 self.theme.interrupt(theme);
+//!             }
+//!             Message::Select(theme) => {
+//!                 self.selected = theme.clone();
+//!                 self.theme.interrupt(theme);
+//!             }
+//!             Message::Animate(event) => self.theme.update(event),
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<Message> {
+//!         let grid = theme_preview(Theme::ALL, Some(&self.selected), Message::Select)
+//!             .on_preview(Message::Preview);
+//!
+//!         Animation::new(&self.theme, grid).on_update(Message::Animate).into()
+//!     }
+//! }
+//! ```
+use iced::{
+    widget::{column, container, mouse_area, text, Column, Row, Space},
+    Border, Color, Element, Length, Theme,
+};
+
+use super::button::{self, button};
+
+/// A grid of theme swatches that previews themes on hover and picks one on click.
+#[allow(missing_debug_implementations)]
+pub struct ThemePreview<'a, Message> {
+    themes: Vec<Theme>,
+    selected: Option<Theme>,
+    on_select: Box<dyn Fn(Theme) -> Message + 'a>,
+    on_preview: Option<Box<dyn Fn(Option<Theme>) -> Message + 'a>>,
+    columns: usize,
+    swatch_width: f32,
+    spacing: f32,
+}
+
+impl<'a, Message> ThemePreview<'a, Message> {
+    /// Creates a new [`ThemePreview`] for the given `themes`, highlighting the `selected` theme.
+    pub fn new(
+        themes: &[Theme],
+        selected: Option<&Theme>,
+        on_select: impl Fn(Theme) -> Message + 'a,
+    ) -> Self {
+        Self {
+            themes: themes.to_vec(),
+            selected: selected.cloned(),
+            on_select: Box::new(on_select),
+            on_preview: None,
+            columns: 4,
+            swatch_width: 120.0,
+            spacing: 8.0,
+        }
+    }
+
+    /// Sets the message produced when a swatch is hovered, or `None` once no swatch is hovered.
+    pub fn on_preview(mut self, on_preview: impl Fn(Option<Theme>) -> Message + 'a) -> Self {
+        self.on_preview = Some(Box::new(on_preview));
+        self
+    }
+
+    /// Sets the number of swatches in each row of the grid.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Sets the width of each swatch.
+    pub fn swatch_width(mut self, width: f32) -> Self {
+        self.swatch_width = width;
+        self
+    }
+
+    /// Sets the spacing between swatches.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+/// The colors of the `theme` as a row of stripes.
+fn stripes<'a, Message: 'a>(theme: &Theme) -> Element<'a, Message> {
+    let palette = theme.palette();
+    let colors = [
+        palette.background,
+        palette.primary,
+        palette.success,
+        palette.danger,
+    ];
+
+    Row::with_children(colors.map(|color| {
+        container(Space::new(Length::Fill, Length::Fixed(32.0)))
+            .width(Length::Fill)
+            .style(move |_| container::Style {
+                background: Some(color.into()),
+                ..Default::default()
+            })
+            .into()
+    }))
+    .into()
+}
+
+/// The style of a swatch, which outlines the selected theme with the primary color of the
+/// current theme.
+fn swatch_style(is_selected: bool) -> impl Fn(&Theme, button::Status) -> button::Style {
+    move |theme, status| {
+        let palette = theme.extended_palette();
+        let border_color = match (is_selected, status) {
+            (true, _) => palette.primary.strong.color,
+            (false, button::Status::Hovered | button::Status::Pressed) => {
+                palette.background.strong.color
+            }
+            (false, _) => Color::TRANSPARENT,
+        };
+
+        button::Style {
+            background: Some(palette.background.weak.color.into()),
+            text_color: palette.background.weak.text,
+            border: Border {
+                color: border_color,
+                width: 2.0,
+                radius: 6.0.into(),
+            },
+            ..button::Style::default()
+        }
+    }
+}
+
+impl<'a, Message> From<ThemePreview<'a, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    fn from(preview: ThemePreview<'a, Message>) -> Self {
+        let ThemePreview {
+            themes,
+            selected,
+            on_select,
+            on_preview,
+            columns,
+            swatch_width,
+            spacing,
+        } = preview;
+
+        let swatches: Vec<Element<'a, Message>> = themes
+            .into_iter()
+            .map(|theme| {
+                let is_selected = selected.as_ref() == Some(&theme);
+                let swatch =
+                    button(column![stripes(&theme), text(theme.to_string()).size(12)].spacing(4))
+                        .on_press(on_select(theme.clone()))
+                        .padding(4)
+                        .width(swatch_width)
+                        .style(swatch_style(is_selected));
+
+                match &on_preview {
+                    Some(on_preview) => mouse_area(swatch).on_enter(on_preview(Some(theme))).into(),
+                    None => swatch.into(),
+                }
+            })
+            .collect();
+
+        let mut rows = Vec::with_capacity(swatches.len().div_ceil(columns));
+        let mut swatches = swatches.into_iter().peekable();
+        while swatches.peek().is_some() {
+            let row: Vec<Element<'a, Message>> = swatches.by_ref().take(columns).collect();
+            rows.push(Row::with_children(row).spacing(spacing).into());
+        }
+
+        let grid = Column::with_children(rows).spacing(spacing);
+
+        // Previews end when leaving the grid rather than each swatch, so moving between swatches
+        // doesn't briefly animate back to the selected theme.
+        match on_preview {
+            Some(on_preview) => mouse_area(grid).on_exit(on_preview(None)).into(),
+            None => grid.into(),
+        }
+    }
+}
+
+/// Creates a new [`ThemePreview`] for the given `themes`, highlighting the `selected` theme.
+pub fn theme_preview<'a, Message>(
+    themes: &[Theme],
+    selected: Option<&Theme>,
+    on_select: impl Fn(Theme) -> Message + 'a,
+) -> ThemePreview<'a, Message> {
+    ThemePreview::new(themes, selected, on_select)
+}
@@ -0,0 +1,865 @@
+//! A toolbar whose items move into an overflow menu when they don't fit.
+//!
+//! Toolbars measure their items whenever they're laid out. Items that no longer fit shrink into
+//! an overflow button at the end of the toolbar while the remaining items slide together, and
+//! they grow back out of it once there's room for them again. Pressing the overflow button opens
+//! a menu with the items that didn't fit, which grows out of the button and shrinks back into it
+//! when it closes.
+//!
+//! ```rust
+//! # use iced::{Element, widget::{button, text}};
+//! # use iced_anim::widget::toolbar;
+//! # #[derive(Debug, Clone)] enum Message { Bold, Italic, Underline }
+//! fn formatting<'a>() -> Element<'a, Message> {
+//!     toolbar(
+//!         [
+//!             button("Bold").on_press(Message::Bold).into(),
+//!             button("Italic").on_press(Message::Italic).into(),
+//!             button("Underline").on_press(Message::Underline).into(),
+//!         ],
+//!         text("..."),
+//!     )
+//!     .spacing(4)
+//!     .into()
+//! }
+//! ```
+//!
+//! Items are matched between views by their position, so items added to the end of the toolbar
+//! grow into place. The menu closes when one of its items is pressed or when the user presses
+//! anywhere else.
+use std::iter;
+
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch, window, Background, Border, Color, Element, Event, Length, Pixels, Point, Rectangle,
+    Shadow, Size, Transformation, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+/// The space between the overflow button and the menu.
+const GAP: f32 = 4.0;
+
+/// A toolbar that moves the items that don't fit into an overflow menu.
+#[allow(missing_debug_implementations)]
+pub struct Toolbar<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    items: Vec<Element<'a, Message, Theme, Renderer>>,
+    more: Element<'a, Message, Theme, Renderer>,
+    spacing: f32,
+    width: Length,
+    menu_padding: f32,
+    motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// The internal state of a [`Toolbar`].
+struct State {
+    /// The placement of each item in the toolbar.
+    items: Vec<Placement>,
+    /// The placement of the overflow button, which is hidden while every item fits.
+    more: Placement,
+    /// How open the menu is, from 0 when it's closed to 1 when it's fully open.
+    menu: Spring<f32>,
+    /// Whether the menu is open.
+    is_open: bool,
+    /// Whether the toolbar has been laid out, after which new items grow into place.
+    is_laid_out: bool,
+}
+
+impl State {
+    /// Whether any part of the toolbar is still animating.
+    fn has_energy(&self) -> bool {
+        self.items
+            .iter()
+            .chain(iter::once(&self.more))
+            .any(Placement::has_energy)
+            || self.menu.has_energy()
+    }
+
+    /// Updates every animation in the toolbar.
+    fn tick(&mut self, now: std::time::Instant) {
+        for placement in self.items.iter_mut().chain(iter::once(&mut self.more)) {
+            placement.x.tick(now);
+            placement.presence.tick(now);
+        }
+        self.menu.tick(now);
+    }
+
+    /// Opens the menu, or closes it if it's open.
+    fn toggle(&mut self) {
+        self.is_open = !self.is_open;
+        self.menu.interrupt(if self.is_open { 1.0 } else { 0.0 });
+    }
+
+    /// Closes the menu.
+    fn close(&mut self) {
+        self.is_open = false;
+        self.menu.interrupt(0.0);
+    }
+}
+
+/// Where an item or the overflow button is placed in a [`Toolbar`].
+struct Placement {
+    /// The animated position from the start of the toolbar.
+    x: Spring<f32>,
+    /// How present it is in the toolbar, from 0 when it's hidden to 1 when it's fully shown.
+    presence: Spring<f32>,
+    /// Whether it's hidden from the toolbar, which means an item is in the menu and the
+    /// overflow button isn't needed.
+    is_hidden: bool,
+    /// Whether it has been placed yet.
+    is_placed: bool,
+}
+
+impl Placement {
+    /// Creates a placement that hasn't been placed yet.
+    fn new(motion: SpringMotion) -> Self {
+        Self {
+            x: Spring::new(0.0).with_motion(motion),
+            presence: Spring::new(0.0).with_motion(motion),
+            is_hidden: false,
+            is_placed: false,
+        }
+    }
+
+    /// Updates the motion of the animations.
+    fn set_motion(&mut self, motion: SpringMotion) {
+        self.x.set_motion(motion);
+        self.presence.set_motion(motion);
+    }
+
+    /// Whether it's still moving, growing, or shrinking.
+    fn has_energy(&self) -> bool {
+        self.x.has_energy() || self.presence.has_energy()
+    }
+
+    /// Moves toward `x`, shown or hidden. The first placement happens right away, unless
+    /// `grows_in` is set, in which case it grows into place.
+    fn place(&mut self, x: f32, is_hidden: bool, grows_in: bool) {
+        let presence = if is_hidden { 0.0 } else { 1.0 };
+        if !self.is_placed {
+            self.is_placed = true;
+            self.x.settle_at(x);
+            self.presence
+                .settle_at(if grows_in { 0.0 } else { presence });
+        }
+
+        if *self.x.target() != x {
+            self.x.interrupt(x);
+        }
+        if *self.presence.target() != presence {
+            self.presence.interrupt(presence);
+        }
+        self.is_hidden = is_hidden;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Toolbar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Toolbar`] with the given `items`, showing the `more` content as the
+    /// button that opens the overflow menu.
+    pub fn new(
+        items: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+        more: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            more: more.into(),
+            spacing: 0.0,
+            width: Length::Fill,
+            menu_padding: 4.0,
+            motion: SpringMotion::Snappy,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds an item to the end of the [`Toolbar`].
+    pub fn push(mut self, item: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Sets the spacing between items, both in the toolbar and in the menu.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Toolbar`], which fills the available space by default.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding around the items in the menu.
+    pub fn menu_padding(mut self, padding: f32) -> Self {
+        self.menu_padding = padding;
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the style of the menu.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the menu.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The items followed by the overflow button.
+    fn elements(&self) -> impl Iterator<Item = &Element<'a, Message, Theme, Renderer>> {
+        self.items.iter().chain(iter::once(&self.more))
+    }
+}
+
+/// The number of items with the given `widths` that fit in the `available` width, leaving room
+/// for an overflow button `more_width` wide unless every item fits.
+fn fitting(widths: &[f32], spacing: f32, more_width: f32, available: f32) -> usize {
+    let total = widths.iter().sum::<f32>() + spacing * widths.len().saturating_sub(1) as f32;
+    if total <= available {
+        return widths.len();
+    }
+
+    let mut used = more_width;
+    widths
+        .iter()
+        .take_while(|width| {
+            used += *width + spacing;
+            used <= available
+        })
+        .count()
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Toolbar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            items: self
+                .items
+                .iter()
+                .map(|_| Placement::new(self.motion))
+                .collect(),
+            more: Placement::new(self.motion),
+            menu: Spring::new(0.0).with_motion(self.motion),
+            is_open: false,
+            is_laid_out: false,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.elements().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state
+            .items
+            .resize_with(self.items.len(), || Placement::new(self.motion));
+        for placement in state.items.iter_mut().chain(iter::once(&mut state.more)) {
+            placement.set_motion(self.motion);
+        }
+        state.menu.set_motion(self.motion);
+
+        let elements: Vec<_> = self.elements().map(Element::as_widget).collect();
+        tree.diff_children(&elements);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+        let max = limits.width(self.width).max();
+        let item_limits = layout::Limits::new(Size::ZERO, Size::new(f32::INFINITY, max.height));
+
+        // Measure every item at its natural size to find out which ones fit.
+        let nodes: Vec<layout::Node> = self
+            .elements()
+            .zip(children.iter_mut())
+            .map(|(element, tree)| element.as_widget().layout(tree, renderer, &item_limits))
+            .collect();
+        let (more, items) = nodes
+            .split_last()
+            .expect("toolbars have an overflow button");
+        let widths: Vec<f32> = items.iter().map(|node| node.size().width).collect();
+        let fits = fitting(&widths, self.spacing, more.size().width, max.width);
+
+        // Items that don't fit shrink into the overflow button, which follows the last item
+        // that does.
+        let grows_in = state.is_laid_out;
+        let mut x = 0.0;
+        for (placement, width) in state.items.iter_mut().zip(&widths).take(fits) {
+            placement.place(x, false, grows_in);
+            x += width + self.spacing;
+        }
+        for placement in state.items.iter_mut().skip(fits) {
+            placement.place(x, true, grows_in);
+        }
+
+        let is_overflowing = fits < self.items.len();
+        state.more.place(x, !is_overflowing, false);
+        if !is_overflowing && state.is_open {
+            state.close();
+        }
+        state.is_laid_out = true;
+
+        let width = if is_overflowing {
+            x + more.size().width
+        } else {
+            (x - self.spacing).max(0.0)
+        };
+        let height = nodes
+            .iter()
+            .map(|node| node.size().height)
+            .fold(0.0, f32::max);
+
+        // Items are laid out where they're headed and centered vertically.
+        let children = nodes
+            .into_iter()
+            .zip(state.items.iter().chain(iter::once(&state.more)))
+            .map(|(node, placement)| {
+                let y = (height - node.size().height) / 2.0;
+                node.move_to(Point::new(*placement.x.value(), y))
+            })
+            .collect();
+
+        let size = limits.width(self.width).height(Length::Shrink).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(width, height),
+        );
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.items
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((item, state), layout)| {
+                    item.as_widget().operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.tick(now);
+        }
+
+        // Pressing the overflow button opens the menu, or closes it if it's open.
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+        let is_over_more = layout
+            .children()
+            .nth(self.items.len())
+            .is_some_and(|more| cursor.is_over(more.bounds()));
+        if is_press && is_over_more && !state.more.is_hidden {
+            state.toggle();
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+            return event::Status::Captured;
+        }
+
+        // Items in the menu get their events from the menu instead.
+        let status = self
+            .items
+            .iter_mut()
+            .zip(&state.items)
+            .zip(children.iter_mut())
+            .zip(layout.children())
+            .filter(|(((_, placement), _), _)| !placement.is_hidden)
+            .map(|(((item, _), state), layout)| {
+                item.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if state.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let placements = state.items.iter().chain(iter::once(&state.more));
+
+        for (((element, placement), tree), layout) in self
+            .elements()
+            .zip(placements)
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            let presence = placement.presence.value().clamp(0.0, 1.0);
+            if presence <= 0.0 {
+                continue;
+            }
+
+            let cursor = if placement.is_hidden {
+                Cursor::Unavailable
+            } else {
+                cursor
+            };
+            let draw = |renderer: &mut Renderer| {
+                element
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
+            };
+
+            // Items shrink in place as they leave the toolbar and grow as they come back.
+            if presence < 1.0 {
+                let center = layout.bounds().center();
+                let transformation = Transformation::translate(center.x, center.y)
+                    * Transformation::scale(presence)
+                    * Transformation::translate(-center.x, -center.y);
+                renderer.with_transformation(transformation, draw);
+            } else {
+                draw(renderer);
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        self.items
+            .iter()
+            .zip(&state.items)
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|(((_, placement), _), _)| !placement.is_hidden)
+            .map(|(((item, _), state), layout)| {
+                item.as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+        let hidden: Vec<bool> = state.items.iter().map(|item| item.is_hidden).collect();
+
+        // Items in the toolbar show their own overlays, while the others are shown in the menu.
+        let mut overlays = Vec::new();
+        let mut menu_items = Vec::new();
+        for (((item, tree), layout), is_hidden) in self
+            .items
+            .iter_mut()
+            .zip(children.iter_mut())
+            .zip(layout.children())
+            .zip(hidden)
+        {
+            if is_hidden {
+                menu_items.push((item, tree));
+            } else if let Some(overlay) =
+                item.as_widget_mut()
+                    .overlay(tree, layout, renderer, translation)
+            {
+                overlays.push(overlay);
+            }
+        }
+
+        // The menu stays around while it's closing.
+        let more = layout
+            .children()
+            .nth(self.items.len())
+            .map(|more| more.bounds());
+        if let Some(more) = more.filter(|_| state.is_open || state.menu.has_energy()) {
+            overlays.push(overlay::Element::new(Box::new(Menu {
+                items: menu_items,
+                state,
+                anchor: more + translation,
+                padding: self.menu_padding,
+                spacing: self.spacing,
+                class: &self.class,
+            })));
+        }
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+/// The overflow menu of a [`Toolbar`], which shows the items that don't fit.
+struct Menu<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    items: Vec<(&'b mut Element<'a, Message, Theme, Renderer>, &'b mut Tree)>,
+    state: &'b mut State,
+    /// The bounds of the overflow button, which the menu opens below.
+    anchor: Rectangle,
+    padding: f32,
+    spacing: f32,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Menu<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let (padding, spacing) = (self.padding, self.spacing);
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                (bounds.width - padding * 2.0).max(0.0),
+                (bounds.height - padding * 2.0).max(0.0),
+            ),
+        );
+
+        let mut y = padding;
+        let mut width: f32 = 0.0;
+        let children: Vec<layout::Node> = self
+            .items
+            .iter_mut()
+            .map(|(item, tree)| {
+                let node = item
+                    .as_widget()
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(padding, y));
+                y += node.size().height + spacing;
+                width = width.max(node.size().width);
+                node
+            })
+            .collect();
+        let height = if children.is_empty() {
+            padding * 2.0
+        } else {
+            y - spacing + padding
+        };
+        let size = Size::new(width + padding * 2.0, height);
+
+        // Open below the overflow button, or above it if there isn't enough room, lined up with
+        // its right edge.
+        let below = self.anchor.y + self.anchor.height + GAP;
+        let y = if below + size.height > bounds.height {
+            (self.anchor.y - GAP - size.height).max(0.0)
+        } else {
+            below
+        };
+        let x = (self.anchor.x + self.anchor.width - size.width)
+            .clamp(0.0, (bounds.width - size.width).max(0.0));
+
+        layout::Node::with_children(size, children).move_to(Point::new(x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // A closing menu can't be interacted with.
+        if !self.state.is_open {
+            return event::Status::Ignored;
+        }
+
+        // Pressing anywhere else closes the menu, except for the overflow button, which
+        // closes it by itself.
+        let bounds = layout.bounds();
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+        if is_press && !cursor.is_over(bounds) && !cursor.is_over(self.anchor) {
+            self.state.close();
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+            return event::Status::Ignored;
+        }
+
+        let status = self
+            .items
+            .iter_mut()
+            .zip(layout.children())
+            .map(|((item, tree), layout)| {
+                item.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &bounds,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        // Items like buttons capture the release that activates them, which closes the menu.
+        let is_release = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+        );
+        if is_release && status == event::Status::Captured {
+            self.state.close();
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let presence = self.state.menu.value().clamp(0.0, 1.0);
+        if presence <= 0.0 {
+            return;
+        }
+
+        let appearance = theme.style(self.class);
+        let bounds = layout.bounds();
+        let cursor = if self.state.is_open {
+            cursor
+        } else {
+            Cursor::Unavailable
+        };
+
+        // Grow out of the overflow button.
+        let origin = Point::new(self.anchor.center_x(), bounds.y);
+        let transformation = Transformation::translate(origin.x, origin.y)
+            * Transformation::scale(presence)
+            * Transformation::translate(-origin.x, -origin.y);
+
+        renderer.with_transformation(transformation, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    shadow: appearance.shadow,
+                },
+                appearance.background,
+            );
+
+            let style = renderer::Style {
+                text_color: appearance.text.unwrap_or(style.text_color),
+            };
+            for ((item, tree), layout) in self.items.iter().zip(layout.children()) {
+                item.as_widget()
+                    .draw(tree, renderer, theme, &style, layout, cursor, &bounds);
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.state.is_open {
+            return mouse::Interaction::default();
+        }
+
+        self.items
+            .iter()
+            .zip(layout.children())
+            .map(|((item, tree), layout)| {
+                item.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        self.state.is_open && layout.bounds().contains(cursor_position)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Toolbar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(toolbar: Toolbar<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(toolbar)
+    }
+}
+
+/// Creates a new [`Toolbar`] with the given `items`, showing the `more` content as the button
+/// that opens the overflow menu.
+pub fn toolbar<'a, Message, Theme, Renderer>(
+    items: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    more: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Toolbar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    Toolbar::new(items, more)
+}
+
+/// The appearance of the overflow menu of a [`Toolbar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background behind the menu.
+    pub background: Background,
+    /// The border around the menu.
+    pub border: Border,
+    /// The shadow of the menu.
+    pub shadow: Shadow,
+    /// The color of text in the menu, if it's different from the surrounding text.
+    pub text: Option<Color>,
+}
+
+/// The theme catalog of a [`Toolbar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Toolbar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of the menu of a [`Toolbar`], a rounded box on the background with a soft
+/// shadow.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border::default()
+            .rounded(6.0)
+            .color(palette.background.strong.color)
+            .width(1.0),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        text: None,
+    }
+}
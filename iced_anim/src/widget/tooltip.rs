@@ -0,0 +1,562 @@
+//! Tooltips show extra information next to content while it's hovered.
+//!
+//! The tooltip grows out of the point it's attached to when the content is hovered and shrinks
+//! back into it when the cursor leaves. That point is animated with its own spring, so tooltips
+//! attached to a side of the content glide along when the content moves, and tooltips that
+//! [follow the cursor](Position::FollowCursor) trail behind it softly instead of being rigidly
+//! attached to it.
+//!
+//! ```rust
+//! # use iced::{widget::{button, text}, Element};
+//! # use iced_anim::widget::tooltip::{tooltip, Position};
+//! # #[derive(Debug, Clone)] enum Message { Save }
+//! fn save<'a>() -> Element<'a, Message> {
+//!     tooltip(
+//!         button("Save").on_press(Message::Save),
+//!         text("Saves the document"),
+//!         Position::FollowCursor,
+//!     )
+//!     .into()
+//! }
+//! ```
+//!
+//! How softly the tooltip trails the cursor is set with [`Tooltip::follow_motion`], where a
+//! longer [`SpringMotion::Custom`] response makes a lazier trail. The tooltip can be any element,
+//! so the same widget works for any overlay that should follow the cursor, like a drag preview.
+use std::time::Duration;
+
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    window, Background, Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size,
+    Transformation, Vector,
+};
+
+use crate::{Spring, SpringMotion};
+
+/// Shows a tooltip while its content is hovered, attached to a point that moves with a spring.
+#[allow(missing_debug_implementations)]
+pub struct Tooltip<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    tooltip: Element<'a, Message, Theme, Renderer>,
+    position: Position,
+    gap: f32,
+    padding: f32,
+    motion: SpringMotion,
+    follow_motion: SpringMotion,
+    class: Theme::Class<'a>,
+}
+
+/// Where a [`Tooltip`] is shown relative to its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Position {
+    /// Above the content.
+    #[default]
+    Top,
+    /// Below the content.
+    Bottom,
+    /// To the left of the content.
+    Left,
+    /// To the right of the content.
+    Right,
+    /// Below and to the right of the cursor, trailing behind it as it moves.
+    FollowCursor,
+}
+
+/// The internal state of a [`Tooltip`].
+#[derive(Debug)]
+struct State {
+    /// The animated point the tooltip is attached to.
+    anchor: Spring<Point>,
+    /// How visible the tooltip is, from 0 when it's hidden to 1 when it's fully shown.
+    presence: Spring<f32>,
+    /// Whether the content is hovered.
+    is_hovered: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Tooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Tooltip`] showing the `tooltip` at the given `position` while the
+    /// `content` is hovered.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+        position: Position,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            tooltip: tooltip.into(),
+            position,
+            gap: 8.0,
+            padding: 8.0,
+            motion: SpringMotion::Snappy,
+            follow_motion: SpringMotion::Custom {
+                response: Duration::from_millis(300),
+                damping: 0.9,
+            },
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the space between the tooltip and the point it's attached to.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the padding around the tooltip.
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the motion of the tooltip appearing and disappearing.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Sets the motion of the tooltip following the point it's attached to, which controls how
+    /// softly it trails the cursor.
+    pub fn follow_motion(mut self, motion: SpringMotion) -> Self {
+        self.follow_motion = motion;
+        self
+    }
+
+    /// Sets the style of the [`Tooltip`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tooltip`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The point the tooltip is attached to for content laid out in `bounds` and hovered at
+    /// `cursor`.
+    fn anchor(&self, bounds: Rectangle, cursor: Point) -> Point {
+        let center = bounds.center();
+        match self.position {
+            Position::Top => Point::new(center.x, bounds.y),
+            Position::Bottom => Point::new(center.x, bounds.y + bounds.height),
+            Position::Left => Point::new(bounds.x, center.y),
+            Position::Right => Point::new(bounds.x + bounds.width, center.y),
+            Position::FollowCursor => cursor,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            anchor: Spring::new(Point::ORIGIN).with_motion(self.follow_motion),
+            presence: Spring::new(0.0).with_motion(self.motion),
+            is_hovered: false,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.tooltip)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.anchor.set_motion(self.follow_motion);
+        state.presence.set_motion(self.motion);
+        tree.diff_children(&[self.content.as_widget(), self.tooltip.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.anchor.tick(now);
+            state.presence.tick(now);
+        }
+
+        let bounds = layout.bounds();
+        if let Some(position) = cursor.position_over(bounds) {
+            let anchor = self.anchor(bounds, position);
+            if !state.is_hovered {
+                state.is_hovered = true;
+                state.presence.interrupt(1.0);
+
+                // Appear right where the tooltip belongs instead of flying in from where it was.
+                if *state.presence.value() <= 0.0 {
+                    state.anchor.settle_at(anchor);
+                }
+            }
+            if *state.anchor.target() != anchor {
+                state.anchor.interrupt(anchor);
+            }
+        } else if state.is_hovered {
+            state.is_hovered = false;
+            state.presence.interrupt(0.0);
+        }
+
+        if state.anchor.has_energy() || state.presence.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_ref::<State>();
+        let (content, tooltip) = children.split_at_mut(1);
+
+        let mut overlays: Vec<_> = self
+            .content
+            .as_widget_mut()
+            .overlay(&mut content[0], layout, renderer, translation)
+            .into_iter()
+            .collect();
+
+        // The tooltip stays around while it's disappearing.
+        if state.is_hovered || *state.presence.value() > 0.0 {
+            overlays.push(overlay::Element::new(Box::new(Follower {
+                tooltip: &self.tooltip,
+                tree: &mut tooltip[0],
+                state,
+                translation,
+                position: self.position,
+                gap: self.gap,
+                padding: self.padding,
+                class: &self.class,
+            })));
+        }
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+/// The overlay of a [`Tooltip`], which shows the tooltip next to the point it's attached to.
+struct Follower<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    tooltip: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    state: &'b State,
+    /// The translation from the layout of the content to the overlay.
+    translation: Vector,
+    position: Position,
+    gap: f32,
+    padding: f32,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Follower<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// The animated point the tooltip is attached to, in overlay coordinates.
+    fn anchor(&self) -> Point {
+        *self.state.anchor.value() + self.translation
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Follower<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let padding = self.padding;
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                (bounds.width - padding * 2.0).max(0.0),
+                (bounds.height - padding * 2.0).max(0.0),
+            ),
+        );
+        let content = self
+            .tooltip
+            .as_widget()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(padding, padding));
+        let size = Size::new(
+            content.size().width + padding * 2.0,
+            content.size().height + padding * 2.0,
+        );
+
+        let anchor = self.anchor();
+        let gap = self.gap;
+        let position = match self.position {
+            Position::Top => Point::new(anchor.x - size.width / 2.0, anchor.y - gap - size.height),
+            Position::Bottom => Point::new(anchor.x - size.width / 2.0, anchor.y + gap),
+            Position::Left => Point::new(anchor.x - gap - size.width, anchor.y - size.height / 2.0),
+            Position::Right => Point::new(anchor.x + gap, anchor.y - size.height / 2.0),
+            Position::FollowCursor => Point::new(anchor.x + gap, anchor.y + gap),
+        };
+
+        // Keep the tooltip inside the window.
+        let position = Point::new(
+            position.x.clamp(0.0, (bounds.width - size.width).max(0.0)),
+            position
+                .y
+                .clamp(0.0, (bounds.height - size.height).max(0.0)),
+        );
+
+        layout::Node::with_children(size, vec![content]).move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let presence = self.state.presence.value().max(0.0);
+        if presence <= 0.0 {
+            return;
+        }
+
+        let appearance = theme.style(self.class);
+        let bounds = layout.bounds();
+
+        // Grow out of the point the tooltip is attached to.
+        let anchor = self.anchor();
+        let transformation = Transformation::translate(anchor.x, anchor.y)
+            * Transformation::scale(presence)
+            * Transformation::translate(-anchor.x, -anchor.y);
+
+        renderer.with_transformation(transformation, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.border,
+                    shadow: appearance.shadow,
+                },
+                appearance.background,
+            );
+
+            let content = layout.children().next().unwrap();
+            self.tooltip.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: appearance.text.unwrap_or(style.text_color),
+                },
+                content,
+                cursor,
+                &bounds,
+            );
+        });
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        // The tooltip never gets in the way of the content it describes.
+        false
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tooltip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(tooltip: Tooltip<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(tooltip)
+    }
+}
+
+/// Creates a new [`Tooltip`] showing the `tooltip` at the given `position` while the `content`
+/// is hovered.
+pub fn tooltip<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+    position: Position,
+) -> Tooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    Tooltip::new(content, tooltip, position)
+}
+
+/// The appearance of a [`Tooltip`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background behind the tooltip.
+    pub background: Background,
+    /// The border around the tooltip.
+    pub border: Border,
+    /// The shadow of the tooltip.
+    pub shadow: Shadow,
+    /// The color of text in the tooltip, if it's different from the surrounding text.
+    pub text: Option<Color>,
+}
+
+/// The theme catalog of a [`Tooltip`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Tooltip`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Tooltip`], a rounded box on a weak background with a soft shadow.
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+    Style {
+        background: palette.background.weak.color.into(),
+        border: Border::default()
+            .rounded(6.0)
+            .color(palette.background.strong.color)
+            .width(1.0),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        text: Some(palette.background.weak.text),
+    }
+}
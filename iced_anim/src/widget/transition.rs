@@ -0,0 +1,212 @@
+//! Ready-made transitions for swapping content, each with a tuned motion.
+//!
+//! Designing a transition means picking how far content moves, how much it scales, when it fades,
+//! and which spring drives it, and getting those to agree across an app takes some care. The
+//! presets here bundle all of that into a single [`Transition`] value that can be passed to
+//! widgets that swap content, like [`Crossfade::transition`](super::Crossfade::transition).
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::widget::{crossfade, transition::{Axis, Transition}};
+//! # #[derive(Clone)] enum Message {}
+//! fn step<'a>(step: usize) -> Element<'a, Message> {
+//!     crossfade(step, |step| text(format!("Step {step}")).into())
+//!         .transition(Transition::SharedAxis(Axis::Horizontal))
+//!         .into()
+//! }
+//! ```
+//!
+//! Iced can't change the opacity of arbitrary content, so transitions that fade do so through a
+//! backdrop color like a [`Veil`](super::Veil). Fading transitions show the outgoing content for
+//! the first half of the transition and the incoming content for the second half, while sliding
+//! transitions show both at once.
+use std::time::Duration;
+
+use iced::{Size, Vector};
+
+use crate::SpringMotion;
+
+/// The distance that content moves in a [`Transition::SharedAxis`].
+const SHARED_AXIS_DISTANCE: f32 = 30.0;
+
+/// A preset transition between outgoing and incoming content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Transition {
+    /// Fades the outgoing content into the backdrop, then fades the incoming content out of it.
+    #[default]
+    Fade,
+    /// Slides the incoming content in from the right while the outgoing content slides out to
+    /// the left, like moving forward through pages.
+    SlideLeft,
+    /// Slides the incoming content up from the bottom while the outgoing content slides out
+    /// the top, like moving forward through a vertical feed.
+    SlideUp,
+    /// Fades through the backdrop while the incoming content grows slightly into place, for
+    /// content that isn't related to what it replaces.
+    FadeThrough,
+    /// Grows the outgoing content as it fades out and the incoming content as it fades in, like
+    /// moving into a detail view.
+    ZoomIn,
+    /// Moves both contents a short distance along an axis while fading through the backdrop,
+    /// for content with a spatial or navigational relationship like the steps of a form.
+    SharedAxis(Axis),
+}
+
+/// The axis along which a [`Transition::SharedAxis`] moves content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Axis {
+    /// Moves content to the left, as when moving forward through steps.
+    #[default]
+    Horizontal,
+    /// Moves content up, as when moving forward through a list.
+    Vertical,
+    /// Grows content toward the viewer, as when moving into a child view.
+    Depth,
+}
+
+/// How to draw one of the contents of a [`Transition`] at a point in the transition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frame {
+    /// How far to move the content from where it was laid out.
+    pub offset: Vector,
+    /// How much to scale the content around its center.
+    pub scale: f32,
+    /// How much of the backdrop covers the content, from 0 to 1.
+    pub veil: f32,
+}
+
+impl Frame {
+    /// A frame that draws content as it was laid out.
+    pub const IDENTITY: Self = Self {
+        offset: Vector::new(0.0, 0.0),
+        scale: 1.0,
+        veil: 0.0,
+    };
+}
+
+impl Default for Frame {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transition {
+    /// The motion tuned for the transition.
+    pub fn motion(self) -> SpringMotion {
+        match self {
+            Self::Fade => SpringMotion::Smooth,
+            Self::SlideLeft | Self::SlideUp => SpringMotion::Custom {
+                response: Duration::from_millis(450),
+                damping: 0.9,
+            },
+            Self::FadeThrough | Self::ZoomIn => SpringMotion::Custom {
+                response: Duration::from_millis(350),
+                damping: 1.0,
+            },
+            Self::SharedAxis(_) => SpringMotion::Custom {
+                response: Duration::from_millis(400),
+                damping: 1.0,
+            },
+        }
+    }
+
+    /// Whether the outgoing and incoming contents are shown at the same time, rather than one
+    /// after the other through the backdrop.
+    pub fn is_simultaneous(self) -> bool {
+        matches!(self, Self::SlideLeft | Self::SlideUp)
+    }
+
+    /// How to draw the outgoing content of the given `size` at the `progress` of the
+    /// transition, from 0 to 1, or `None` if it isn't visible.
+    pub fn outgoing(self, progress: f32, size: Size) -> Option<Frame> {
+        let progress = progress.clamp(0.0, 1.0);
+        if self.is_simultaneous() {
+            return Some(Frame {
+                offset: self.slide(size) * -progress,
+                ..Frame::IDENTITY
+            });
+        }
+
+        // The outgoing content fades out during the first half of the transition.
+        if progress >= 0.5 {
+            return None;
+        }
+        let fade = progress * 2.0;
+        let frame = Frame {
+            veil: fade,
+            ..Frame::IDENTITY
+        };
+
+        Some(match self {
+            Self::ZoomIn => Frame {
+                scale: 1.0 + 0.1 * fade,
+                ..frame
+            },
+            Self::SharedAxis(axis) => self.shared_axis(axis, -fade, frame),
+            _ => frame,
+        })
+    }
+
+    /// How to draw the incoming content of the given `size` at the `progress` of the
+    /// transition, from 0 to 1, or `None` if it isn't visible.
+    pub fn incoming(self, progress: f32, size: Size) -> Option<Frame> {
+        let progress = progress.clamp(0.0, 1.0);
+        if self.is_simultaneous() {
+            return Some(Frame {
+                offset: self.slide(size) * (1.0 - progress),
+                ..Frame::IDENTITY
+            });
+        }
+
+        // The incoming content fades in during the second half of the transition.
+        if progress < 0.5 {
+            return None;
+        }
+        let remaining = (1.0 - progress) * 2.0;
+        let frame = Frame {
+            veil: remaining,
+            ..Frame::IDENTITY
+        };
+
+        Some(match self {
+            Self::FadeThrough => Frame {
+                scale: 1.0 - 0.08 * remaining,
+                ..frame
+            },
+            Self::ZoomIn => Frame {
+                scale: 1.0 - 0.15 * remaining,
+                ..frame
+            },
+            Self::SharedAxis(axis) => self.shared_axis(axis, remaining, frame),
+            _ => frame,
+        })
+    }
+
+    /// The offset from the incoming content's resting place to where it slides in from.
+    fn slide(self, size: Size) -> Vector {
+        match self {
+            Self::SlideLeft => Vector::new(size.width, 0.0),
+            Self::SlideUp => Vector::new(0.0, size.height),
+            _ => Vector::new(0.0, 0.0),
+        }
+    }
+
+    /// Moves the `frame` along the `axis` by the given `amount` of the shared axis distance,
+    /// where positive amounts are ahead of the resting place and negative amounts behind it.
+    fn shared_axis(self, axis: Axis, amount: f32, frame: Frame) -> Frame {
+        match axis {
+            Axis::Horizontal => Frame {
+                offset: Vector::new(SHARED_AXIS_DISTANCE * amount, 0.0),
+                ..frame
+            },
+            Axis::Vertical => Frame {
+                offset: Vector::new(0.0, SHARED_AXIS_DISTANCE * amount),
+                ..frame
+            },
+            Axis::Depth => Frame {
+                scale: 1.0 - 0.1 * amount,
+                ..frame
+            },
+        }
+    }
+}
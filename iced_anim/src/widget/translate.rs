@@ -0,0 +1,185 @@
+//! Offset content from where it was laid out without affecting the layout.
+//!
+//! Translating content is useful for transient effects like shaking or sliding, where the
+//! content should move without pushing around anything next to it. The cursor is offset by the
+//! same amount, so interacting with translated content works as if it had been laid out there.
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    overlay, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+/// Draws its content offset from where it was laid out.
+pub struct Translate<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    offset: Vector,
+}
+
+impl<'a, Message, Theme, Renderer> Translate<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Translate`] that draws the `content` moved by the given `offset`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, offset: Vector) -> Self {
+        Self {
+            content: content.into(),
+            offset,
+        }
+    }
+
+    /// The cursor relative to the translated content.
+    fn cursor(&self, cursor: Cursor) -> Cursor {
+        match cursor {
+            Cursor::Available(position) => Cursor::Available(position - self.offset),
+            Cursor::Unavailable => Cursor::Unavailable,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Translate<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let cursor = self.cursor(cursor);
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let cursor = self.cursor(cursor);
+        let viewport = *viewport - self.offset;
+        renderer.with_translation(self.offset, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                &viewport,
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            self.cursor(cursor),
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation + self.offset,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Translate<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(translate: Translate<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(translate)
+    }
+}
+
+/// Creates a new [`Translate`] that draws the `content` moved by the given `offset`.
+pub fn translate<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    offset: Vector,
+) -> Translate<'a, Message, Theme, Renderer> {
+    Translate::new(content, offset)
+}
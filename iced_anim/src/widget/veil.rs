@@ -0,0 +1,276 @@
+//! Cover content with a translucent backdrop color.
+//!
+//! Iced doesn't support changing the opacity of arbitrary content, so fading content in and out
+//! is done by drawing a backdrop color on top of it instead. Fading content out means increasing
+//! the amount of the veil, while fading content in means decreasing it. This works best when the
+//! backdrop matches whatever is behind the content, like the theme's background color.
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    overlay, Color, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+/// Covers its content with a translucent backdrop color.
+pub struct Veil<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    /// How much of the backdrop covers the content, from 0 to 1.
+    amount: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Veil<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Veil`] covering the `content` by the given `amount`, where `0.0`
+    /// leaves the content fully visible and `1.0` completely hides it.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, amount: f32) -> Self {
+        Self {
+            content: content.into(),
+            amount,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the style of the [`Veil`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Veil`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Veil<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+    Theme: Catalog,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let backdrop = theme.style(&self.class).backdrop;
+        draw(renderer, layout.bounds(), backdrop, self.amount);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Veil<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(veil: Veil<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(veil)
+    }
+}
+
+/// Creates a new [`Veil`] covering the `content` by the given `amount`.
+pub fn veil<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    amount: f32,
+) -> Veil<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    Veil::new(content, amount)
+}
+
+/// Draws the `backdrop` over the `bounds` by the given `amount`.
+///
+/// The backdrop is drawn in its own layer so that it covers any text drawn beneath it.
+pub(crate) fn draw<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    backdrop: Color,
+    amount: f32,
+) where
+    Renderer: iced::advanced::Renderer,
+{
+    let amount = amount.clamp(0.0, 1.0);
+    if amount <= 0.0 {
+        return;
+    }
+
+    renderer.with_layer(bounds, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..Default::default()
+            },
+            Color {
+                a: backdrop.a * amount,
+                ..backdrop
+            },
+        );
+    });
+}
+
+/// The appearance of a [`Veil`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The color drawn on top of the content.
+    /// This should generally match the background behind the content.
+    pub backdrop: Color,
+}
+
+/// The theme catalog of a [`Veil`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Veil`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for iced::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Veil`], which uses the theme's background as the backdrop.
+pub fn default(theme: &iced::Theme) -> Style {
+    Style {
+        backdrop: theme.palette().background,
+    }
+}
@@ -0,0 +1,136 @@
+//! Fade the content of a window in on launch and out before it closes.
+//!
+//! A [`WindowFade`] lives in your app state and animates the opacity of everything within
+//! [`WindowFade::view`]. Closing the window can be delayed until the content has faded out by
+//! disabling `exit_on_close_request` in your window settings, listening to close requests, and
+//! calling [`WindowFade::close`] when one arrives. The window will be closed by the [`Task`]
+//! returned from [`WindowFade::update`] once the fade out completes.
+//!
+//! ```rust
+//! # use iced::{widget::text, window, Element, Subscription, Task};
+//! # use iced_anim::{SpringEvent, widget::window_fade::WindowFade};
+//! #[derive(Default)]
+//! struct State {
+//!     fade: WindowFade,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Fade(SpringEvent<f32>),
+//!     CloseRequested(window::Id),
+//! }
+//!
+//! impl State {
+//!     fn update(&mut self, message: Message) -> Task<Message> {
+//!         match message {
+//!             Message::Fade(event) => self.fade.update(event),
+//!             Message::CloseRequested(id) => self.fade.close(id),
+//!         }
+//!     }
+//!
+//!     fn subscription(&self) -> Subscription<Message> {
+//!         window::close_requests().map(Message::CloseRequested)
+//!     }
+//!
+//!     fn view(&self) -> Element<Message> {
+//!         self.fade.view(text("Hello!"), Message::Fade)
+//!     }
+//! }
+//! ```
+use iced::{window, Element, Task};
+
+use super::veil::{self, veil};
+use crate::{Animation, Spring, SpringEvent, SpringMotion};
+
+/// Animates the opacity of a window's content when it opens and before it closes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowFade {
+    /// The opacity of the window's content, from 0 to 1.
+    opacity: Spring<f32>,
+    /// The window that will be closed once the content has faded out.
+    closing: Option<window::Id>,
+}
+
+impl WindowFade {
+    /// Creates a [`WindowFade`] that fades the content in from being fully transparent.
+    pub fn new() -> Self {
+        Self {
+            opacity: Spring::new(0.0).with_target(1.0),
+            closing: None,
+        }
+    }
+
+    /// Creates a [`WindowFade`] whose content is already fully visible.
+    pub fn visible() -> Self {
+        Self {
+            opacity: Spring::new(1.0),
+            closing: None,
+        }
+    }
+
+    /// Returns an updated fade with the given `motion`.
+    pub fn with_motion(mut self, motion: SpringMotion) -> Self {
+        self.opacity.set_motion(motion);
+        self
+    }
+
+    /// The current opacity of the window's content, from 0 to 1.
+    pub fn opacity(&self) -> f32 {
+        *self.opacity.value()
+    }
+
+    /// Whether the content is fading out before the window closes.
+    pub fn is_closing(&self) -> bool {
+        self.closing.is_some()
+    }
+
+    /// Starts fading out the content before closing the window with the given `id`.
+    ///
+    /// The returned task closes the window immediately if the content is already transparent.
+    pub fn close<Message>(&mut self, id: window::Id) -> Task<Message> {
+        self.closing = Some(id);
+        self.opacity.interrupt(0.0);
+        self.close_if_faded()
+    }
+
+    /// Updates the fade with the given `event`, returning a task that closes the window
+    /// once the content has faded out.
+    pub fn update<Message>(&mut self, event: SpringEvent<f32>) -> Task<Message> {
+        self.opacity.update(event);
+        self.close_if_faded()
+    }
+
+    /// Closes the window if the content is closing and has finished fading out.
+    fn close_if_faded<Message>(&mut self) -> Task<Message> {
+        match self.closing {
+            Some(id) if !self.opacity.has_energy() => {
+                self.closing = None;
+                window::close(id)
+            }
+            _ => Task::none(),
+        }
+    }
+
+    /// Wraps the `content` so that it fades with the window, emitting messages
+    /// created by `on_update` that should be passed to [`WindowFade::update`].
+    pub fn view<'a, Message, Theme, Renderer>(
+        &'a self,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_update: impl Fn(SpringEvent<f32>) -> Message + 'static,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Message: 'a + Clone,
+        Theme: 'a + veil::Catalog,
+        Renderer: 'a + iced::advanced::Renderer,
+    {
+        Animation::new(&self.opacity, veil(content, 1.0 - self.opacity()))
+            .on_update(on_update)
+            .into()
+    }
+}
+
+impl Default for WindowFade {
+    fn default() -> Self {
+        Self::new()
+    }
+}
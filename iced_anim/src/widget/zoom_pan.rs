@@ -0,0 +1,727 @@
+//! A container that zooms and pans its content with spring-smoothed gestures.
+//!
+//! Scrolling zooms toward the cursor, dragging pans the content, and releasing a drag flings the
+//! content with the velocity of the drag before it glides to a stop. Every change goes through a
+//! spring animating the view's [`Transformation`], so zooming in steps with a mouse wheel still
+//! feels continuous.
+//!
+//! The view can also be changed with tasks, like [`fit`] to fit the content in the container or
+//! [`reset`] to go back to the original view, given the container has an [`Id`]. [`zoom_to`]
+//! frames a specific area of the content, which is handy for focusing on an element of a diagram,
+//! and double-clicking the content zooms in on the area around the cursor the same way.
+//!
+//! ```rust
+//! # use iced::{Element, Task, widget::text};
+//! # use iced_anim::widget::zoom_pan::{self, zoom_pan};
+//! # #[derive(Clone)] enum Message { Fit }
+//! fn view<'a>() -> Element<'a, Message> {
+//!     zoom_pan(text("A large diagram"))
+//!         .id(zoom_pan::Id::new("map"))
+//!         .into()
+//! }
+//!
+//! fn update(message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::Fit => zoom_pan::fit(zoom_pan::Id::new("map")),
+//!     }
+//! }
+//! ```
+//!
+//! The view lives in a [`Viewport`], which can be given to the container to control its view
+//! from the application or to share it with a [`Minimap`](super::Minimap) that follows along.
+//!
+//! Overlays can't be transformed, so overlays of the content aren't shown.
+use std::{
+    any::Any,
+    cell::RefCell,
+    ops::RangeInclusive,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{self, tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor, ScrollDelta},
+    touch, window, Element, Event, Length, Point, Rectangle, Size, Task, Transformation, Vector,
+};
+
+use crate::{SharedSpring, SpringMotion};
+
+pub use widget::Id;
+
+/// How much a single line of scrolling zooms the content.
+const ZOOM_PER_LINE: f32 = 1.2;
+
+/// The number of pixels of scrolling that count as a single line, for touchpads.
+const PIXELS_PER_LINE: f32 = 60.0;
+
+/// How far ahead the velocity of a fling is projected to pick where the content comes to rest.
+const FLING_PROJECTION: Duration = Duration::from_millis(150);
+
+/// How quickly the measured drag velocity follows the latest movement, from 0 to 1.
+const VELOCITY_SMOOTHING: f32 = 0.4;
+
+/// Drags that stopped moving for longer than this before being released don't fling the content.
+const FLING_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// A container that zooms toward the cursor when scrolled and pans when dragged.
+#[allow(missing_debug_implementations)]
+pub struct ZoomPan<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    id: Option<Id>,
+    viewport: Option<Viewport>,
+    width: Length,
+    height: Length,
+    scale_range: RangeInclusive<f32>,
+    motion: SpringMotion,
+}
+
+/// The view of a [`ZoomPan`], which can be shared to follow or control the view from elsewhere.
+///
+/// Cloning a [`Viewport`] gives another handle to the same view, so a [`ZoomPan`] and a
+/// [`Minimap`](super::Minimap) given the same viewport show the same animated view.
+#[derive(Debug, Clone, Default)]
+pub struct Viewport(Rc<RefCell<State>>);
+
+/// The internal state of a [`ZoomPan`].
+#[derive(Debug)]
+struct State {
+    /// The transformation from the content to the container, relative to the container.
+    view: SharedSpring<Transformation>,
+    /// The size of the container from the latest layout.
+    viewport: Size,
+    /// The size of the content from the latest layout.
+    content: Size,
+    /// The smallest and largest scales of the view.
+    scale_range: RangeInclusive<f32>,
+    /// The drag in progress, if any.
+    drag: Option<Drag>,
+    /// The latest click, to recognize double-clicks.
+    last_click: Option<mouse::Click>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            view: SharedSpring::new(Transformation::IDENTITY),
+            viewport: Size::ZERO,
+            content: Size::ZERO,
+            scale_range: 0.1..=10.0,
+            drag: None,
+            last_click: None,
+        }
+    }
+}
+
+/// A drag panning the content of a [`ZoomPan`].
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// The latest position of the cursor.
+    position: Point,
+    /// When the cursor last moved.
+    moved_at: Instant,
+    /// The smoothed velocity of the drag in pixels per second.
+    velocity: Vector,
+}
+
+/// A change to the view of a [`ZoomPan`] requested by a task.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Action {
+    /// Fits the whole content in the container.
+    Fit,
+    /// Returns to the original view of the content.
+    Reset,
+    /// Frames an area of the content.
+    ZoomTo(Rectangle),
+}
+
+impl State {
+    /// The target of the view, which new changes build upon so repeated gestures add up.
+    fn target(&self) -> Transformation {
+        self.view.target()
+    }
+
+    /// Scales the view by the given `factor` around a `center` relative to the container.
+    fn zoom(&mut self, factor: f32, center: Point) {
+        let target = self.target();
+        let scale = target.scale_factor();
+        let new_scale = (scale * factor).clamp(*self.scale_range.start(), *self.scale_range.end());
+
+        // Keep the point of the content under the center in place.
+        let ratio = new_scale / scale;
+        let translation = target.translation();
+        let x = center.x - (center.x - translation.x) * ratio;
+        let y = center.y - (center.y - translation.y) * ratio;
+        self.view
+            .interrupt(Transformation::translate(x, y) * Transformation::scale(new_scale));
+    }
+
+    /// Moves the view directly by the given `delta` while dragging.
+    fn pan(&mut self, delta: Vector) {
+        let view = self.view.value();
+        let translation = view.translation() + delta;
+        self.view.settle_at(
+            Transformation::translate(translation.x, translation.y)
+                * Transformation::scale(view.scale_factor()),
+        );
+    }
+
+    /// Ends the drag at `now`, flinging the content with the velocity of the drag.
+    fn release(&mut self, now: Instant) {
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+        if now.saturating_duration_since(drag.moved_at) > FLING_TIMEOUT {
+            return;
+        }
+
+        let view = self.view.value();
+        let velocity = drag.velocity;
+        let translation = view.translation() + velocity * FLING_PROJECTION.as_secs_f32();
+        self.view.interrupt(
+            Transformation::translate(translation.x, translation.y)
+                * Transformation::scale(view.scale_factor()),
+        );
+        self.view.impulse(vec![velocity.x, velocity.y, 0.0]);
+    }
+
+    /// Zooms in on the area around a `position` relative to the container, showing half as much
+    /// of the content as is currently visible.
+    fn zoom_in_at(&mut self, position: Point) {
+        let target = self.target();
+        let scale = target.scale_factor();
+        let center = (position - target.translation()) * (1.0 / scale);
+        let size = self.viewport * (0.5 / scale);
+        self.frame(Rectangle::new(
+            Point::new(center.x - size.width / 2.0, center.y - size.height / 2.0),
+            size,
+        ));
+    }
+
+    /// Animates the view to center the given `point` of the content without changing the scale.
+    fn center_on(&mut self, point: Point) {
+        let scale = self.target().scale_factor();
+        let x = self.viewport.width / 2.0 - point.x * scale;
+        let y = self.viewport.height / 2.0 - point.y * scale;
+        self.view
+            .interrupt(Transformation::translate(x, y) * Transformation::scale(scale));
+    }
+
+    /// Animates the view to show the given `area` of the content, centered in the container.
+    fn frame(&mut self, area: Rectangle) {
+        if area.width <= 0.0 || area.height <= 0.0 {
+            return;
+        }
+
+        let scale = (self.viewport.width / area.width)
+            .min(self.viewport.height / area.height)
+            .clamp(*self.scale_range.start(), *self.scale_range.end());
+        let center = area.center();
+        let x = self.viewport.width / 2.0 - center.x * scale;
+        let y = self.viewport.height / 2.0 - center.y * scale;
+        self.view
+            .interrupt(Transformation::translate(x, y) * Transformation::scale(scale));
+    }
+
+    /// Applies an [`Action`] requested by a task.
+    fn apply(&mut self, action: Action) {
+        self.drag = None;
+        match action {
+            Action::Fit => self.frame(Rectangle::with_size(self.content)),
+            Action::Reset => self.view.interrupt(Transformation::IDENTITY),
+            Action::ZoomTo(area) => self.frame(area),
+        }
+    }
+}
+
+impl Viewport {
+    /// Creates a new [`Viewport`] showing the content as it was laid out.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The current animated transformation from the content to the container, relative to the
+    /// top-left corner of the container.
+    pub fn transformation(&self) -> Transformation {
+        self.0.borrow().view.value()
+    }
+
+    /// The part of the content currently visible in the container, relative to the top-left
+    /// corner of the content.
+    pub fn visible_area(&self) -> Rectangle {
+        let state = self.0.borrow();
+        let view = state.view.value();
+        content_viewport(Rectangle::with_size(state.viewport), view)
+    }
+
+    /// The size of the content from the latest layout of the container.
+    pub fn content_size(&self) -> Size {
+        self.0.borrow().content
+    }
+
+    /// Animates the view to fit the whole content in the container.
+    pub fn fit(&self) {
+        self.0.borrow_mut().apply(Action::Fit);
+    }
+
+    /// Animates the view back to showing the content as it was laid out.
+    pub fn reset(&self) {
+        self.0.borrow_mut().apply(Action::Reset);
+    }
+
+    /// Animates the view to frame an `area` of the content, relative to its top-left corner.
+    pub fn zoom_to(&self, area: Rectangle) {
+        self.0.borrow_mut().apply(Action::ZoomTo(area));
+    }
+
+    /// Animates the view to center the given `point` of the content without changing the scale.
+    pub fn center_on(&self, point: Point) {
+        let mut state = self.0.borrow_mut();
+        state.drag = None;
+        state.center_on(point);
+    }
+
+    /// The spring animating the view, for sampling the view in other widgets.
+    pub fn spring(&self) -> SharedSpring<Transformation> {
+        self.0.borrow().view.clone()
+    }
+
+    /// Whether the view is animating, meaning it needs to be ticked.
+    pub fn has_energy(&self) -> bool {
+        self.0.borrow().view.has_energy()
+    }
+
+    /// Updates the animated view based on the elapsed time since the last update.
+    ///
+    /// Like any [`SharedSpring`], ticking more than once with the same `now` has no further
+    /// effect, so every widget showing the view can tick it.
+    pub fn tick(&self, now: Instant) {
+        self.0.borrow().view.tick(now);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> ZoomPan<'a, Message, Theme, Renderer> {
+    /// Creates a new [`ZoomPan`] containing the given `content`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            id: None,
+            viewport: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            scale_range: 0.1..=10.0,
+            motion: SpringMotion::Smooth,
+        }
+    }
+
+    /// Sets the [`Id`] of the [`ZoomPan`], which tasks like [`fit`] use to find it.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the [`Viewport`] holding the view of the [`ZoomPan`], so the view can be controlled
+    /// by the application or shared with a [`Minimap`](super::Minimap).
+    pub fn viewport(mut self, viewport: &Viewport) -> Self {
+        self.viewport = Some(viewport.clone());
+        self
+    }
+
+    /// Sets the width of the [`ZoomPan`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`ZoomPan`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the smallest and largest scales the content can be zoomed to.
+    pub fn scale_range(mut self, scale_range: RangeInclusive<f32>) -> Self {
+        self.scale_range = scale_range;
+        self
+    }
+
+    /// Sets the motion that will be used by animations.
+    pub fn motion(mut self, motion: SpringMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+}
+
+/// The transformation that draws content laid out in `bounds` through the `view`.
+fn transformation(bounds: Rectangle, view: Transformation) -> Transformation {
+    Transformation::translate(bounds.x, bounds.y)
+        * view
+        * Transformation::translate(-bounds.x, -bounds.y)
+}
+
+/// The cursor as seen by content laid out in `bounds` and drawn through the `view`.
+fn content_cursor(bounds: Rectangle, view: Transformation, cursor: Cursor) -> Cursor {
+    match cursor.position_over(bounds) {
+        Some(position) => {
+            let local = position - Vector::new(bounds.x, bounds.y) - view.translation();
+            let scale = view.scale_factor();
+            Cursor::Available(Point::new(
+                bounds.x + local.x / scale,
+                bounds.y + local.y / scale,
+            ))
+        }
+        None => Cursor::Unavailable,
+    }
+}
+
+/// The part of the content laid out in `bounds` that is visible through the `view`.
+fn content_viewport(bounds: Rectangle, view: Transformation) -> Rectangle {
+    let scale = view.scale_factor();
+    let translation = view.translation();
+    Rectangle {
+        x: bounds.x - translation.x / scale,
+        y: bounds.y - translation.y / scale,
+        width: bounds.width / scale,
+        height: bounds.height / scale,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ZoomPan<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Viewport>()
+    }
+
+    fn state(&self) -> tree::State {
+        let viewport = self.viewport.clone().unwrap_or_default();
+        {
+            let mut state = viewport.0.borrow_mut();
+            state.view.set_motion(self.motion);
+            state.scale_range = self.scale_range.clone();
+        }
+
+        tree::State::new(viewport)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if let Some(viewport) = &self.viewport {
+            if !Rc::ptr_eq(&tree.state.downcast_ref::<Viewport>().0, &viewport.0) {
+                tree.state = tree::State::new(viewport.clone());
+            }
+        }
+
+        let mut state = tree.state.downcast_ref::<Viewport>().0.borrow_mut();
+        state.view.set_motion(self.motion);
+        state.scale_range = self.scale_range.clone();
+        drop(state);
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, &limits);
+        let size = limits.resolve(self.width, self.height, content.size());
+
+        let mut state = tree.state.downcast_ref::<Viewport>().0.borrow_mut();
+        state.viewport = size;
+        state.content = content.size();
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.custom(tree.state.downcast_mut::<Viewport>(), self.id.as_ref());
+        operation.container(self.id.as_ref(), layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let handle = tree.state.downcast_ref::<Viewport>().clone();
+        let view = handle.transformation();
+        let is_dragging = handle.0.borrow().drag.is_some();
+
+        // The content gets the first chance to handle events, like a scrollable.
+        let status = if !is_dragging {
+            self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                layout.children().next().unwrap(),
+                content_cursor(bounds, view, cursor),
+                renderer,
+                clipboard,
+                shell,
+                &content_viewport(bounds, view)
+                    .intersection(viewport)
+                    .unwrap_or_default(),
+            )
+        } else {
+            event::Status::Ignored
+        };
+
+        let mut state = handle.0.borrow_mut();
+        let status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.view.tick(now);
+                event::Status::Ignored
+            }
+            _ if status == event::Status::Captured => status,
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return status;
+                };
+                let lines = match delta {
+                    ScrollDelta::Lines { y, .. } => y,
+                    ScrollDelta::Pixels { y, .. } => y / PIXELS_PER_LINE,
+                };
+                state.zoom(ZOOM_PER_LINE.powf(lines), position);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return status;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+                if click.kind() == mouse::click::Kind::Double {
+                    state.zoom_in_at(position - Vector::new(bounds.x, bounds.y));
+                    return event::Status::Captured;
+                }
+
+                state.drag = Some(Drag {
+                    position,
+                    moved_at: Instant::now(),
+                    velocity: Vector::new(0.0, 0.0),
+                });
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(mut drag) = state.drag else {
+                    return status;
+                };
+
+                let now = Instant::now();
+                let delta = position - drag.position;
+                let elapsed = now.saturating_duration_since(drag.moved_at).as_secs_f32();
+                if elapsed > 0.0 {
+                    let velocity = delta * (1.0 / elapsed);
+                    drag.velocity = drag.velocity + (velocity - drag.velocity) * VELOCITY_SMOOTHING;
+                }
+                drag.position = position;
+                drag.moved_at = now;
+                state.drag = Some(drag);
+
+                state.pan(delta);
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.drag.is_none() {
+                    return status;
+                }
+                state.release(Instant::now());
+                event::Status::Captured
+            }
+            _ => status,
+        };
+
+        if state.view.has_energy() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let view = tree.state.downcast_ref::<Viewport>().transformation();
+        let Some(visible) = content_viewport(bounds, view).intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_transformation(transformation(bounds, view), |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    layout.children().next().unwrap(),
+                    content_cursor(bounds, view, cursor),
+                    &visible,
+                );
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let viewport = tree.state.downcast_ref::<Viewport>();
+        if viewport.0.borrow().drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let bounds = layout.bounds();
+        let view = viewport.transformation();
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            content_cursor(bounds, view, cursor),
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default() && cursor.is_over(bounds) {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ZoomPan<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(zoom_pan: ZoomPan<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(zoom_pan)
+    }
+}
+
+/// Creates a new [`ZoomPan`] containing the given `content`.
+pub fn zoom_pan<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> ZoomPan<'a, Message, Theme, Renderer> {
+    ZoomPan::new(content)
+}
+
+/// Animates the [`ZoomPan`] with the given [`Id`] to fit its whole content.
+pub fn fit<T>(id: Id) -> Task<T>
+where
+    T: Send + 'static,
+{
+    widget::operate(Command {
+        id,
+        action: Action::Fit,
+    })
+}
+
+/// Animates the [`ZoomPan`] with the given [`Id`] back to its original view.
+pub fn reset<T>(id: Id) -> Task<T>
+where
+    T: Send + 'static,
+{
+    widget::operate(Command {
+        id,
+        action: Action::Reset,
+    })
+}
+
+/// Animates the [`ZoomPan`] with the given [`Id`] to frame an `area` of its content, centered
+/// and as large as fits in the container.
+///
+/// The `area` is relative to the top-left corner of the content as it was laid out.
+pub fn zoom_to<T>(id: Id, area: Rectangle) -> Task<T>
+where
+    T: Send + 'static,
+{
+    widget::operate(Command {
+        id,
+        action: Action::ZoomTo(area),
+    })
+}
+
+/// An operation applying an [`Action`] to the [`ZoomPan`] with the given [`Id`].
+struct Command {
+    id: Id,
+    action: Action,
+}
+
+impl<T> Operation<T> for Command {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if id != Some(&self.id) {
+            return;
+        }
+
+        if let Some(viewport) = state.downcast_mut::<Viewport>() {
+            viewport.0.borrow_mut().apply(self.action);
+        }
+    }
+}
@@ -12,11 +12,40 @@ use proc_macro::TokenStream;
 use syn::{parse_macro_input, Data, DeriveInput, Fields};
 
 /// Derive macro generating an impl of the trait `Animate`.
-#[proc_macro_derive(Animate)]
+///
+/// Adding `#[animate(component_names)]` to the struct also generates an associated
+/// `fn component_names() -> &'static [&'static str]` that labels each animated component
+/// with the field it belongs to. Fields with a single component are labelled with the field
+/// name, while fields with several components are labelled like `field[0]`, `field[1]`, etc.
+///
+/// Fields can be grouped with `#[animate(group = "name")]` so that each group settles
+/// independently and retargeting one group doesn't restart the others. Fields without a group
+/// share a default group.
+#[proc_macro_derive(Animate, attributes(animate))]
 pub fn animate_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
     let input = parse_macro_input!(input as DeriveInput);
 
+    let mut with_component_names = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("animate"))
+    {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("component_names") {
+                with_component_names = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported animate attribute"))
+            }
+        });
+
+        if let Err(error) = result {
+            return error.to_compile_error().into();
+        }
+    }
+
     // Extract the identifier and data from the input
     let name = input.ident;
     let data = input.data;
@@ -51,6 +80,88 @@ pub fn animate_derive(input: TokenStream) -> TokenStream {
         }
     });
 
+    // Fields without a group share the default group, while each named group gets its own.
+    let mut group_names: Vec<String> = Vec::new();
+    let mut field_groups = Vec::with_capacity(fields.named.len());
+    for field in &fields.named {
+        let mut group = 0_usize;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("animate"))
+        {
+            let result = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("group") {
+                    let name: syn::LitStr = meta.value()?.parse()?;
+                    let name = name.value();
+                    group = match group_names.iter().position(|existing| *existing == name) {
+                        Some(index) => index + 1,
+                        None => {
+                            group_names.push(name);
+                            group_names.len()
+                        }
+                    };
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported animate attribute"))
+                }
+            });
+
+            if let Err(error) = result {
+                return error.to_compile_error().into();
+            }
+        }
+        field_groups.push(group);
+    }
+
+    let component_groups = (!group_names.is_empty()).then(|| {
+        let group_fields = fields.named.iter().zip(&field_groups).map(|(f, group)| {
+            let ty = &f.ty;
+            quote! {
+                groups.extend(::std::iter::repeat(#group).take(<#ty as ::iced_anim::Animate>::components()));
+            }
+        });
+
+        quote! {
+            fn component_groups() -> ::std::vec::Vec<usize> {
+                let mut groups = ::std::vec::Vec::with_capacity(Self::components());
+                #(#group_fields)*
+                groups
+            }
+        }
+    });
+
+    let component_names = with_component_names.then(|| {
+        let name_fields = fields.named.iter().map(|f| {
+            let ty = &f.ty;
+            let label = f.ident.as_ref().map(ToString::to_string).unwrap_or_default();
+            quote! {
+                match <#ty as ::iced_anim::Animate>::components() {
+                    1 => names.push(#label),
+                    count => names.extend((0..count).map(|index| {
+                        &*::std::boxed::Box::leak(::std::format!("{}[{}]", #label, index).into_boxed_str())
+                    })),
+                }
+            }
+        });
+
+        quote! {
+            impl #name {
+                /// The labels of each animated component, in the same order as the components
+                /// are updated and measured by `Animate`.
+                pub fn component_names() -> &'static [&'static str] {
+                    static NAMES: ::std::sync::OnceLock<::std::vec::Vec<&'static str>> =
+                        ::std::sync::OnceLock::new();
+                    NAMES.get_or_init(|| {
+                        let mut names = ::std::vec::Vec::new();
+                        #(#name_fields)*
+                        names
+                    })
+                }
+            }
+        }
+    });
+
     let impl_gen = quote! {
         impl ::iced_anim::Animate for #name {
             fn components() -> usize {
@@ -68,7 +179,11 @@ pub fn animate_derive(input: TokenStream) -> TokenStream {
                 #(#distance_fields)*
                 distances.concat()
             }
+
+            #component_groups
         }
+
+        #component_names
     };
 
     TokenStream::from(impl_gen)